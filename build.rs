@@ -0,0 +1,77 @@
+use std::path::Path;
+
+/// Pin the boilerplate_moduls ref the remote templates are fetched from. Release builds
+/// use the tag matching the CLI version; set T3_MONO_TEMPLATES_REF to build against
+/// another tag, branch, or commit.
+fn main() {
+    println!("cargo:rerun-if-env-changed=T3_MONO_TEMPLATES_REF");
+
+    let reference = std::env::var("T3_MONO_TEMPLATES_REF")
+        .ok()
+        .filter(|value| !value.is_empty())
+        .unwrap_or_else(|| {
+            format!(
+                "v{}",
+                std::env::var("CARGO_PKG_VERSION").unwrap_or_default()
+            )
+        });
+    println!("cargo:rustc-env=T3_MONO_TEMPLATES_REF={}", reference);
+
+    record_executables();
+}
+
+/// rust-embed keeps only file contents, so list the templates with an executable bit for
+/// `embedded::is_executable`
+fn record_executables() {
+    println!("cargo:rerun-if-changed=templates");
+
+    let mut executables = Vec::new();
+    list_executables(Path::new("templates"), "", &mut executables);
+    executables.sort();
+
+    let out = Path::new(&std::env::var("OUT_DIR").expect("OUT_DIR is set by cargo"))
+        .join("executable_templates.rs");
+    let entries: String = executables
+        .iter()
+        .map(|path| format!("    {:?},\n", path))
+        .collect();
+    std::fs::write(
+        out,
+        format!(
+            "pub const EXECUTABLE_TEMPLATES: &[&str] = &[\n{}];\n",
+            entries
+        ),
+    )
+    .expect("Failed to write executable_templates.rs");
+}
+
+fn list_executables(root: &Path, relative: &str, executables: &mut Vec<String>) {
+    let Ok(entries) = std::fs::read_dir(root.join(relative)) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().to_string();
+        let path = if relative.is_empty() {
+            name
+        } else {
+            format!("{}/{}", relative, name)
+        };
+        match entry.file_type() {
+            Ok(kind) if kind.is_dir() => list_executables(root, &path, executables),
+            Ok(_) if is_executable(&entry.path()) => executables.push(path),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    std::fs::metadata(path).is_ok_and(|metadata| metadata.permissions().mode() & 0o111 != 0)
+}
+
+/// Checkouts without file modes: scripts start with a shebang
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    std::fs::read(path).is_ok_and(|content| content.starts_with(b"#!"))
+}
@@ -1,6 +1,14 @@
 use clap::{Parser, Subcommand, ValueEnum};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+use crate::scaffolding::images;
+
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "kebab-case")]
 pub enum AuthProvider {
     #[default]
     #[value(name = "better-auth")]
@@ -9,12 +17,266 @@ pub enum AuthProvider {
     NextAuth,
 }
 
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum TsStrictness {
+    /// `strict` only
+    #[default]
+    Standard,
+    /// Adds noUncheckedIndexedAccess, noImplicitOverride, and verbatimModuleSyntax
+    Strict,
+    /// Adds exactOptionalPropertyTypes, noImplicitReturns, and noFallthroughCasesInSwitch on top
+    Strictest,
+}
+
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum BiomeProfile {
+    /// Correctness, suspicious, and security rules only
+    Minimal,
+    /// Biome's recommended rules and Tailwind class sorting
+    #[default]
+    Recommended,
+    /// Adds unused-code, explicit-any, console, import-type, and block-statement errors
+    Strict,
+}
+
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum DarkMode {
+    /// `.dark` class set by next-themes, with a toggle in the Header
+    #[default]
+    Class,
+    /// Follow the OS setting through prefers-color-scheme
+    Media,
+    /// Light theme only
+    None,
+}
+
+/// Language of the CLI's own messages
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Lang {
+    #[default]
+    En,
+    De,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum TailwindPlugin {
+    /// `prose` classes for rendered rich text and markdown
+    Typography,
+    /// Form element resets, opt-in per element with `form-input` and friends
+    Forms,
+    /// Enter/exit animation utilities (tw-animate-css)
+    Animate,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum CmsProvider {
+    /// Payload, read over its REST API
+    Payload,
+    /// Sanity, queried with GROQ through next-sanity
+    Sanity,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum SearchBackend {
+    /// Full-text search in the app's PostgreSQL database, nothing to run or sync
+    Postgres,
+    /// Meilisearch, kept in sync from Prisma writes
+    Meilisearch,
+    /// Typesense, kept in sync from Prisma writes
+    Typesense,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum TaskRunner {
+    /// Generate a justfile
+    Just,
+    /// Generate a Makefile
+    Make,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum Pooling {
+    /// Pooled DATABASE_URL through PgBouncer, one connection per instance
+    Pgbouncer,
+    /// Prisma Accelerate connection pool and cache
+    Accelerate,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum Iac {
+    /// Terraform module for AWS: the database instance, the cmd upload bucket, and Secrets Manager
+    Terraform,
+}
+
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum SessionStrategy {
+    /// Signed JWT cookie; works with the credentials provider
+    #[default]
+    Jwt,
+    /// Session rows looked up on every request, revocable server-side; OAuth providers only
+    Database,
+}
+
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum DatabaseProvider {
+    /// PostgreSQL through @prisma/adapter-pg
+    #[default]
+    Postgres,
+    /// MySQL through @prisma/adapter-mariadb
+    Mysql,
+    /// A SQLite file through @prisma/adapter-better-sqlite3
+    Sqlite,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum ChatStream {
+    /// Server-sent events from a route handler; works on serverless hosts
+    Sse,
+    /// WebSocket endpoint on a custom Node server; needs a long-running process
+    Websocket,
+}
+
+/// LLM provider behind the CommandIsland chat and its planners
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum LlmProvider {
+    #[default]
+    Anthropic,
+    Openai,
+    Mistral,
+    Google,
+    /// Local models from an Ollama server
+    Ollama,
+}
+
+/// Where CommandIsland searches attachment chunks
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum VectorStore {
+    /// Embeddings in a pgvector column, searched by similarity and keywords
+    #[default]
+    Pgvector,
+    /// Keyword search only; no embedding model needed
+    Keyword,
+}
+
+/// Where CommandIsland keeps attachments and generated documents
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum StorageBackend {
+    /// AWS S3
+    #[default]
+    S3,
+    /// An S3-compatible store such as MinIO or Cloudflare R2 at `AWS_S3_ENDPOINT`
+    S3Compatible,
+}
+
+/// CommandIsland modes next to the chat
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum CmdModule {
+    /// AI tables filled column by column
+    Tables,
+    /// AI-generated documents
+    Docs,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ComponentVariant {
+    /// Card with title, description, content, and footer slots
+    Card,
+    /// Dialog opened by a trigger element
+    Dialog,
+    /// Form layout with a submit button and form-level error
+    Form,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum AgentTool {
+    /// Read rows from an allowlist of Prisma models
+    Db,
+    /// Semantic search over an in-memory embedding index
+    Search,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum WorkflowPattern {
+    /// Steps that undo the completed ones in reverse order when a later step fails
+    Saga,
+    /// Journaled steps run one after another, each feeding the next
+    Sequential,
+    /// Waits on a durable promise that approve/reject handlers resolve
+    HumanApproval,
+}
+
+/// Flags that describe the project, which `--answers` and `--from-manifest` supply instead
+const PROJECT_FLAGS: [&str; 30] = [
+    "ai",
+    "ui",
+    "restate",
+    "restate_kafka",
+    "cmd",
+    "cmd_stream",
+    "analytics",
+    "example",
+    "rest",
+    "seed",
+    "seo",
+    "standalone",
+    "soft_delete",
+    "bundle_analyzer",
+    "security_headers",
+    "pooling",
+    "migrations",
+    "nix",
+    "iac",
+    "task_runner",
+    "ts_strictness",
+    "coverage_threshold",
+    "tailwind_plugins",
+    "dark_mode",
+    "biome_profile",
+    "image_hosts",
+    "image_cdn",
+    "interactive",
+    "auth",
+    "session",
+];
+
 /// CLI tool to scaffold T3 stack apps with authentication and optional extensions
 #[derive(Parser, Debug)]
 #[command(name = "t3-mono")]
 #[command(author = "Elijah Ross")]
 #[command(version)]
-#[command(about = "Scaffold T3 stack apps with authentication (Better Auth or NextAuth), optional AI agents, UI components, and Restate workflows")]
+#[command(
+    about = "Scaffold T3 stack apps with authentication (Better Auth or NextAuth), optional AI agents, UI components, and Restate workflows"
+)]
 #[command(long_about = r#"
 Create a new T3 stack monorepo with authentication pre-configured.
 
@@ -34,26 +296,183 @@ Examples:
   # With Restate durable workflows
   npx t3-mono my-app --restate
 
+  # With a Kafka broker and a Kafka-triggered Restate workflow
+  npx t3-mono my-app --restate --restate-kafka
+
   # With CommandIsland AI layer
   npx t3-mono my-app --cmd
 
+  # CommandIsland with chat replies streamed token by token
+  npx t3-mono my-app --cmd --cmd-stream sse
+
   # With all extensions
   npx t3-mono my-app --ai --ui --restate --cmd
 
+  # With UI components and a charts dashboard at /dashboard/analytics
+  npx t3-mono my-app --ui --analytics
+
+  # With an example tRPC router and page
+  npx t3-mono my-app --example
+
+  # With a database seed script (demo user, sample posts with --example)
+  npx t3-mono my-app --example --seed
+
+  # With a REST endpoint at /api/v1/posts next to the tRPC router
+  npx t3-mono my-app --example --rest
+
+  # With OpenGraph images rendered from the site name and description
+  npx t3-mono my-app --seo
+
+  # With standalone output, an app Dockerfile, and an `app` compose service
+  npx t3-mono my-app --standalone
+
+  # With soft deletes and createdBy/updatedBy audit columns
+  npx t3-mono my-app --example --soft-delete
+
+  # With a pooled DATABASE_URL and a DIRECT_URL for migrations
+  npx t3-mono my-app --pooling pgbouncer
+
+  # With an initial Prisma migration and migrate-based db scripts
+  npx t3-mono my-app --migrations
+
+  # See what the AI and chart dependencies cost with `npm run analyze`
+  npx t3-mono my-app --cmd --bundle-analyzer
+
+  # With a nonce-based Content-Security-Policy, HSTS, and frame-options headers
+  npx t3-mono my-app --security-headers
+
+  # With a Nix flake dev shell and direnv
+  npx t3-mono my-app --nix
+
+  # With a Terraform module for the AWS database, upload bucket, and secrets
+  npx t3-mono my-app --cmd --iac terraform
+
+  # With a justfile (or Makefile) wrapping dev, db, restate, and test workflows
+  npx t3-mono my-app --task-runner just
+
+  # With stricter TypeScript settings (standard, strict, or strictest)
+  npx t3-mono my-app --ts-strictness strict
+
+  # Enforce a minimum test coverage in `npm run test:coverage` and CI
+  npx t3-mono my-app --coverage-threshold 80
+
+  # With the Tailwind typography, forms, and animation plugins
+  npx t3-mono my-app --tailwind-plugins typography,forms,animate
+
+  # Follow the OS color scheme instead of a toggle (class, media, or none)
+  npx t3-mono my-app --dark-mode media
+
+  # Lint with a stricter Biome rule set (minimal, recommended, or strict)
+  npx t3-mono my-app --biome-profile strict
+
+  # Serve next/image from these hosts and a CDN in front of uploads
+  npx t3-mono my-app --image-hosts images.unsplash.com,*.githubusercontent.com --image-cdn https://cdn.example.com
+
+  # NextAuth with sessions stored in the database instead of a JWT cookie
+  npx t3-mono my-app --auth next-auth --session database
+
+  # Use the newest compatible dependency versions from the npm registry
+  npx t3-mono my-app --latest
+
+  # Package name my-app, created in apps/web
+  npx t3-mono my-app --dir apps/web
+
+  # Every answer from a file, for reproducible scaffolds in CI
+  npx t3-mono --answers answers.yaml
+
+  # The configuration of an existing project, in a new directory
+  npx t3-mono other-app --from-manifest ../my-app/.t3mono.json
+
   # Interactive mode (prompts for auth and extensions)
   npx t3-mono my-app -i
 
+  # List the files and which steps write them, without creating anything
+  npx t3-mono my-app --ai --ui --dry-run
+
+  # Install, generate the Prisma client, and push the schema in one go (start the database first)
+  npx t3-mono my-app --setup-db
+
   # Add to existing project
   npx t3-mono add ai
   npx t3-mono add ui
+  npx t3-mono add forms
+  npx t3-mono add cms --provider sanity
+  npx t3-mono add compliance
+  npx t3-mono add logging
+  npx t3-mono add perf
+  npx t3-mono add maintenance
+  npx t3-mono add privacy
   npx t3-mono add restate
   npx t3-mono add cmd
+  npx t3-mono add webhooks
+  npx t3-mono add search --backend meilisearch
+
+  # Add without prompting about files that already exist
+  npx t3-mono add ui --skip-existing
+
+  # Generate a full-stack resource in an existing project
+  npx t3-mono generate resource Product name:string price:float
+
+  # Generate a UI kit component
+  npx t3-mono generate component confirm-dialog --variant dialog
+
+  # Generate an AI agent with database and search tools
+  npx t3-mono generate agent support --tools db,search
+
+  # Add a French translation
+  npx t3-mono generate locale fr
+
+  # Generate a signed-in, translated page with a nav entry
+  npx t3-mono generate page reports --protected --translated
+
+  # Generate a Restate workflow that waits for a human decision
+  npx t3-mono generate workflow expense-approval --pattern human-approval
+
+  # Show which generated files you changed and which have template updates
+  npx t3-mono status
+
+  # Apply template updates to files you haven't modified
+  npx t3-mono sync
+
+  # Rebrand the project in the current directory
+  npx t3-mono rename acme-shop --title "Acme Shop"
+
+  # Move an existing project from Better Auth to NextAuth, previewing the changes first
+  npx t3-mono switch-auth next-auth --dry-run
+
+  # Move a SQLite prototype to PostgreSQL
+  npx t3-mono switch-db postgres
+
+  # Find missing or malformed variables in the env files before the app fails on them
+  npx t3-mono envcheck
+
+  # Check t3mono.toml (or an answers file) for mistakes, with line numbers
+  npx t3-mono config validate
+
+  # Fetch templates through an internal mirror
+  npx t3-mono my-app --ui --template-mirror https://artifactory.example.com/github-raw/elijahross/boilerplate_moduls
+
+  # Register local Restate services with the Restate admin API
+  npx t3-mono restate register
+
+  # Subscribe a Restate handler to a Kafka topic
+  npx t3-mono restate subscribe orders OrderEvents/process
+
+  # Show the exact npm dependencies the ui extension adds
+  npx t3-mono info ui
+
+  # Opt out of anonymous usage data
+  npx t3-mono telemetry disable
 "#)]
 pub struct Args {
-    /// Name of the project to create
+    /// Name of the project to create, also its directory unless --dir is given
     #[arg(default_value = ".")]
     pub name: String,
 
+    /// Directory to create the project in, so the package name and location can differ
+    #[arg(long, value_name = "PATH")]
+    pub dir: Option<String>,
+
     /// Include LangChain AI agents framework
     #[arg(long, short = 'a')]
     pub ai: bool,
@@ -70,6 +489,111 @@ pub struct Args {
     #[arg(long, short = 'c')]
     pub cmd: bool,
 
+    /// Stream CommandIsland chat replies over sse or websocket instead of one tRPC response (requires --cmd)
+    #[arg(long, value_enum, requires = "cmd")]
+    pub cmd_stream: Option<ChatStream>,
+
+    /// Generate a `/dashboard/analytics` page with charts fed by a tRPC procedure (requires --ui)
+    #[arg(long, requires = "ui")]
+    pub analytics: bool,
+
+    /// Add a Kafka broker and a Kafka-triggered OrderEvents workflow to the Restate stack (requires --restate)
+    #[arg(long, requires = "restate")]
+    pub restate_kafka: bool,
+
+    /// Generate an example `post` router, Prisma model, and page
+    #[arg(long)]
+    pub example: bool,
+
+    /// Generate a REST `/api/v1/posts` endpoint with API-key auth, a shared service layer, and `/api-docs` (requires --example)
+    #[arg(long, requires = "example")]
+    pub rest: bool,
+
+    /// Generate a `prisma/seed.ts` with a demo user and sample data (uses faker)
+    #[arg(long)]
+    pub seed: bool,
+
+    /// Render the OpenGraph image at `/opengraph-image` from the site config in `src/lib/site.ts`
+    #[arg(long)]
+    pub seo: bool,
+
+    /// Build a standalone Next.js server and generate an app Dockerfile and compose service
+    #[arg(long)]
+    pub standalone: bool,
+
+    /// Wire @next/bundle-analyzer into next.config.js behind `ANALYZE=true`, with an `analyze` script
+    #[arg(long)]
+    pub bundle_analyzer: bool,
+
+    /// Send a nonce-based Content-Security-Policy from `src/proxy.ts` and HSTS, frame-options, and related headers from next.config.js
+    #[arg(long)]
+    pub security_headers: bool,
+
+    /// Soft deletes (`deletedAt`) and `createdBy`/`updatedBy` audit columns via a Prisma client extension
+    #[arg(long)]
+    pub soft_delete: bool,
+
+    /// Pooled runtime DATABASE_URL with a DIRECT_URL for migrations (pgbouncer or accelerate)
+    #[arg(long, value_enum)]
+    pub pooling: Option<Pooling>,
+
+    /// Generate an initial Prisma migration and use `prisma migrate` instead of `db push`
+    #[arg(long)]
+    pub migrations: bool,
+
+    /// Generate a Nix flake and direnv `.envrc` for a reproducible dev environment
+    #[arg(long)]
+    pub nix: bool,
+
+    /// Generate infrastructure as code for the database, upload bucket, and secrets the app expects
+    #[arg(long, value_enum)]
+    pub iac: Option<Iac>,
+
+    /// Generate a task runner file wrapping the common workflows (just or make)
+    #[arg(long, value_enum)]
+    pub task_runner: Option<TaskRunner>,
+
+    /// Strictness tier for the generated tsconfig.json
+    #[arg(long, value_enum, default_value_t = TsStrictness::Standard)]
+    pub ts_strictness: TsStrictness,
+
+    /// Fail `npm run test:coverage` (and CI) when coverage drops below this percentage
+    #[arg(long, value_name = "PERCENT", value_parser = clap::value_parser!(u8).range(0..=100))]
+    pub coverage_threshold: Option<u8>,
+
+    /// Tailwind plugins to install and load from globals.css, comma-separated
+    #[arg(long, value_enum, value_delimiter = ',', value_name = "PLUGINS")]
+    pub tailwind_plugins: Vec<TailwindPlugin>,
+
+    /// How dark mode is switched: a class with a Header toggle, the OS setting, or not at all
+    #[arg(long, value_enum, default_value_t = DarkMode::Class)]
+    pub dark_mode: DarkMode,
+
+    /// Lint rule set for the generated biome.jsonc
+    #[arg(long, value_enum, default_value_t = BiomeProfile::Recommended)]
+    pub biome_profile: BiomeProfile,
+
+    /// Hosts next/image may load remote images from, comma-separated; `*.` matches a subdomain
+    #[arg(long, value_delimiter = ',', value_name = "HOSTS", value_parser = images::parse_host)]
+    pub image_hosts: Vec<String>,
+
+    /// Base URL of the CDN serving uploads, allowed for next/image and used by `cdnUrl`
+    #[arg(long, value_name = "URL", value_parser = images::parse_cdn_url)]
+    pub image_cdn: Option<String>,
+
+    /// Resolve the newest versions the dependency ranges allow from the npm registry
+    /// instead of the versions built into t3-mono
+    #[arg(long)]
+    pub latest: bool,
+
+    /// Read every answer (name, auth, extensions, locales, and the flags above) from a YAML file
+    #[arg(long, value_name = "FILE", conflicts_with_all = PROJECT_FLAGS, conflicts_with_all = ["latest", "no_git"])]
+    pub answers: Option<PathBuf>,
+
+    /// Create a project with the configuration recorded in another project's .t3mono.json (path or URL)
+    #[arg(long, value_name = "MANIFEST", conflicts_with_all = PROJECT_FLAGS, conflicts_with = "answers")]
+    pub from_manifest: Option<String>,
+
     /// Run in interactive mode with prompts
     #[arg(long, short = 'i')]
     pub interactive: bool,
@@ -78,10 +602,49 @@ pub struct Args {
     #[arg(long)]
     pub no_git: bool,
 
+    /// List the files the project would have, and which steps write them, without writing anything
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// After creating the project, install dependencies, run prisma generate, and push the
+    /// schema (or apply the migrations with --migrations) to DATABASE_URL
+    #[arg(long, conflicts_with = "dry_run")]
+    pub setup_db: bool,
+
     /// Authentication provider (better-auth or next-auth)
     #[arg(long, value_enum, default_value_t = AuthProvider::BetterAuth)]
     pub auth: AuthProvider,
 
+    /// NextAuth session strategy: a signed JWT cookie or Session rows in the database (jwt, database)
+    #[arg(long, value_enum, default_value_t = SessionStrategy::Jwt)]
+    pub session: SessionStrategy,
+
+    /// Fetch remote templates from this mirror instead of raw.githubusercontent.com
+    /// (also T3_MONO_TEMPLATE_MIRROR or `[templates] mirror` in t3mono.toml)
+    #[arg(long, global = true, value_name = "URL")]
+    pub template_mirror: Option<String>,
+
+    /// Skip TLS certificate verification when fetching templates (prefer `[network] ca_certs`)
+    #[arg(long, global = true)]
+    pub insecure: bool,
+
+    /// Fetch remote templates again instead of using the cache
+    #[arg(long, global = true)]
+    pub refresh: bool,
+
+    /// Plain text output without colors, spinners, or symbols, for screen readers and logs
+    #[arg(long, global = true)]
+    pub plain: bool,
+
+    /// Print which layer (project, user, remote, embedded) each template file came from
+    #[arg(long, short = 'v', global = true)]
+    pub verbose: bool,
+
+    /// Language of create's summary, prompts, and progress (en or de); defaults to the system
+    /// locale. Other commands print English
+    #[arg(long, value_enum)]
+    pub lang: Option<Lang>,
+
     #[command(subcommand)]
     pub command: Option<Command>,
 }
@@ -90,8 +653,258 @@ pub struct Args {
 pub enum Command {
     /// Add an extension to an existing project
     Add {
-        /// Extension to add: 'ai', 'ui', 'restate', or 'cmd'
-        #[arg(value_parser = ["ai", "ui", "restate", "cmd"])]
+        /// Extension to add: 'ai', 'ui', 'forms', 'cms', 'compliance', 'logging', 'perf', 'maintenance', 'privacy', 'restate', 'cmd', 'webhooks', or 'search'
+        #[arg(value_parser = ["ai", "ui", "forms", "cms", "compliance", "logging", "perf", "maintenance", "privacy", "restate", "cmd", "webhooks", "search"])]
         extension: String,
+
+        /// Headless CMS to integrate (cms only)
+        #[arg(long, value_enum, required_if_eq("extension", "cms"))]
+        provider: Option<CmsProvider>,
+
+        /// Where the search index lives (search only)
+        #[arg(long, value_enum, required_if_eq("extension", "search"))]
+        backend: Option<SearchBackend>,
+
+        /// Overwrite existing files without asking
+        #[arg(long, short = 'y', conflicts_with = "skip_existing")]
+        yes: bool,
+
+        /// Keep existing files without asking
+        #[arg(long)]
+        skip_existing: bool,
+    },
+
+    /// Generate code in an existing project
+    Generate {
+        #[command(subcommand)]
+        generator: GenerateCommand,
+    },
+
+    /// Compare project files with what t3-mono generated and with the current templates
+    Status {
+        /// Show the template changes as unified diffs
+        #[arg(long)]
+        diff: bool,
+    },
+
+    /// Rebrand the project: package name, site title, Header, messages, and container names
+    Rename {
+        /// New package name, e.g. acme-shop or @acme/shop
+        name: String,
+
+        /// Title shown in the layout and Header (defaults to the name in title case)
+        #[arg(long)]
+        title: Option<String>,
+    },
+
+    /// Move the project to another auth provider, merging the change into files you edited
+    SwitchAuth {
+        /// Auth provider to switch to
+        #[arg(value_enum)]
+        provider: AuthProvider,
+
+        /// Show what would change, with diffs, without writing anything
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Show a diff for every file that changes
+        #[arg(long)]
+        diff: bool,
+    },
+
+    /// Move the project to another database, merging the change into files you edited
+    SwitchDb {
+        /// Database to switch to
+        #[arg(value_enum)]
+        database: DatabaseProvider,
+
+        /// Show what would change, with diffs, without writing anything
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Show a diff for every file that changes
+        #[arg(long)]
+        diff: bool,
     },
+
+    /// Check the env files against the variables src/env.js validates
+    Envcheck {
+        /// Dotenv file to check [default: the files `next dev` loads, merged in its order]
+        #[arg(long, value_name = "FILE")]
+        file: Option<String>,
+    },
+
+    /// Apply template updates to generated files you haven't modified
+    Sync {
+        /// Show what would change without writing anything
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Show diffs for updated files and for modified files that were skipped
+        #[arg(long)]
+        diff: bool,
+    },
+
+    /// Validate t3mono.toml or an answers file, or print their JSON Schemas
+    Config {
+        #[command(subcommand)]
+        action: ConfigCommand,
+    },
+
+    /// Work with the Restate services of an existing project
+    Restate {
+        #[command(subcommand)]
+        action: RestateCommand,
+    },
+
+    /// Print the npm dependencies each extension adds, the targeted versions, and the template source
+    Info {
+        /// Only show this extension ('base' is the always-included stack)
+        #[arg(value_parser = ["base", "ai", "ui", "forms", "cms", "compliance", "logging", "perf", "maintenance", "privacy", "restate", "cmd", "webhooks", "search"])]
+        extension: Option<String>,
+    },
+
+    /// Opt in to or out of anonymous usage data
+    Telemetry {
+        #[command(subcommand)]
+        action: TelemetryCommand,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum GenerateCommand {
+    /// Prisma model, zod schemas, CRUD tRPC router, and list/detail pages
+    Resource {
+        /// Model name, e.g. Product or blog-post
+        name: String,
+
+        /// Columns as name:type with types string, text, int, float, boolean, datetime;
+        /// a trailing '?' makes the column optional (id and timestamps are added)
+        #[arg(required = true)]
+        fields: Vec<String>,
+    },
+
+    /// Typed, cva-styled UI kit component exported from src/components/ui/index.ts
+    Component {
+        /// Component name, e.g. ProductCard or confirm-dialog
+        name: String,
+
+        /// Starting layout for the component
+        #[arg(long, value_enum, default_value_t = ComponentVariant::Card)]
+        variant: ComponentVariant,
+    },
+
+    /// LangChain agent in src/components/ai/agents/ with a system prompt, tools, and a vitest file
+    Agent {
+        /// Agent name, e.g. support or invoice-triage
+        name: String,
+
+        /// Tools to register, comma-separated
+        #[arg(long, value_enum, value_delimiter = ',')]
+        tools: Vec<AgentTool>,
+    },
+
+    /// New language: messages/<code>.json from en.json, dictionary.ts, and the LanguageSwitcher
+    Locale {
+        /// Locale code, e.g. fr or pt-BR
+        code: String,
+
+        /// Machine-translate the messages with Claude instead of marking them TODO
+        /// (needs the ai extension and ANTHROPIC_API_KEY)
+        #[arg(long)]
+        translate: bool,
+    },
+
+    /// Server-component page with a Header nav entry
+    Page {
+        /// Route under src/app, e.g. reports or settings/billing
+        route: String,
+
+        /// Redirect visitors without a session to the sign-in page
+        #[arg(long)]
+        protected: bool,
+
+        /// Read the page copy from its own namespace in every messages/*.json file
+        #[arg(long)]
+        translated: bool,
+    },
+
+    /// Restate workflow in restate/services/src/ from a pattern, bound in the services index
+    Workflow {
+        /// Workflow name, e.g. order-fulfillment or expense-approval
+        name: String,
+
+        /// Pattern the workflow starts from
+        #[arg(long, value_enum, default_value_t = WorkflowPattern::Sequential)]
+        pattern: WorkflowPattern,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum RestateCommand {
+    /// Register the services deployment with the local Restate admin API
+    Register {
+        /// URI under which Restate can reach the services endpoint
+        #[arg(long, default_value = "http://host.docker.internal:9082")]
+        uri: String,
+
+        /// Base URL of the Restate admin API
+        #[arg(long, default_value = "http://localhost:9070")]
+        admin_url: String,
+
+        /// Overwrite an existing deployment registered under the same URI
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Subscribe a service handler to a Kafka topic (see --restate-kafka)
+    Subscribe {
+        /// Kafka topic to consume, e.g. orders
+        topic: String,
+
+        /// Handler that receives each record, as Service/handler, e.g. OrderEvents/process
+        handler: String,
+
+        /// Kafka cluster name from restate/restate.toml
+        #[arg(long, default_value = "local")]
+        cluster: String,
+
+        /// Base URL of the Restate admin API
+        #[arg(long, default_value = "http://localhost:9070")]
+        admin_url: String,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ConfigCommand {
+    /// Check a config or answers file against its schema and the rules t3-mono applies
+    Validate {
+        /// t3mono.toml, or an answers file (.yaml, .yml, .json); defaults to the t3mono.toml
+        /// t3-mono would read
+        file: Option<PathBuf>,
+    },
+    /// Print the JSON Schema for editors and CI
+    Schema {
+        #[arg(value_enum)]
+        file: ConfigFile,
+    },
+}
+
+/// Which file a JSON Schema describes
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ConfigFile {
+    /// t3mono.toml
+    Config,
+    /// An `--answers` file
+    Answers,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum TelemetryCommand {
+    /// Send anonymous usage data (command, flag names, success, duration)
+    Enable,
+    /// Stop sending usage data
+    Disable,
+    /// Show whether usage data is sent and what it contains
+    Status,
 }
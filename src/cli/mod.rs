@@ -1,3 +1,8 @@
 mod args;
 
-pub use args::{Args, AuthProvider, Command};
+pub use args::{
+    AgentTool, Args, AuthProvider, BiomeProfile, ChatStream, CmdModule, CmsProvider, Command,
+    ComponentVariant, ConfigCommand, ConfigFile, DarkMode, DatabaseProvider, GenerateCommand, Iac,
+    Lang, LlmProvider, Pooling, RestateCommand, SearchBackend, SessionStrategy, StorageBackend,
+    TailwindPlugin, TaskRunner, TelemetryCommand, TsStrictness, VectorStore, WorkflowPattern,
+};
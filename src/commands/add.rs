@@ -1,18 +1,44 @@
 use anyhow::{Context, Result};
-use console::style;
+use console::{style, Term};
 use std::path::Path;
 
-use crate::scaffolding::{ai, cmd, restate, ui};
+use crate::cli::{CmsProvider, SearchBackend, StorageBackend};
+use crate::scaffolding::options::CmdSettings;
+use crate::scaffolding::{
+    ai, assistant, cmd, cms, compliance, docs, forms, images, logging, maintenance, manifest, perf,
+    privacy, restate, search, skills, ui, webhooks,
+};
+use crate::utils::conflict::{self, ConflictMode};
+use crate::utils::hooks::{self, Hook};
+use crate::utils::npm;
+use crate::utils::output::glyph;
 
-pub async fn execute(extension: &str) -> Result<()> {
+pub async fn execute(
+    extension: &str,
+    provider: Option<CmsProvider>,
+    backend: Option<SearchBackend>,
+    conflicts: ConflictMode,
+) -> Result<()> {
     // Check if we're in a valid project directory
     let package_json = Path::new("package.json");
     if !package_json.exists() {
-        anyhow::bail!(
-            "No package.json found. Run this command from the root of your project."
-        );
+        anyhow::bail!("No package.json found. Run this command from the root of your project.");
     }
 
+    if provider.is_some() && extension != "cms" {
+        anyhow::bail!("--provider only applies to the cms extension");
+    }
+    if backend.is_some() && extension != "search" {
+        anyhow::bail!("--backend only applies to the search extension");
+    }
+    // Asked before anything is written, so answering can't leave a partial extension behind
+    let cmd_settings = if extension == "cmd" {
+        Some(cmd_settings(conflicts)?)
+    } else {
+        None
+    };
+    conflict::set_mode(conflicts);
+
     println!();
     println!(
         "  {} {} extension...",
@@ -21,191 +47,380 @@ pub async fn execute(extension: &str) -> Result<()> {
     );
     println!();
 
+    hooks::run(Hook::PreAdd(extension), ".")?;
+
     match extension {
         "ai" => {
             ai::scaffold(".").await?;
-            update_package_json_ai()?;
+            docs::add_guide(".", "ai")?;
+            assistant::add_extension(".", "ai")?;
+            npm::add_dependencies(".", ai::AI_DEPENDENCIES, &[])?;
             println!(
                 "  {} AI agents added to {}",
-                style("✓").green().bold(),
+                style(glyph("✓")).green().bold(),
                 style("src/components/ai/").yellow()
             );
         }
         "ui" => {
             ui::scaffold(".").await?;
-            update_package_json_ui()?;
+            assistant::add_extension(".", "ui")?;
+            npm::add_dependencies(".", ui::UI_DEPENDENCIES, &[])?;
             println!(
                 "  {} UI components added to {}",
-                style("✓").green().bold(),
+                style(glyph("✓")).green().bold(),
                 style("src/components/ui/").yellow()
             );
         }
+        "forms" => {
+            forms::scaffold(".")?;
+            assistant::add_extension(".", "forms")?;
+            println!(
+                "  {} Form helpers added to {}",
+                style(glyph("✓")).green().bold(),
+                style("src/components/forms/").yellow()
+            );
+            println!(
+                "  {} Example profile form at {}",
+                style(glyph("✓")).green().bold(),
+                style("/settings/profile").yellow()
+            );
+        }
+        "cms" => {
+            let provider = provider.context("Choose a CMS with --provider payload|sanity")?;
+            cms::scaffold(".", provider).await?;
+            assistant::add_extension(".", "cms")?;
+            println!(
+                "  {} CMS content fetchers added to {}",
+                style(glyph("✓")).green().bold(),
+                style("src/server/cms/").yellow()
+            );
+            println!(
+                "  {} Blog pages at {} with draft preview via {}",
+                style(glyph("✓")).green().bold(),
+                style("/blog").yellow(),
+                style("/api/draft/enable").yellow()
+            );
+            println!();
+            println!(
+                "  Set the CMS variables from {} in your {}",
+                style(".env.example").yellow(),
+                style(".env.local").yellow()
+            );
+        }
+        "compliance" => {
+            compliance::scaffold(".").await?;
+            assistant::add_extension(".", "compliance")?;
+            println!(
+                "  {} Cookie consent banner added to {}",
+                style(glyph("✓")).green().bold(),
+                style("src/components/consent/").yellow()
+            );
+            println!(
+                "  {} Legal pages at {} and {}",
+                style(glyph("✓")).green().bold(),
+                style("/[locale]/privacy").yellow(),
+                style("/[locale]/imprint").yellow()
+            );
+            println!();
+            println!(
+                "  Fill in the placeholders in the {} namespace of {}",
+                style("legal").yellow(),
+                style("messages/*.json").yellow()
+            );
+        }
+        "logging" => {
+            logging::scaffold(".")?;
+            assistant::add_extension(".", "logging")?;
+            println!(
+                "  {} Structured logger added to {}",
+                style(glyph("✓")).green().bold(),
+                style("src/server/logger.ts").yellow()
+            );
+            println!(
+                "  {} tRPC procedures now log their duration with a request id",
+                style(glyph("✓")).green().bold(),
+            );
+            println!();
+            println!(
+                "  Set {} in your {} and use {} for readable local logs",
+                style("LOG_LEVEL").yellow(),
+                style(".env").yellow(),
+                style("npm run dev:pretty").cyan()
+            );
+        }
+        "perf" => {
+            perf::scaffold(".")?;
+            assistant::add_extension(".", "perf")?;
+            println!(
+                "  {} Query timing added to {}",
+                style(glyph("✓")).green().bold(),
+                style("src/server/perf.ts").yellow()
+            );
+            println!(
+                "  {} tRPC procedures now report their duration and query count",
+                style(glyph("✓")).green().bold(),
+            );
+            println!();
+            println!(
+                "  Set {} and {} in your {} to change when a warning is logged",
+                style("SLOW_PROCEDURE_MS").yellow(),
+                style("SLOW_QUERY_MS").yellow(),
+                style(".env").yellow()
+            );
+        }
+        "maintenance" => {
+            maintenance::scaffold(".")?;
+            assistant::add_extension(".", "maintenance")?;
+            println!(
+                "  {} Runtime flags added to {}",
+                style(glyph("✓")).green().bold(),
+                style("src/server/runtime-config.ts").yellow()
+            );
+            println!(
+                "  {} {} serves {} while maintenance mode is on",
+                style(glyph("✓")).green().bold(),
+                style("src/proxy.ts").yellow(),
+                style("/maintenance").yellow()
+            );
+            println!();
+            println!("  Post-install steps:");
+            println!(
+                "    1. Run {} to add the RuntimeFlag table",
+                style(schema_sync("add_runtime_flags")).cyan()
+            );
+            println!(
+                "    2. Switch it with {}, or set {} when the database is down",
+                style("npm run maintenance -- on").cyan(),
+                style("MAINTENANCE_MODE=on").yellow()
+            );
+        }
+        "privacy" => {
+            privacy::scaffold(".")?;
+            assistant::add_extension(".", "privacy")?;
+            println!(
+                "  {} Data export and account deletion added to {}",
+                style(glyph("✓")).green().bold(),
+                style("/settings").yellow()
+            );
+            println!();
+            println!("  Post-install steps:");
+            println!(
+                "    1. Run {} to add the deletionRequestedAt column",
+                style(schema_sync("add_account_deletion")).cyan()
+            );
+            println!(
+                "    2. Run {} daily to delete accounts after {} days",
+                style("npm run privacy:purge").cyan(),
+                style("ACCOUNT_DELETION_GRACE_DAYS").yellow()
+            );
+        }
         "restate" => {
             restate::scaffold(".").await?;
+            docs::add_guide(".", "restate")?;
+            assistant::add_extension(".", "restate")?;
             println!(
                 "  {} Restate workflows added to {}",
-                style("✓").green().bold(),
+                style(glyph("✓")).green().bold(),
                 style("restate/").yellow()
             );
             println!();
             println!("  To start Restate:");
-            println!("    {} {}", style("cd").cyan(), "restate && docker-compose up -d");
-            println!("    {} {}", style("cd").cyan(), "services && npm install && npm run dev");
+            println!("    {} run restate:up", style("npm").cyan());
+            println!(
+                "    {} --prefix restate/services install",
+                style("npm").cyan()
+            );
+            println!("    {} run restate:dev", style("npm").cyan());
+            println!("    {} run restate:register", style("npm").cyan());
         }
         "cmd" => {
-            cmd::scaffold(".").await?;
-            update_package_json_cmd()?;
+            let settings = cmd_settings.unwrap_or_default();
+            cmd::scaffold(".", &settings).await?;
+            images::add_s3(".")?;
+            docs::add_guide(".", "cmd")?;
+            assistant::add_extension(".", "cmd")?;
+            npm::add_dependencies(".", cmd::CMD_DEPENDENCIES, cmd::CMD_DEV_DEPENDENCIES)?;
+            record_cmd(&settings)?;
             println!(
                 "  {} CommandIsland AI layer added",
-                style("✓").green().bold(),
+                style(glyph("✓")).green().bold(),
+            );
+            println!();
+            println!("  Post-install steps:");
+            println!(
+                "    1. Review {} for pgvector config and new models",
+                style("prisma/schema.prisma").yellow()
+            );
+            println!(
+                "    2. Run {} to apply schema changes",
+                style("npx prisma migrate dev --name add_commandisland").cyan()
+            );
+            let storage_env = match settings.storage {
+                StorageBackend::S3 => "AWS_S3_BUCKET_NAME, AWS_REGION",
+                StorageBackend::S3Compatible => "AWS_S3_BUCKET_NAME, AWS_S3_ENDPOINT",
+            };
+            println!(
+                "    3. Set env vars: {}",
+                style(format!(
+                    "{}, {}",
+                    cmd::provider_env(settings.provider),
+                    storage_env
+                ))
+                .yellow()
+            );
+            println!(
+                "    4. Change the model, storage, or modes in {}",
+                style("src/server/chat/config.ts").yellow()
+            );
+        }
+        "webhooks" => {
+            webhooks::scaffold(".").await?;
+            assistant::add_extension(".", "webhooks")?;
+            println!(
+                "  {} Webhook delivery added to {}",
+                style(glyph("✓")).green().bold(),
+                style("src/server/webhooks/").yellow()
+            );
+            println!(
+                "  {} Endpoints are managed at {}",
+                style(glyph("✓")).green().bold(),
+                style("/settings/webhooks").yellow()
             );
             println!();
             println!("  Post-install steps:");
-            println!("    1. Review {} for pgvector config and new models", style("prisma/schema.prisma").yellow());
-            println!("    2. Run {} to apply schema changes", style("npx prisma migrate dev --name add_commandisland").cyan());
-            println!("    3. Set env vars: {}", style("ANTHROPIC_API_KEY, AWS_S3_BUCKET_NAME, AWS_REGION").yellow());
+            println!(
+                "    1. Run {} to add the webhook models",
+                style("npm run db:push").cyan()
+            );
+            if webhooks::uses_restate(".") {
+                println!(
+                    "    2. Restart {} and run {} for the WebhookDelivery service",
+                    style("npm run restate:dev").cyan(),
+                    style("npm run restate:register").cyan()
+                );
+            } else {
+                println!(
+                    "    2. Call {} every minute with {} to retry failed deliveries",
+                    style("GET /api/webhooks/process").cyan(),
+                    style("Authorization: Bearer $WEBHOOK_WORKER_SECRET").yellow()
+                );
+            }
+        }
+        "search" => {
+            let backend = backend
+                .context("Choose where to search with --backend postgres|meilisearch|typesense")?;
+            search::scaffold(".", backend).await?;
+            assistant::add_extension(".", "search")?;
+            println!(
+                "  {} Search indexes added to {}",
+                style(glyph("✓")).green().bold(),
+                style("src/server/search/").yellow()
+            );
+            println!(
+                "  {} Search palette in the Header, opened with {}",
+                style(glyph("✓")).green().bold(),
+                style("Cmd+K / Ctrl+K").yellow()
+            );
+            println!();
+            println!("  Post-install steps:");
+            if backend == SearchBackend::Postgres {
+                println!(
+                    "    1. Register your models in {}",
+                    style("src/server/search/indexes.ts").yellow()
+                );
+            } else {
+                println!(
+                    "    1. Start the search engine with {} and register your models in {}",
+                    style("docker compose up -d").cyan(),
+                    style("src/server/search/indexes.ts").yellow()
+                );
+            }
+            println!(
+                "    2. Run {} to build the indexes",
+                style("npm run search:reindex").cyan()
+            );
         }
         _ => {
-            anyhow::bail!("Unknown extension: {}. Use 'ai', 'ui', 'restate', or 'cmd'.", extension);
+            anyhow::bail!("Unknown extension: {}. Use 'ai', 'ui', 'forms', 'cms', 'compliance', 'logging', 'perf', 'maintenance', 'privacy', 'restate', 'cmd', 'webhooks', or 'search'.", extension);
         }
     }
 
-    println!();
-    if extension != "restate" {
-        println!("  Run {} to install new dependencies", style("npm install").cyan());
-        println!();
-    }
-
-    Ok(())
-}
-
-fn update_package_json_ai() -> Result<()> {
-    let package_json_path = Path::new("package.json");
-    let content = std::fs::read_to_string(package_json_path)?;
-    let mut pkg: serde_json::Value = serde_json::from_str(&content)?;
-
-    let deps = pkg["dependencies"]
-        .as_object_mut()
-        .context("Invalid package.json: missing dependencies")?;
+    // Skills describe the whole stack, so any extension can change them
+    let edited_skills = skills::sync(".")?;
 
-    // Add AI dependencies
-    let ai_deps = [
-        ("@langchain/anthropic", "^1.3.18"),
-        ("@langchain/core", "^1.1.26"),
-        ("@langchain/openai", "^1.2.8"),
-        ("langchain", "^1.2.25"),
-        ("zod", "^4.3.6"),
-        ("winston", "^3.19.0"),
-        ("pg", "^8.18.0"),
-    ];
+    hooks::run(Hook::PostAdd(extension), ".")?;
 
-    for (name, version) in ai_deps {
-        if !deps.contains_key(name) {
-            deps.insert(name.to_string(), serde_json::Value::String(version.to_string()));
+    let (kept, written_as) = conflict::summary();
+    if !kept.is_empty() || !written_as.is_empty() || !edited_skills.is_empty() {
+        println!();
+        for file in &edited_skills {
+            println!(
+                "  {} Kept your edited {}; the regenerated skill is in {}",
+                style(glyph("•")).dim(),
+                style(file).yellow(),
+                style(format!("{}.new", file)).yellow()
+            );
+        }
+        for file in kept {
+            println!(
+                "  {} Kept existing {}",
+                style(glyph("•")).dim(),
+                style(file).yellow()
+            );
+        }
+        for file in written_as {
+            println!(
+                "  {} Wrote {} for you to merge",
+                style(glyph("•")).dim(),
+                style(file).yellow()
+            );
         }
     }
 
-    let content = serde_json::to_string_pretty(&pkg)?;
-    std::fs::write(package_json_path, content)?;
+    println!();
+    if extension != "restate" {
+        println!(
+            "  Run {} to install new dependencies",
+            style("npm install").cyan()
+        );
+        println!();
+    }
 
     Ok(())
 }
 
-fn update_package_json_ui() -> Result<()> {
-    let package_json_path = Path::new("package.json");
-    let content = std::fs::read_to_string(package_json_path)?;
-    let mut pkg: serde_json::Value = serde_json::from_str(&content)?;
-
-    let deps = pkg["dependencies"]
-        .as_object_mut()
-        .context("Invalid package.json: missing dependencies")?;
-
-    // Add UI dependencies
-    let ui_deps = [
-        ("@floating-ui/react", "^0.27.18"),
-        ("class-variance-authority", "^0.7.1"),
-        ("clsx", "^2.1.1"),
-        ("date-fns", "^4.1.0"),
-        ("lucide-react", "^0.574.0"),
-        ("react-day-picker", "^9.13.2"),
-        ("recharts", "^2.15.4"),
-        ("sonner", "^2.0.7"),
-        ("tailwind-merge", "^3.4.1"),
-    ];
-
-    for (name, version) in ui_deps {
-        if !deps.contains_key(name) {
-            deps.insert(name.to_string(), serde_json::Value::String(version.to_string()));
-        }
+/// Apply schema changes the way the project manages its database: a named migration when it
+/// has `prisma/migrations`, otherwise `db push`
+fn schema_sync(migration: &str) -> String {
+    if Path::new("prisma/migrations").exists() {
+        format!("npm run db:migrate -- --name {}", migration)
+    } else {
+        "npm run db:push".to_string()
     }
-
-    let content = serde_json::to_string_pretty(&pkg)?;
-    std::fs::write(package_json_path, content)?;
-
-    Ok(())
 }
 
-fn update_package_json_cmd() -> Result<()> {
-    let package_json_path = Path::new("package.json");
-    let content = std::fs::read_to_string(package_json_path)?;
-    let mut pkg: serde_json::Value = serde_json::from_str(&content)?;
-
-    let deps = pkg["dependencies"]
-        .as_object_mut()
-        .context("Invalid package.json: missing dependencies")?;
-
-    let cmd_deps = [
-        // LangChain
-        ("@langchain/anthropic", "^1.3.18"),
-        ("@langchain/cohere", "^1.0.2"),
-        ("@langchain/core", "^1.1.26"),
-        ("@langchain/google-genai", "^2.1.19"),
-        ("@langchain/mistralai", "^1.0.4"),
-        ("@langchain/ollama", "^1.2.3"),
-        ("@langchain/openai", "^1.2.8"),
-        ("@langchain/textsplitters", "^1.0.1"),
-        ("langchain", "^1.2.25"),
-        // Backend
-        ("winston", "^3.19.0"),
-        ("pg", "^8.18.0"),
-        ("server-only", "^0.0.1"),
-        // Frontend
-        ("react-markdown", "^10.1.0"),
-        ("remark-gfm", "^4.0.1"),
-        ("@floating-ui/react", "^0.27.18"),
-        ("sonner", "^2.0.7"),
-        ("class-variance-authority", "^0.7.1"),
-        ("date-fns", "^4.1.0"),
-        // DocGen
-        ("pdfmake", "^0.3.4"),
-        ("exceljs", "^4.4.0"),
-        ("pptxgenjs", "^4.0.1"),
-        // AWS
-        ("@aws-sdk/client-s3", "^3.993.0"),
-        ("@aws-sdk/s3-request-presigner", "^3.993.0"),
-    ];
-
-    for (name, version) in cmd_deps {
-        if !deps.contains_key(name) {
-            deps.insert(name.to_string(), serde_json::Value::String(version.to_string()));
-        }
+/// CommandIsland settings, asked for in a terminal starting from those the manifest recorded
+fn cmd_settings(conflicts: ConflictMode) -> Result<CmdSettings> {
+    let recorded = manifest::read(".")
+        .ok()
+        .and_then(|manifest| manifest.options.cmd_settings);
+    if conflicts == ConflictMode::Prompt && Term::stdout().is_term() {
+        cmd::prompt_settings(&recorded.unwrap_or_default())
+    } else {
+        Ok(recorded.unwrap_or_default())
     }
+}
 
-    // Add dev dependencies
-    if let Some(dev_deps) = pkg["devDependencies"].as_object_mut() {
-        let cmd_dev_deps = [
-            ("@types/pdfmake", "^0.3.1"),
-            ("@types/pg", "^8.16.0"),
-        ];
-        for (name, version) in cmd_dev_deps {
-            if !dev_deps.contains_key(name) {
-                dev_deps.insert(name.to_string(), serde_json::Value::String(version.to_string()));
-            }
-        }
+/// Record the scaffolded extension and its settings in the manifest, so
+/// `create --from-manifest` reproduces it
+fn record_cmd(settings: &CmdSettings) -> Result<()> {
+    let Ok(mut manifest) = manifest::read(".") else {
+        return Ok(());
+    };
+    if manifest.options.cmd && manifest.options.cmd_settings.as_ref() == Some(settings) {
+        return Ok(());
     }
-
-    let content = serde_json::to_string_pretty(&pkg)?;
-    std::fs::write(package_json_path, content)?;
-
-    Ok(())
+    manifest.options.cmd = true;
+    manifest.options.cmd_settings = Some(settings.clone());
+    manifest::write(".", &manifest)
 }
@@ -0,0 +1,199 @@
+use anyhow::{Context, Result};
+use console::style;
+use reqwest::Url;
+use schemars::schema_for;
+use std::path::Path;
+
+use crate::cli::ConfigFile;
+use crate::templates::remote::FetchStrategy;
+use crate::utils::answers::{self, Answers};
+use crate::utils::config::{self, Config};
+use crate::utils::output::glyph;
+use crate::utils::registry;
+
+/// A rule the file breaks, at the line of the key it is about when that can be found
+struct Problem {
+    line: Option<usize>,
+    message: String,
+}
+
+/// `t3-mono config validate [FILE]`: parse a config or answers file and apply the checks
+/// t3-mono would otherwise hit one at a time during a run
+pub fn validate(file: Option<&Path>) -> Result<()> {
+    let path = match file {
+        Some(path) => path.to_path_buf(),
+        None => config::find()
+            .context("No t3mono.toml in the current directory or the user config directory")?,
+    };
+    let content = std::fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    let is_answers = matches!(
+        path.extension().and_then(|ext| ext.to_str()),
+        Some("yaml" | "yml" | "json")
+    );
+
+    println!();
+    println!(
+        "  {} {}...",
+        style("Validating").cyan().bold(),
+        path.display()
+    );
+    println!();
+
+    // Syntax errors, unknown keys, and wrong types fail here with the parser's line and column
+    let problems = if is_answers {
+        check_answers(answers::load(&path)?, &content)
+    } else {
+        check_config(&config::read(&path)?, &content)
+    };
+
+    if problems.is_empty() {
+        println!(
+            "  {} {} is valid",
+            style(glyph("✓")).green().bold(),
+            path.display()
+        );
+        println!();
+        return Ok(());
+    }
+    for problem in &problems {
+        let location = match problem.line {
+            Some(line) => format!("{}:{}", path.display(), line),
+            None => path.display().to_string(),
+        };
+        println!(
+            "    {} {} {}",
+            style("!").yellow().bold(),
+            style(location).dim(),
+            problem.message
+        );
+    }
+    println!();
+
+    anyhow::bail!("{} did not pass validation", path.display())
+}
+
+/// `t3-mono config schema <config|answers>`: print the JSON Schema of a file
+pub fn schema(file: ConfigFile) -> Result<()> {
+    let schema = match file {
+        ConfigFile::Config => schema_for!(Config),
+        ConfigFile::Answers => schema_for!(Answers),
+    };
+    println!("{}", serde_json::to_string_pretty(&schema)?);
+    Ok(())
+}
+
+/// The values `remote::configure`, the fetchers, and `--latest` reject or ignore
+fn check_config(config: &Config, content: &str) -> Vec<Problem> {
+    let mut problems = Vec::new();
+    let mut problem = |key: &str, message: String| {
+        problems.push(Problem {
+            line: line_of(content, key),
+            message,
+        })
+    };
+
+    let templates = &config.templates;
+    if let Some(repo) = &templates.repo {
+        let parts: Vec<&str> = repo.split('/').collect();
+        if parts.len() != 2 || parts.iter().any(|part| part.is_empty()) {
+            problem("repo", format!("repo must be 'owner/name', got '{}'", repo));
+        }
+    }
+    if let Some(mirror) = &templates.mirror {
+        if !mirror.starts_with("https://") && !mirror.starts_with("http://") {
+            problem(
+                "mirror",
+                format!("mirror must be an http(s) URL, got '{}'", mirror),
+            );
+        }
+    }
+    if templates.git_url.is_some() && templates.strategy != FetchStrategy::Git {
+        problem(
+            "git_url",
+            "git_url is only used with strategy = \"git\"".to_string(),
+        );
+    }
+
+    if let Some(proxy) = &config.network.proxy {
+        if Url::parse(proxy).is_err() {
+            problem("proxy", format!("proxy is not a URL: '{}'", proxy));
+        }
+    }
+    for cert in &config.network.ca_certs {
+        if !cert.exists() {
+            problem(
+                "ca_certs",
+                format!("CA certificate {} does not exist", cert.display()),
+            );
+        }
+    }
+
+    if let Some(registry) = &config.npm.registry {
+        if Url::parse(registry).is_err() {
+            problem("registry", format!("registry is not a URL: '{}'", registry));
+        }
+    }
+    for (package, range) in &config.npm.ranges {
+        if registry::to_version_req(range).is_none() {
+            problem(
+                package,
+                format!(
+                    "'{}' for {} is not a range --latest can resolve",
+                    range, package
+                ),
+            );
+        }
+    }
+
+    let hooks = &config.hooks;
+    let lists = [
+        ("pre_create", &hooks.pre_create),
+        ("post_create", &hooks.post_create),
+    ];
+    let by_extension = hooks
+        .pre_add
+        .iter()
+        .chain(&hooks.post_add)
+        .map(|(extension, commands)| (extension.as_str(), commands));
+    for (key, commands) in lists.into_iter().chain(by_extension) {
+        if commands.iter().any(|command| command.trim().is_empty()) {
+            problem(key, format!("{} has an empty hook command", key));
+        }
+    }
+
+    problems
+}
+
+/// The requirements between answers that `create --answers` checks
+fn check_answers(answers: Answers, content: &str) -> Vec<Problem> {
+    match answers.into_options(".") {
+        Ok(_) => Vec::new(),
+        // These errors start with the key they are about, e.g. "analytics requires ..."
+        Err(err) => {
+            let message = err.to_string();
+            let key = message.split_whitespace().next().unwrap_or_default();
+            vec![Problem {
+                line: line_of(content, key),
+                message,
+            }]
+        }
+    }
+}
+
+/// 1-based line of the first `key = ` (TOML) or `key:` (YAML/JSON), quoted or not
+fn line_of(content: &str, key: &str) -> Option<usize> {
+    if key.is_empty() {
+        return None;
+    }
+    content
+        .lines()
+        .position(|line| {
+            let line = line.trim_start().trim_start_matches(['"', '\'']);
+            line.strip_prefix(key).is_some_and(|rest| {
+                let rest = rest.trim_start_matches(['"', '\'']).trim_start();
+                rest.starts_with('=') || rest.starts_with(':') || rest.starts_with('.')
+            })
+        })
+        .map(|index| index + 1)
+}
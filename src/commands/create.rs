@@ -1,43 +1,77 @@
 use anyhow::Result;
 use console::style;
-use dialoguer::{MultiSelect, Select};
-use indicatif::{ProgressBar, ProgressStyle};
-use std::path::Path;
-use std::time::Duration;
-
-use crate::cli::AuthProvider;
-use crate::scaffolding::{ai, better_auth, cmd, next_auth, restate, t3, ui};
-use crate::utils::fs;
-
+use dialoguer::{Confirm, Input, MultiSelect, Select};
+use std::path::{Path, PathBuf};
+
+use crate::cli::{
+    AuthProvider, BiomeProfile, ChatStream, DarkMode, Iac, Pooling, SessionStrategy, TaskRunner,
+};
+use crate::scaffolding::options::{DomainModel, ScaffoldOptions};
+use crate::scaffolding::{cmd, manifest, project, resource, setup_db, tailwind};
+use crate::templates::resolver;
+use crate::utils::fs::to_slash;
+use crate::utils::hooks::{self, Hook};
+use crate::utils::lang::Msg;
+use crate::utils::naming;
+use crate::utils::output::{glyph, Progress};
+use crate::utils::plan::Plan;
+use crate::utils::registry::{self, LatestSettings};
+
+/// Create a project in `dir`, or in the directory `options.name` names when unset
 pub async fn execute(
-    name: &str,
-    include_ai: bool,
-    include_ui: bool,
-    include_restate: bool,
-    include_cmd: bool,
+    mut options: ScaffoldOptions,
+    dir: Option<String>,
     interactive: bool,
     init_git: bool,
-    auth_provider: AuthProvider,
+    latest: Option<LatestSettings>,
+    dry_run: bool,
+    setup_db: bool,
 ) -> Result<()> {
-    let (selected_auth, ai_enabled, ui_enabled, restate_enabled, cmd_enabled) = if interactive {
-        let auth = prompt_auth_provider(auth_provider)?;
-        let (ai, ui, restate, cmd) = prompt_extensions(include_ai, include_ui, include_restate, include_cmd)?;
-        (auth, ai, ui, restate, cmd)
-    } else {
-        (auth_provider, include_ai, include_ui, include_restate, include_cmd)
-    };
+    if interactive {
+        options.auth = prompt_auth_provider(options.auth)?;
+        let (ai, ui, restate, cmd) =
+            prompt_extensions(options.ai, options.ui, options.restate, options.cmd)?;
+        options.ai = ai;
+        options.ui = ui;
+        options.restate = restate;
+        options.cmd = cmd;
+        if cmd {
+            options.cmd_settings = Some(cmd::prompt_settings(
+                &options.cmd_settings.unwrap_or_default(),
+            )?);
+        }
+        options.models = prompt_models()?;
+        if !options.models.is_empty() {
+            options.seed = true;
+        }
+    }
 
-    let project_path = Path::new(name);
+    let dir = dir.unwrap_or_else(|| options.name.clone());
+    // A path as the name (`apps/web`, `.`) names the package after its directory
+    if options.name == "." || (options.name.contains(['/', '\\']) && !options.name.starts_with('@'))
+    {
+        options.name = directory_name(&dir);
+    }
+    options.name = naming::package_name(&options.name);
+    let name = options.name.as_str();
+    let project_path = Path::new(&dir);
 
     // Check if directory exists and is not empty
-    if project_path.exists() && name != "." {
+    if project_path.exists() && dir != "." {
         let is_empty = project_path.read_dir()?.next().is_none();
         if !is_empty {
-            anyhow::bail!("Directory '{}' already exists and is not empty", name);
+            anyhow::bail!(Msg::DirectoryNotEmpty.fill(&[&dir]));
         }
     }
 
-    let auth_name = match selected_auth {
+    if options.standalone && options.cmd && options.cmd_stream == Some(ChatStream::Websocket) {
+        anyhow::bail!(Msg::StandaloneWebsocket.text());
+    }
+    if options.session == SessionStrategy::Database && options.auth != AuthProvider::NextAuth {
+        anyhow::bail!(Msg::SessionNeedsNextAuth.text());
+    }
+
+    let auth_name = match options.auth {
         AuthProvider::BetterAuth => "Better Auth",
         AuthProvider::NextAuth => "NextAuth",
     };
@@ -45,104 +79,353 @@ pub async fn execute(
     println!();
     println!(
         "  {} {} {}",
-        style("Creating").cyan().bold(),
+        style(Msg::Creating).cyan().bold(),
         style(name).white().bold(),
-        style(format!("with T3 Stack + {}", auth_name)).dim()
+        style(Msg::WithStack.fill(&[&auth_name])).dim()
     );
+    if dir != "." && dir != name {
+        println!("  {}", style(Msg::InDirectory.fill(&[&dir])).dim());
+    }
 
-    if ai_enabled {
-        println!("  {} LangChain AI agents", style("+").green().bold());
+    if options.session == SessionStrategy::Database {
+        println!("  {} {}", style("+").green().bold(), Msg::DatabaseSessions);
+    }
+    if options.ai {
+        println!("  {} {}", style("+").green().bold(), Msg::AiAgents);
+    }
+    if options.ui {
+        println!("  {} {}", style("+").green().bold(), Msg::UiLibrary);
+    }
+    if options.analytics {
+        println!("  {} {}", style("+").green().bold(), Msg::AnalyticsPage);
+    }
+    if options.restate {
+        println!("  {} {}", style("+").green().bold(), Msg::RestateWorkflows);
+        if options.restate_kafka {
+            println!("  {} {}", style("+").green().bold(), Msg::RestateKafka);
+        }
+    }
+    if options.cmd {
+        println!("  {} {}", style("+").green().bold(), Msg::CmdLayer);
+        if let Some(transport) = options.cmd_stream {
+            let transport = match transport {
+                ChatStream::Sse => Msg::ServerSentEvents.text(),
+                ChatStream::Websocket => "WebSocket",
+            };
+            println!(
+                "  {} {}",
+                style("+").green().bold(),
+                Msg::ChatStreaming.fill(&[&transport])
+            );
+        }
+    }
+    if options.example {
+        println!("  {} {}", style("+").green().bold(), Msg::ExamplePostRouter);
+    }
+    if options.rest {
+        println!("  {} {}", style("+").green().bold(), Msg::RestEndpoint);
+    }
+    if !options.models.is_empty() {
+        let names: Vec<String> = options
+            .models
+            .iter()
+            .map(|model| naming::pascal_case(&model.name))
+            .collect();
+        println!(
+            "  {} {}",
+            style("+").green().bold(),
+            Msg::OwnModels.fill(&[&names.join(", ")])
+        );
+    }
+    if options.seed {
+        println!("  {} {}", style("+").green().bold(), Msg::SeedScript);
+    }
+    if options.seo {
+        println!("  {} {}", style("+").green().bold(), Msg::OpenGraphRoute);
+    }
+    if options.standalone {
+        println!("  {} {}", style("+").green().bold(), Msg::StandaloneBuild);
+    }
+    if options.bundle_analyzer {
+        println!("  {} {}", style("+").green().bold(), Msg::BundleAnalyzer);
+    }
+    if options.security_headers {
+        println!("  {} {}", style("+").green().bold(), Msg::SecurityHeaders);
     }
-    if ui_enabled {
-        println!("  {} UI component library", style("+").green().bold());
+    if let Some(pooling) = options.pooling {
+        let pooler = match pooling {
+            Pooling::Pgbouncer => "PgBouncer",
+            Pooling::Accelerate => "Prisma Accelerate",
+        };
+        println!(
+            "  {} {}",
+            style("+").green().bold(),
+            Msg::ConnectionPooling.fill(&[&pooler])
+        );
     }
-    if restate_enabled {
-        println!("  {} Restate durable workflows", style("+").green().bold());
+    if options.soft_delete {
+        println!("  {} {}", style("+").green().bold(), Msg::SoftDeletes);
     }
-    if cmd_enabled {
-        println!("  {} CommandIsland AI layer", style("+").green().bold());
+    if !options.image_hosts.is_empty() {
+        println!(
+            "  {} {}",
+            style("+").green().bold(),
+            Msg::RemoteImages.fill(&[&options.image_hosts.join(", ")])
+        );
+    }
+    if let Some(cdn) = &options.image_cdn {
+        println!(
+            "  {} {}",
+            style("+").green().bold(),
+            Msg::ImageCdn.fill(&[cdn])
+        );
+    }
+    if options.migrations {
+        println!("  {} {}", style("+").green().bold(), Msg::InitialMigration);
+    }
+    if options.nix {
+        println!("  {} {}", style("+").green().bold(), Msg::NixShell);
+    }
+    if options.iac == Some(Iac::Terraform) {
+        println!("  {} {}", style("+").green().bold(), Msg::TerraformModule);
+    }
+    if let Some(runner) = options.task_runner {
+        let file = match runner {
+            TaskRunner::Just => "justfile",
+            TaskRunner::Make => "Makefile",
+        };
+        println!(
+            "  {} {}",
+            style("+").green().bold(),
+            Msg::TaskRunnerFile.fill(&[&file])
+        );
+    }
+    if let Some(threshold) = options.coverage_threshold {
+        println!(
+            "  {} {}",
+            style("+").green().bold(),
+            Msg::CoverageThreshold.fill(&[&threshold])
+        );
+    }
+    if !options.tailwind_plugins.is_empty() {
+        let plugins: Vec<&str> = options
+            .tailwind_plugins
+            .iter()
+            .map(|p| tailwind::plugin(*p).0)
+            .collect();
+        println!(
+            "  {} {}",
+            style("+").green().bold(),
+            Msg::TailwindPlugins.fill(&[&plugins.join(", ")])
+        );
+    }
+    match options.dark_mode {
+        DarkMode::Class => {}
+        DarkMode::Media => println!("  {} {}", style("+").green().bold(), Msg::DarkModeMedia),
+        DarkMode::None => println!("  {} {}", style("+").green().bold(), Msg::LightThemeOnly),
+    }
+    if options.biome_profile != BiomeProfile::Recommended {
+        let profile = match options.biome_profile {
+            BiomeProfile::Minimal => "Minimal",
+            BiomeProfile::Recommended => "Recommended",
+            BiomeProfile::Strict => "Strict",
+        };
+        println!(
+            "  {} {}",
+            style("+").green().bold(),
+            Msg::BiomeRules.fill(&[&profile])
+        );
+    }
+    if !options.locales.is_empty() {
+        println!(
+            "  {} {}",
+            style("+").green().bold(),
+            Msg::Languages.fill(&[&options.locales.join(", ")])
+        );
+    }
+    if latest.is_some() {
+        println!("  {} {}", style("+").green().bold(), Msg::LatestVersions);
     }
     println!();
 
-    // Create progress bar
-    let pb = create_progress_bar();
-
-    // Step 1: Create directory structure
-    pb.set_message("Creating project structure...");
-    fs::create_project_dir(name, selected_auth)?;
-    pb.inc(1);
-
-    // Step 2: Scaffold T3 base
-    pb.set_message("Setting up T3 stack...");
-    t3::scaffold(name).await?;
-    pb.inc(1);
+    // Overrides are looked up in and next to the project, where `status` and `sync` find them too
+    resolver::set_project_root(Path::new(&dir));
+    let pb = Progress::spinner();
+    if dry_run {
+        let plan = project::prepare(&dir, &options, &pb, init_git).await?;
+        pb.finish_and_clear();
+        print_plan(&plan, &dir);
+        return Ok(());
+    }
 
-    // Step 3: Add authentication
-    match selected_auth {
-        AuthProvider::BetterAuth => {
-            pb.set_message("Configuring Better Auth...");
-            better_auth::scaffold(name).await?;
-        }
-        AuthProvider::NextAuth => {
-            pb.set_message("Configuring NextAuth...");
-            next_auth::scaffold(name).await?;
+    hooks::run(Hook::PreCreate, &dir)?;
+
+    let plan = project::prepare(&dir, &options, &pb, init_git).await?;
+    // Record what was generated so `status` and `sync` can detect drift later
+    manifest::record(&dir, &options, init_git, &plan)?;
+    plan.execute().await?;
+    let migration_generated = project::finish(&dir, &options, &pb, init_git, true)?;
+
+    // After the manifest, so the resolved versions read as local edits that `sync` keeps
+    let mut resolutions = Vec::new();
+    if let Some(latest) = &latest {
+        pb.set_message(Msg::ResolvingLatest.text());
+        for package_dir in [dir.clone(), format!("{}/restate/services", dir)] {
+            if Path::new(&package_dir).join("package.json").exists() {
+                resolutions.push(registry::resolve_latest(&package_dir, latest).await?);
+            }
         }
     }
-    pb.inc(1);
 
-    // Step 4: Add AI if enabled
-    if ai_enabled {
-        pb.set_message("Adding AI agents framework...");
-        ai::scaffold(name).await?;
-        pb.inc(1);
-    }
+    pb.finish_and_clear();
 
-    // Step 5: Add UI if enabled
-    if ui_enabled {
-        pb.set_message("Adding UI components...");
-        ui::scaffold(name).await?;
-        pb.inc(1);
+    if latest.is_some() {
+        print_latest(&resolutions);
     }
 
-    // Step 6: Add Restate if enabled
-    if restate_enabled {
-        pb.set_message("Adding Restate workflows...");
-        restate::scaffold(name).await?;
-        pb.inc(1);
+    if setup_db {
+        println!();
+        setup_db::run(&dir, &options)?;
     }
 
-    // Step 6b: Add CommandIsland if enabled
-    if cmd_enabled {
-        pb.set_message("Adding CommandIsland AI layer...");
-        cmd::scaffold(name).await?;
-        pb.inc(1);
+    hooks::run(Hook::PostCreate, &dir)?;
+
+    // Print success message
+    print_success(&options, &dir, migration_generated, setup_db);
+
+    Ok(())
+}
+
+/// `--from-manifest`: create `name` with the options another project was created with;
+/// git is initialized when it was there and `allow_git` is set
+pub async fn replay(
+    source: &str,
+    name: &str,
+    dir: Option<String>,
+    allow_git: bool,
+    latest: Option<LatestSettings>,
+    dry_run: bool,
+    setup_db: bool,
+) -> Result<()> {
+    let manifest = manifest::load(source).await?;
+
+    let current = env!("CARGO_PKG_VERSION");
+    if manifest.version != current {
+        println!();
+        let command = style(format!(
+            "npx t3-mono@{} {} --from-manifest {}",
+            manifest.version, name, source
+        ))
+        .cyan();
+        println!(
+            "  {} {}",
+            style("!").yellow().bold(),
+            Msg::ManifestVersion.fill(&[
+                &manifest.options.name,
+                &manifest.version,
+                &current,
+                &command
+            ])
+        );
     }
 
-    // Step 7: Initialize git
-    if init_git {
-        pb.set_message("Initializing git repository...");
-        fs::init_git(name)?;
-        pb.inc(1);
+    let options = ScaffoldOptions {
+        name: name.to_string(),
+        ..manifest.options
+    };
+    execute(
+        options,
+        dir,
+        false,
+        manifest.git && allow_git,
+        latest,
+        dry_run,
+        setup_db,
+    )
+    .await
+}
+
+/// `--dry-run`: every file the project would have, with what each step did to it
+fn print_plan(plan: &Plan, dir: &str) {
+    println!("  {}", style(Msg::DryRun).cyan().bold());
+    println!();
+    for (path, steps) in plan.operations() {
+        let shown = path.strip_prefix(dir).unwrap_or(&path);
+        println!(
+            "  {} {}  {}",
+            style("+").green(),
+            to_slash(&shown.to_string_lossy()),
+            style(steps.join(", ")).dim()
+        );
     }
 
-    // Step 8: Final package.json assembly
-    pb.set_message("Finalizing package.json...");
-    t3::finalize_package_json(name, ai_enabled, ui_enabled, cmd_enabled, selected_auth)?;
-    pb.inc(1);
+    let clobbers = plan.clobbers();
+    if !clobbers.is_empty() {
+        println!();
+        for clobber in &clobbers {
+            let shown = clobber.path.strip_prefix(dir).unwrap_or(&clobber.path);
+            let shown = style(to_slash(&shown.to_string_lossy())).yellow();
+            println!(
+                "  {} {}",
+                style(glyph("•")).dim(),
+                Msg::Clobbered.fill(&[&shown, &clobber.replaced_by, &clobber.written_by])
+            );
+        }
+    }
 
-    pb.finish_and_clear();
+    let mut count = 0;
+    let _ = plan.each_file(Path::new(dir), |_, _| {
+        count += 1;
+        Ok(())
+    });
+    println!();
+    println!(
+        "  {} {}",
+        style(glyph("•")).dim(),
+        Msg::DryRunFiles.fill(&[&count, &dir])
+    );
+    println!();
+}
 
-    // Print success message
-    print_success(name, ai_enabled, ui_enabled, restate_enabled, cmd_enabled);
+fn print_latest(resolutions: &[registry::Resolution]) {
+    let upgrades: Vec<_> = resolutions.iter().flat_map(|r| &r.upgrades).collect();
+    let failed: Vec<_> = resolutions.iter().flat_map(|r| &r.failed).collect();
 
-    Ok(())
+    println!();
+    if upgrades.is_empty() {
+        println!(
+            "  {} {}",
+            style(glyph("✓")).green().bold(),
+            Msg::NewestVersions
+        );
+    } else {
+        println!(
+            "  {} {}",
+            style(glyph("✓")).green().bold(),
+            Msg::ResolvedVersions.fill(&[&upgrades.len()])
+        );
+        for upgrade in &upgrades {
+            println!(
+                "    {} {} {} {}",
+                upgrade.name,
+                style(&upgrade.from).dim(),
+                glyph("→"),
+                style(&upgrade.to).green()
+            );
+        }
+    }
+    if !failed.is_empty() {
+        let names: Vec<&str> = failed.iter().map(|name| name.as_str()).collect();
+        println!(
+            "  {} {}",
+            style("!").yellow().bold(),
+            Msg::LookupFailed.fill(&[&names.join(", ")])
+        );
+    }
 }
 
 fn prompt_auth_provider(default: AuthProvider) -> Result<AuthProvider> {
-    let auth_options = vec![
-        "Better Auth (recommended)",
-        "NextAuth (v4)",
-    ];
+    let auth_options = vec![Msg::BetterAuthRecommended.text(), "NextAuth (v4)"];
 
     let default_index = match default {
         AuthProvider::BetterAuth => 0,
@@ -150,7 +433,7 @@ fn prompt_auth_provider(default: AuthProvider) -> Result<AuthProvider> {
     };
 
     let selection = Select::new()
-        .with_prompt("Select authentication provider")
+        .with_prompt(Msg::SelectAuth.text())
         .items(&auth_options)
         .default(default_index)
         .interact()?;
@@ -162,12 +445,22 @@ fn prompt_auth_provider(default: AuthProvider) -> Result<AuthProvider> {
     })
 }
 
-fn prompt_extensions(default_ai: bool, default_ui: bool, default_restate: bool, default_cmd: bool) -> Result<(bool, bool, bool, bool)> {
-    let extensions = vec!["AI Agents (LangChain)", "UI Components", "Restate Workflows", "CommandIsland AI Layer"];
+fn prompt_extensions(
+    default_ai: bool,
+    default_ui: bool,
+    default_restate: bool,
+    default_cmd: bool,
+) -> Result<(bool, bool, bool, bool)> {
+    let extensions = vec![
+        Msg::AiExtension.text(),
+        Msg::UiExtension.text(),
+        Msg::RestateExtension.text(),
+        Msg::CmdExtension.text(),
+    ];
     let defaults = vec![default_ai, default_ui, default_restate, default_cmd];
 
     let selections = MultiSelect::new()
-        .with_prompt("Select extensions to include")
+        .with_prompt(Msg::SelectExtensions.text())
         .items(&extensions)
         .defaults(&defaults)
         .interact()?;
@@ -180,62 +473,274 @@ fn prompt_extensions(default_ai: bool, default_ui: bool, default_restate: bool,
     Ok((ai, ui, restate, cmd))
 }
 
-fn create_progress_bar() -> ProgressBar {
-    let pb = ProgressBar::new(8);
-    pb.set_style(
-        ProgressStyle::default_bar()
-            .template("  {spinner:.green} {msg}")
-            .unwrap()
-            .tick_chars("⠋⠙⠹⠸⠼⠴⠦⠧⠇⠏"),
-    );
-    pb.enable_steady_tick(Duration::from_millis(80));
-    pb
+/// Ask for models, each a name and its fields, until the name is left empty
+fn prompt_models() -> Result<Vec<DomainModel>> {
+    let mut models: Vec<DomainModel> = Vec::new();
+    if !Confirm::new()
+        .with_prompt(Msg::DefineModels.text())
+        .default(false)
+        .interact()?
+    {
+        return Ok(models);
+    }
+
+    loop {
+        let taken: Vec<String> = models
+            .iter()
+            .map(|model| naming::pascal_case(&model.name))
+            .collect();
+        let name: String = Input::new()
+            .with_prompt(Msg::ModelName.text())
+            .allow_empty(true)
+            .validate_with(|name: &String| -> Result<(), String> {
+                let name = name.trim();
+                if name.is_empty() {
+                    return Ok(());
+                }
+                if taken.contains(&naming::pascal_case(name)) {
+                    return Err(format!("{} is defined already", naming::pascal_case(name)));
+                }
+                resource::validate_name(name).map_err(|e| e.to_string())
+            })
+            .interact_text()?;
+        let name = name.trim().to_string();
+        if name.is_empty() {
+            return Ok(models);
+        }
+
+        let fields: String = Input::new()
+            .with_prompt(Msg::ModelFields.fill(&[&naming::pascal_case(&name)]))
+            .validate_with(|fields: &String| -> Result<(), String> {
+                resource::validate(&name, &split_fields(fields)).map_err(|e| e.to_string())
+            })
+            .interact_text()?;
+        models.push(DomainModel {
+            fields: split_fields(&fields),
+            name,
+        });
+    }
+}
+
+/// Fields typed as one line, separated by spaces or commas
+fn split_fields(input: &str) -> Vec<String> {
+    input
+        .split([' ', ','])
+        .filter(|field| !field.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Last component of `dir`, or of the current directory for `.`
+fn directory_name(dir: &str) -> String {
+    let path = if dir == "." {
+        std::env::current_dir().unwrap_or_default()
+    } else {
+        PathBuf::from(dir)
+    };
+    path.file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default()
 }
 
-fn print_success(name: &str, ai_enabled: bool, ui_enabled: bool, restate_enabled: bool, cmd_enabled: bool) {
+/// Quote a path for the `cd` hint when it has spaces or shell metacharacters
+fn shell_quote(path: &str) -> String {
+    if path
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || "-_./@~+".contains(c))
+    {
+        path.to_string()
+    } else {
+        format!("'{}'", path.replace('\'', "'\\''"))
+    }
+}
+
+fn print_success(
+    options: &ScaffoldOptions,
+    dir: &str,
+    migration_generated: bool,
+    database_ready: bool,
+) {
     println!();
-    println!("  {} Project created successfully!", style("✓").green().bold());
+    println!(
+        "  {} {}",
+        style(glyph("✓")).green().bold(),
+        Msg::ProjectCreated
+    );
     println!();
-    println!("  Next steps:");
+    println!("  {}", Msg::NextSteps);
     println!();
 
-    if name != "." {
-        println!("    {} {}", style("cd").cyan(), name);
+    if dir != "." {
+        println!("    {} {}", style("cd").cyan(), shell_quote(dir));
+    }
+    if options.nix {
+        println!("    {} allow", style("direnv").cyan());
+    }
+    // --setup-db already ran these
+    if !database_ready {
+        println!("    {} install", style("npm").cyan());
+        if migration_generated {
+            println!("    {} run db:migrate", style("npm").cyan());
+        } else if options.migrations {
+            println!("    {} run db:migrate -- --name init", style("npm").cyan());
+        } else {
+            println!("    {} prisma db push", style("npx").cyan());
+        }
     }
-    println!("    {} {}", style("npm").cyan(), "install");
-    println!("    {} {}", style("npx").cyan(), "prisma db push");
-    println!("    {} {}", style("npm").cyan(), "run dev");
+    if options.seed {
+        println!("    {} run db:seed", style("npm").cyan());
+    }
+    println!("    {} run dev", style("npm").cyan());
 
-    if restate_enabled {
+    if options.restate {
         println!();
-        println!("  For Restate:");
-        println!("    {} {}", style("cd").cyan(), "restate && docker-compose up -d");
-        println!("    {} {}", style("cd").cyan(), "services && npm install && npm run dev");
+        println!("  {}", Msg::ForRestate);
+        println!("    {} run restate:up", style("npm").cyan());
+        println!(
+            "    {} --prefix restate/services install",
+            style("npm").cyan()
+        );
+        println!("    {} run restate:dev", style("npm").cyan());
+        println!("    {} run restate:register", style("npm").cyan());
+        if options.restate_kafka {
+            println!("    {} run restate:subscribe", style("npm").cyan());
+        }
     }
     println!();
 
-    if ai_enabled || ui_enabled || restate_enabled || cmd_enabled {
-        println!("  Included extensions:");
-        if ai_enabled {
-            println!("    {} AI agents in {}", style("•").dim(), style("src/components/ai/").yellow());
+    if options.ai
+        || options.ui
+        || options.restate
+        || options.cmd
+        || options.example
+        || !options.models.is_empty()
+    {
+        println!("  {}", Msg::IncludedExtensions);
+        if options.ai {
+            println!(
+                "    {} {}",
+                style(glyph("•")).dim(),
+                Msg::AiAgentsIn.fill(&[&style("src/components/ai/").yellow()])
+            );
+        }
+        if options.ui {
+            println!(
+                "    {} {}",
+                style(glyph("•")).dim(),
+                Msg::UiComponentsIn.fill(&[&style("src/components/ui/").yellow()])
+            );
         }
-        if ui_enabled {
-            println!("    {} UI components in {}", style("•").dim(), style("src/components/ui/").yellow());
+        if options.analytics {
+            println!(
+                "    {} {}",
+                style(glyph("•")).dim(),
+                Msg::AnalyticsAt.fill(&[&style("/dashboard/analytics").yellow()])
+            );
         }
-        if restate_enabled {
-            println!("    {} Restate workflows in {}", style("•").dim(), style("restate/").yellow());
+        if options.restate {
+            println!(
+                "    {} {}",
+                style(glyph("•")).dim(),
+                Msg::RestateIn.fill(&[&style("restate/").yellow()])
+            );
         }
-        if cmd_enabled {
-            println!("    {} CommandIsland AI layer in {}", style("•").dim(), style("src/components/{chat,tables,docs,layout}/").yellow());
-            println!("    {} tRPC routers in {}", style("•").dim(), style("src/server/api/routers/{chat,tables,docs}.ts").yellow());
-            println!("    {} Claude skill in {}", style("•").dim(), style(".claude/skills/commandisland.md").yellow());
+        if options.cmd {
+            println!(
+                "    {} {}",
+                style(glyph("•")).dim(),
+                Msg::CmdIn.fill(&[&style("src/components/{chat,tables,docs,layout}/").yellow()])
+            );
+            println!(
+                "    {} {}",
+                style(glyph("•")).dim(),
+                Msg::RoutersIn
+                    .fill(&[&style("src/server/api/routers/{chat,tables,docs}.ts").yellow()])
+            );
+            println!(
+                "    {} {}",
+                style(glyph("•")).dim(),
+                Msg::CmdSettingsIn.fill(&[&style("src/server/chat/config.ts").yellow()])
+            );
+            println!(
+                "    {} {}",
+                style(glyph("•")).dim(),
+                Msg::SkillIn.fill(&[&style(".claude/skills/commandisland.md").yellow()])
+            );
+            if options.cmd_stream == Some(ChatStream::Websocket) {
+                println!(
+                    "    {} {}",
+                    style(glyph("•")).dim(),
+                    Msg::ChatSocket.fill(&[
+                        &style("server.ts").yellow(),
+                        &style("npm run dev").cyan(),
+                        &style("npm start").cyan()
+                    ])
+                );
+            }
         }
+        if options.example {
+            println!(
+                "    {} {}",
+                style(glyph("•")).dim(),
+                Msg::ExampleRouterIn.fill(&[&style("src/server/api/routers/post.ts").yellow()])
+            );
+            println!(
+                "    {} {}",
+                style(glyph("•")).dim(),
+                Msg::ExamplePageAt.fill(&[&style("/posts").yellow()])
+            );
+        }
+        if options.rest {
+            println!(
+                "    {} {}",
+                style(glyph("•")).dim(),
+                Msg::RestAt.fill(&[
+                    &style("/api/v1/posts").yellow(),
+                    &style("API_KEYS").yellow()
+                ])
+            );
+            println!(
+                "    {} {}",
+                style(glyph("•")).dim(),
+                Msg::ApiReferenceAt.fill(&[&style("/api-docs").yellow()])
+            );
+            println!(
+                "    {} {}",
+                style(glyph("•")).dim(),
+                Msg::CorsFrom.fill(&[&style("CORS_ALLOWED_ORIGINS").yellow()])
+            );
+        }
+        if !options.models.is_empty() {
+            let routes: Vec<String> = options
+                .models
+                .iter()
+                .map(|model| format!("/{}", naming::pluralize(&naming::kebab_case(&model.name))))
+                .collect();
+            println!(
+                "    {} {}",
+                style(glyph("•")).dim(),
+                Msg::ModelPagesAt.fill(&[&style(routes.join(", ")).yellow()])
+            );
+        }
+        println!();
+    }
+
+    if !options.locales.is_empty() {
+        let files: Vec<String> = options
+            .locales
+            .iter()
+            .map(|code| format!("messages/{}.json", code))
+            .collect();
+        println!(
+            "  {}",
+            Msg::TranslateTodos.fill(&[&style("TODO:").yellow(), &style(files.join(", ")).cyan()])
+        );
         println!();
     }
 
     println!(
         "  {} {}",
-        style("Docs:").dim(),
+        style(Msg::Docs).dim(),
         style("https://github.com/elijahross/boilerplate_moduls").underlined()
     );
     println!();
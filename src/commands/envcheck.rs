@@ -0,0 +1,184 @@
+use anyhow::Result;
+use console::style;
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::scaffolding::env::{self, EnvScope, SchemaEntry};
+use crate::utils::output::glyph;
+
+/// What is wrong with one variable
+enum Problem {
+    Missing,
+    Empty,
+    Invalid(String),
+}
+
+/// `t3-mono envcheck [--file <FILE>]`: check a dotenv file, or the files `next dev` loads,
+/// against the schema in `src/env.js`
+pub fn execute(file: Option<&str>) -> Result<()> {
+    let schema = env::read_schema(".")?;
+    let (file, values): (String, HashMap<String, String>) = match file {
+        Some(file) => {
+            let path = Path::new(file);
+            if !path.exists() {
+                anyhow::bail!(
+                    "No {} found; copy .env.example to {} and fill it in",
+                    file,
+                    file
+                );
+            }
+            (
+                file.to_string(),
+                env::read_dotenv(path)?.into_iter().collect(),
+            )
+        }
+        None => {
+            let (found, values) = env::read_development(".")?;
+            if found.is_empty() {
+                anyhow::bail!("No env files found; copy .env.example to .env.local and fill it in");
+            }
+            (found.join(" + "), values)
+        }
+    };
+
+    println!();
+    println!(
+        "  {} {} against src/env.js...",
+        style("Checking").cyan().bold(),
+        file
+    );
+    println!();
+
+    // The schema may switch on NODE_ENV, as the Better Auth secret does for production
+    let production = values
+        .get("NODE_ENV")
+        .is_some_and(|value| value == "production");
+
+    let mut valid = 0;
+    let mut problems = Vec::new();
+    for entry in &schema {
+        match check(
+            entry,
+            values.get(&entry.name).map(String::as_str),
+            production,
+        ) {
+            Some(problem) => problems.push((entry, problem)),
+            None => valid += 1,
+        }
+    }
+
+    println!(
+        "  {} {} of {} variables valid",
+        style(glyph("✓")).green().bold(),
+        valid,
+        schema.len()
+    );
+    if problems.is_empty() {
+        println!();
+        return Ok(());
+    }
+
+    println!();
+    for (entry, problem) in &problems {
+        let scope = match entry.scope {
+            EnvScope::Server => "server",
+            EnvScope::Client => "client",
+        };
+        let (marker, message) = match problem {
+            Problem::Missing => (style("-").red(), "missing".to_string()),
+            Problem::Empty => (style("!").yellow(), "empty".to_string()),
+            Problem::Invalid(reason) => (style("!").yellow(), reason.clone()),
+        };
+        println!(
+            "    {} {} {}",
+            marker.bold(),
+            entry.name,
+            style(format!("{} ({})", message, scope)).dim()
+        );
+    }
+    println!();
+
+    anyhow::bail!(
+        "{} of {} variables in {} need attention",
+        problems.len(),
+        schema.len(),
+        file
+    )
+}
+
+/// Check `value` against the parts of the Zod schema t3-mono generates: optional and
+/// defaulted values, URLs, emails, numbers, enums, and minimum lengths
+fn check(entry: &SchemaEntry, value: Option<&str>, production: bool) -> Option<Problem> {
+    let schema = branch(&entry.schema, production);
+    let optional = schema.contains(".optional()") || schema.contains(".default(");
+
+    // env.js treats empty strings as unset
+    let value = match value {
+        None if optional => return None,
+        None => return Some(Problem::Missing),
+        Some("") if optional => return None,
+        Some("") => return Some(Problem::Empty),
+        Some(value) => value,
+    };
+
+    if schema.contains(".url()")
+        && !reqwest::Url::parse(value).is_ok_and(|url| url.has_host() || url.scheme() == "file")
+    {
+        return Some(Problem::Invalid("not a URL".to_string()));
+    }
+    if schema.contains(".email()") && !value.contains('@') {
+        return Some(Problem::Invalid("not an email address".to_string()));
+    }
+    if schema.contains("number()") && value.parse::<f64>().is_err() {
+        return Some(Problem::Invalid("not a number".to_string()));
+    }
+    if let Some(allowed) = enum_values(schema) {
+        if !allowed.contains(&value) {
+            return Some(Problem::Invalid(format!(
+                "must be one of {}",
+                allowed.join(", ")
+            )));
+        }
+    }
+    if let Some(min) = min_length(schema) {
+        if value.chars().count() < min {
+            return Some(Problem::Invalid(format!("shorter than {} characters", min)));
+        }
+    }
+
+    None
+}
+
+/// `cond === "production" ? a : b` picks a branch; other schemas are used as they are
+fn branch(schema: &str, production: bool) -> &str {
+    let Some((condition, branches)) = schema.split_once(" ? ") else {
+        return schema;
+    };
+    let Some((when_true, when_false)) = branches.split_once(" : ") else {
+        return schema;
+    };
+    if condition.contains("\"production\"") == production {
+        when_true
+    } else {
+        when_false
+    }
+}
+
+/// The values of a `z.enum([...])`
+fn enum_values(schema: &str) -> Option<Vec<&str>> {
+    let start = schema.find("z.enum([")? + "z.enum([".len();
+    let end = start + schema[start..].find("])")?;
+    Some(
+        schema[start..end]
+            .split(',')
+            .map(|value| value.trim().trim_matches('"').trim_matches('\''))
+            .collect(),
+    )
+}
+
+/// The `N` of a `.min(N)`
+fn min_length(schema: &str) -> Option<usize> {
+    let start = schema.find(".min(")? + ".min(".len();
+    let end = start + schema[start..].find(')')?;
+    schema[start..end].trim().parse().ok()
+}
@@ -0,0 +1,256 @@
+use anyhow::Result;
+use console::style;
+use std::path::Path;
+
+use crate::cli::{AgentTool, ComponentVariant, WorkflowPattern};
+use crate::scaffolding::{agent, component, locale, page, resource, workflow};
+use crate::utils::naming;
+use crate::utils::output::glyph;
+
+/// Generators write into the project in the current directory
+fn ensure_project_root() -> Result<()> {
+    if !Path::new("package.json").exists() {
+        anyhow::bail!("No package.json found. Run this command from the root of your project.");
+    }
+    Ok(())
+}
+
+fn ensure_valid_name(name: &str) -> Result<()> {
+    if !naming::is_valid_name(name) {
+        anyhow::bail!(
+            "Invalid name '{}'; use letters, digits, '-' or '_', starting with a letter",
+            name
+        );
+    }
+    Ok(())
+}
+
+fn print_files(files: &[String]) {
+    for file in files {
+        println!(
+            "  {} {}",
+            style(glyph("✓")).green().bold(),
+            style(file).yellow()
+        );
+    }
+}
+
+/// `t3-mono generate resource <Name> field:type...`
+pub fn resource(name: &str, fields: &[String]) -> Result<()> {
+    ensure_project_root()?;
+    ensure_valid_name(name)?;
+
+    println!();
+    println!(
+        "  {} {} resource...",
+        style("Generating").cyan().bold(),
+        style(naming::pascal_case(name)).white().bold()
+    );
+    println!();
+
+    let generated = resource::generate(".", name, fields)?;
+    print_files(&generated.files);
+
+    let sync = if Path::new("prisma/migrations").exists() {
+        format!(
+            "npm run db:migrate -- --name add_{}",
+            naming::kebab_case(&generated.model).replace('-', "_")
+        )
+    } else {
+        "npm run db:push".to_string()
+    };
+    println!();
+    println!(
+        "  Apply the {} model with {}",
+        style(&generated.model).yellow(),
+        style(sync).cyan()
+    );
+    println!();
+
+    Ok(())
+}
+
+/// `t3-mono generate component <name> --variant card|dialog|form`
+pub fn component(name: &str, variant: ComponentVariant) -> Result<()> {
+    ensure_project_root()?;
+    ensure_valid_name(name)?;
+
+    println!();
+    println!(
+        "  {} {} component...",
+        style("Generating").cyan().bold(),
+        style(naming::pascal_case(name)).white().bold()
+    );
+    println!();
+
+    let path = component::generate(".", name, variant)?;
+    print_files(&[path, "src/components/ui/index.ts".to_string()]);
+    println!();
+    println!(
+        "  Import it with {}",
+        style(format!(
+            "import {{ {} }} from \"@/components/ui\"",
+            naming::pascal_case(name)
+        ))
+        .cyan()
+    );
+    println!();
+
+    Ok(())
+}
+
+/// `t3-mono generate agent <name> --tools db,search`
+pub fn agent(name: &str, tools: &[AgentTool]) -> Result<()> {
+    ensure_project_root()?;
+    ensure_valid_name(name)?;
+
+    println!();
+    println!(
+        "  {} {} agent...",
+        style("Generating").cyan().bold(),
+        style(naming::kebab_case(name)).white().bold()
+    );
+    println!();
+
+    let files = agent::generate(".", name, tools)?;
+    print_files(&files);
+    println!();
+    println!(
+        "  Fill in {} and call {}",
+        style("SYSTEM_PROMPT").yellow(),
+        style(format!("run{}Agent(input)", naming::pascal_case(name))).cyan()
+    );
+    println!();
+
+    Ok(())
+}
+
+/// `t3-mono generate locale <code> [--translate]`
+pub async fn locale(code: &str, translate: bool) -> Result<()> {
+    ensure_project_root()?;
+    if !locale::is_valid_code(code) {
+        anyhow::bail!(
+            "Invalid locale '{}'; use a language code such as fr or pt-BR",
+            code
+        );
+    }
+
+    println!();
+    println!(
+        "  {} {} locale...",
+        style("Generating").cyan().bold(),
+        style(code).white().bold()
+    );
+    println!();
+
+    let source = locale::source_messages(".")?;
+    let messages = if translate {
+        println!(
+            "  {} Translating messages with Claude...",
+            style(glyph("→")).dim()
+        );
+        locale::machine_translate(".", code, &source).await?
+    } else {
+        locale::todo_messages(&source)
+    };
+
+    let generated = locale::add(".", code, messages)?;
+    print_files(&generated.files);
+    for file in &generated.skipped {
+        println!(
+            "  {} {} does not match the scaffolded layout; add \"{}\" to it by hand",
+            style("!").yellow().bold(),
+            style(file).yellow(),
+            code
+        );
+    }
+    println!();
+    if !translate {
+        println!(
+            "  Translate the {} values in {}",
+            style("TODO:").yellow(),
+            style(format!("messages/{}.json", code)).cyan()
+        );
+        println!();
+    }
+
+    Ok(())
+}
+
+/// `t3-mono generate page <route> [--protected] [--translated]`
+pub fn page(route: &str, protected: bool, translated: bool) -> Result<()> {
+    ensure_project_root()?;
+    let route = page::normalize_route(route)?;
+
+    println!();
+    println!(
+        "  {} {} page...",
+        style("Generating").cyan().bold(),
+        style(format!("/{}", route)).white().bold()
+    );
+    println!();
+
+    let generated = page::generate(".", &route, protected, translated)?;
+    print_files(&generated.files);
+    for file in &generated.skipped {
+        println!(
+            "  {} {} does not match the scaffolded layout; add a nav entry for {} by hand",
+            style("!").yellow().bold(),
+            style(file).yellow(),
+            generated.url
+        );
+    }
+    println!();
+    println!(
+        "  Translate the {} values outside messages/en.json, then open {}",
+        style("TODO:").yellow(),
+        style(format!("http://localhost:3000{}", generated.url)).cyan()
+    );
+    println!();
+
+    Ok(())
+}
+
+/// `t3-mono generate workflow <name> --pattern saga|sequential|human-approval`
+pub fn workflow(name: &str, pattern: WorkflowPattern) -> Result<()> {
+    ensure_project_root()?;
+    ensure_valid_name(name)?;
+
+    println!();
+    println!(
+        "  {} {} workflow...",
+        style("Generating").cyan().bold(),
+        style(format!("{}Workflow", naming::pascal_case(name)))
+            .white()
+            .bold()
+    );
+    println!();
+
+    let generated = workflow::generate(".", name, pattern)?;
+    print_files(&generated.files);
+    for file in &generated.skipped {
+        println!(
+            "  {} {} does not match the scaffolded layout; import and .bind({}) it by hand",
+            style("!").yellow().bold(),
+            style(file).yellow(),
+            generated.service
+        );
+    }
+    println!();
+    println!(
+        "  Fill in the {} step bodies, re-register with {}, then start a run with",
+        style("TODO:").yellow(),
+        style("npm run restate:register -- --force").cyan()
+    );
+    println!(
+        "    {}",
+        style(format!(
+            "curl -X POST http://localhost:8080/{}/<id>/run/send -H 'content-type: application/json' -d '{{...}}'",
+            generated.service
+        ))
+        .cyan()
+    );
+    println!();
+
+    Ok(())
+}
@@ -0,0 +1,304 @@
+use anyhow::{Context, Result};
+use console::style;
+use serde_json::Value;
+use std::time::Duration;
+
+use crate::cli::AuthProvider;
+use crate::scaffolding::options::ScaffoldOptions;
+use crate::scaffolding::{ai, cmd, cms, forms, logging, maintenance, privacy, search, t3, ui};
+use crate::templates::{embedded, remote};
+
+/// Node.js major the generated CI workflow, app Dockerfile, and Nix shell use
+const NODE_VERSION: &str = "22";
+
+/// What one extension adds to a project
+struct Extension {
+    name: &'static str,
+    summary: &'static str,
+    /// Directory fetched from boilerplate_moduls instead of the embedded templates
+    remote_templates: Option<&'static str>,
+    dependencies: Vec<(String, String)>,
+    dev_dependencies: Vec<(String, String)>,
+    note: Option<String>,
+}
+
+/// `t3-mono info [extension]`
+pub fn execute(extension: Option<&str>) -> Result<()> {
+    let base = t3::package_json(&ScaffoldOptions::default());
+    let extensions = extensions(&base)?;
+
+    println!();
+    println!(
+        "  {} {}",
+        style("t3-mono").cyan().bold(),
+        env!("CARGO_PKG_VERSION")
+    );
+    println!();
+
+    println!("  {}", style("Targets").bold());
+    let version = |name: &str| {
+        let version = base["dependencies"][name]
+            .as_str()
+            .or(base["devDependencies"][name].as_str());
+        version.unwrap_or("-").to_string()
+    };
+    print_rows(&[
+        ("Node.js".to_string(), NODE_VERSION.to_string()),
+        ("Next.js".to_string(), version("next")),
+        ("React".to_string(), version("react")),
+        ("Prisma".to_string(), version("prisma")),
+        ("TypeScript".to_string(), version("typescript")),
+        ("Tailwind CSS".to_string(), version("tailwindcss")),
+    ]);
+    println!();
+
+    println!("  {}", style("Templates").bold());
+    let mut rows = vec![(
+        "embedded".to_string(),
+        format!(
+            "everything else, as of t3-mono {}",
+            env!("CARGO_PKG_VERSION")
+        ),
+    )];
+    for remote_path in extensions
+        .iter()
+        .filter_map(|extension| extension.remote_templates)
+    {
+        let cached = match remote::cache_age(remote_path) {
+            Some(age) => format!("cached {}", format_age(age)),
+            None => "fetched on use, embedded copy if unreachable".to_string(),
+        };
+        rows.push((
+            remote_path.to_string(),
+            format!("{} ({})", remote::source(), cached),
+        ));
+    }
+    print_rows(&rows);
+
+    for extension in extensions
+        .iter()
+        .filter(|e| extension.is_none_or(|name| name == e.name))
+    {
+        println!();
+        println!(
+            "  {} {}",
+            style(extension.name).cyan().bold(),
+            style(extension.summary).dim()
+        );
+        for (label, deps) in [
+            ("dependencies", &extension.dependencies),
+            ("devDependencies", &extension.dev_dependencies),
+        ] {
+            if deps.is_empty() {
+                continue;
+            }
+            println!("    {}", label);
+            print_rows_indented(deps, 6);
+        }
+        if extension.dependencies.is_empty() && extension.dev_dependencies.is_empty() {
+            println!("    {}", style("no npm dependencies").dim());
+        }
+        if let Some(note) = &extension.note {
+            println!("    {}", style(note).dim());
+        }
+    }
+    println!();
+
+    Ok(())
+}
+
+fn extensions(base: &Value) -> Result<Vec<Extension>> {
+    let next_auth = t3::package_json(&ScaffoldOptions {
+        auth: AuthProvider::NextAuth,
+        ..ScaffoldOptions::default()
+    });
+    let next_auth_only: Vec<String> = section(&next_auth, "dependencies")
+        .into_iter()
+        .filter(|(name, _)| base["dependencies"].get(name).is_none())
+        .map(|(name, version)| format!("{} {}", name, version))
+        .collect();
+
+    let restate = embedded::get_template("restate/services/package.json")
+        .context("Missing embedded template restate/services/package.json")?;
+    let restate: Value = serde_json::from_str(&restate)?;
+
+    Ok(vec![
+        Extension {
+            name: "base",
+            summary: "T3 stack with Better Auth (always included)",
+            remote_templates: None,
+            dependencies: section(base, "dependencies"),
+            dev_dependencies: section(base, "devDependencies"),
+            note: Some(format!(
+                "--auth next-auth swaps better-auth for {}",
+                next_auth_only.join(", ")
+            )),
+        },
+        Extension {
+            name: "ai",
+            summary: "LangChain agents framework",
+            remote_templates: Some("agents/core"),
+            dependencies: pairs(ai::AI_DEPENDENCIES),
+            dev_dependencies: Vec::new(),
+            note: None,
+        },
+        Extension {
+            name: "ui",
+            summary: "UI component library",
+            remote_templates: Some("ui"),
+            dependencies: pairs(ui::UI_DEPENDENCIES),
+            dev_dependencies: Vec::new(),
+            note: None,
+        },
+        Extension {
+            name: "forms",
+            summary: "react-hook-form helpers wired to the UI kit",
+            remote_templates: None,
+            dependencies: pairs(forms::FORMS_DEPENDENCIES),
+            dev_dependencies: Vec::new(),
+            note: None,
+        },
+        Extension {
+            name: "cms",
+            summary: "Headless CMS content fetchers and blog pages",
+            remote_templates: None,
+            dependencies: pairs(cms::SANITY_DEPENDENCIES),
+            dev_dependencies: Vec::new(),
+            note: Some(
+                "Dependencies are for --provider sanity; payload is read over its REST API"
+                    .to_string(),
+            ),
+        },
+        Extension {
+            name: "compliance",
+            summary: "Cookie consent, consent-gated analytics, privacy and imprint pages",
+            remote_templates: None,
+            dependencies: Vec::new(),
+            dev_dependencies: Vec::new(),
+            note: None,
+        },
+        Extension {
+            name: "logging",
+            summary: "Shared pino logger with tRPC request logging",
+            remote_templates: None,
+            dependencies: pairs(logging::LOGGING_DEPENDENCIES),
+            dev_dependencies: pairs(logging::LOGGING_DEV_DEPENDENCIES),
+            note: None,
+        },
+        Extension {
+            name: "perf",
+            summary: "tRPC procedure timing with Prisma query counts and slow-query warnings",
+            remote_templates: None,
+            dependencies: Vec::new(),
+            dev_dependencies: Vec::new(),
+            note: None,
+        },
+        Extension {
+            name: "maintenance",
+            summary: "Runtime flags in the database and a localized maintenance page",
+            remote_templates: None,
+            dependencies: Vec::new(),
+            dev_dependencies: pairs(maintenance::MAINTENANCE_DEV_DEPENDENCIES),
+            note: None,
+        },
+        Extension {
+            name: "privacy",
+            summary: "Personal data export and account deletion with a grace period",
+            remote_templates: None,
+            dependencies: Vec::new(),
+            dev_dependencies: pairs(privacy::PRIVACY_DEV_DEPENDENCIES),
+            note: None,
+        },
+        Extension {
+            name: "restate",
+            summary: "Restate durable workflow services",
+            remote_templates: None,
+            dependencies: section(&restate, "dependencies"),
+            dev_dependencies: section(&restate, "devDependencies"),
+            note: Some("Installed in restate/services/package.json, not the app".to_string()),
+        },
+        Extension {
+            name: "cmd",
+            summary: "CommandIsland AI layer",
+            remote_templates: None,
+            dependencies: pairs(cmd::CMD_DEPENDENCIES),
+            dev_dependencies: pairs(cmd::CMD_DEV_DEPENDENCIES),
+            note: None,
+        },
+        Extension {
+            name: "webhooks",
+            summary: "Signed webhook delivery with retries and endpoint settings",
+            remote_templates: None,
+            dependencies: Vec::new(),
+            dev_dependencies: Vec::new(),
+            note: Some(
+                "Retries run through Restate when the restate extension is installed".to_string(),
+            ),
+        },
+        Extension {
+            name: "search",
+            summary:
+                "Full-text search with Postgres, Meilisearch, or Typesense and a command palette",
+            remote_templates: None,
+            dependencies: Vec::new(),
+            dev_dependencies: pairs(search::SEARCH_DEV_DEPENDENCIES),
+            note: Some(
+                "Meilisearch and Typesense are called over REST, so they need no packages"
+                    .to_string(),
+            ),
+        },
+    ])
+}
+
+fn pairs(deps: &[(&str, &str)]) -> Vec<(String, String)> {
+    deps.iter()
+        .map(|(name, version)| (name.to_string(), version.to_string()))
+        .collect()
+}
+
+fn section(pkg: &Value, key: &str) -> Vec<(String, String)> {
+    pkg[key]
+        .as_object()
+        .map(|deps| {
+            deps.iter()
+                .map(|(name, version)| {
+                    (
+                        name.clone(),
+                        version.as_str().unwrap_or_default().to_string(),
+                    )
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn print_rows(rows: &[(String, String)]) {
+    print_rows_indented(rows, 4);
+}
+
+fn print_rows_indented(rows: &[(String, String)], indent: usize) {
+    let width = rows
+        .iter()
+        .map(|(name, _)| name.len())
+        .max()
+        .unwrap_or_default();
+    for (name, value) in rows {
+        println!(
+            "{}{:<width$}  {}",
+            " ".repeat(indent),
+            name,
+            value,
+            width = width
+        );
+    }
+}
+
+fn format_age(age: Duration) -> String {
+    let minutes = age.as_secs() / 60;
+    match minutes {
+        0 => "just now".to_string(),
+        1..=59 => format!("{}m ago", minutes),
+        60..=1439 => format!("{}h ago", minutes / 60),
+        _ => format!("{}d ago", minutes / 1440),
+    }
+}
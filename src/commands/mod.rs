@@ -1,2 +1,14 @@
 pub mod add;
+pub mod config;
 pub mod create;
+pub mod envcheck;
+pub mod generate;
+pub mod info;
+pub mod rename;
+pub mod restate;
+pub mod status;
+pub mod switch;
+pub mod switch_auth;
+pub mod switch_db;
+pub mod sync;
+pub mod telemetry;
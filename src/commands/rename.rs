@@ -0,0 +1,218 @@
+use anyhow::{Context, Result};
+use console::style;
+use serde_json::Value;
+use std::path::Path;
+
+use crate::scaffolding::{database, i18n, manifest};
+use crate::utils::fs::update_file;
+use crate::utils::output::glyph;
+use crate::utils::{naming, npm};
+
+const SITE_CONFIG_PATH: &str = "src/lib/site.ts";
+const COMPOSE_PATH: &str = "docker-compose.yml";
+
+/// `t3-mono rename <name> [--title <TITLE>]`: rebrand the project in the current directory
+pub fn execute(name: &str, title: Option<&str>) -> Result<()> {
+    let mut manifest = manifest::read(".")?;
+    let old_name = manifest.options.name.clone();
+    let new_name = naming::package_name(name);
+    let new_title = title.map_or_else(|| naming::title_case(&new_name), str::to_string);
+    let old_title = site_name().unwrap_or_else(|| "My App".to_string());
+
+    println!();
+    println!(
+        "  {} {} {}",
+        style("Renaming").cyan().bold(),
+        style(&old_name).white().bold(),
+        style(format!("to {} ({})", new_name, new_title)).dim()
+    );
+    println!();
+
+    // Files the templates derive from the project name; re-rendering with the new name gives
+    // the same content, so unmodified ones stay unmodified in `status`
+    let mut derived = Vec::new();
+    let mut pkg = npm::read_package_json(".")?;
+    pkg["name"] = Value::String(new_name.clone());
+    derived.push((
+        "package.json".to_string(),
+        serde_json::to_string_pretty(&pkg)?,
+    ));
+    for path in manifest
+        .files
+        .keys()
+        .filter(|path| path.starts_with("docs/") && path.ends_with(".md"))
+    {
+        let Ok(content) = std::fs::read_to_string(path) else {
+            continue;
+        };
+        let renamed = content
+            .replacen(
+                &format!("# {}\n", old_name),
+                &format!("# {}\n", new_name),
+                1,
+            )
+            .replace(&format!("`{}`", old_name), &format!("`{}`", new_name));
+        derived.push((path.clone(), renamed));
+    }
+
+    // Branding the templates write as "My App"; these read as your edits afterwards
+    let mut branded = Vec::new();
+    for (path, quoted) in [
+        (SITE_CONFIG_PATH, true),
+        ("src/app/_components/Header.tsx", false),
+        ("src/app/_components/Header.test.tsx", true),
+    ] {
+        let Ok(content) = std::fs::read_to_string(path) else {
+            continue;
+        };
+        // A string literal, or the JSX text on a line of its own
+        let (from, to) = if quoted {
+            (format!("\"{}\"", old_title), format!("\"{}\"", new_title))
+        } else {
+            (format!(" {}\n", old_title), format!(" {}\n", new_title))
+        };
+        branded.push((path.to_string(), content.replace(&from, &to)));
+    }
+    if let Ok(content) = std::fs::read_to_string(COMPOSE_PATH) {
+        let slug = new_name.rsplit('/').next().unwrap_or(&new_name).to_string();
+        let suffix = database::container_suffix(manifest.options.database);
+        let content = set_default(
+            &content,
+            "DB_CONTAINER_NAME",
+            &format!("{}-{}", slug, suffix),
+        );
+        branded.push((
+            COMPOSE_PATH.to_string(),
+            set_default(&content, "APP_CONTAINER_NAME", &slug),
+        ));
+    }
+
+    let mut changed = 0;
+    for (path, content) in &derived {
+        let previous = std::fs::read(path).unwrap_or_default();
+        if previous == content.as_bytes() {
+            continue;
+        }
+        if manifest
+            .files
+            .get(path)
+            .is_some_and(|hash| *hash == manifest::hash(&previous))
+        {
+            manifest
+                .files
+                .insert(path.clone(), manifest::hash(content.as_bytes()));
+        }
+        update_file(".", path, content)?;
+        println!(
+            "  {} {}",
+            style(glyph("✓")).green().bold(),
+            style(path).yellow()
+        );
+        changed += 1;
+    }
+    for (path, content) in &branded {
+        if std::fs::read_to_string(path).is_ok_and(|previous| previous == *content) {
+            continue;
+        }
+        update_file(".", path, content)?;
+        println!(
+            "  {} {}",
+            style(glyph("✓")).green().bold(),
+            style(path).yellow()
+        );
+        changed += 1;
+    }
+    for file in i18n::locale_files(".").unwrap_or_default() {
+        if rename_in_messages(&file, &old_title, &new_title)? {
+            println!(
+                "  {} {}",
+                style(glyph("✓")).green().bold(),
+                style(&file).yellow()
+            );
+            changed += 1;
+        }
+    }
+
+    manifest.options.name = new_name.clone();
+    manifest::write(".", &manifest)?;
+
+    println!();
+    println!("  Updated {} files", changed);
+    let directory = std::env::current_dir()?;
+    if directory
+        .file_name()
+        .is_some_and(|dir| dir.to_string_lossy() == old_name)
+    {
+        println!(
+            "  The directory keeps its name; rename it with {}",
+            style(format!(
+                "cd .. && mv {} {}",
+                old_name,
+                new_name.rsplit('/').next().unwrap_or(&new_name)
+            ))
+            .cyan()
+        );
+    }
+    if Path::new(COMPOSE_PATH).exists() {
+        println!(
+            "  Recreate the containers under their new names with {}",
+            style("docker compose up -d --force-recreate").cyan()
+        );
+    }
+    println!();
+
+    Ok(())
+}
+
+/// The `name` in `src/lib/site.ts`, which the layout title and OpenGraph cards use
+fn site_name() -> Option<String> {
+    let content = std::fs::read_to_string(SITE_CONFIG_PATH).ok()?;
+    let start = content.find("  name: \"")? + "  name: \"".len();
+    let end = start + content[start..].find('"')?;
+    Some(content[start..end].to_string())
+}
+
+/// Replace the default in a compose `${VAR:-default}`
+fn set_default(content: &str, var: &str, value: &str) -> String {
+    let prefix = format!("${{{}:-", var);
+    let Some(start) = content.find(&prefix).map(|i| i + prefix.len()) else {
+        return content.to_string();
+    };
+    let Some(end) = content[start..].find('}').map(|i| start + i) else {
+        return content.to_string();
+    };
+    format!("{}{}{}", &content[..start], value, &content[end..])
+}
+
+/// Replace the old title in every message of a `messages/*.json` file; returns whether any changed
+fn rename_in_messages(file: &str, from: &str, to: &str) -> Result<bool> {
+    let content =
+        std::fs::read_to_string(file).with_context(|| format!("Failed to read {}", file))?;
+    let mut messages: Value =
+        serde_json::from_str(&content).with_context(|| format!("{} is not valid JSON", file))?;
+    if !replace_strings(&mut messages, from, to) {
+        return Ok(false);
+    }
+    update_file(".", file, &serde_json::to_string_pretty(&messages)?)?;
+    Ok(true)
+}
+
+fn replace_strings(value: &mut Value, from: &str, to: &str) -> bool {
+    match value {
+        Value::String(text) if text.contains(from) => {
+            *text = text.replace(from, to);
+            true
+        }
+        Value::Object(map) => replace_all(map.values_mut(), from, to),
+        Value::Array(items) => replace_all(items.iter_mut(), from, to),
+        _ => false,
+    }
+}
+
+fn replace_all<'a>(values: impl Iterator<Item = &'a mut Value>, from: &str, to: &str) -> bool {
+    let mut changed = false;
+    for value in values {
+        changed |= replace_strings(value, from, to);
+    }
+    changed
+}
@@ -0,0 +1,202 @@
+use anyhow::{Context, Result};
+use console::style;
+use reqwest::Client;
+use std::time::Duration;
+
+use crate::utils::output::{glyph, Progress};
+
+/// How often to poll the admin API before giving up on Restate coming up
+const HEALTH_ATTEMPTS: u32 = 15;
+/// How often to retry the registration request itself
+const REGISTER_ATTEMPTS: u32 = 5;
+const RETRY_DELAY: Duration = Duration::from_secs(2);
+
+/// Register a services deployment with the Restate admin API
+pub async fn register(uri: &str, admin_url: &str, force: bool) -> Result<()> {
+    let client = Client::builder()
+        .timeout(Duration::from_secs(30))
+        .build()
+        .context("Failed to create HTTP client")?;
+    let admin_url = admin_url.trim_end_matches('/');
+
+    println!();
+    println!(
+        "  {} {} {}",
+        style("Registering").cyan().bold(),
+        style(uri).white().bold(),
+        style(format!("with {}", admin_url)).dim()
+    );
+    println!();
+
+    let pb = Progress::spinner();
+
+    pb.set_message("Waiting for the Restate admin API...");
+    wait_for_admin(&client, admin_url).await?;
+
+    pb.set_message("Registering deployment...");
+    let response = register_deployment(&client, admin_url, uri, force).await?;
+
+    pb.finish_and_clear();
+
+    println!(
+        "  {} Deployment registered",
+        style(glyph("✓")).green().bold()
+    );
+
+    let services = response["services"].as_array().cloned().unwrap_or_default();
+    if !services.is_empty() {
+        println!();
+        println!("  Discovered services:");
+        for service in services {
+            if let Some(name) = service["name"].as_str() {
+                println!("    {} {}", style(glyph("•")).dim(), name);
+            }
+        }
+    }
+    println!();
+
+    Ok(())
+}
+
+/// Subscribe `handler` (`Service/handler`) to a Kafka topic, unless it already is
+pub async fn subscribe(topic: &str, handler: &str, cluster: &str, admin_url: &str) -> Result<()> {
+    if handler.split('/').filter(|part| !part.is_empty()).count() != 2 {
+        anyhow::bail!(
+            "Expected the handler as Service/handler, e.g. OrderEvents/process, not '{}'",
+            handler
+        );
+    }
+    let client = Client::builder()
+        .timeout(Duration::from_secs(30))
+        .build()
+        .context("Failed to create HTTP client")?;
+    let admin_url = admin_url.trim_end_matches('/');
+    let source = format!("kafka://{}/{}", cluster, topic);
+    let sink = format!("service://{}", handler);
+
+    println!();
+    println!(
+        "  {} {} {}",
+        style("Subscribing").cyan().bold(),
+        style(handler).white().bold(),
+        style(format!("to {}", source)).dim()
+    );
+    println!();
+
+    let pb = Progress::spinner();
+
+    pb.set_message("Waiting for the Restate admin API...");
+    wait_for_admin(&client, admin_url).await?;
+
+    let url = format!("{}/subscriptions", admin_url);
+    let filter = reqwest::Url::parse_with_params(&url, [("source", &source), ("sink", &sink)])
+        .with_context(|| format!("Invalid admin URL {}", admin_url))?;
+    let existing: serde_json::Value = client
+        .get(filter)
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .context("Failed to list subscriptions")?
+        .json()
+        .await
+        .context("Failed to parse the Restate admin API response")?;
+    if existing["subscriptions"]
+        .as_array()
+        .is_some_and(|subscriptions| !subscriptions.is_empty())
+    {
+        pb.finish_and_clear();
+        println!("  {} Already subscribed", style(glyph("✓")).green().bold());
+        println!();
+        return Ok(());
+    }
+
+    pb.set_message("Creating subscription...");
+    // A new subscription starts at the beginning of the topic, so earlier records aren't lost
+    let body = serde_json::json!({
+        "source": source,
+        "sink": sink,
+        "options": { "auto.offset.reset": "earliest" },
+    });
+    let response = client
+        .post(&url)
+        .json(&body)
+        .send()
+        .await
+        .context("Failed to create the subscription")?;
+    pb.finish_and_clear();
+    if !response.status().is_success() {
+        let status = response.status();
+        let text = response.text().await.unwrap_or_default();
+        anyhow::bail!(
+            "Failed to subscribe {} to {}: {} {}. Is the {} cluster in restate/restate.toml and the handler registered?",
+            handler,
+            source,
+            status,
+            text.trim(),
+            cluster
+        );
+    }
+
+    println!("  {} Subscribed", style(glyph("✓")).green().bold());
+    println!();
+
+    Ok(())
+}
+
+/// Poll the admin health endpoint until Restate answers
+async fn wait_for_admin(client: &Client, admin_url: &str) -> Result<()> {
+    let url = format!("{}/health", admin_url);
+
+    for attempt in 1..=HEALTH_ATTEMPTS {
+        match client.get(&url).send().await {
+            Ok(response) if response.status().is_success() => return Ok(()),
+            _ if attempt < HEALTH_ATTEMPTS => tokio::time::sleep(RETRY_DELAY).await,
+            _ => {}
+        }
+    }
+
+    anyhow::bail!(
+        "Restate admin API at {} is not reachable. Start it with `npm run restate:up` and try again.",
+        admin_url
+    )
+}
+
+/// POST the deployment, retrying while the services endpoint is still starting up
+async fn register_deployment(
+    client: &Client,
+    admin_url: &str,
+    uri: &str,
+    force: bool,
+) -> Result<serde_json::Value> {
+    let url = format!("{}/deployments", admin_url);
+    let body = serde_json::json!({ "uri": uri, "force": force });
+    let mut last_error = String::new();
+
+    for attempt in 1..=REGISTER_ATTEMPTS {
+        match client.post(&url).json(&body).send().await {
+            Ok(response) if response.status().is_success() => {
+                return response
+                    .json()
+                    .await
+                    .context("Failed to parse the Restate admin API response");
+            }
+            Ok(response) => {
+                let status = response.status();
+                let text = response.text().await.unwrap_or_default();
+                last_error = format!("{} {}", status, text.trim());
+            }
+            Err(e) => last_error = e.to_string(),
+        }
+
+        if attempt < REGISTER_ATTEMPTS {
+            tokio::time::sleep(RETRY_DELAY).await;
+        }
+    }
+
+    anyhow::bail!(
+        "Failed to register {} after {} attempts: {}",
+        uri,
+        REGISTER_ATTEMPTS,
+        last_error
+    )
+}
@@ -0,0 +1,114 @@
+use anyhow::Result;
+use console::style;
+
+use crate::scaffolding::drift::{self, FileDrift, FileState};
+use crate::utils::diff;
+use crate::utils::output::glyph;
+
+/// `t3-mono status [--diff]`
+pub async fn execute(show_diff: bool) -> Result<()> {
+    println!();
+    println!(
+        "  {} project files against the t3-mono {} templates...",
+        style("Checking").cyan().bold(),
+        env!("CARGO_PKG_VERSION")
+    );
+    println!();
+
+    let drift = drift::analyze(".").await?;
+
+    let unchanged = drift.in_state(|state| state == FileState::Unchanged);
+    let updated = drift.in_state(|state| {
+        matches!(
+            state,
+            FileState::TemplateUpdated | FileState::TemplateAdded | FileState::TemplateRemoved
+        )
+    });
+    let modified = drift
+        .in_state(|state| matches!(state, FileState::UserModified { .. } | FileState::Deleted));
+
+    println!(
+        "  {} {} files unchanged {}",
+        style(glyph("✓")).green().bold(),
+        unchanged.len(),
+        style(format!("(created with t3-mono {})", drift.manifest.version)).dim()
+    );
+
+    if !updated.is_empty() {
+        println!();
+        println!(
+            "  Template updates {}:",
+            style("(apply with t3-mono sync)").dim()
+        );
+        for file in &updated {
+            print_file(file);
+        }
+    }
+
+    if !modified.is_empty() {
+        println!();
+        println!("  Modified by you:");
+        for file in &modified {
+            print_file(file);
+        }
+    }
+
+    if show_diff {
+        for file in updated.iter().chain(&modified) {
+            let changed = matches!(
+                file.state,
+                FileState::TemplateUpdated
+                    | FileState::TemplateAdded
+                    | FileState::UserModified {
+                        template_changed: true
+                    }
+            );
+            if !changed {
+                continue;
+            }
+            let current = std::fs::read_to_string(&file.path).unwrap_or_default();
+            let template = String::from_utf8_lossy(&drift.rendered(&file.path)?).to_string();
+            println!();
+            print!(
+                "{}",
+                diff::unified(
+                    &current,
+                    &template,
+                    &file.path,
+                    &format!("{} (template)", file.path)
+                )
+            );
+        }
+    }
+
+    println!();
+    if updated.is_empty() && modified.is_empty() {
+        println!("  Everything matches the current templates.");
+        println!();
+    } else if !show_diff && !updated.is_empty() {
+        println!(
+            "  Run {} to see the template changes",
+            style("t3-mono status --diff").cyan()
+        );
+        println!();
+    }
+
+    Ok(())
+}
+
+fn print_file(file: &FileDrift) {
+    let (marker, note) = match file.state {
+        FileState::TemplateUpdated => (style("~").cyan(), ""),
+        FileState::TemplateAdded => (style("+").green(), " (new)"),
+        FileState::TemplateRemoved => (style("-").red(), " (no longer generated)"),
+        FileState::UserModified {
+            template_changed: true,
+        } => (style("M").yellow(), " (template changed too)"),
+        FileState::UserModified {
+            template_changed: false,
+        } => (style("M").yellow(), ""),
+        FileState::Deleted => (style("D").red(), " (deleted)"),
+        FileState::Unchanged => (style(" ").dim(), ""),
+    };
+    println!("    {} {}{}", marker.bold(), file.path, style(note).dim());
+}
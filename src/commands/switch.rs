@@ -0,0 +1,190 @@
+use anyhow::{Context, Result};
+use console::style;
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use crate::scaffolding::drift;
+use crate::scaffolding::manifest::{self, Manifest};
+use crate::scaffolding::options::ScaffoldOptions;
+use crate::utils::diff;
+use crate::utils::fs::{is_executable, set_executable, write_bytes};
+use crate::utils::output::glyph;
+
+/// What happens to a file both renders write, or that only the new one writes
+enum Change {
+    Write(&'static str, Vec<u8>),
+    Same,
+    /// Kept as it is, with the new version written next to it
+    Conflict,
+}
+
+/// Where `switch-<kind>` copies files before it replaces or removes them
+pub fn backup_dir(kind: &str) -> String {
+    format!(".t3mono-backup/switch-{}", kind)
+}
+
+/// Move the project in the current directory from its manifest options to `target`. The
+/// changes are whatever differs between the project rendered with each: files only the old
+/// options write are removed, untouched files are replaced, edited ones get the change merged
+/// in, and where the edits overlap it the new version is written next to them as `.new`.
+/// `label` names the target in diff headers. Returns how many files conflicted.
+pub async fn apply(
+    manifest: &mut Manifest,
+    target: ScaffoldOptions,
+    kind: &str,
+    label: &str,
+    dry_run: bool,
+    show_diff: bool,
+) -> Result<usize> {
+    let before =
+        drift::render(&manifest.options, manifest.git, &format!("{}-before", kind)).await?;
+    let after = drift::render(&target, manifest.git, &format!("{}-after", kind)).await?;
+    let before_files = manifest::hash_files(&before.path())?;
+    let after_files = manifest::hash_files(&after.path())?;
+    let backup_dir = backup_dir(kind);
+
+    let mut conflicts = 0;
+    let paths: BTreeSet<&String> = before_files.keys().chain(after_files.keys()).collect();
+    for path in paths {
+        let (old_hash, new_hash) = (before_files.get(path), after_files.get(path));
+        if old_hash == new_hash {
+            continue;
+        }
+        let current = std::fs::read(path).ok();
+        // Left as the old options generated it, then or with the current templates
+        let untouched = current
+            .as_deref()
+            .map(manifest::hash)
+            .is_some_and(|hash| old_hash == Some(&hash) || manifest.files.get(path) == Some(&hash));
+
+        let Some(new_hash) = new_hash else {
+            // Only the old options write this file
+            let Some(current) = current else {
+                manifest.files.remove(path);
+                continue;
+            };
+            let note = if untouched {
+                ""
+            } else {
+                " (edited by you; see the backup)"
+            };
+            println!(
+                "  {} Removed {}{}",
+                style("-").red().bold(),
+                style(path).yellow(),
+                style(note).dim()
+            );
+            if !dry_run {
+                write_bytes(&format!("{}/{}", backup_dir, path), &current)?;
+                std::fs::remove_file(path).with_context(|| format!("Failed to remove {}", path))?;
+                remove_empty_parents(path);
+                manifest.files.remove(path);
+            }
+            continue;
+        };
+
+        let new = after.read(path)?;
+        let current_text = current
+            .as_deref()
+            .map(String::from_utf8_lossy)
+            .unwrap_or_default();
+        let change = match (&current, old_hash) {
+            (None, _) => Change::Write("Added", new.clone()),
+            (Some(current), _) if *current == new => Change::Same,
+            (Some(_), Some(_)) if untouched => Change::Write("Updated", new.clone()),
+            (Some(_), Some(_)) => {
+                let old = before.read(path)?;
+                match diff::merge(
+                    &String::from_utf8_lossy(&old),
+                    &String::from_utf8_lossy(&new),
+                    &current_text,
+                ) {
+                    Some(merged) => Change::Write("Merged your edits into", merged.into_bytes()),
+                    None => Change::Conflict,
+                }
+            }
+            // Only the new options write this path, but a file of yours is already there
+            (Some(_), None) => Change::Conflict,
+        };
+
+        match change {
+            Change::Write(verb, content) => {
+                println!(
+                    "  {} {} {}",
+                    style(glyph("✓")).green().bold(),
+                    verb,
+                    style(path).yellow()
+                );
+                if show_diff || dry_run {
+                    print!(
+                        "{}",
+                        diff::unified(
+                            &current_text,
+                            &String::from_utf8_lossy(&content),
+                            path,
+                            &format!("{} ({})", path, label)
+                        )
+                    );
+                }
+                if !dry_run {
+                    if let Some(current) = &current {
+                        write_bytes(&format!("{}/{}", backup_dir, path), current)?;
+                    }
+                    write_bytes(path, &content)?;
+                    // A script only the new options write, e.g. start-database.sh
+                    if is_executable(&Path::new(&after.path()).join(path)) {
+                        set_executable(Path::new(path))?;
+                    }
+                }
+            }
+            Change::Same => {}
+            Change::Conflict => {
+                let new_path = format!("{}.new", path);
+                println!(
+                    "  {} Kept {} {}",
+                    style("!").yellow().bold(),
+                    style(path).yellow(),
+                    style(format!(
+                        "(your edits overlap the change; wrote {} to merge by hand)",
+                        new_path
+                    ))
+                    .dim()
+                );
+                print!(
+                    "{}",
+                    diff::unified(
+                        &current_text,
+                        &String::from_utf8_lossy(&new),
+                        path,
+                        &new_path
+                    )
+                );
+                if !dry_run {
+                    write_bytes(&new_path, &new)?;
+                }
+                conflicts += 1;
+            }
+        }
+        if !dry_run {
+            manifest.files.insert(path.clone(), new_hash.clone());
+        }
+    }
+
+    if !dry_run {
+        manifest.options = target;
+        manifest::write(".", manifest)?;
+    }
+
+    Ok(conflicts)
+}
+
+/// Remove the directories a removed file leaves empty, e.g. `src/app/api/auth/[...all]`
+fn remove_empty_parents(path: &str) {
+    let mut dir = Path::new(path).parent();
+    while let Some(parent) = dir.filter(|parent| !parent.as_os_str().is_empty()) {
+        if std::fs::remove_dir(parent).is_err() {
+            break;
+        }
+        dir = parent.parent();
+    }
+}
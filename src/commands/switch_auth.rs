@@ -0,0 +1,113 @@
+use anyhow::Result;
+use console::style;
+
+use crate::cli::{AuthProvider, SessionStrategy};
+use crate::commands::switch;
+use crate::scaffolding::manifest;
+use crate::scaffolding::options::ScaffoldOptions;
+use crate::utils::output::glyph;
+
+/// `t3-mono switch-auth <provider> [--dry-run] [--diff]`: move the project in the current
+/// directory to another auth provider
+pub async fn execute(provider: AuthProvider, dry_run: bool, show_diff: bool) -> Result<()> {
+    let mut manifest = manifest::read(".")?;
+    let from = manifest.options.auth;
+    if from == provider {
+        anyhow::bail!("This project already uses {}", provider_name(provider));
+    }
+
+    println!();
+    println!(
+        "  {} auth from {} to {}{}...",
+        style("Switching").cyan().bold(),
+        provider_name(from),
+        style(provider_name(provider)).white().bold(),
+        if dry_run { " (dry run)" } else { "" }
+    );
+    println!();
+
+    // The provider's files, Prisma models, dependencies, env vars, and the tRPC and cmd session
+    // code are whatever differs between the project rendered with each provider
+    let target = ScaffoldOptions {
+        auth: provider,
+        // Better Auth keeps its own database sessions
+        session: match provider {
+            AuthProvider::BetterAuth => SessionStrategy::Jwt,
+            AuthProvider::NextAuth => manifest.options.session,
+        },
+        ..manifest.options.clone()
+    };
+    let conflicts = switch::apply(
+        &mut manifest,
+        target,
+        "auth",
+        provider_name(provider),
+        dry_run,
+        show_diff,
+    )
+    .await?;
+
+    if dry_run {
+        println!();
+        println!(
+            "  Run {} to apply these changes",
+            style(format!("t3-mono switch-auth {}", cli_name(provider))).cyan()
+        );
+        println!();
+        return Ok(());
+    }
+
+    println!();
+    println!(
+        "  {} Switched to {}",
+        style(glyph("✓")).green().bold(),
+        provider_name(provider)
+    );
+    println!(
+        "  {} Replaced and removed files are backed up in {}",
+        style(glyph("•")).dim(),
+        style(switch::backup_dir("auth")).yellow()
+    );
+    if conflicts > 0 {
+        println!(
+            "  {} Merge the {} .new files above by hand, then delete them",
+            style("!").yellow().bold(),
+            conflicts
+        );
+    }
+    println!();
+    println!("  Next steps:");
+    println!("    {}", style("npm install").cyan());
+    let schema_step = if manifest.options.migrations {
+        "npm run db:migrate -- --name switch_auth"
+    } else {
+        "npx prisma db push"
+    };
+    println!(
+        "    {} {}",
+        style(schema_step).cyan(),
+        style("(the auth tables change shape; existing users and sessions don't carry over)").dim()
+    );
+    println!(
+        "    {} {}",
+        style("t3-mono envcheck").cyan(),
+        style("(set the new provider's secret in .env)").dim()
+    );
+    println!();
+
+    Ok(())
+}
+
+fn provider_name(provider: AuthProvider) -> &'static str {
+    match provider {
+        AuthProvider::BetterAuth => "Better Auth",
+        AuthProvider::NextAuth => "NextAuth",
+    }
+}
+
+fn cli_name(provider: AuthProvider) -> &'static str {
+    match provider {
+        AuthProvider::BetterAuth => "better-auth",
+        AuthProvider::NextAuth => "next-auth",
+    }
+}
@@ -0,0 +1,161 @@
+use anyhow::Result;
+use console::style;
+use std::path::Path;
+
+use crate::cli::DatabaseProvider;
+use crate::commands::switch;
+use crate::scaffolding::options::ScaffoldOptions;
+use crate::scaffolding::{database, manifest};
+use crate::utils::fs::update_file;
+use crate::utils::output::glyph;
+
+const SCHEMA_PATH: &str = "prisma/schema.prisma";
+
+/// `t3-mono switch-db <database> [--dry-run] [--diff]`: move the project in the current
+/// directory to another database
+pub async fn execute(
+    target_database: DatabaseProvider,
+    dry_run: bool,
+    show_diff: bool,
+) -> Result<()> {
+    let mut manifest = manifest::read(".")?;
+    let from = manifest.options.database;
+    if from == target_database {
+        anyhow::bail!(
+            "This project already uses {}",
+            database_name(target_database)
+        );
+    }
+
+    let reasons = database::incompatibilities(&manifest.options, target_database);
+    if !reasons.is_empty() {
+        println!();
+        for reason in &reasons {
+            println!("  {} {}", style("!").yellow().bold(), reason);
+        }
+        println!();
+        anyhow::bail!(
+            "This project can't move to {}",
+            database_name(target_database)
+        );
+    }
+
+    println!();
+    println!(
+        "  {} the database from {} to {}{}...",
+        style("Switching").cyan().bold(),
+        database_name(from),
+        style(database_name(target_database)).white().bold(),
+        if dry_run { " (dry run)" } else { "" }
+    );
+    println!();
+
+    // The datasource, driver adapter, env example, auth adapter, database container, and docs
+    // are whatever differs between the project rendered with each database
+    let target = ScaffoldOptions {
+        database: target_database,
+        ..manifest.options.clone()
+    };
+    let conflicts = switch::apply(
+        &mut manifest,
+        target,
+        "db",
+        database_name(target_database),
+        dry_run,
+        show_diff,
+    )
+    .await?;
+
+    if dry_run {
+        println!();
+        println!(
+            "  Run {} to apply these changes",
+            style(format!("t3-mono switch-db {}", cli_name(target_database))).cyan()
+        );
+        println!();
+        return Ok(());
+    }
+
+    // Models added since create, e.g. by `generate resource`, aren't in either render
+    if target_database == DatabaseProvider::Sqlite {
+        if let Ok(schema) = std::fs::read_to_string(SCHEMA_PATH) {
+            let adapted = database::adapt_schema(&schema, DatabaseProvider::Sqlite);
+            if adapted != schema {
+                update_file(".", SCHEMA_PATH, &adapted)?;
+                println!(
+                    "  {} Removed @db.Text from {} {}",
+                    style(glyph("✓")).green().bold(),
+                    style(SCHEMA_PATH).yellow(),
+                    style("(SQLite strings have no length limit)").dim()
+                );
+            }
+        }
+    }
+
+    println!();
+    println!(
+        "  {} Switched to {}",
+        style(glyph("✓")).green().bold(),
+        database_name(target_database)
+    );
+    println!(
+        "  {} Replaced and removed files are backed up in {}",
+        style(glyph("•")).dim(),
+        style(switch::backup_dir("db")).yellow()
+    );
+    if conflicts > 0 {
+        println!(
+            "  {} Merge the {} .new files above by hand, then delete them",
+            style("!").yellow().bold(),
+            conflicts
+        );
+    }
+    if Path::new("prisma/migrations").is_dir() {
+        println!(
+            "  {} prisma/migrations holds {} SQL; move it aside before creating a new baseline",
+            style("!").yellow().bold(),
+            database_name(from)
+        );
+    }
+    println!();
+    println!("  Next steps:");
+    println!("    {}", style("npm install").cyan());
+    println!(
+        "    {} {}",
+        style("t3-mono envcheck").cyan(),
+        style("(.env.development has the new DATABASE_URL; update any override in .env.local)")
+            .dim()
+    );
+    if target_database != DatabaseProvider::Sqlite {
+        println!("    {}", style("./start-database.sh").cyan());
+    }
+    let schema_step = if manifest.options.migrations {
+        "npm run db:migrate -- --name init"
+    } else {
+        "npx prisma db push"
+    };
+    println!(
+        "    {} {}",
+        style(schema_step).cyan(),
+        style("(creates the tables; existing data doesn't carry over)").dim()
+    );
+    println!();
+
+    Ok(())
+}
+
+fn database_name(database: DatabaseProvider) -> &'static str {
+    match database {
+        DatabaseProvider::Postgres => "PostgreSQL",
+        DatabaseProvider::Mysql => "MySQL",
+        DatabaseProvider::Sqlite => "SQLite",
+    }
+}
+
+fn cli_name(database: DatabaseProvider) -> &'static str {
+    match database {
+        DatabaseProvider::Postgres => "postgres",
+        DatabaseProvider::Mysql => "mysql",
+        DatabaseProvider::Sqlite => "sqlite",
+    }
+}
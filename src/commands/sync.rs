@@ -0,0 +1,126 @@
+use anyhow::Result;
+use console::style;
+
+use crate::scaffolding::drift::{self, FileState};
+use crate::scaffolding::manifest;
+use crate::utils::diff;
+use crate::utils::fs::write_bytes;
+use crate::utils::output::glyph;
+
+/// `t3-mono sync [--dry-run] [--diff]`
+pub async fn execute(dry_run: bool, show_diff: bool) -> Result<()> {
+    println!();
+    println!(
+        "  {} template updates from t3-mono {}{}...",
+        style("Syncing").cyan().bold(),
+        env!("CARGO_PKG_VERSION"),
+        if dry_run { " (dry run)" } else { "" }
+    );
+    println!();
+
+    let drift = drift::analyze(".").await?;
+    let mut manifest = drift.manifest.clone();
+    let mut applied = 0;
+
+    for file in &drift.files {
+        match file.state {
+            FileState::TemplateUpdated | FileState::TemplateAdded => {
+                let content = drift.rendered(&file.path)?;
+                let verb = match (file.state, dry_run) {
+                    (FileState::TemplateAdded, false) => "Added",
+                    (FileState::TemplateAdded, true) => "Would add",
+                    (_, false) => "Updated",
+                    (_, true) => "Would update",
+                };
+                println!(
+                    "  {} {} {}",
+                    style(glyph("✓")).green().bold(),
+                    verb,
+                    style(&file.path).yellow()
+                );
+                if show_diff || dry_run {
+                    let current = std::fs::read_to_string(&file.path).unwrap_or_default();
+                    print!(
+                        "{}",
+                        diff::unified(
+                            &current,
+                            &String::from_utf8_lossy(&content),
+                            &file.path,
+                            &format!("{} (template)", file.path)
+                        )
+                    );
+                }
+                if !dry_run {
+                    write_bytes(&file.path, &content)?;
+                    manifest
+                        .files
+                        .insert(file.path.clone(), manifest::hash(&content));
+                }
+                applied += 1;
+            }
+            FileState::TemplateRemoved => {
+                if !dry_run {
+                    manifest.files.remove(&file.path);
+                }
+                println!(
+                    "  {} {} is no longer generated; delete it if nothing uses it",
+                    style(glyph("•")).dim(),
+                    style(&file.path).yellow()
+                );
+            }
+            FileState::UserModified {
+                template_changed: true,
+            } => {
+                println!(
+                    "  {} Skipped {} {}",
+                    style("!").yellow().bold(),
+                    style(&file.path).yellow(),
+                    style("(modified by you; merge the template change by hand)").dim()
+                );
+                if show_diff {
+                    let current = std::fs::read_to_string(&file.path).unwrap_or_default();
+                    let template = drift.rendered(&file.path)?;
+                    print!(
+                        "{}",
+                        diff::unified(
+                            &current,
+                            &String::from_utf8_lossy(&template),
+                            &file.path,
+                            &format!("{} (template)", file.path)
+                        )
+                    );
+                }
+            }
+            FileState::Unchanged
+            | FileState::Deleted
+            | FileState::UserModified {
+                template_changed: false,
+            } => {}
+        }
+    }
+
+    if !dry_run {
+        manifest.version = env!("CARGO_PKG_VERSION").to_string();
+        manifest::write(".", &manifest)?;
+    }
+
+    println!();
+    if applied == 0 {
+        println!("  No template updates to apply.");
+    } else if dry_run {
+        println!(
+            "  Run {} to apply {} update(s)",
+            style("t3-mono sync").cyan(),
+            applied
+        );
+    } else {
+        println!(
+            "  Applied {} update(s). Review them with {}",
+            applied,
+            style("git diff").cyan()
+        );
+    }
+    println!();
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use anyhow::Result;
+use console::style;
+
+use crate::utils::output::glyph;
+use crate::utils::telemetry;
+
+/// `t3-mono telemetry enable`
+pub fn enable() -> Result<()> {
+    telemetry::write_state(true)?;
+    println!();
+    println!(
+        "  {} Anonymous usage data is enabled. Thank you!",
+        style(glyph("✓")).green().bold()
+    );
+    if let Some(var) = telemetry::env_override() {
+        println!(
+            "  {}",
+            style(format!(
+                "{} is set, so nothing is sent from this shell",
+                var
+            ))
+            .dim()
+        );
+    } else if telemetry::endpoint().is_none() {
+        println!(
+            "  {}",
+            style(format!(
+                "No collector is configured, so nothing is sent until {} is set",
+                telemetry::ENDPOINT_ENV
+            ))
+            .dim()
+        );
+    }
+    println!();
+    Ok(())
+}
+
+/// `t3-mono telemetry disable`
+pub fn disable() -> Result<()> {
+    telemetry::write_state(false)?;
+    println!();
+    println!(
+        "  {} Anonymous usage data is disabled",
+        style(glyph("✓")).green().bold()
+    );
+    println!();
+    Ok(())
+}
+
+/// `t3-mono telemetry status`
+pub fn status() -> Result<()> {
+    let state = telemetry::read_state();
+    let enabled = state.as_ref().is_some_and(|state| state.enabled);
+
+    println!();
+    match (&state, telemetry::env_override()) {
+        (_, Some(var)) => println!(
+            "  Telemetry is {} ({} is set)",
+            style("off").yellow().bold(),
+            var
+        ),
+        (_, None) if telemetry::endpoint().is_none() => println!(
+            "  Telemetry is {} (no collector is configured; set {})",
+            style("off").yellow().bold(),
+            telemetry::ENDPOINT_ENV
+        ),
+        (None, None) => println!(
+            "  Telemetry is {} (you haven't been asked yet)",
+            style("off").yellow().bold()
+        ),
+        (Some(_), None) if enabled => println!("  Telemetry is {}", style("on").green().bold()),
+        (Some(_), None) => println!("  Telemetry is {}", style("off").yellow().bold()),
+    }
+    if let Some(endpoint) = telemetry::endpoint() {
+        println!("  {}", style(format!("Events go to {}", endpoint)).dim());
+    }
+    if let Some(state) = &state {
+        println!(
+            "  {}",
+            style(format!("Anonymous id: {}", state.anonymous_id)).dim()
+        );
+    }
+    println!(
+        "  {}",
+        style(format!("Saved in {}", telemetry::state_path()?.display())).dim()
+    );
+    println!();
+    println!("  Each event holds the command, flag names, success, duration, CLI version, and OS.");
+    println!("  Project names, paths, and flag values are never sent.");
+    println!();
+    Ok(())
+}
@@ -0,0 +1,11 @@
+//! The t3-mono CLI's scaffolders and commands; `src/main.rs` is the command line on top.
+//! With the `testing` feature, [`testing`] renders projects for template tests.
+
+pub mod cli;
+pub mod commands;
+pub mod scaffolding;
+pub mod templates;
+pub mod utils;
+
+#[cfg(feature = "testing")]
+pub mod testing;
@@ -1,41 +1,284 @@
-mod cli;
-mod commands;
-mod scaffolding;
-mod templates;
-mod utils;
-
 use anyhow::Result;
 use clap::Parser;
-use cli::Args;
 use console::style;
+use std::time::{Duration, Instant};
+use t3_mono::cli::{self, Args, Lang};
+use t3_mono::commands;
+use t3_mono::scaffolding::options::ScaffoldOptions;
+use t3_mono::templates::remote::{self, FetchSettings};
+use t3_mono::utils::conflict::ConflictMode;
+use t3_mono::utils::lang::{self, Msg};
+use t3_mono::utils::registry::{self, LatestSettings};
+use t3_mono::utils::{answers, config};
+use t3_mono::utils::{hooks, output, telemetry, update};
 
 #[tokio::main]
 async fn main() -> Result<()> {
     let args = Args::parse();
+    // Only create is translated; the other commands print English whatever the locale
+    lang::init(match args.command {
+        None => args.lang,
+        Some(_) => Some(Lang::En),
+    });
+    output::init(args.plain, args.verbose);
+
+    let command = telemetry::command_name(&args.command);
+    if !matches!(args.command, Some(cli::Command::Telemetry { .. })) {
+        telemetry::ask_consent_once(is_scripted(&args));
+    }
+    let update_check = update::spawn_check();
+    let started = Instant::now();
+    let result = run(args).await;
+    telemetry::record(
+        &command,
+        &telemetry::flag_names(),
+        result.is_ok(),
+        started.elapsed(),
+    )
+    .await;
 
-    if let Err(e) = run(args).await {
-        eprintln!("{} {}", style("Error:").red().bold(), e);
+    if let Err(e) = &result {
+        eprintln!("{} {}", style(Msg::Error).red().bold(), e);
+    }
+    update::notify(update_check).await;
+    if result.is_err() {
         std::process::exit(1);
     }
 
     Ok(())
 }
 
+/// Runs that take every answer from flags or files, e.g. in scripts and CI
+fn is_scripted(args: &Args) -> bool {
+    match &args.command {
+        None => args.answers.is_some() || args.from_manifest.is_some(),
+        Some(cli::Command::Add {
+            yes, skip_existing, ..
+        }) => *yes || *skip_existing,
+        _ => false,
+    }
+}
+
+fn latest_settings(npm: config::NpmConfig) -> LatestSettings {
+    LatestSettings {
+        registry: npm
+            .registry
+            .unwrap_or_else(|| registry::DEFAULT_REGISTRY.to_string()),
+        ranges: npm.ranges,
+    }
+}
+
 async fn run(args: Args) -> Result<()> {
+    // `config validate` reports every problem in the file instead of stopping at the first
+    let config = match args.command {
+        Some(cli::Command::Config { .. }) => config::Config::default(),
+        _ => config::load()?,
+    };
+
+    // --template-mirror, then the environment, then t3mono.toml
+    let mirror = args
+        .template_mirror
+        .or_else(|| {
+            std::env::var(remote::MIRROR_ENV)
+                .ok()
+                .filter(|value| !value.is_empty())
+        })
+        .or(config.templates.mirror);
+    let github_token = std::env::var(remote::TOKEN_ENV)
+        .ok()
+        .filter(|value| !value.is_empty())
+        .or(config.templates.github_token);
+    remote::configure(FetchSettings {
+        strategy: config.templates.strategy,
+        git_url: config.templates.git_url,
+        repo: config.templates.repo,
+        github_token,
+        mirror,
+        proxy: config.network.proxy,
+        ca_certs: config.network.ca_certs,
+        insecure: args.insecure,
+        cache_ttl: config
+            .templates
+            .cache_ttl_hours
+            .map_or(remote::DEFAULT_CACHE_TTL, |hours| {
+                Duration::from_secs(hours * 60 * 60)
+            }),
+        refresh: args.refresh,
+    })?;
+    hooks::configure(config.hooks);
+
     match args.command {
-        Some(cli::Command::Add { extension }) => {
-            commands::add::execute(&extension).await?;
+        Some(cli::Command::Add {
+            extension,
+            provider,
+            backend,
+            yes,
+            skip_existing,
+        }) => {
+            let conflicts = if yes {
+                ConflictMode::Overwrite
+            } else if skip_existing {
+                ConflictMode::Skip
+            } else {
+                ConflictMode::Prompt
+            };
+            commands::add::execute(&extension, provider, backend, conflicts).await?;
+        }
+        Some(cli::Command::Generate { generator }) => match generator {
+            cli::GenerateCommand::Resource { name, fields } => {
+                commands::generate::resource(&name, &fields)?;
+            }
+            cli::GenerateCommand::Component { name, variant } => {
+                commands::generate::component(&name, variant)?;
+            }
+            cli::GenerateCommand::Agent { name, tools } => {
+                commands::generate::agent(&name, &tools)?;
+            }
+            cli::GenerateCommand::Locale { code, translate } => {
+                commands::generate::locale(&code, translate).await?;
+            }
+            cli::GenerateCommand::Page {
+                route,
+                protected,
+                translated,
+            } => {
+                commands::generate::page(&route, protected, translated)?;
+            }
+            cli::GenerateCommand::Workflow { name, pattern } => {
+                commands::generate::workflow(&name, pattern)?;
+            }
+        },
+        Some(cli::Command::Status { diff }) => {
+            commands::status::execute(diff).await?;
+        }
+        Some(cli::Command::Rename { name, title }) => {
+            commands::rename::execute(&name, title.as_deref())?;
+        }
+        Some(cli::Command::SwitchAuth {
+            provider,
+            dry_run,
+            diff,
+        }) => {
+            commands::switch_auth::execute(provider, dry_run, diff).await?;
         }
+        Some(cli::Command::SwitchDb {
+            database,
+            dry_run,
+            diff,
+        }) => {
+            commands::switch_db::execute(database, dry_run, diff).await?;
+        }
+        Some(cli::Command::Envcheck { file }) => {
+            commands::envcheck::execute(file.as_deref())?;
+        }
+        Some(cli::Command::Sync { dry_run, diff }) => {
+            commands::sync::execute(dry_run, diff).await?;
+        }
+        Some(cli::Command::Info { extension }) => {
+            commands::info::execute(extension.as_deref())?;
+        }
+        Some(cli::Command::Telemetry { action }) => match action {
+            cli::TelemetryCommand::Enable => commands::telemetry::enable()?,
+            cli::TelemetryCommand::Disable => commands::telemetry::disable()?,
+            cli::TelemetryCommand::Status => commands::telemetry::status()?,
+        },
+        Some(cli::Command::Config { action }) => match action {
+            cli::ConfigCommand::Validate { file } => commands::config::validate(file.as_deref())?,
+            cli::ConfigCommand::Schema { file } => commands::config::schema(file)?,
+        },
+        Some(cli::Command::Restate { action }) => match action {
+            cli::RestateCommand::Register {
+                uri,
+                admin_url,
+                force,
+            } => {
+                commands::restate::register(&uri, &admin_url, force).await?;
+            }
+            cli::RestateCommand::Subscribe {
+                topic,
+                handler,
+                cluster,
+                admin_url,
+            } => {
+                commands::restate::subscribe(&topic, &handler, &cluster, &admin_url).await?;
+            }
+        },
         None => {
+            if let Some(path) = &args.answers {
+                let answers = answers::load(path)?;
+                let (init_git, latest) = (answers.git, answers.latest);
+                let options = answers.into_options(&args.name)?;
+                let latest = latest.then(|| latest_settings(config.npm));
+                return commands::create::execute(
+                    options,
+                    args.dir.clone(),
+                    false,
+                    init_git,
+                    latest,
+                    args.dry_run,
+                    args.setup_db,
+                )
+                .await;
+            }
+            if let Some(source) = &args.from_manifest {
+                let latest = args.latest.then(|| latest_settings(config.npm));
+                return commands::create::replay(
+                    source,
+                    &args.name,
+                    args.dir.clone(),
+                    !args.no_git,
+                    latest,
+                    args.dry_run,
+                    args.setup_db,
+                )
+                .await;
+            }
+
+            let options = ScaffoldOptions {
+                name: args.name,
+                auth: args.auth,
+                session: args.session,
+                ai: args.ai,
+                ui: args.ui,
+                restate: args.restate,
+                restate_kafka: args.restate_kafka,
+                cmd: args.cmd,
+                cmd_stream: args.cmd_stream,
+                cmd_settings: None,
+                analytics: args.analytics,
+                example: args.example,
+                rest: args.rest,
+                seed: args.seed,
+                models: Vec::new(),
+                seo: args.seo,
+                standalone: args.standalone,
+                bundle_analyzer: args.bundle_analyzer,
+                security_headers: args.security_headers,
+                soft_delete: args.soft_delete,
+                database: cli::DatabaseProvider::Postgres,
+                pooling: args.pooling,
+                migrations: args.migrations,
+                nix: args.nix,
+                iac: args.iac,
+                task_runner: args.task_runner,
+                ts_strictness: args.ts_strictness,
+                coverage_threshold: args.coverage_threshold,
+                tailwind_plugins: args.tailwind_plugins,
+                dark_mode: args.dark_mode,
+                biome_profile: args.biome_profile,
+                image_hosts: args.image_hosts,
+                image_cdn: args.image_cdn,
+                locales: Vec::new(),
+            };
+            let latest = args.latest.then(|| latest_settings(config.npm));
             commands::create::execute(
-                &args.name,
-                args.ai,
-                args.ui,
-                args.restate,
-                args.cmd,
+                options,
+                args.dir,
                 args.interactive,
                 !args.no_git,
-                args.auth,
+                latest,
+                args.dry_run,
+                args.setup_db,
             )
             .await?;
         }
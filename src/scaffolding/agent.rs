@@ -0,0 +1,167 @@
+use anyhow::{Context, Result};
+use clap::ValueEnum;
+use std::path::Path;
+
+use crate::cli::AgentTool;
+use crate::templates::embedded;
+use crate::utils::fs::{self, write_file};
+use crate::utils::naming::{camel_case, kebab_case, pascal_case};
+
+const AI_CORE_PATH: &str = "src/components/ai/core";
+
+/// Generate a tool-calling agent and its vitest file in `src/components/ai/agents/`.
+/// Returns the paths written.
+pub fn generate(project_path: &str, name: &str, tools: &[AgentTool]) -> Result<Vec<String>> {
+    let project = Path::new(project_path);
+    if !fs::path_exists(project.join(AI_CORE_PATH)) {
+        anyhow::bail!("Agents build on the AI core. Run `t3-mono add ai` first.");
+    }
+
+    let file = kebab_case(name);
+    let agent_path = format!("src/components/ai/agents/{}.ts", file);
+    let test_path = format!("src/components/ai/agents/{}.test.ts", file);
+    if fs::path_exists(project.join(&agent_path)) {
+        anyhow::bail!("{} already exists", agent_path);
+    }
+
+    let snippets: Vec<&ToolSnippets> = AgentTool::value_variants()
+        .iter()
+        .filter(|tool| tools.contains(tool))
+        .map(|tool| snippets(*tool))
+        .collect();
+    let join = |part: fn(&ToolSnippets) -> &'static str| {
+        snippets.iter().map(|s| part(s)).collect::<String>()
+    };
+
+    let vars = [
+        ("tool_imports", join(|s| s.import)),
+        ("tool_setup", join(|s| s.setup)),
+        ("tool_definitions", join(|s| s.definition)),
+        ("tool_cases", join(|s| s.case)),
+        (
+            "tool_list",
+            snippets
+                .iter()
+                .map(|s| format!("\"{}\"", s.name))
+                .collect::<Vec<_>>()
+                .join(", "),
+        ),
+        ("test_mocks", join(|s| s.test_mock)),
+        ("test_cases", join(|s| s.test_case)),
+        // Replaced last so the tool snippets above can use them too
+        ("Name", pascal_case(name)),
+        ("name", camel_case(name)),
+        ("file", file.clone()),
+        ("title", file.replace('-', " ")),
+    ];
+
+    let mut files = Vec::new();
+    for (template, destination) in [("agent.ts", agent_path), ("agent.test.ts", test_path)] {
+        let content = embedded::render_template(&format!("generate/agent/{}", template), &vars)
+            .with_context(|| format!("Missing embedded template generate/agent/{}", template))?;
+        write_file(project_path, &destination, &content)?;
+        files.push(destination);
+    }
+
+    Ok(files)
+}
+
+/// The pieces a tool contributes to the agent and its test
+struct ToolSnippets {
+    name: &'static str,
+    import: &'static str,
+    setup: &'static str,
+    definition: &'static str,
+    case: &'static str,
+    test_mock: &'static str,
+    test_case: &'static str,
+}
+
+fn snippets(tool: AgentTool) -> &'static ToolSnippets {
+    match tool {
+        AgentTool::Db => &DB_TOOL,
+        AgentTool::Search => &SEARCH_TOOL,
+    }
+}
+
+// ============================================================================
+// Embedded Templates
+// ============================================================================
+
+const DB_TOOL: ToolSnippets = ToolSnippets {
+    name: "query_records",
+    import: "import { db } from \"@/server/db\";\n",
+    setup: r#"// Prisma models query_records may read, as client keys, e.g. "post"
+const DB_MODELS: string[] = [];
+
+"#,
+    definition: r#"  {
+    name: "query_records",
+    description: `Read up to 20 rows from one of these database models: ${DB_MODELS.join(", ") || "none"}. Filters match fields exactly.`,
+    schema: z.object({
+      model: z.string().describe("Model to read"),
+      where: z
+        .record(z.string(), z.union([z.string(), z.number(), z.boolean()]))
+        .optional()
+        .describe("Exact-match filters by field name"),
+      limit: z.number().int().min(1).max(20).optional().describe("Maximum rows, 20 by default"),
+    }),
+  },
+"#,
+    case: r#"    case "query_records": {
+      if (!DB_MODELS.includes(args.model)) {
+        return { error: `Model ${args.model} is not readable by this agent` };
+      }
+      return (db as any)[args.model].findMany({ where: args.where, take: args.limit ?? 20 });
+    }
+"#,
+    test_mock: "vi.mock(\"@/server/db\", () => ({ db: {} }));\n",
+    test_case: r#"
+  it("only reads allowlisted models", async () => {
+    await expect(execute{{Name}}Tool("query_records", { model: "secret" })).resolves.toEqual({
+      error: "Model secret is not readable by this agent",
+    });
+  });
+"#,
+};
+
+const SEARCH_TOOL: ToolSnippets = ToolSnippets {
+    name: "search_documents",
+    import: "import { EmbeddingGenerator, EmbeddingPresets, SemanticSearch } from \"@/components/ai/core/embedding\";\n",
+    setup: r#"// In-memory index over the documents passed to indexDocuments
+const searchIndex = new SemanticSearch(new EmbeddingGenerator(EmbeddingPresets.balanced));
+
+export async function indexDocuments(documents: { text: string; metadata?: any }[]): Promise<void> {
+  await searchIndex.index(documents);
+}
+
+"#,
+    definition: r#"  {
+    name: "search_documents",
+    description: "Find the indexed documents most similar to a query. Returns text, score, and metadata.",
+    schema: z.object({
+      query: z.string().describe("What to search for"),
+      topK: z.number().int().min(1).max(20).optional().describe("Maximum results, 5 by default"),
+    }),
+  },
+"#,
+    case: r#"    case "search_documents":
+      return searchIndex.search(args.query, args.topK ?? 5);
+"#,
+    test_mock: r#"vi.mock("@/components/ai/core/embedding", () => ({
+  EmbeddingPresets: { balanced: {} },
+  EmbeddingGenerator: class {},
+  SemanticSearch: class {
+    index = vi.fn();
+    search = vi.fn(async () => [{ text: "Match", score: 0.9 }]);
+  },
+}));
+"#,
+    test_case: r#"
+  it("searches the document index", async () => {
+    await expect(execute{{Name}}Tool("search_documents", { query: "refunds" })).resolves.toEqual([
+      { text: "Match", score: 0.9 },
+    ]);
+  });
+"#,
+};
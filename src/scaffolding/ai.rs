@@ -1,8 +1,44 @@
 use anyhow::Result;
 use std::path::Path;
 
-use crate::templates::embedded;
-use crate::utils::fs::write_file;
+use crate::scaffolding::env::{self, EnvFragment, EnvVar};
+use crate::templates::remote;
+use crate::utils::fs::{self, write_file};
+
+/// Provider keys read by the AI framework; every provider is optional
+pub(crate) const ENV: EnvFragment = EnvFragment {
+    section: "AI Providers",
+    vars: &[
+        EnvVar::server("ANTHROPIC_API_KEY", "z.string().optional()"),
+        EnvVar::server("OPENAI_API_KEY", "z.string().optional()"),
+        EnvVar::server("GOOGLE_API_KEY", "z.string().optional()"),
+        EnvVar::server("MISTRAL_API_KEY", "z.string().optional()"),
+        EnvVar::server("COHERE_API_KEY", "z.string().optional()"),
+        EnvVar::server("OLLAMA_BASE_URL", "z.string().url().optional()")
+            .example("http://localhost:11434")
+            .production(""),
+    ],
+};
+
+const LOGGING_ENV: EnvFragment = EnvFragment {
+    section: "LLM Call Logging (optional)",
+    vars: &[
+        EnvVar::server("LLMLOG", "z.string().optional()"),
+        EnvVar::server("LLMLOG_DIR", "z.string().optional()"),
+        EnvVar::server("LLMLOG_DATABASE_URL", "z.string().url().optional()"),
+    ],
+};
+
+/// npm packages the AI framework imports
+pub const AI_DEPENDENCIES: &[(&str, &str)] = &[
+    ("@langchain/anthropic", "^1.3.18"),
+    ("@langchain/core", "^1.1.26"),
+    ("@langchain/openai", "^1.2.8"),
+    ("langchain", "^1.2.25"),
+    ("zod", "^4.3.6"),
+    ("winston", "^3.19.0"),
+    ("pg", "^8.18.0"),
+];
 
 /// Scaffold AI agents framework
 pub async fn scaffold(project_path: &str) -> Result<()> {
@@ -10,22 +46,25 @@ pub async fn scaffold(project_path: &str) -> Result<()> {
 
     // Create AI directory structure at src/components/ai/core
     let ai_path = project.join("src/components/ai/core");
-    tokio::fs::create_dir_all(&ai_path).await?;
+    fs::create_dir_all(&ai_path)?;
 
-    // Copy embedded AI templates
-    embedded::copy_embedded_dir("ai/core", &ai_path).await?;
+    // Copy the AI core from the pinned template ref, or the embedded copy
+    remote::copy_template_dir("agents/core", "ai/core", &ai_path).await?;
 
     // Create AI index file
     write_file(project_path, "src/components/ai/index.ts", AI_INDEX)?;
 
-    // Create Claude skill file
-    let claude_dir = project.join(".claude/skills");
-    tokio::fs::create_dir_all(&claude_dir).await?;
-    write_file(project_path, ".claude/skills/ai.md", CLAUDE_AI_SKILL)?;
-
     // Create example agent file
-    tokio::fs::create_dir_all(project.join("src/components/ai/agents")).await?;
-    write_file(project_path, "src/components/ai/agents/example.ts", EXAMPLE_AGENT)?;
+    fs::create_dir_all(project.join("src/components/ai/agents"))?;
+    write_file(
+        project_path,
+        "src/components/ai/agents/example.ts",
+        EXAMPLE_AGENT,
+    )?;
+
+    // Validate provider keys and list them in .env.example
+    env::register(project_path, &ENV)?;
+    env::register(project_path, &LOGGING_ENV)?;
 
     Ok(())
 }
@@ -41,63 +80,6 @@ export * from "@/components/ai/core/chunking";
 export * from "@/components/ai/core/embedding";
 "#;
 
-const CLAUDE_AI_SKILL: &str = r#"# AI Agents Skill
-
-This project includes a LangChain-based AI agents framework.
-
-## Available Modules
-
-### Providers (`src/components/ai/core/providers`)
-- Unified interface for Anthropic, OpenAI, Google, Mistral, Ollama
-- Model registry with cost estimation
-- Fallback chains for reliability
-
-### Logging (`src/components/ai/core/logging`)
-- LLM call logging to terminal, database, or file
-- Token counting and cost tracking
-- Usage statistics
-
-### Chunking (`src/components/ai/core/chunking`)
-- Text chunking strategies: character, token, semantic, recursive, markdown
-- Presets for different use cases
-
-### Embedding (`src/components/ai/core/embedding`)
-- Multi-provider embedding generation
-- Batch processing and semantic search
-
-## Usage
-
-```typescript
-import { createLLM, LLMLogger, TextChunker, EmbeddingGenerator } from "@/components/ai";
-
-// Create LLM instance
-const llm = createLLM({
-  provider: "anthropic",
-  model: "claude-sonnet-4-20250514",
-  temperature: 0.7,
-});
-
-// Log calls
-LLMLogger.getInstance().initialize({
-  destinations: ["terminal"]
-});
-
-// Chunk text
-const chunker = new TextChunker({ strategy: "semantic" });
-const chunks = await chunker.chunk(longText);
-
-// Generate embeddings
-const embedder = new EmbeddingGenerator("openai");
-const embeddings = await embedder.embed(texts);
-```
-
-## Environment Variables
-
-Required for AI features:
-- `ANTHROPIC_API_KEY` - For Claude models
-- `OPENAI_API_KEY` - For GPT models and embeddings
-"#;
-
 const EXAMPLE_AGENT: &str = r#"import { createLLM, ModelRegistry } from "@/components/ai/core/providers";
 import { LLMLogger } from "@/components/ai/core/logging";
 
@@ -0,0 +1,283 @@
+use anyhow::Result;
+
+use crate::scaffolding::nav::{self, NavEntry};
+use crate::scaffolding::trpc;
+use crate::utils::fs::write_file;
+
+const NAV: NavEntry = NavEntry {
+    href: "/dashboard/analytics",
+    key: "analytics",
+    en: "Analytics",
+    de: "Analysen",
+};
+
+/// Scaffold the `/dashboard/analytics` charts page and the `analytics` router feeding it.
+/// Requires the UI kit for the chart and card components.
+pub fn scaffold(project_path: &str) -> Result<()> {
+    write_file(
+        project_path,
+        "src/server/api/routers/analytics.ts",
+        ANALYTICS_ROUTER,
+    )?;
+    trpc::register_router(
+        project_path,
+        "analytics",
+        "analyticsRouter",
+        "@/server/api/routers/analytics",
+    )?;
+    write_file(
+        project_path,
+        "src/app/dashboard/analytics/page.tsx",
+        ANALYTICS_PAGE,
+    )?;
+    write_file(
+        project_path,
+        "src/app/dashboard/analytics/AnalyticsCharts.tsx",
+        ANALYTICS_CHARTS,
+    )?;
+    nav::register(project_path, &NAV)?;
+
+    Ok(())
+}
+
+// ============================================================================
+// Embedded Templates
+// ============================================================================
+
+const ANALYTICS_ROUTER: &str = r#"import { z } from "zod";
+import { createTRPCRouter, protectedProcedure } from "@/server/api/trpc";
+
+const SOURCES = ["direct", "search", "social", "referral", "email"] as const;
+
+/** Deterministic pseudo-random numbers so the mock charts don't jump between reloads */
+function seeded(seed: number) {
+  let state = seed;
+  return () => {
+    state = (state * 16807) % 2147483647;
+    return state / 2147483647;
+  };
+}
+
+export const analyticsRouter = createTRPCRouter({
+  // Mock aggregates; replace with real queries (e.g. `ctx.db.$queryRaw` with `date_trunc`)
+  overview: protectedProcedure
+    .input(z.object({ days: z.number().int().min(7).max(90).default(30) }))
+    .query(({ input }) => {
+      const random = seeded(input.days);
+      const today = new Date();
+
+      const daily = Array.from({ length: input.days }, (_, i) => {
+        const date = new Date(today);
+        date.setDate(today.getDate() - (input.days - 1 - i));
+        const visitors = Math.round(800 + i * 12 + random() * 400);
+        return {
+          date: date.toISOString().slice(0, 10),
+          visitors,
+          signups: Math.round(visitors * (0.03 + random() * 0.02)),
+        };
+      });
+
+      const revenueByMonth = Array.from({ length: 6 }, (_, i) => {
+        const month = new Date(today.getFullYear(), today.getMonth() - (5 - i), 1);
+        return {
+          month: month.toLocaleString("en", { month: "short" }),
+          revenue: Math.round(12000 + i * 1500 + random() * 4000),
+        };
+      });
+
+      const sources = SOURCES.map((source) => ({
+        source,
+        visitors: Math.round(1000 + random() * 4000),
+      }));
+
+      const visitors = daily.reduce((sum, day) => sum + day.visitors, 0);
+      const signups = daily.reduce((sum, day) => sum + day.signups, 0);
+
+      return {
+        totals: {
+          visitors,
+          signups,
+          conversion: signups / visitors,
+          revenue: revenueByMonth.at(-1)?.revenue ?? 0,
+        },
+        daily,
+        revenueByMonth,
+        sources,
+      };
+    }),
+});
+"#;
+
+const ANALYTICS_PAGE: &str = r#"import { redirect } from "next/navigation";
+
+import { Header } from "@/app/_components/Header";
+import { getSession, SIGN_IN_PATH } from "@/server/session";
+import { api } from "@/trpc/server";
+import { AnalyticsCharts } from "./AnalyticsCharts";
+
+export default async function AnalyticsPage() {
+  const session = await getSession();
+  if (!session) {
+    redirect(SIGN_IN_PATH);
+  }
+
+  const overview = await api.analytics.overview({ days: 30 });
+
+  return (
+    <div className="min-h-screen flex flex-col bg-background">
+      <Header />
+
+      <main className="flex-1 max-w-7xl mx-auto px-4 sm:px-6 lg:px-8 py-8 w-full">
+        <h1 className="text-2xl font-semibold mb-6">Analytics</h1>
+        <AnalyticsCharts overview={overview} />
+      </main>
+    </div>
+  );
+}
+"#;
+
+const ANALYTICS_CHARTS: &str = r#""use client";
+
+import { Area, AreaChart, Bar, BarChart, CartesianGrid, Pie, PieChart, XAxis } from "recharts";
+import {
+  Card,
+  CardContent,
+  CardDescription,
+  CardHeader,
+  CardTitle,
+  type ChartConfig,
+  ChartContainer,
+  ChartLegend,
+  ChartLegendContent,
+  ChartTooltip,
+  ChartTooltipContent,
+} from "@/components/ui";
+import type { RouterOutputs } from "@/trpc/react";
+
+type Overview = RouterOutputs["analytics"]["overview"];
+
+// Colors come from the --color-chart-* tokens in globals.css, so charts follow the theme
+const trafficConfig = {
+  visitors: { label: "Visitors", color: "var(--color-chart-1)" },
+  signups: { label: "Sign-ups", color: "var(--color-chart-2)" },
+} satisfies ChartConfig;
+
+const revenueConfig = {
+  revenue: { label: "Revenue", color: "var(--color-chart-3)" },
+} satisfies ChartConfig;
+
+const sourcesConfig = {
+  visitors: { label: "Visitors" },
+  direct: { label: "Direct", color: "var(--color-chart-1)" },
+  search: { label: "Search", color: "var(--color-chart-2)" },
+  social: { label: "Social", color: "var(--color-chart-3)" },
+  referral: { label: "Referral", color: "var(--color-chart-4)" },
+  email: { label: "Email", color: "var(--color-chart-5)" },
+} satisfies ChartConfig;
+
+const number = new Intl.NumberFormat("en");
+const currency = new Intl.NumberFormat("en", { style: "currency", currency: "USD", maximumFractionDigits: 0 });
+const percent = new Intl.NumberFormat("en", { style: "percent", maximumFractionDigits: 1 });
+
+export function AnalyticsCharts({ overview }: { overview: Overview }) {
+  // ChartContainer defines --color-<key> for every config entry
+  const sources = overview.sources.map((entry) => ({
+    ...entry,
+    fill: `var(--color-${entry.source})`,
+  }));
+
+  const stats = [
+    { label: "Visitors", value: number.format(overview.totals.visitors) },
+    { label: "Sign-ups", value: number.format(overview.totals.signups) },
+    { label: "Conversion", value: percent.format(overview.totals.conversion) },
+    { label: "Revenue this month", value: currency.format(overview.totals.revenue) },
+  ];
+
+  return (
+    <div className="grid gap-6">
+      <div className="grid gap-4 sm:grid-cols-2 lg:grid-cols-4">
+        {stats.map((stat) => (
+          <Card key={stat.label}>
+            <CardHeader>
+              <CardDescription>{stat.label}</CardDescription>
+              <CardTitle className="text-2xl tabular-nums">{stat.value}</CardTitle>
+            </CardHeader>
+          </Card>
+        ))}
+      </div>
+
+      <Card>
+        <CardHeader>
+          <CardTitle>Traffic</CardTitle>
+          <CardDescription>Daily visitors and sign-ups, last {overview.daily.length} days</CardDescription>
+        </CardHeader>
+        <CardContent>
+          <ChartContainer config={trafficConfig} className="aspect-auto h-72 w-full">
+            <AreaChart data={overview.daily}>
+              <CartesianGrid vertical={false} />
+              <XAxis
+                dataKey="date"
+                tickLine={false}
+                axisLine={false}
+                minTickGap={32}
+                tickFormatter={(value: string) => value.slice(5)}
+              />
+              <ChartTooltip content={<ChartTooltipContent indicator="dot" />} />
+              <Area
+                dataKey="visitors"
+                type="monotone"
+                fill="var(--color-visitors)"
+                fillOpacity={0.3}
+                stroke="var(--color-visitors)"
+              />
+              <Area
+                dataKey="signups"
+                type="monotone"
+                fill="var(--color-signups)"
+                fillOpacity={0.3}
+                stroke="var(--color-signups)"
+              />
+              <ChartLegend content={<ChartLegendContent />} />
+            </AreaChart>
+          </ChartContainer>
+        </CardContent>
+      </Card>
+
+      <div className="grid gap-6 lg:grid-cols-2">
+        <Card>
+          <CardHeader>
+            <CardTitle>Revenue</CardTitle>
+            <CardDescription>Last 6 months</CardDescription>
+          </CardHeader>
+          <CardContent>
+            <ChartContainer config={revenueConfig} className="aspect-auto h-64 w-full">
+              <BarChart data={overview.revenueByMonth}>
+                <CartesianGrid vertical={false} />
+                <XAxis dataKey="month" tickLine={false} axisLine={false} />
+                <ChartTooltip content={<ChartTooltipContent />} />
+                <Bar dataKey="revenue" fill="var(--color-revenue)" radius={6} />
+              </BarChart>
+            </ChartContainer>
+          </CardContent>
+        </Card>
+
+        <Card>
+          <CardHeader>
+            <CardTitle>Traffic sources</CardTitle>
+            <CardDescription>Visitors by channel</CardDescription>
+          </CardHeader>
+          <CardContent>
+            <ChartContainer config={sourcesConfig} className="mx-auto aspect-square h-64">
+              <PieChart>
+                <ChartTooltip content={<ChartTooltipContent nameKey="source" hideLabel />} />
+                <Pie data={sources} dataKey="visitors" nameKey="source" innerRadius={56} />
+                <ChartLegend content={<ChartLegendContent nameKey="source" />} />
+              </PieChart>
+            </ChartContainer>
+          </CardContent>
+        </Card>
+      </div>
+    </div>
+  );
+}
+"#;
@@ -0,0 +1,429 @@
+use anyhow::Result;
+use std::path::Path;
+
+use crate::cli::{AuthProvider, DarkMode};
+use crate::scaffolding::options::ScaffoldOptions;
+use crate::utils::fs::{self, update_file, write_file};
+
+/// A section of the assistant context, contributed by the base stack or an extension
+struct ContextFragment {
+    /// Extension key (`ai`, `cmd`, ...) or the base part it belongs to
+    key: &'static str,
+    /// Cursor rule description
+    description: &'static str,
+    /// Files the Cursor rule applies to; empty means always applied
+    globs: &'static str,
+    body: &'static str,
+}
+
+/// Write AGENTS.md, CLAUDE.md, and `.cursor/rules/` for the selected stack
+pub fn scaffold(project_path: &str, options: &ScaffoldOptions) -> Result<()> {
+    let auth = match options.auth {
+        AuthProvider::BetterAuth => &BETTER_AUTH_FRAGMENT,
+        AuthProvider::NextAuth => &NEXT_AUTH_FRAGMENT,
+    };
+
+    let mut fragments = vec![&BASE_FRAGMENT, auth];
+    fragments.extend(
+        EXTENSION_FRAGMENTS
+            .iter()
+            .filter(|fragment| is_selected(fragment.key, options)),
+    );
+
+    let sections: Vec<String> = fragments
+        .iter()
+        .map(|fragment| body(fragment, options))
+        .collect();
+    write_file(
+        project_path,
+        "AGENTS.md",
+        &format!("{}\n{}", AGENTS_HEADER, sections.join("\n")),
+    )?;
+
+    // skills::sync() adds the note on `.claude/skills/` once there are skills
+    write_file(project_path, "CLAUDE.md", CLAUDE_MD)?;
+
+    for fragment in fragments {
+        write_cursor_rule(project_path, fragment, &body(fragment, options))?;
+    }
+
+    Ok(())
+}
+
+/// Add an extension's section to an existing project's AGENTS.md and Cursor rules.
+/// Projects scaffolded without assistant context are left alone.
+pub fn add_extension(project_path: &str, extension: &str) -> Result<()> {
+    let agents_path = Path::new(project_path).join("AGENTS.md");
+    let Some(fragment) = EXTENSION_FRAGMENTS.iter().find(|f| f.key == extension) else {
+        return Ok(());
+    };
+    if !fs::path_exists(&agents_path) {
+        return Ok(());
+    }
+
+    let mut content = fs::read_to_string(&agents_path)?;
+    if !content.contains(fragment.body) {
+        content.push('\n');
+        content.push_str(fragment.body);
+        update_file(project_path, "AGENTS.md", &content)?;
+    }
+
+    write_cursor_rule(project_path, fragment, fragment.body)
+}
+
+fn is_selected(key: &str, options: &ScaffoldOptions) -> bool {
+    match key {
+        "ai" => options.ai,
+        "ui" => options.ui,
+        "restate" => options.restate,
+        "cmd" => options.cmd,
+        "cmd-stream" => options.cmd && options.cmd_stream.is_some(),
+        "example" => options.example,
+        "migrations" => options.migrations,
+        "rest" => options.rest,
+        "pooling" => options.pooling.is_some(),
+        "soft-delete" => options.soft_delete,
+        _ => false,
+    }
+}
+
+/// The fragment's text; the stack overview drops next-themes when dark mode doesn't use it
+fn body(fragment: &ContextFragment, options: &ScaffoldOptions) -> String {
+    if fragment.key != "stack" || options.dark_mode == DarkMode::Class {
+        return fragment.body.to_string();
+    }
+    fragment
+        .body
+        .replace(
+            "Tailwind CSS, next-themes, next-intl",
+            "Tailwind CSS, next-intl",
+        )
+        .replace(" (see `docs/THEMING.md`)", "")
+}
+
+fn write_cursor_rule(project_path: &str, fragment: &ContextFragment, body: &str) -> Result<()> {
+    let always_apply = fragment.globs.is_empty();
+    let content = format!(
+        "---\ndescription: {}\nglobs: {}\nalwaysApply: {}\n---\n\n{}",
+        fragment.description, fragment.globs, always_apply, body
+    );
+    write_file(
+        project_path,
+        &format!(".cursor/rules/{}.mdc", fragment.key),
+        &content,
+    )
+}
+
+// ============================================================================
+// Embedded Templates
+// ============================================================================
+
+const AGENTS_HEADER: &str = r#"# Project Context
+
+Guidance for AI coding assistants working in this repository. Keep it up to date as the
+project grows -- it is read by Claude Code (via CLAUDE.md), Codex, Cursor, and other agents.
+"#;
+
+const CLAUDE_MD: &str = "@AGENTS.md\n";
+
+const BASE_FRAGMENT: ContextFragment = ContextFragment {
+    key: "stack",
+    description: "Stack overview and project conventions",
+    globs: "",
+    body: r#"## Stack
+
+- Next.js App Router, React 19, TypeScript
+- tRPC with superjson for the API, TanStack Query on the client
+- Prisma with PostgreSQL (`prisma/schema.prisma`)
+- Tailwind CSS, next-themes, next-intl (`messages/*.json`)
+- Biome for linting and formatting
+
+## Commands
+
+- `npm run dev` -- start the dev server
+- `npm run check` -- lint and format with Biome (run before committing)
+- `npm run db:push` / `npm run db:migrate` -- apply schema changes
+- `npm run test` -- run Vitest; `npm run test:coverage` adds a coverage report
+
+## Conventions
+
+- Use the `@/` alias for imports from `src/`.
+- Server components by default; add `"use client"` only for interactivity.
+- Every API lives in a tRPC router under `src/server/api/routers/` and is registered in
+  `src/server/api/root.ts`.
+- Zod schemas for inputs and outputs live in `src/schemas/<domain>.ts` and are imported by
+  procedures, forms, and route handlers alike; don't define them inline.
+- Read environment variables through `env` from `src/env.js`; add new variables there,
+  to `.env.example` and `.env.production.example`, and non-secret local defaults to
+  `.env.development`. Secrets go in `.env.local`, never in a committed file.
+- User-facing strings go through next-intl; add keys to every file in `messages/`.
+
+## Customization Points
+
+- `src/app/_components/Header.tsx` -- navigation items and branding
+- `src/app/_components/UserMenu.tsx` -- account menu (avatar, settings link, sign-out)
+- `src/app/settings/` -- account settings, backed by the `user` router
+- `src/styles/globals.css` -- theme tokens (see `docs/THEMING.md`)
+- `src/i18n/` -- locales and routing (see `docs/I18N.md`)
+"#,
+};
+
+const BETTER_AUTH_FRAGMENT: ContextFragment = ContextFragment {
+    key: "auth",
+    description: "Authentication with Better Auth",
+    globs: "src/server/**,src/lib/auth-client.ts,src/app/api/auth/**,src/app/_components/UserMenu.tsx,src/app/settings/**",
+    body: r#"## Authentication (Better Auth)
+
+- Server config: `src/server/auth.ts`; client helpers: `src/lib/auth-client.ts`.
+- Read the session on the server with `getSession()` from `src/server/session.ts`.
+- In client components, use `useSession()` from `src/lib/auth-client.ts`, as the Header's
+  account menu (`src/app/_components/UserMenu.tsx`) does.
+- Use `protectedProcedure` for any tRPC procedure that needs a user; `ctx.userId` is set.
+- Account changes go through the `user` router (`src/server/api/routers/user.ts`); password
+  changes call `auth.api.changePassword` with the request headers.
+- Auth models (User, Session, Account, Verification) live in `prisma/schema.prisma`.
+"#,
+};
+
+const NEXT_AUTH_FRAGMENT: ContextFragment = ContextFragment {
+    key: "auth",
+    description: "Authentication with NextAuth",
+    globs: "src/server/**,src/lib/auth-client.ts,src/app/api/auth/**,src/app/_components/UserMenu.tsx,src/app/settings/**",
+    body: r#"## Authentication (NextAuth)
+
+- Server config: `src/server/auth.ts` (`authOptions`); client helpers: `src/lib/auth-client.ts`.
+- Read the session on the server with `getSession()` from `src/server/session.ts`.
+- In client components, use `useSession()` from `src/lib/auth-client.ts` under a
+  `SessionProvider`, as the Header's account menu (`src/app/_components/UserMenu.tsx`) does.
+- Use `protectedProcedure` for any tRPC procedure that needs a user; `ctx.userId` is set.
+- Add OAuth providers in `authOptions.providers` and their keys to `src/env.js`.
+- Account changes go through the `user` router (`src/server/api/routers/user.ts`); there is no
+  password form until the credentials provider stores password hashes.
+"#,
+};
+
+const EXTENSION_FRAGMENTS: &[ContextFragment] = &[
+    ContextFragment {
+        key: "example",
+        description: "Example post router and page",
+        globs: "src/server/api/routers/post.ts,src/schemas/post.ts,src/app/posts/**",
+        body: r#"## Example Feature
+
+`src/schemas/post.ts`, `src/server/api/routers/post.ts`, and `src/app/posts/` show the
+end-to-end pattern: Prisma model, shared zod schemas, tRPC router, server component prefetch,
+and a client mutation. Copy it for new features.
+"#,
+    },
+    ContextFragment {
+        key: "ai",
+        description: "AI agents framework",
+        globs: "src/components/ai/**",
+        body: r#"## AI Agents
+
+- Framework in `src/components/ai/` (providers, logging, chunking, embedding); agents in
+  `src/components/ai/agents/`.
+- Create models with `createLLM` instead of instantiating provider SDKs directly.
+- Only call agents from server code; provider keys are server-only.
+- See `.claude/skills/ai.md` and `docs/AI.md`.
+"#,
+    },
+    ContextFragment {
+        key: "soft-delete",
+        description: "Soft deletes and audit columns",
+        globs: "src/server/**,prisma/**",
+        body: r#"## Soft Deletes and Audit Columns
+
+- `db.<model>.delete()` only sets `deletedAt` for models in `AUDITED_MODELS` (`src/server/audit.ts`); reads skip those rows unless the `where` filters on `deletedAt`.
+- `createdBy`/`updatedBy` are filled from the signed-in user in `protectedProcedure`; wrap background jobs in `runAsActor()`.
+- New models get `deletedAt DateTime?`, `createdBy String?`, `updatedBy String?`, `@@index([deletedAt])` and an entry in `AUDITED_MODELS`.
+"#,
+    },
+    ContextFragment {
+        key: "rest",
+        description: "Public REST endpoints",
+        globs: "src/app/api/v1/**,src/server/services/**,src/server/rest.ts,src/server/cors.ts",
+        body: r#"## REST API
+
+- Public endpoints live under `src/app/api/v1/`, wrapped in `withApiKey` and validated with `parseBody`/`parseQuery` from `src/server/rest.ts`.
+- Wrap every handler in `withCors` from `src/server/cors.ts` and export `OPTIONS = preflight([...methods])`; allowed origins come from `CORS_ALLOWED_ORIGINS`.
+- Put logic in `src/server/services/` and call it from both the route handler and the tRPC router; handlers only parse, authorize, and shape responses.
+- Errors use `errorResponse(status, code, message)`; never change a `v1` response shape, add `v2` instead.
+- Document new endpoints in `src/server/openapi.ts` (served at `/api/v1/openapi.json`, browsable at `/api-docs`).
+"#,
+    },
+    ContextFragment {
+        key: "pooling",
+        description: "Pooled database connections",
+        globs: "src/server/db.ts,prisma.config.ts",
+        body: r#"## Connection Pooling
+
+- The app connects through the pooler via `DATABASE_URL`; the Prisma CLI (migrate, db push, studio) uses `DIRECT_URL` from `prisma.config.ts`.
+- Don't rely on session state (advisory locks, `SET`, prepared statements across queries) in app code; pooled connections are shared.
+"#,
+    },
+    ContextFragment {
+        key: "migrations",
+        description: "Prisma migration workflow",
+        globs: "prisma/**",
+        body: r#"## Database Migrations
+
+- Change the schema with `npm run db:migrate -- --name <change>`; never use `prisma db push`, it bypasses `prisma/migrations/`.
+- Commit every migration directory; don't edit migrations that have already been applied elsewhere.
+- Deployments run `npm run db:deploy` (`prisma migrate deploy`).
+"#,
+    },
+    ContextFragment {
+        key: "ui",
+        description: "shadcn/ui component library",
+        globs: "src/components/ui/**,src/app/**/*.tsx",
+        body: r#"## UI Components
+
+- shadcn/ui components live in `src/components/ui/` and are exported from its `index.ts`.
+- Reuse them before writing new primitives; style with Tailwind theme tokens, not raw colors.
+- Tables: `DataTable` with `sortableHeader` and `rowActionsColumn`; see
+  `src/app/dashboard/members/` for server-side pagination through tRPC.
+- Mutation errors: `onError: onMutationError({ setFieldError })` from `src/trpc/errors.ts` shows zod
+  field errors in the form and toasts the rest; don't surface `error.message` directly.
+"#,
+    },
+    ContextFragment {
+        key: "forms",
+        description: "Forms with react-hook-form and zod",
+        globs: "src/components/forms/**,src/schemas/**,src/app/**/*Form.tsx",
+        body: r#"## Forms
+
+- Build forms with `useZodForm`, `Form`, and the `*Field` components from `src/components/forms/`.
+- Keep the zod schema in `src/schemas/` and use it for both the form and the tRPC procedure input.
+- `src/app/settings/profile/` is the reference example.
+"#,
+    },
+    ContextFragment {
+        key: "cms",
+        description: "Headless CMS content",
+        globs: "src/server/cms/**,src/app/blog/**,src/app/api/draft/**",
+        body: r#"## CMS Content
+
+- Fetch content only through `src/server/cms/`; every response is parsed with zod there.
+- Draft mode (`/api/draft/enable?secret=...&slug=...`) switches the fetchers to unpublished content.
+- `src/app/blog/` is the reference consumer.
+"#,
+    },
+    ContextFragment {
+        key: "compliance",
+        description: "Cookie consent and legal pages",
+        globs: "src/components/consent/**,src/app/[locale]/**",
+        body: r#"## Cookie Consent
+
+- Load anything that sets non-essential cookies only when `useConsent()` returns `"all"`; see `ConsentedAnalytics`.
+- Privacy policy and imprint texts live in the `legal` namespace of `messages/*.json`; keep `en` and `de` in sync.
+"#,
+    },
+    ContextFragment {
+        key: "logging",
+        description: "Structured server logging",
+        globs: "src/server/**,src/app/api/**",
+        body: r#"## Logging
+
+- Log through `src/server/logger.ts` (pino), never `console.log`; inside tRPC procedures use `ctx.log`, which carries the request id.
+- Pass context as the first argument and a short message second: `ctx.log.info({ postId }, "post created")`.
+- Level comes from `LOG_LEVEL`; tRPC calls are already logged with their duration, so don't time procedures by hand.
+"#,
+    },
+    ContextFragment {
+        key: "perf",
+        description: "Procedure timing and query counts",
+        globs: "src/server/**",
+        body: r#"## Performance
+
+- Every tRPC procedure reports its duration and Prisma query count (`src/server/perf.ts`); warnings above `SLOW_PROCEDURE_MS`/`SLOW_QUERY_MS` mean a procedure needs attention, often an N+1 loop to replace with `include` or one `findMany`.
+- Queries are counted per procedure, so code run outside tRPC (scripts, route handlers) only gets slow-query warnings.
+"#,
+    },
+    ContextFragment {
+        key: "maintenance",
+        description: "Runtime flags and maintenance mode",
+        globs: "src/server/**,src/proxy.ts",
+        body: r#"## Runtime Flags
+
+- Kill switches and other runtime settings are `RuntimeFlag` rows read with `getFlag(key)` from `src/server/runtime-config.ts`; they're cached for 10 seconds, and off when missing or when the database is unreachable.
+- Maintenance mode is the `maintenance` flag (`npm run maintenance -- on|off`), overridden by `MAINTENANCE_MODE`; keep `/maintenance` and `/api/health` reachable in `src/proxy.ts`.
+"#,
+    },
+    ContextFragment {
+        key: "privacy",
+        description: "Data export and account deletion",
+        globs: "prisma/schema.prisma,src/server/privacy.ts",
+        body: r#"## Personal Data
+
+- The data export (`exportUserData` in `src/server/privacy.ts`) includes every model related to `User` and every model with a `userId` column; relate new per-user models to `User` with `onDelete: Cascade` so the export and the purge reach them.
+- Credential fields (`password`, `token`, `accessToken`, `refreshToken`, `idToken`, `secret`) are left out of exports; add new ones to `SECRET_FIELDS`.
+- Accounts are deleted by `npm run privacy:purge` once `ACCOUNT_DELETION_GRACE_DAYS` have passed since the request.
+"#,
+    },
+    ContextFragment {
+        key: "restate",
+        description: "Restate durable services",
+        globs: "restate/**,src/server/restate.ts",
+        body: r#"## Restate Services
+
+- Durable services live in `restate/services/` as a separate package with its own `package.json`.
+- Service inputs and outputs are defined once in `restate/services/src/contracts.ts`; update
+  the contract before changing a handler.
+- Call services from the app with `callRestate` from `src/server/restate.ts`.
+- See `docs/RESTATE.md`.
+"#,
+    },
+    ContextFragment {
+        key: "cmd",
+        description: "CommandIsland AI layer",
+        globs: "src/components/{chat,tables,docs,layout}/**,src/server/api/routers/{chat,tables,docs}.ts",
+        body: r#"## CommandIsland
+
+- Chat, AI tables, and docs UI in `src/components/{chat,tables,docs,layout}/`, backed by the
+  `chat`, `tables`, and `docs` tRPC routers.
+- Mounted globally through `src/app/_components/CommandIslandLayout.tsx`.
+- Provider, model, attachment search, storage, and modes are set in `src/server/chat/config.ts`.
+- Translations live under the `commandIsland` namespace in `messages/*.json`.
+- See `.claude/skills/commandisland.md` and `docs/COMMAND_ISLAND.md`.
+"#,
+    },
+    ContextFragment {
+        key: "webhooks",
+        description: "Outgoing webhooks",
+        globs: "src/server/webhooks/**,src/schemas/webhook.ts,src/server/api/routers/webhook.ts,src/app/settings/webhooks/**",
+        body: r#"## Webhooks
+
+- Send events with `sendWebhook(userId, event, payload)` from `src/server/webhooks/`; it records
+  the deliveries and sends them after the response, signed in the Standard Webhooks format.
+- Add event types and their payload schemas to `webhookEvents` in `src/schemas/webhook.ts`.
+- Failed deliveries are retried by `/api/webhooks/process` (or the `WebhookDelivery` Restate
+  service when Restate is installed); never call endpoints directly.
+"#,
+    },
+    ContextFragment {
+        key: "search",
+        description: "Full-text search",
+        globs: "src/server/search/**,src/server/api/routers/search.ts,src/components/search/**",
+        body: r#"## Search
+
+- Make a model searchable by adding a `defineIndex` entry to `src/server/search/indexes.ts`, then
+  run `npm run search:reindex`.
+- With Meilisearch or Typesense, writes through `db` sync the index (`src/server/search/sync.ts`);
+  raw SQL and `createMany` don't, so reindex after those.
+- The `search.find` procedure returns hits to every signed-in user; filter by user there before
+  indexing private rows.
+"#,
+    },
+    ContextFragment {
+        key: "cmd-stream",
+        description: "Streaming CommandIsland chat replies",
+        globs: "src/lib/chat-stream.ts,src/server/chat/**,src/app/api/chat/**,server.ts",
+        body: r#"## Chat Streaming
+
+- `ChatPanel` sends messages with `streamChatMessage` from `src/lib/chat-stream.ts`, not `chat.sendMessage`.
+- Both paths run `sendChatMessage` in `src/server/chat/send-message.ts`; change chat behavior there.
+- Events are `token`, `reset` (drop text streamed before a tool call), `done` (saved messages), and `error`.
+- With WebSockets, `server.ts` replaces `next dev`/`next start` and tickets are in memory, so scale out with sticky sessions.
+"#,
+    },
+];
@@ -1,5 +1,24 @@
-use anyhow::Result;
+use crate::scaffolding::env::{self, EnvFragment, EnvVar};
+use crate::scaffolding::trpc;
 use crate::utils::fs::write_file;
+use crate::utils::plan::{self, Op};
+use anyhow::Result;
+use std::path::Path;
+
+const ENV: EnvFragment = EnvFragment {
+    section: "Better Auth",
+    vars: &[
+        EnvVar::server(
+            "BETTER_AUTH_SECRET",
+            r#"process.env.NODE_ENV === "production" ? z.string().min(32) : z.string().optional()"#,
+        )
+        .example("your-secret-key-min-32-chars-here")
+        .secret(),
+        EnvVar::server("BETTER_AUTH_URL", "z.string().url().optional()")
+            .example("http://localhost:3000")
+            .production("https://example.com"),
+    ],
+};
 
 /// Scaffold Better Auth integration
 pub async fn scaffold(project_path: &str) -> Result<()> {
@@ -7,22 +26,36 @@ pub async fn scaffold(project_path: &str) -> Result<()> {
     write_file(project_path, "src/server/auth.ts", AUTH_CONFIG)?;
 
     // Write auth API route
-    write_file(project_path, "src/app/api/auth/[...all]/route.ts", AUTH_ROUTE)?;
+    write_file(
+        project_path,
+        "src/app/api/auth/[...all]/route.ts",
+        AUTH_ROUTE,
+    )?;
 
     // Write auth client
     write_file(project_path, "src/lib/auth-client.ts", AUTH_CLIENT)?;
 
-    // Append Better Auth models to Prisma schema
-    append_to_prisma_schema(project_path)?;
+    // Account menu in the Header
+    write_file(project_path, "src/app/_components/UserMenu.tsx", USER_MENU)?;
+    write_file(
+        project_path,
+        "src/app/_components/UserMenu.test.tsx",
+        USER_MENU_TEST,
+    )?;
 
-    Ok(())
-}
+    // Server-side session helper, used by tRPC and the dashboard
+    write_file(project_path, "src/server/session.ts", SESSION_HELPER)?;
+    trpc::add_session_support(project_path)?;
+
+    // Validate the auth secrets and list them in .env.example
+    env::register(project_path, &ENV)?;
+
+    // Append Better Auth models to Prisma schema
+    plan::apply(Op::PatchPrisma {
+        path: Path::new(project_path).join("prisma/schema.prisma"),
+        blocks: PRISMA_AUTH_MODELS.to_string(),
+    })?;
 
-fn append_to_prisma_schema(project_path: &str) -> Result<()> {
-    let schema_path = std::path::Path::new(project_path).join("prisma/schema.prisma");
-    let mut content = std::fs::read_to_string(&schema_path)?;
-    content.push_str(PRISMA_AUTH_MODELS);
-    std::fs::write(schema_path, content)?;
     Ok(())
 }
 
@@ -65,6 +98,200 @@ export const authClient = createAuthClient({
 export const { signIn, signUp, signOut, useSession } = authClient;
 "#;
 
+const USER_MENU: &str = r#""use client";
+
+import Image from "next/image";
+import Link from "next/link";
+import { useRouter } from "next/navigation";
+import { useEffect, useRef, useState } from "react";
+import { useTranslations } from "next-intl";
+import { signOut, useSession } from "@/lib/auth-client";
+
+/** Matches SIGN_IN_PATH in src/server/session.ts; point both at your sign-in page */
+const SIGN_IN_PATH = "/";
+
+function initials(name: string) {
+  const letters = name
+    .split(/\s+/)
+    .filter(Boolean)
+    .slice(0, 2)
+    .map((part) => part[0]?.toUpperCase())
+    .join("");
+  return letters || "?";
+}
+
+export function UserMenu() {
+  const t = useTranslations("nav");
+  const router = useRouter();
+  const { data: session, isPending } = useSession();
+  const [isOpen, setIsOpen] = useState(false);
+  const menuRef = useRef<HTMLDivElement>(null);
+
+  // Close menu when clicking outside
+  useEffect(() => {
+    function handleClickOutside(event: MouseEvent) {
+      if (menuRef.current && !menuRef.current.contains(event.target as Node)) {
+        setIsOpen(false);
+      }
+    }
+
+    if (isOpen) {
+      document.addEventListener("mousedown", handleClickOutside);
+    }
+
+    return () => {
+      document.removeEventListener("mousedown", handleClickOutside);
+    };
+  }, [isOpen]);
+
+  if (isPending) {
+    return <div className="h-9 w-9 rounded-full bg-muted animate-pulse" aria-hidden="true" />;
+  }
+
+  if (!session) {
+    return (
+      <Link
+        href={SIGN_IN_PATH}
+        className="px-3 py-2 rounded-lg text-sm font-medium text-muted-foreground hover:text-primary hover:bg-muted transition-colors"
+      >
+        {t("signIn")}
+      </Link>
+    );
+  }
+
+  const { user } = session;
+  const name = user.name || user.email;
+
+  async function handleSignOut() {
+    setIsOpen(false);
+    await signOut();
+    router.push(SIGN_IN_PATH);
+    router.refresh();
+  }
+
+  return (
+    <div className="relative" ref={menuRef}>
+      <button
+        type="button"
+        onClick={() => setIsOpen(!isOpen)}
+        className="rounded-full cursor-pointer focus:outline-none focus-visible:ring-2 focus-visible:ring-primary"
+        aria-label={t("account")}
+        aria-expanded={isOpen}
+      >
+        {user.image ? (
+          <Image
+            src={user.image}
+            alt=""
+            width={36}
+            height={36}
+            unoptimized
+            className="h-9 w-9 rounded-full object-cover"
+          />
+        ) : (
+          <span className="flex h-9 w-9 items-center justify-center rounded-full bg-primary text-sm font-medium text-primary-foreground">
+            {initials(name)}
+          </span>
+        )}
+      </button>
+
+      {isOpen && (
+        <div className="absolute right-0 mt-2 w-56 bg-card rounded-xl border border-border/50 shadow-lg py-2 z-50">
+          <div className="px-4 py-2 border-b border-border/50">
+            <p className="text-sm font-medium truncate">{name}</p>
+            {user.name && <p className="text-xs text-muted-foreground truncate">{user.email}</p>}
+          </div>
+          <Link
+            href="/settings"
+            onClick={() => setIsOpen(false)}
+            className="block px-4 py-2.5 text-sm font-medium text-muted-foreground hover:text-primary hover:bg-muted transition-colors"
+          >
+            {t("settings")}
+          </Link>
+          <button
+            type="button"
+            onClick={handleSignOut}
+            className="block w-full text-left px-4 py-2.5 text-sm font-medium text-muted-foreground hover:text-primary hover:bg-muted cursor-pointer transition-colors"
+          >
+            {t("signOut")}
+          </button>
+        </div>
+      )}
+    </div>
+  );
+}
+"#;
+
+const USER_MENU_TEST: &str = r#"import { fireEvent, render, screen, waitFor } from "@testing-library/react";
+import { NextIntlClientProvider } from "next-intl";
+import { beforeEach, describe, expect, it, vi } from "vitest";
+import messages from "../../../messages/en.json";
+import { UserMenu } from "./UserMenu";
+
+const auth = vi.hoisted(() => ({
+  useSession: vi.fn(),
+  signOut: vi.fn(),
+}));
+const router = vi.hoisted(() => ({ push: vi.fn(), refresh: vi.fn() }));
+
+vi.mock("@/lib/auth-client", () => auth);
+vi.mock("next/navigation", () => ({
+  useRouter: () => router,
+}));
+
+function renderMenu() {
+  return render(
+    <NextIntlClientProvider locale="en" messages={messages}>
+      <UserMenu />
+    </NextIntlClientProvider>,
+  );
+}
+
+describe("UserMenu", () => {
+  beforeEach(() => {
+    auth.signOut.mockResolvedValue(undefined);
+  });
+
+  it("links to sign-in when signed out", () => {
+    auth.useSession.mockReturnValue({ data: null, isPending: false });
+    renderMenu();
+
+    expect(screen.getByRole("link", { name: messages.nav.signIn })).toBeInTheDocument();
+  });
+
+  it("shows the user, a settings link, and signs out", async () => {
+    auth.useSession.mockReturnValue({
+      data: { user: { name: "Ada Lovelace", email: "ada@example.com", image: null } },
+      isPending: false,
+    });
+    renderMenu();
+
+    fireEvent.click(screen.getByRole("button", { name: messages.nav.account }));
+    expect(screen.getByText("Ada Lovelace")).toBeInTheDocument();
+    expect(screen.getByRole("link", { name: messages.nav.settings })).toHaveAttribute("href", "/settings");
+
+    fireEvent.click(screen.getByRole("button", { name: messages.nav.signOut }));
+    expect(auth.signOut).toHaveBeenCalled();
+    await waitFor(() => expect(router.refresh).toHaveBeenCalled());
+  });
+});
+"#;
+
+const SESSION_HELPER: &str = r#"import "server-only";
+
+import { headers } from "next/headers";
+import { auth } from "@/server/auth";
+
+/** Where protected pages send anonymous visitors; point it at your sign-in page */
+export const SIGN_IN_PATH = "/";
+
+/** Current Better Auth session, or `null` when signed out */
+export async function getSession() {
+  return auth.api.getSession({ headers: await headers() });
+}
+
+export type AppSession = NonNullable<Awaited<ReturnType<typeof getSession>>>;
+"#;
+
 const PRISMA_AUTH_MODELS: &str = r#"
 // ============================================================================
 // Better Auth Models
@@ -0,0 +1,117 @@
+use anyhow::{Context, Result};
+use serde_json::{json, Value};
+use std::path::Path;
+
+use crate::cli::{BiomeProfile, ChatStream};
+use crate::scaffolding::options::ScaffoldOptions;
+use crate::utils::fs::{self, update_file};
+
+/// Rewrite the base biome.jsonc for the chosen profile, with overrides that relax
+/// rules in the generated code of the selected extensions
+pub fn scaffold(project_path: &str, options: &ScaffoldOptions) -> Result<()> {
+    let overrides = overrides(options);
+    if options.biome_profile == BiomeProfile::Recommended && overrides.is_empty() {
+        return Ok(());
+    }
+
+    let config_path = Path::new(project_path).join("biome.jsonc");
+    let content = fs::read_to_string(&config_path).context("Failed to read biome.jsonc")?;
+    let mut config: Value =
+        serde_json::from_str(&content).context("biome.jsonc is not valid JSON")?;
+
+    match options.biome_profile {
+        BiomeProfile::Minimal => {
+            // Bugs only: no style opinions and no class sorting
+            config["linter"]["rules"] = json!({
+                "recommended": false,
+                "correctness": { "recommended": true },
+                "suspicious": { "recommended": true },
+                "security": { "recommended": true }
+            });
+        }
+        BiomeProfile::Recommended => {}
+        BiomeProfile::Strict => {
+            let rules = &mut config["linter"]["rules"];
+            for (group, group_rules) in strict_rules().as_object().into_iter().flatten() {
+                for (rule, level) in group_rules.as_object().into_iter().flatten() {
+                    rules[group][rule] = level.clone();
+                }
+            }
+        }
+    }
+
+    if !overrides.is_empty() {
+        config["overrides"] = Value::Array(overrides);
+    }
+
+    let content = serde_json::to_string_pretty(&config)?;
+    update_file(project_path, "biome.jsonc", &format!("{}\n", content))?;
+
+    Ok(())
+}
+
+/// Rules the strict profile turns on or up, by group
+fn strict_rules() -> Value {
+    json!({
+        "correctness": {
+            "noUnusedImports": "error",
+            "noUnusedVariables": "error"
+        },
+        "suspicious": {
+            "noExplicitAny": "error",
+            "noConsole": { "level": "warn", "options": { "allow": ["error", "warn"] } },
+            "useAwait": "error"
+        },
+        "style": {
+            "noNonNullAssertion": "error",
+            "useBlockStatements": "error",
+            "useImportType": "error"
+        },
+        "complexity": {
+            "noExcessiveCognitiveComplexity": "warn"
+        }
+    })
+}
+
+/// Relaxed rules for generated code the project owner didn't write; minimal has none to relax
+fn overrides(options: &ScaffoldOptions) -> Vec<Value> {
+    let strict = options.biome_profile == BiomeProfile::Strict;
+    let mut overrides = Vec::new();
+    if options.biome_profile == BiomeProfile::Minimal {
+        return overrides;
+    }
+
+    // shadcn-style primitives: ARIA roles on divs, index keys, and the chart's injected styles
+    if options.ui {
+        let mut rules = json!({
+            "a11y": { "useSemanticElements": "off", "useFocusableInteractive": "off" },
+            "security": { "noDangerouslySetInnerHtml": "off" },
+            "suspicious": { "noArrayIndexKey": "off" }
+        });
+        if strict {
+            rules["style"] = json!({ "noNonNullAssertion": "off", "useBlockStatements": "off" });
+            rules["complexity"] = json!({ "noExcessiveCognitiveComplexity": "off" });
+        }
+        overrides.push(json!({
+            "includes": ["src/components/ui/**"],
+            "linter": { "rules": rules }
+        }));
+    }
+
+    // Long-running processes that log to stdout on purpose
+    if strict {
+        let mut includes = vec!["prisma/seed.ts", "scripts/**"];
+        if options.restate {
+            includes.push("restate/**");
+        }
+        if options.cmd_stream == Some(ChatStream::Websocket) {
+            includes.push("server.ts");
+        }
+        overrides.push(json!({
+            "includes": includes,
+            "linter": { "rules": { "suspicious": { "noConsole": "off" } } }
+        }));
+    }
+
+    overrides
+}
@@ -0,0 +1,47 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use crate::utils::fs::{self, update_file};
+use crate::utils::npm;
+
+// @next/bundle-analyzer is a webpack plugin, and `next build` uses Turbopack by default
+const ANALYZER_SCRIPTS: &[(&str, &str)] = &[("analyze", "ANALYZE=true next build --webpack")];
+
+const ANALYZER_DEV_DEPENDENCIES: &[(&str, &str)] = &[("@next/bundle-analyzer", "^16.1.6")];
+
+/// Wrap next.config.js in @next/bundle-analyzer, enabled by `ANALYZE=true`, and add an
+/// `analyze` script that writes the treemaps to `.next/analyze/`
+pub fn scaffold(project_path: &str) -> Result<()> {
+    wrap_next_config(project_path)?;
+    npm::add_scripts(project_path, ANALYZER_SCRIPTS)?;
+    npm::add_dependencies(project_path, &[], ANALYZER_DEV_DEPENDENCIES)?;
+
+    Ok(())
+}
+
+fn wrap_next_config(project_path: &str) -> Result<()> {
+    let config_path = Path::new(project_path).join("next.config.js");
+    let content = fs::read_to_string(&config_path).context("Failed to read next.config.js")?;
+
+    if content.contains("withBundleAnalyzer") {
+        return Ok(());
+    }
+
+    let patched = content
+        .replacen(
+            "import createNextIntlPlugin from \"next-intl/plugin\";\n",
+            "import bundleAnalyzer from \"@next/bundle-analyzer\";\nimport createNextIntlPlugin from \"next-intl/plugin\";\n",
+            1,
+        )
+        .replacen(
+            "const withNextIntl = createNextIntlPlugin();\n",
+            "const withNextIntl = createNextIntlPlugin();\n// `npm run analyze` writes client and server treemaps to .next/analyze/\nconst withBundleAnalyzer = bundleAnalyzer({ enabled: process.env.ANALYZE === \"true\" });\n",
+            1,
+        )
+        .replacen(
+            "export default withNextIntl(config);",
+            "export default withBundleAnalyzer(withNextIntl(config));",
+            1,
+        );
+    update_file(project_path, "next.config.js", &patched)
+}
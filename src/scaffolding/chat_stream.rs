@@ -0,0 +1,446 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use crate::cli::ChatStream;
+use crate::utils::fs::{self, update_file, write_file};
+use crate::utils::npm;
+
+const WEBSOCKET_DEPENDENCIES: &[(&str, &str)] = &[("ws", "^8.18.3")];
+const WEBSOCKET_DEV_DEPENDENCIES: &[(&str, &str)] = &[("@types/ws", "^8.18.1"), ("tsx", "^4.20.6")];
+
+/// The blocking `chat.sendMessage` call in the copied ChatPanel
+const CHAT_PANEL_SEND: &str = r#"        const viewContext = getViewState();
+        const result = await trpc.chat.sendMessage.mutate({
+          threadId,
+          content,
+          viewContext,
+          attachmentIds: attachmentIds?.length ? attachmentIds : undefined,
+        });
+
+        // Replace optimistic message and add assistant response
+        setMessages((prev) => [
+          ...prev.filter((m) => m.id !== tempId),
+          ...toMessages([result.userMessage, result.assistantMessage]),
+        ]);
+      } catch {
+        // Remove optimistic message on failure
+        setMessages((prev) => prev.filter((m) => m.id !== tempId));"#;
+
+const CHAT_PANEL_STREAM: &str = r#"        const viewContext = getViewState();
+        const result = await streamChatMessage(
+          {
+            threadId,
+            content,
+            viewContext,
+            attachmentIds: attachmentIds?.length ? attachmentIds : undefined,
+          },
+          {
+            onToken: (text) =>
+              setMessages((prev) =>
+                prev.some((m) => m.id === streamId)
+                  ? prev.map((m) => (m.id === streamId ? { ...m, content: m.content + text } : m))
+                  : [...prev, { id: streamId, role: "assistant", content: text, createdAt: new Date() }],
+              ),
+            onReset: () => setMessages((prev) => prev.filter((m) => m.id !== streamId)),
+          },
+        );
+
+        // Replace the optimistic and streamed messages with the saved ones
+        setMessages((prev) => [
+          ...prev.filter((m) => m.id !== tempId && m.id !== streamId),
+          ...toMessages([result.userMessage, result.assistantMessage]),
+        ]);
+      } catch {
+        // Remove optimistic and partial messages on failure
+        setMessages((prev) => prev.filter((m) => m.id !== tempId && m.id !== streamId));"#;
+
+/// Stream CommandIsland chat replies token by token instead of waiting for the whole
+/// `chat.sendMessage` mutation: over an SSE route handler (works on serverless hosts) or
+/// a WebSocket endpoint on a custom Node server (needs a long-running process).
+///
+/// Run after `cmd::scaffold`.
+pub fn scaffold(project_path: &str, transport: ChatStream) -> Result<()> {
+    match transport {
+        ChatStream::Sse => {
+            write_file(project_path, "src/app/api/chat/stream/route.ts", SSE_ROUTE)?;
+            write_file(
+                project_path,
+                "src/lib/chat-stream.ts",
+                &format!("{}{}", STREAM_EVENTS, SSE_CLIENT),
+            )?;
+        }
+        ChatStream::Websocket => {
+            write_file(project_path, "server.ts", CUSTOM_SERVER)?;
+            write_file(project_path, "src/server/chat/socket.ts", CHAT_SOCKET)?;
+            write_file(
+                project_path,
+                "src/server/chat/stream-tickets.ts",
+                STREAM_TICKETS,
+            )?;
+            write_file(
+                project_path,
+                "src/app/api/chat/ws-ticket/route.ts",
+                TICKET_ROUTE,
+            )?;
+            write_file(
+                project_path,
+                "src/lib/chat-stream.ts",
+                &format!("{}{}", STREAM_EVENTS, WEBSOCKET_CLIENT),
+            )?;
+
+            // `finalize_package_json` points `dev` and `start` at server.ts
+            npm::add_dependencies(
+                project_path,
+                WEBSOCKET_DEPENDENCIES,
+                WEBSOCKET_DEV_DEPENDENCIES,
+            )?;
+        }
+    }
+
+    stream_chat_panel(project_path)
+}
+
+fn stream_chat_panel(project_path: &str) -> Result<()> {
+    let panel_path = Path::new(project_path).join("src/components/chat/ChatPanel.tsx");
+    let content = fs::read_to_string(&panel_path)
+        .context("Failed to read src/components/chat/ChatPanel.tsx")?;
+
+    if !content.contains(CHAT_PANEL_SEND) {
+        anyhow::bail!(
+            "Could not find the chat.sendMessage call in src/components/chat/ChatPanel.tsx; use `streamChatMessage` from src/lib/chat-stream.ts manually"
+        );
+    }
+
+    let patched = content
+        .replacen(CHAT_PANEL_SEND, CHAT_PANEL_STREAM, 1)
+        .replacen(
+            "      const tempId = `temp-${Date.now()}`;\n",
+            "      const tempId = `temp-${Date.now()}`;\n      const streamId = `stream-${Date.now()}`;\n",
+            1,
+        )
+        .replacen(
+            "import { trpc } from \"@/lib/trpc\";\n",
+            "import { trpc } from \"@/lib/trpc\";\nimport { streamChatMessage } from \"@/lib/chat-stream\";\n",
+            1,
+        );
+
+    update_file(project_path, "src/components/chat/ChatPanel.tsx", &patched)
+}
+
+// ============================================================================
+// Embedded Templates
+// ============================================================================
+
+const STREAM_EVENTS: &str = r#"import type { ChatStreamHandlers } from "@/server/chat/llm";
+import type { SendMessageInput, sendChatMessage } from "@/server/chat/send-message";
+
+/** Saved messages as they arrive over JSON (dates are ISO strings) */
+type Jsonify<T> = T extends Date ? string : T extends object ? { [K in keyof T]: Jsonify<T[K]> } : T;
+export type ChatStreamResult = Jsonify<Awaited<ReturnType<typeof sendChatMessage>>>;
+
+/** Apply one server event; returns the result once the reply is saved */
+function dispatch(event: string, data: any, handlers: ChatStreamHandlers): ChatStreamResult | undefined {
+  switch (event) {
+    case "token":
+      handlers.onToken(data.text);
+      return undefined;
+    case "reset":
+      handlers.onReset?.();
+      return undefined;
+    case "done":
+      return data as ChatStreamResult;
+    case "error":
+      throw new Error(data?.message ?? "Failed to generate a reply");
+    default:
+      return undefined;
+  }
+}
+"#;
+
+const SSE_CLIENT: &str = r#"
+/**
+ * Send a chat message and stream the reply over server-sent events from
+ * `/api/chat/stream`. Resolves with the saved user and assistant messages.
+ */
+export async function streamChatMessage(
+  input: SendMessageInput,
+  handlers: ChatStreamHandlers,
+): Promise<ChatStreamResult> {
+  const res = await fetch("/api/chat/stream", {
+    method: "POST",
+    headers: { "content-type": "application/json" },
+    body: JSON.stringify(input),
+  });
+  if (!res.ok || !res.body) {
+    throw new Error(`Chat stream failed (${res.status})`);
+  }
+
+  const reader = res.body.pipeThrough(new TextDecoderStream()).getReader();
+  let buffer = "";
+
+  for (;;) {
+    const { value, done } = await reader.read();
+    if (done) break;
+    buffer += value;
+
+    let boundary = buffer.indexOf("\n\n");
+    while (boundary !== -1) {
+      const frame = buffer.slice(0, boundary);
+      buffer = buffer.slice(boundary + 2);
+      boundary = buffer.indexOf("\n\n");
+
+      let event = "message";
+      let data = "";
+      for (const line of frame.split("\n")) {
+        if (line.startsWith("event: ")) event = line.slice(7);
+        else if (line.startsWith("data: ")) data += line.slice(6);
+      }
+
+      const result = dispatch(event, data ? JSON.parse(data) : undefined, handlers);
+      if (result) {
+        await reader.cancel();
+        return result;
+      }
+    }
+  }
+
+  throw new Error("Chat stream ended before the reply was saved");
+}
+"#;
+
+const WEBSOCKET_CLIENT: &str = r#"
+/**
+ * Send a chat message and stream the reply over a WebSocket to `/api/chat/ws`, served
+ * by `server.ts`. Resolves with the saved user and assistant messages.
+ */
+export async function streamChatMessage(
+  input: SendMessageInput,
+  handlers: ChatStreamHandlers,
+): Promise<ChatStreamResult> {
+  // server.ts runs outside Next.js and can't read the session, so trade it for a one-time ticket
+  const res = await fetch("/api/chat/ws-ticket", { method: "POST" });
+  if (!res.ok) {
+    throw new Error(`Chat stream failed (${res.status})`);
+  }
+  const { ticket } = (await res.json()) as { ticket: string };
+
+  const url = new URL("/api/chat/ws", window.location.href);
+  url.protocol = url.protocol === "https:" ? "wss:" : "ws:";
+  url.searchParams.set("ticket", ticket);
+
+  return new Promise((resolve, reject) => {
+    const socket = new WebSocket(url);
+
+    socket.onopen = () => socket.send(JSON.stringify(input));
+    socket.onmessage = (message) => {
+      try {
+        const { event, data } = JSON.parse(String(message.data));
+        const result = dispatch(event, data, handlers);
+        if (result) {
+          resolve(result);
+          socket.close();
+        }
+      } catch (error) {
+        reject(error);
+        socket.close();
+      }
+    };
+    // No-ops once the promise has settled
+    socket.onerror = () => reject(new Error("Chat connection failed"));
+    socket.onclose = () => reject(new Error("Chat connection closed before the reply was saved"));
+  });
+}
+"#;
+
+const SSE_ROUTE: &str = r#"import { TRPCError } from "@trpc/server";
+import { sendChatMessage, sendMessageInput } from "@/server/chat/send-message";
+import { db } from "@/server/db";
+import { getSession } from "@/server/session";
+
+export const dynamic = "force-dynamic";
+// Tool calls can take a while; raise this if your host allows longer functions
+export const maxDuration = 60;
+
+/** POST /api/chat/stream -- `chat.sendMessage` as server-sent events: `token`, `reset`, `done`, `error` */
+export async function POST(req: Request) {
+  const session = await getSession();
+  const userId = session?.user?.id;
+  if (!userId) {
+    return new Response("Unauthorized", { status: 401 });
+  }
+
+  const input = sendMessageInput.safeParse(await req.json().catch(() => null));
+  if (!input.success) {
+    return new Response("Invalid request", { status: 400 });
+  }
+
+  const encoder = new TextEncoder();
+  const body = new ReadableStream<Uint8Array>({
+    async start(controller) {
+      const send = (event: string, data: unknown) =>
+        controller.enqueue(encoder.encode(`event: ${event}\ndata: ${JSON.stringify(data)}\n\n`));
+
+      try {
+        const result = await sendChatMessage(db, userId, input.data, {
+          onToken: (text) => send("token", { text }),
+          onReset: () => send("reset", {}),
+        });
+        send("done", result);
+      } catch (error) {
+        const notFound = error instanceof TRPCError && error.code === "NOT_FOUND";
+        send("error", { message: notFound ? "Thread not found" : "Failed to generate a reply" });
+      } finally {
+        controller.close();
+      }
+    },
+  });
+
+  return new Response(body, {
+    headers: {
+      "content-type": "text/event-stream",
+      "cache-control": "no-cache, no-transform",
+      connection: "keep-alive",
+    },
+  });
+}
+"#;
+
+const TICKET_ROUTE: &str = r#"import { issueStreamTicket } from "@/server/chat/stream-tickets";
+import { getSession } from "@/server/session";
+
+/** POST /api/chat/ws-ticket -- one-time ticket that authenticates the chat WebSocket */
+export async function POST() {
+  const session = await getSession();
+  const userId = session?.user?.id;
+  if (!userId) {
+    return new Response("Unauthorized", { status: 401 });
+  }
+
+  return Response.json({ ticket: issueStreamTicket(userId) });
+}
+"#;
+
+const STREAM_TICKETS: &str = r#"import { randomUUID } from "node:crypto";
+
+const TICKET_TTL_MS = 30_000;
+
+interface Ticket {
+  userId: string;
+  expiresAt: number;
+}
+
+// On globalThis so the Next.js route handler and server.ts share one store. Tickets are
+// in memory, so every app instance must handle its own sockets (use sticky sessions).
+const globalForTickets = globalThis as unknown as {
+  chatStreamTickets: Map<string, Ticket> | undefined;
+};
+const tickets = (globalForTickets.chatStreamTickets ??= new Map());
+
+export function issueStreamTicket(userId: string): string {
+  const now = Date.now();
+  for (const [id, ticket] of tickets) {
+    if (ticket.expiresAt <= now) tickets.delete(id);
+  }
+
+  const id = randomUUID();
+  tickets.set(id, { userId, expiresAt: now + TICKET_TTL_MS });
+  return id;
+}
+
+/** The ticket's user id, or `null` if it is unknown or expired; each ticket works once */
+export function redeemStreamTicket(id: string): string | null {
+  const ticket = tickets.get(id);
+  tickets.delete(id);
+  return ticket && ticket.expiresAt > Date.now() ? ticket.userId : null;
+}
+"#;
+
+const CHAT_SOCKET: &str = r#"import type { IncomingMessage } from "node:http";
+import { TRPCError } from "@trpc/server";
+import type { WebSocket } from "ws";
+import { sendChatMessage, sendMessageInput } from "@/server/chat/send-message";
+import { redeemStreamTicket } from "@/server/chat/stream-tickets";
+import { db } from "@/server/db";
+
+export const CHAT_SOCKET_PATH = "/api/chat/ws";
+
+/**
+ * One reply per connection: the client sends a `chat.sendMessage` input and receives
+ * `{ event, data }` frames (`token`, `reset`, `done`, `error`) until the socket closes.
+ */
+export function handleChatSocket(socket: WebSocket, req: IncomingMessage) {
+  const send = (event: string, data: unknown) => socket.send(JSON.stringify({ event, data }));
+
+  const ticket = new URL(req.url ?? "/", "http://localhost").searchParams.get("ticket");
+  const userId = ticket ? redeemStreamTicket(ticket) : null;
+  if (!userId) {
+    send("error", { message: "Unauthorized" });
+    socket.close(4401, "Unauthorized");
+    return;
+  }
+
+  socket.once("message", async (raw) => {
+    let body: unknown = null;
+    try {
+      body = JSON.parse(raw.toString());
+    } catch {
+      // Reported as an invalid request below
+    }
+
+    const input = sendMessageInput.safeParse(body);
+    if (!input.success) {
+      send("error", { message: "Invalid request" });
+      socket.close(4400, "Invalid request");
+      return;
+    }
+
+    try {
+      const result = await sendChatMessage(db, userId, input.data, {
+        onToken: (text) => send("token", { text }),
+        onReset: () => send("reset", {}),
+      });
+      send("done", result);
+    } catch (error) {
+      const notFound = error instanceof TRPCError && error.code === "NOT_FOUND";
+      send("error", { message: notFound ? "Thread not found" : "Failed to generate a reply" });
+    } finally {
+      socket.close();
+    }
+  });
+}
+"#;
+
+const CUSTOM_SERVER: &str = r#"import { createServer } from "node:http";
+import next from "next";
+import { WebSocketServer } from "ws";
+import { CHAT_SOCKET_PATH, handleChatSocket } from "@/server/chat/socket";
+
+// Next.js plus the CommandIsland chat socket on one port. Route handlers can't accept
+// WebSocket upgrades, so this replaces `next dev` / `next start` (see package.json).
+
+const dev = process.env.NODE_ENV !== "production";
+const port = Number(process.env.PORT ?? 3000);
+
+const app = next({ dev });
+const handle = app.getRequestHandler();
+await app.prepare();
+
+const upgradeNext = app.getUpgradeHandler();
+const sockets = new WebSocketServer({ noServer: true });
+
+const server = createServer((req, res) => handle(req, res));
+
+server.on("upgrade", (req, socket, head) => {
+  const { pathname } = new URL(req.url ?? "/", "http://localhost");
+  if (pathname === CHAT_SOCKET_PATH) {
+    sockets.handleUpgrade(req, socket, head, (ws) => handleChatSocket(ws, req));
+  } else {
+    // Hot reloading in development
+    upgradeNext(req, socket, head);
+  }
+});
+
+server.listen(port, () => {
+  console.log(`> Ready on http://localhost:${port}`);
+});
+"#;
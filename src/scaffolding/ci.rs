@@ -0,0 +1,59 @@
+use anyhow::Result;
+
+use crate::utils::fs::write_file;
+
+/// Scaffold a GitHub Actions workflow that lints, runs the tests with coverage,
+/// and uploads the coverage report
+pub fn scaffold(project_path: &str) -> Result<()> {
+    write_file(project_path, ".github/workflows/ci.yml", CI_WORKFLOW)
+}
+
+// ============================================================================
+// Embedded Templates
+// ============================================================================
+
+const CI_WORKFLOW: &str = r#"name: CI
+
+on:
+  push:
+    branches: [main]
+  pull_request:
+
+jobs:
+  check:
+    runs-on: ubuntu-latest
+    steps:
+      - uses: actions/checkout@v4
+
+      - uses: actions/setup-node@v4
+        with:
+          node-version: 22
+          cache: npm
+
+      - run: npm ci
+
+      - name: Lint
+        run: npm run lint
+
+      - name: Check translations
+        run: npm run i18n:check
+
+      - name: Test with coverage
+        run: npm run test:coverage
+
+      - name: Upload coverage report
+        if: ${{ !cancelled() }}
+        uses: actions/upload-artifact@v4
+        with:
+          name: coverage
+          path: coverage/
+
+      # Set CODECOV_TOKEN in the repository secrets to publish coverage on pull requests
+      - name: Upload to Codecov
+        if: ${{ !cancelled() }}
+        uses: codecov/codecov-action@v5
+        with:
+          files: coverage/lcov.info
+          token: ${{ secrets.CODECOV_TOKEN }}
+          fail_ci_if_error: false
+"#;
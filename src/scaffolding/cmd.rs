@@ -1,41 +1,222 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
+use dialoguer::{MultiSelect, Select};
 use std::path::Path;
 
+use crate::cli::{CmdModule, LlmProvider, StorageBackend, VectorStore};
+use crate::scaffolding::env::{self, EnvFragment, EnvVar};
+use crate::scaffolding::options::CmdSettings;
+use crate::scaffolding::{ai, i18n, search, security_headers, trpc};
 use crate::templates::embedded;
-use crate::utils::fs::write_file;
+use crate::utils::fs::{self, update_file, write_file};
+use crate::utils::lang::Msg;
+use crate::utils::plan::{self, Op};
+
+const CONFIG_PATH: &str = "src/server/chat/config.ts";
+const ISLAND_PATH: &str = "src/components/layout/CommandIsland.tsx";
+
+const STORAGE_ENV: EnvFragment = EnvFragment {
+    section: "S3 / Object Storage (for file attachments)",
+    vars: &[
+        EnvVar::server("AWS_REGION", "z.string().optional()").example("eu-central-1"),
+        EnvVar::server("AWS_S3_BUCKET_NAME", "z.string().optional()"),
+        EnvVar::server("AWS_ACCESS_KEY_ID", "z.string().optional()"),
+        EnvVar::server("AWS_SECRET_ACCESS_KEY", "z.string().optional()"),
+        // Only read with storage: "s3-compatible" in src/server/chat/config.ts
+        EnvVar::server("AWS_S3_ENDPOINT", "z.string().url().optional()")
+            .example("http://localhost:9000"),
+    ],
+};
+
+const DOCLING_ENV: EnvFragment = EnvFragment {
+    section: "Document Processing (optional)",
+    vars: &[
+        EnvVar::server("DOCLING_ENDPOINT", "z.string().url().optional()")
+            .example("http://localhost:5001")
+            .production(""),
+        EnvVar::server("DOCLING_API_KEY", "z.string().optional()"),
+    ],
+};
+
+/// npm packages CommandIsland imports
+pub const CMD_DEPENDENCIES: &[(&str, &str)] = &[
+    // LangChain
+    ("@langchain/anthropic", "^1.3.18"),
+    ("@langchain/cohere", "^1.0.2"),
+    ("@langchain/core", "^1.1.26"),
+    ("@langchain/google-genai", "^2.1.19"),
+    ("@langchain/mistralai", "^1.0.4"),
+    ("@langchain/ollama", "^1.2.3"),
+    ("@langchain/openai", "^1.2.8"),
+    ("@langchain/textsplitters", "^1.0.1"),
+    ("langchain", "^1.2.25"),
+    // Backend
+    ("winston", "^3.19.0"),
+    ("pg", "^8.18.0"),
+    ("server-only", "^0.0.1"),
+    // Frontend
+    ("react-markdown", "^10.1.0"),
+    ("remark-gfm", "^4.0.1"),
+    ("@floating-ui/react", "^0.27.18"),
+    ("sonner", "^2.0.7"),
+    ("class-variance-authority", "^0.7.1"),
+    ("date-fns", "^4.1.0"),
+    // DocGen
+    ("pdfmake", "^0.3.4"),
+    ("exceljs", "^4.4.0"),
+    ("pptxgenjs", "^4.0.1"),
+    // AWS
+    ("@aws-sdk/client-s3", "^3.993.0"),
+    ("@aws-sdk/s3-request-presigner", "^3.993.0"),
+];
+
+pub const CMD_DEV_DEPENDENCIES: &[(&str, &str)] =
+    &[("@types/pdfmake", "^0.3.1"), ("@types/pg", "^8.16.0")];
+
+/// Models offered per provider, default first; the ids `ModelRegistry` knows
+pub fn models(provider: LlmProvider) -> &'static [&'static str] {
+    match provider {
+        LlmProvider::Anthropic => &[
+            "claude-sonnet-4-20250514",
+            "claude-opus-4-20250514",
+            "claude-3-5-haiku-20241022",
+        ],
+        LlmProvider::Openai => &["gpt-4o", "gpt-4o-mini", "o1"],
+        LlmProvider::Mistral => &["mistral-large-latest", "mistral-small-latest"],
+        LlmProvider::Google => &["gemini-2.0-flash-exp", "gemini-1.5-pro", "gemini-1.5-flash"],
+        LlmProvider::Ollama => &["llama3.2", "mistral", "phi3"],
+    }
+}
+
+/// Environment variable the provider needs
+pub fn provider_env(provider: LlmProvider) -> &'static str {
+    match provider {
+        LlmProvider::Anthropic => "ANTHROPIC_API_KEY",
+        LlmProvider::Openai => "OPENAI_API_KEY",
+        LlmProvider::Mistral => "MISTRAL_API_KEY",
+        LlmProvider::Google => "GOOGLE_API_KEY",
+        LlmProvider::Ollama => "OLLAMA_BASE_URL",
+    }
+}
+
+/// Ask for the provider, model, attachment search, storage, and modes, starting from `defaults`
+pub fn prompt_settings(defaults: &CmdSettings) -> Result<CmdSettings> {
+    const PROVIDERS: [(LlmProvider, &str); 5] = [
+        (LlmProvider::Anthropic, "Anthropic"),
+        (LlmProvider::Openai, "OpenAI"),
+        (LlmProvider::Mistral, "Mistral"),
+        (LlmProvider::Google, "Google"),
+        (LlmProvider::Ollama, "Ollama"),
+    ];
+    let selection = Select::new()
+        .with_prompt(Msg::SelectCmdProvider.text())
+        .items(PROVIDERS.map(|(_, label)| label))
+        .default(
+            PROVIDERS
+                .iter()
+                .position(|(provider, _)| *provider == defaults.provider)
+                .unwrap_or(0),
+        )
+        .interact()?;
+    let provider = PROVIDERS[selection].0;
+
+    let models = models(provider);
+    let selection = Select::new()
+        .with_prompt(Msg::SelectCmdModel.text())
+        .items(models)
+        .default(
+            models
+                .iter()
+                .position(|model| provider == defaults.provider && *model == defaults.model())
+                .unwrap_or(0),
+        )
+        .interact()?;
+    let model = models[selection];
+
+    let selection = Select::new()
+        .with_prompt(Msg::SelectCmdVectors.text())
+        .items([Msg::PgvectorSearch.text(), Msg::KeywordSearch.text()])
+        .default(usize::from(defaults.vectors == VectorStore::Keyword))
+        .interact()?;
+    let vectors = [VectorStore::Pgvector, VectorStore::Keyword][selection];
+
+    let selection = Select::new()
+        .with_prompt(Msg::SelectCmdStorage.text())
+        .items([Msg::AwsS3.text(), Msg::S3Compatible.text()])
+        .default(usize::from(
+            defaults.storage == StorageBackend::S3Compatible,
+        ))
+        .interact()?;
+    let storage = [StorageBackend::S3, StorageBackend::S3Compatible][selection];
+
+    let modules = [CmdModule::Tables, CmdModule::Docs];
+    let selections = MultiSelect::new()
+        .with_prompt(Msg::SelectCmdModules.text())
+        .items([Msg::AiTables.text(), Msg::AiDocs.text()])
+        .defaults(&modules.map(|module| defaults.modules.contains(&module)))
+        .interact()?;
+
+    Ok(CmdSettings {
+        provider,
+        // The default stays unset, so it follows the provider's list
+        model: (model != models[0]).then(|| model.to_string()),
+        vectors,
+        storage,
+        modules: selections.into_iter().map(|index| modules[index]).collect(),
+    })
+}
 
 /// Scaffold CommandIsland AI layer (chat, tables, docs, split-view)
-pub async fn scaffold(project_path: &str) -> Result<()> {
+pub async fn scaffold(project_path: &str, settings: &CmdSettings) -> Result<()> {
     let project = Path::new(project_path);
 
     // ── 1. Copy embedded template files ──────────────────────────────────────
     // components -> src/components
     let components_dest = project.join("src/components");
-    tokio::fs::create_dir_all(&components_dest).await?;
+    fs::create_dir_all(&components_dest)?;
     embedded::copy_embedded_dir("cmd/components/", &components_dest).await?;
 
     // lib -> src/lib
     let lib_dest = project.join("src/lib");
-    tokio::fs::create_dir_all(&lib_dest).await?;
+    fs::create_dir_all(&lib_dest)?;
     embedded::copy_embedded_dir("cmd/lib/", &lib_dest).await?;
 
     // server -> src/server
     let server_dest = project.join("src/server");
-    tokio::fs::create_dir_all(&server_dest).await?;
+    fs::create_dir_all(&server_dest)?;
     embedded::copy_embedded_dir("cmd/server/", &server_dest).await?;
 
-    // ── 2. Overwrite tRPC init with auth-aware version ───────────────────────
-    write_file(project_path, "src/server/api/trpc.ts", TRPC_INIT_WITH_AUTH)?;
+    // public -> public: a sample document to try uploads and extraction with
+    embedded::copy_embedded_dir("cmd/public/", &project.join("public")).await?;
 
-    // ── 3. Overwrite tRPC root to register cmd routers ───────────────────────
-    write_file(project_path, "src/server/api/root.ts", TRPC_ROOT_WITH_CMD)?;
+    // ── 2. Make sure the tRPC init exposes the session ───────────────────────
+    trpc::write_protected_init(project_path)?;
+
+    // ── 3. Register cmd routers in the tRPC root ─────────────────────────────
+    trpc::register_router(
+        project_path,
+        "chat",
+        "chatRouter",
+        "@/server/api/routers/chat",
+    )?;
+    trpc::register_router(
+        project_path,
+        "tables",
+        "tablesRouter",
+        "@/server/api/routers/tables",
+    )?;
+    trpc::register_router(
+        project_path,
+        "docs",
+        "docsRouter",
+        "@/server/api/routers/docs",
+    )?;
 
     // ── 4. Modify Prisma schema ──────────────────────────────────────────────
     modify_prisma_schema(project_path)?;
 
     // ── 5. Merge translations ────────────────────────────────────────────────
-    merge_translations(project_path, "messages/en.json", CMD_MESSAGES_EN)?;
-    merge_translations(project_path, "messages/de.json", CMD_MESSAGES_DE)?;
+    i18n::merge_translations(project_path, "messages/en.json", CMD_MESSAGES_EN)?;
+    i18n::merge_translations(project_path, "messages/de.json", CMD_MESSAGES_DE)?;
 
     // ── 6. Write CommandIslandLayout wrapper ─────────────────────────────────
     write_file(
@@ -46,6 +227,12 @@ pub async fn scaffold(project_path: &str) -> Result<()> {
 
     // ── 7. Overwrite root layout to include CommandIslandLayout ──────────────
     write_file(project_path, "src/app/layout.tsx", APP_LAYOUT_WITH_CMD)?;
+    if search::is_installed(project_path) {
+        search::mount_island(project_path).await?;
+    }
+    if security_headers::is_configured(project_path) {
+        security_headers::patch_layout(project_path)?;
+    }
 
     // ── 8. Write PageGuide stub ──────────────────────────────────────────────
     write_file(
@@ -54,25 +241,239 @@ pub async fn scaffold(project_path: &str) -> Result<()> {
         PAGE_GUIDE_STUB,
     )?;
 
-    // ── 9. Write Claude skill ────────────────────────────────────────────────
-    let claude_dir = project.join(".claude/skills");
-    tokio::fs::create_dir_all(&claude_dir).await?;
-    write_file(
-        project_path,
-        ".claude/skills/commandisland.md",
-        CLAUDE_CMD_SKILL,
-    )?;
+    // ── 9. Validate AI, storage, and docling variables ───────────────────────
+    env::register(project_path, &ai::ENV)?;
+    env::register(project_path, &STORAGE_ENV)?;
+    env::register(project_path, &DOCLING_ENV)?;
+
+    // ── 10. Write the settings and read them where the templates hardcode them
+    write_config(project_path, settings)?;
+    use_config(project_path)?;
 
     Ok(())
 }
 
+/// Write `src/server/chat/config.ts` for `settings`
+pub fn write_config(project_path: &str, settings: &CmdSettings) -> Result<()> {
+    let provider = match settings.provider {
+        LlmProvider::Anthropic => "anthropic",
+        LlmProvider::Openai => "openai",
+        LlmProvider::Mistral => "mistral",
+        LlmProvider::Google => "google",
+        LlmProvider::Ollama => "ollama",
+    };
+    let vectors = match settings.vectors {
+        VectorStore::Pgvector => "pgvector",
+        VectorStore::Keyword => "keyword",
+    };
+    let storage = match settings.storage {
+        StorageBackend::S3 => "s3",
+        StorageBackend::S3Compatible => "s3-compatible",
+    };
+    let content = CMD_CONFIG
+        .replace("{{provider}}", provider)
+        .replace("{{model}}", settings.model())
+        .replace("{{vectors}}", vectors)
+        .replace("{{storage}}", storage)
+        .replace(
+            "{{tables}}",
+            &settings.modules.contains(&CmdModule::Tables).to_string(),
+        )
+        .replace(
+            "{{docs}}",
+            &settings.modules.contains(&CmdModule::Docs).to_string(),
+        );
+    write_file(project_path, CONFIG_PATH, &content)
+}
+
+/// Point the copied templates' model, storage, search, and modes at `cmdConfig`
+fn use_config(project_path: &str) -> Result<()> {
+    for path in [
+        "src/server/chat/llm.ts",
+        "src/server/tables/orchestrator.ts",
+        "src/server/docs/orchestrator.ts",
+    ] {
+        patch(project_path, path, |content| {
+            let content = content.replacen(REGISTRY_IMPORT, CONFIG_LLM_IMPORT, 1);
+            ["sonnet", "haiku"].into_iter().fold(content, |content, model| {
+                content.replacen(
+                    &format!("    provider: \"anthropic\",\n    model: ModelRegistry.anthropic.{},\n", model),
+                    "    provider: cmdConfig.provider,\n    model: cmdConfig.model,\n",
+                    1,
+                )
+            })
+        })?;
+    }
+
+    for path in [
+        "src/server/api/routers/chat.ts",
+        "src/server/api/routers/docs.ts",
+        "src/server/docs/file-generator.ts",
+    ] {
+        patch(project_path, path, |content| {
+            let content = content.replacen(
+                "const s3Client = new S3Client({ region: process.env.AWS_REGION || \"us-east-1\" });",
+                "const s3Client = new S3Client(s3ClientConfig());",
+                1,
+            );
+            add_import(
+                &content,
+                "from \"@aws-sdk/client-s3\";\n",
+                "import { s3ClientConfig } from \"@/server/chat/config\";\n",
+            )
+        })?;
+    }
+
+    // Embed attachments only for pgvector; the keyword half of the search works without
+    patch(project_path, "src/server/api/routers/chat.ts", |content| {
+        wrap_in_if(
+            &content.replacen(
+                "import { s3ClientConfig }",
+                "import { cmdConfig, s3ClientConfig }",
+                1,
+            ),
+            "        try {\n          const embeddingGen = new EmbeddingGenerator({",
+            "\n        // Update attachment status",
+            "cmdConfig.vectors === \"pgvector\"",
+        )
+    })?;
+    patch(project_path, "src/server/chat/chat-tools.ts", |content| {
+        let content = wrap_in_if(
+            content,
+            "        const embeddingGen = new EmbeddingGenerator({",
+            "\n        // Keyword search via ILIKE",
+            "cmdConfig.vectors === \"pgvector\"",
+        )
+        .replacen("          const semanticResults: any[] = await", "          semanticResults = await", 1)
+        .replacen(
+            "        if (cmdConfig.vectors === \"pgvector\") {\n          const embeddingGen",
+            "        let semanticResults: any[] = [];\n        if (cmdConfig.vectors === \"pgvector\") {\n          const embeddingGen",
+            1,
+        );
+        add_import(
+            &content,
+            "from \"@/components/ai/core/embedding\";\n",
+            "import { cmdConfig } from \"@/server/chat/config\";\n",
+        )
+    })?;
+
+    // Hide the modes left out, with their quick suggestions
+    patch(project_path, ISLAND_PATH, |content| {
+        let content = content
+            .replacen("const QUICK_SUGGESTIONS: QuickSuggestion[] = [", "const ALL_SUGGESTIONS: QuickSuggestion[] = [", 1)
+            .replacen(
+                "\n];\n\ninterface PendingAttachment",
+                "\n];\nconst QUICK_SUGGESTIONS = ALL_SUGGESTIONS.filter((s) => s.type !== \"table\" || cmdConfig.modules.tables);\n\ninterface PendingAttachment",
+                1,
+            );
+        let content = ["tables", "docs"]
+            .into_iter()
+            .fold(content, |content, mode| {
+                wrap_buttons(&content, mode, &format!("cmdConfig.modules.{}", mode))
+            });
+        add_import(
+            &content,
+            "from \"@/lib/split-view-context\";\n",
+            "import { cmdConfig } from \"@/server/chat/config\";\n",
+        )
+    })
+}
+
+/// Rewrite a copied template, failing when a patch found nothing to change
+fn patch(project_path: &str, path: &str, rewrite: impl Fn(&str) -> String) -> Result<()> {
+    let content = fs::read_to_string(Path::new(project_path).join(path))
+        .with_context(|| format!("Failed to read {}", path))?;
+    let patched = rewrite(&content);
+    if patched == content || !patched.contains("cmdConfig") && !patched.contains("s3ClientConfig") {
+        anyhow::bail!(
+            "Could not find the settings in {}; read them from cmdConfig in {} manually",
+            path,
+            CONFIG_PATH
+        );
+    }
+    update_file(project_path, path, &patched)
+}
+
+/// `content` with `import` on the line after the one ending in `anchor`
+fn add_import(content: &str, anchor: &str, import: &str) -> String {
+    match content.find(anchor) {
+        Some(start) => {
+            let end = start + anchor.len();
+            format!("{}{}{}", &content[..end], import, &content[end..])
+        }
+        None => content.to_string(),
+    }
+}
+
+/// Wrap the lines from the one starting with `from` up to `until` in `if (condition) { .. }`
+fn wrap_in_if(content: &str, from: &str, until: &str, condition: &str) -> String {
+    let Some(start) = content.find(from) else {
+        return content.to_string();
+    };
+    let Some(end) = content[start..].find(until).map(|offset| start + offset) else {
+        return content.to_string();
+    };
+    let indent = &from[..from.len() - from.trim_start().len()];
+    format!(
+        "{}{}if ({}) {{\n{}{}}}\n{}",
+        &content[..start],
+        indent,
+        condition,
+        indent_lines(&content[start..end]),
+        indent,
+        &content[end..]
+    )
+}
+
+/// Render each `<button>` switching to `mode` only when `condition` holds
+fn wrap_buttons(content: &str, mode: &str, condition: &str) -> String {
+    let on_click = format!("onClick={{() => setMode(\"{}\")}}", mode);
+    let mut result = String::new();
+    let mut rest = content;
+    while let Some(click) = rest.find(&on_click) {
+        let (Some(button), Some(close)) = (
+            rest[..click].rfind("<button"),
+            rest[click..].find("</button>\n"),
+        ) else {
+            break;
+        };
+        let start = rest[..button].rfind('\n').map_or(0, |newline| newline + 1);
+        let end = click + close + "</button>\n".len();
+        let indent = &rest[start..button];
+        result.push_str(&rest[..start]);
+        result.push_str(&format!(
+            "{}{{{} && (\n{}{})}}\n",
+            indent,
+            condition,
+            indent_lines(&rest[start..end]),
+            indent
+        ));
+        rest = &rest[end..];
+    }
+    result.push_str(rest);
+    result
+}
+
+fn indent_lines(block: &str) -> String {
+    block
+        .lines()
+        .map(|line| {
+            if line.is_empty() {
+                "\n".to_string()
+            } else {
+                format!("  {}\n", line)
+            }
+        })
+        .collect()
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Prisma schema modification
 // ─────────────────────────────────────────────────────────────────────────────
 
 fn modify_prisma_schema(project_path: &str) -> Result<()> {
     let schema_path = Path::new(project_path).join("prisma/schema.prisma");
-    let mut content = std::fs::read_to_string(&schema_path)?;
+    let mut content = fs::read_to_string(&schema_path)?;
 
     // Replace generator block to add previewFeatures
     content = content.replace(
@@ -98,47 +499,20 @@ fn modify_prisma_schema(project_path: &str) -> Result<()> {
 }"#,
     );
 
-    // Add reverse relations to User model
-    // Find the User model's closing fields and inject before the last }
-    if content.contains("model User {") {
-        content = content.replace(
-            "  sessions Session[]\n  accounts Account[]\n}",
-            "  sessions Session[]\n  accounts Account[]\n\n  chatThreads     ChatThread[]\n  aiTableSessions AITableSession[]\n  aiDocSessions   AIDocSession[]\n}",
-        );
-    }
-
-    // Append cmd models
-    content.push_str(CMD_PRISMA_MODELS);
-
-    std::fs::write(schema_path, content)?;
-
-    Ok(())
-}
-
-// ─────────────────────────────────────────────────────────────────────────────
-// Translation merging
-// ─────────────────────────────────────────────────────────────────────────────
-
-fn merge_translations(
-    project_path: &str,
-    relative_path: &str,
-    cmd_json: &str,
-) -> Result<()> {
-    let file_path = Path::new(project_path).join(relative_path);
-    let existing = std::fs::read_to_string(&file_path)?;
-    let mut base: serde_json::Value = serde_json::from_str(&existing)?;
-    let additions: serde_json::Value = serde_json::from_str(cmd_json)?;
-
-    if let (Some(base_obj), Some(additions_obj)) =
-        (base.as_object_mut(), additions.as_object())
-    {
-        for (key, value) in additions_obj {
-            base_obj.insert(key.clone(), value.clone());
+    // Add reverse relations before the User model's closing brace, whichever auth provider wrote it
+    if let Some(start) = content.find("model User {") {
+        if let Some(end) = content[start..].find("\n}").map(|offset| start + offset) {
+            content.insert_str(end, "\n\n  chatThreads     ChatThread[]\n  aiTableSessions AITableSession[]\n  aiDocSessions   AIDocSession[]");
         }
     }
 
-    let merged = serde_json::to_string_pretty(&base)?;
-    std::fs::write(file_path, merged)?;
+    update_file(project_path, "prisma/schema.prisma", &content)?;
+
+    // Append cmd models
+    plan::apply(Op::PatchPrisma {
+        path: schema_path,
+        blocks: CMD_PRISMA_MODELS.to_string(),
+    })?;
 
     Ok(())
 }
@@ -147,72 +521,46 @@ fn merge_translations(
 // Inline Constants
 // ============================================================================
 
-const TRPC_INIT_WITH_AUTH: &str = r#"import { initTRPC, TRPCError } from "@trpc/server";
-import superjson from "superjson";
-import { ZodError } from "zod";
-import { db } from "@/server/db";
-import { auth } from "@/server/auth";
-import { headers } from "next/headers";
-
-export const createTRPCContext = async (opts: { headers: Headers }) => {
-  const session = await auth.api.getSession({
-    headers: await headers(),
-  });
-
-  return {
-    db,
-    session,
-    userId: session?.user?.id,
-    ...opts,
-  };
+const REGISTRY_IMPORT: &str =
+    "import {\n  createLLM,\n  ModelRegistry,\n} from \"@/components/ai/core/providers/index\";\n";
+
+const CONFIG_LLM_IMPORT: &str = "import { createLLM } from \"@/components/ai/core/providers/index\";\nimport { cmdConfig } from \"@/server/chat/config\";\n";
+
+const CMD_CONFIG: &str = r#"/**
+ * CommandIsland settings, chosen when it was added and recorded in .t3mono.json.
+ * The chat, the AI table and document planners, attachment search, file storage,
+ * and the island's modes read them from here.
+ */
+
+type CmdConfig = {
+  /** Provider and model for the chat and the planners; the provider reads its key from .env */
+  provider: "anthropic" | "openai" | "mistral" | "google" | "ollama";
+  model: string;
+  /** "pgvector" embeds attachments and searches by similarity and keywords, "keyword" by keywords only */
+  vectors: "pgvector" | "keyword";
+  /** "s3" for AWS, "s3-compatible" for MinIO, R2, and others at AWS_S3_ENDPOINT */
+  storage: "s3" | "s3-compatible";
+  /** Modes offered next to the chat */
+  modules: { tables: boolean; docs: boolean };
 };
 
-const t = initTRPC.context<typeof createTRPCContext>().create({
-  transformer: superjson,
-  errorFormatter({ shape, error }) {
-    return {
-      ...shape,
-      data: {
-        ...shape.data,
-        zodError:
-          error.cause instanceof ZodError ? error.cause.flatten() : null,
-      },
-    };
-  },
-});
-
-export const createCallerFactory = t.createCallerFactory;
-export const createTRPCRouter = t.router;
-export const publicProcedure = t.procedure;
+export const cmdConfig: CmdConfig = {
+  provider: "{{provider}}",
+  model: "{{model}}",
+  vectors: "{{vectors}}",
+  storage: "{{storage}}",
+  modules: { tables: {{tables}}, docs: {{docs}} },
+};
 
-const enforceAuth = t.middleware(({ ctx, next }) => {
-  if (!ctx.session?.user?.id) {
-    throw new TRPCError({ code: "UNAUTHORIZED" });
+/** Options for the S3 clients keeping attachments and generated documents */
+export function s3ClientConfig() {
+  const region = process.env.AWS_REGION || "us-east-1";
+  if (cmdConfig.storage === "s3-compatible") {
+    // Most self-hosted stores don't serve buckets as subdomains
+    return { region, endpoint: process.env.AWS_S3_ENDPOINT, forcePathStyle: true };
   }
-  return next({
-    ctx: {
-      session: ctx.session,
-      userId: ctx.session.user.id,
-    },
-  });
-});
-
-export const protectedProcedure = t.procedure.use(enforceAuth);
-"#;
-
-const TRPC_ROOT_WITH_CMD: &str = r#"import { createCallerFactory, createTRPCRouter } from "@/server/api/trpc";
-import { chatRouter } from "@/server/api/routers/chat";
-import { tablesRouter } from "@/server/api/routers/tables";
-import { docsRouter } from "@/server/api/routers/docs";
-
-export const appRouter = createTRPCRouter({
-  chat: chatRouter,
-  tables: tablesRouter,
-  docs: docsRouter,
-});
-
-export type AppRouter = typeof appRouter;
-export const createCaller = createCallerFactory(appRouter);
+  return { region };
+}
 "#;
 
 const CMD_PRISMA_MODELS: &str = r#"
@@ -584,14 +932,36 @@ const APP_LAYOUT_WITH_CMD: &str = r#"import "@/styles/globals.css";
 import { type Metadata } from "next";
 import { Geist } from "next/font/google";
 import { NextIntlClientProvider, useLocale } from "next-intl";
+import { siteConfig } from "@/lib/site";
 import { TRPCReactProvider } from "@/trpc/react";
 import { ThemeProvider } from "./_components/ThemeProvider";
 import { CommandIslandLayout } from "./_components/CommandIslandLayout";
 
 export const metadata: Metadata = {
-  title: "My App",
-  description: "Built with t3-mono",
-  icons: [{ rel: "icon", url: "/favicon.ico" }],
+  metadataBase: new URL(siteConfig.url),
+  title: {
+    default: siteConfig.name,
+    template: `%s | ${siteConfig.name}`,
+  },
+  description: siteConfig.description,
+  icons: [
+    { rel: "icon", url: "/favicon.ico" },
+    { rel: "apple-touch-icon", url: "/apple-touch-icon.png" },
+  ],
+  openGraph: {
+    type: "website",
+    siteName: siteConfig.name,
+    title: siteConfig.name,
+    description: siteConfig.description,
+    url: "/",
+    images: [{ url: "/og-image.png", width: 1200, height: 630 }],
+  },
+  twitter: {
+    card: "summary_large_image",
+    title: siteConfig.name,
+    description: siteConfig.description,
+    creator: siteConfig.twitterHandle,
+  },
 };
 
 const geist = Geist({
@@ -746,52 +1116,3 @@ export function PageGuide() {
 
 export default PageGuide;
 "#;
-
-const CLAUDE_CMD_SKILL: &str = r#"---
-skill: commandisland-integration
-description: >
-  Integrate the CommandIsland AI module (floating command bar, AI chat, AI Tables,
-  AI Docs, SplitView panel system) into an existing Next.js + tRPC + Prisma project.
-  The module ships as an export/ directory of source files that get copied, wired,
-  and customized for the target domain.
-prerequisites:
-  - Next.js 16+ (App Router)
-  - tRPC v11 with superjson transformer
-  - Prisma 7+ with PostgreSQL (pgvector extension)
-  - Tailwind CSS 4+ with shadcn/ui
-  - next-intl for translations
-author: prototype-ppap
-version: "1.0"
----
-
-# CommandIsland AI Module -- Integration Skill
-
-This project includes the CommandIsland AI module with:
-
-## Components
-- **CommandIsland** (`src/components/layout/CommandIsland.tsx`) - Floating command bar with AI/Tables/Docs modes
-- **SplitViewShell** (`src/components/layout/SplitViewShell.tsx`) - Split-panel layout system
-- **ChatPanel** (`src/components/chat/`) - Full AI chat with streaming, file attachments, reference tokens
-- **AITable** (`src/components/tables/`) - AI-powered data tables with agent columns
-- **AIDocGenerator** (`src/components/docs/`) - AI document generation (PDF, Excel, PowerPoint)
-
-## Server
-- **Chat routers** (`src/server/api/routers/chat.ts`) - tRPC endpoints for chat threads
-- **Tables routers** (`src/server/api/routers/tables.ts`) - tRPC endpoints for AI tables
-- **Docs routers** (`src/server/api/routers/docs.ts`) - tRPC endpoints for doc generation
-- **LLM integration** (`src/server/chat/llm.ts`) - Multi-provider LLM with tool calling
-- **Chat tools** (`src/server/chat/chat-tools.ts`) - Database query tools for LLM
-
-## Customization Points
-- `src/server/chat/chat-tools.ts` - Add domain-specific tools the LLM can call
-- `src/server/chat/llm.ts` - Customize the system prompt
-- `src/server/chat/context-builder.ts` - Build entity context for LLM
-- `src/lib/ai-table-agent-presets.ts` - Define AI table agent presets
-- `src/lib/chat-tokens.ts` - Define inline reference token types
-- `src/components/layout/CommandIsland.tsx` - Customize quick suggestions and context entries
-
-## Environment Variables
-- `ANTHROPIC_API_KEY` - Required for Claude models
-- `OPENAI_API_KEY` - Optional for GPT models
-- `AWS_REGION`, `AWS_S3_BUCKET_NAME`, `AWS_ACCESS_KEY_ID`, `AWS_SECRET_ACCESS_KEY` - For file uploads
-"#;
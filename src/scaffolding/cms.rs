@@ -0,0 +1,88 @@
+use anyhow::Result;
+use std::path::Path;
+
+use crate::cli::{CmsProvider, TailwindPlugin};
+use crate::scaffolding::env::{self, EnvFragment, EnvVar};
+use crate::scaffolding::nav::{self, NavEntry};
+use crate::scaffolding::tailwind;
+use crate::templates::embedded;
+use crate::utils::fs::{self, update_file};
+use crate::utils::npm;
+
+const PREVIEW_ENV: EnvFragment = EnvFragment {
+    section: "CMS Preview",
+    vars: &[EnvVar::server(
+        "CMS_PREVIEW_SECRET",
+        "z.string().optional()",
+    )],
+};
+
+const SANITY_ENV: EnvFragment = EnvFragment {
+    section: "Sanity",
+    vars: &[
+        EnvVar::client("NEXT_PUBLIC_SANITY_PROJECT_ID", "z.string().optional()"),
+        EnvVar::client(
+            "NEXT_PUBLIC_SANITY_DATASET",
+            r#"z.string().default("production")"#,
+        )
+        .example("production"),
+        EnvVar::server("SANITY_API_READ_TOKEN", "z.string().optional()"),
+    ],
+};
+
+const PAYLOAD_ENV: EnvFragment = EnvFragment {
+    section: "Payload",
+    vars: &[
+        EnvVar::server("PAYLOAD_URL", "z.string().url().optional()")
+            .example("http://localhost:3001")
+            .production(""),
+        EnvVar::server("PAYLOAD_API_KEY", "z.string().optional()"),
+    ],
+};
+
+const NAV: NavEntry = NavEntry {
+    href: "/blog",
+    key: "blog",
+    en: "Blog",
+    de: "Blog",
+};
+
+const POST_BODY_PATH: &str = "src/app/blog/[slug]/PostBody.tsx";
+const POST_BODY_CLASSES: &str = "className=\"mt-8 space-y-4 leading-7\"";
+const POST_BODY_PROSE_CLASSES: &str =
+    "className=\"prose prose-neutral dark:prose-invert mt-8 max-w-none\"";
+
+pub const SANITY_DEPENDENCIES: &[(&str, &str)] = &[("next-sanity", "^11.4.0")];
+
+/// Scaffold typed content fetchers for the chosen CMS, `/blog` pages rendering them,
+/// and draft mode routes for previewing unpublished content
+pub async fn scaffold(project_path: &str, provider: CmsProvider) -> Result<()> {
+    let project = Path::new(project_path);
+
+    // Blog pages and preview routes are shared; fetchers and body rendering are per provider
+    embedded::copy_embedded_dir("cms/common/", project).await?;
+    match provider {
+        CmsProvider::Sanity => {
+            embedded::copy_embedded_dir("cms/sanity/", project).await?;
+            env::register(project_path, &SANITY_ENV)?;
+            npm::add_dependencies(project_path, SANITY_DEPENDENCIES, &[])?;
+        }
+        CmsProvider::Payload => {
+            // Talks to a separate Payload deployment over REST, so no packages are needed
+            embedded::copy_embedded_dir("cms/payload/", project).await?;
+            env::register(project_path, &PAYLOAD_ENV)?;
+        }
+    }
+    env::register(project_path, &PREVIEW_ENV)?;
+    nav::register(project_path, &NAV)?;
+
+    // Rich text gets the typography plugin's styles when the project has it
+    if tailwind::is_installed(project_path, TailwindPlugin::Typography) {
+        let body = project.join(POST_BODY_PATH);
+        let content = fs::read_to_string(&body)?;
+        let patched = content.replacen(POST_BODY_CLASSES, POST_BODY_PROSE_CLASSES, 1);
+        update_file(project_path, POST_BODY_PATH, &patched)?;
+    }
+
+    Ok(())
+}
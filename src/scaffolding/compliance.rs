@@ -0,0 +1,194 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use crate::scaffolding::env::{self, EnvFragment, EnvVar};
+use crate::scaffolding::i18n;
+use crate::templates::embedded;
+use crate::utils::fs::{self, update_file};
+
+const ANALYTICS_ENV: EnvFragment = EnvFragment {
+    section: "Analytics",
+    vars: &[
+        EnvVar::client(
+            "NEXT_PUBLIC_ANALYTICS_SCRIPT_URL",
+            "z.string().url().optional()",
+        )
+        .example("https://plausible.io/js/script.js"),
+        EnvVar::client("NEXT_PUBLIC_ANALYTICS_DOMAIN", "z.string().optional()"),
+    ],
+};
+
+/// Scaffold the cookie-consent banner, a consent-gated analytics loader, and localized
+/// privacy policy and imprint pages under `/[locale]/`
+pub async fn scaffold(project_path: &str) -> Result<()> {
+    embedded::copy_embedded_dir("compliance/", Path::new(project_path)).await?;
+
+    i18n::merge_translations(project_path, "messages/en.json", COMPLIANCE_MESSAGES_EN)?;
+    i18n::merge_translations(project_path, "messages/de.json", COMPLIANCE_MESSAGES_DE)?;
+    env::register(project_path, &ANALYTICS_ENV)?;
+    mount_in_layout(project_path)?;
+
+    Ok(())
+}
+
+/// Render the banner and the analytics loader inside the intl provider of the root layout
+fn mount_in_layout(project_path: &str) -> Result<()> {
+    let layout_path = Path::new(project_path).join("src/app/layout.tsx");
+    let content = fs::read_to_string(&layout_path).context("Failed to read src/app/layout.tsx")?;
+
+    if content.contains("<CookieConsent />") {
+        return Ok(());
+    }
+
+    let patched = content
+        .replacen(
+            "import { TRPCReactProvider } from \"@/trpc/react\";\n",
+            "import { TRPCReactProvider } from \"@/trpc/react\";\nimport { ConsentedAnalytics, CookieConsent } from \"@/components/consent\";\n",
+            1,
+        )
+        .replacen(
+            "          </NextIntlClientProvider>",
+            "            <CookieConsent />\n            <ConsentedAnalytics />\n          </NextIntlClientProvider>",
+            1,
+        );
+
+    if !patched.contains("<CookieConsent />") {
+        anyhow::bail!(
+            "Could not find <NextIntlClientProvider> in src/app/layout.tsx; render <CookieConsent /> and <ConsentedAnalytics /> there manually"
+        );
+    }
+
+    update_file(project_path, "src/app/layout.tsx", &patched)
+}
+
+// ============================================================================
+// Embedded Templates
+// ============================================================================
+
+const COMPLIANCE_MESSAGES_EN: &str = r#"{
+  "consent": {
+    "title": "Cookies on this site",
+    "description": "We use strictly necessary cookies to run this site. With your permission we also load privacy-friendly analytics to understand how it is used.",
+    "privacyLink": "Privacy policy",
+    "necessaryOnly": "Only necessary",
+    "acceptAll": "Accept all"
+  },
+  "legal": {
+    "privacy": {
+      "title": "Privacy Policy",
+      "lastUpdated": "Last updated: [date]. This is a template, not legal advice; have it reviewed before publishing.",
+      "controller": {
+        "heading": "Controller",
+        "body": "[Company name]\n[Street and number]\n[Postcode and city]\n[Email address]"
+      },
+      "data": {
+        "heading": "Data we process",
+        "body": "When you create an account we store your name, email address, and profile image to provide the service. Server logs contain IP addresses and are deleted after [n] days."
+      },
+      "cookies": {
+        "heading": "Cookies",
+        "body": "Strictly necessary cookies keep you signed in and remember your language and cookie choice. Optional cookies are only set after you accept them in the cookie banner.",
+        "settings": "Change cookie settings"
+      },
+      "analytics": {
+        "heading": "Analytics",
+        "body": "If you accept all cookies, we load [analytics provider] to measure page views. Legal basis: your consent (Art. 6(1)(a) GDPR), which you can withdraw at any time."
+      },
+      "rights": {
+        "heading": "Your rights",
+        "body": "You have the right to access, rectify, erase, and restrict processing of your data, to data portability, to object, and to lodge a complaint with a supervisory authority."
+      },
+      "contact": {
+        "heading": "Contact",
+        "body": "For privacy questions contact [privacy contact email]."
+      }
+    },
+    "imprint": {
+      "title": "Imprint",
+      "provider": {
+        "heading": "Service provider",
+        "body": "[Company name]\n[Street and number]\n[Postcode and city]"
+      },
+      "contact": {
+        "heading": "Contact",
+        "body": "Phone: [phone number]\nEmail: [email address]"
+      },
+      "register": {
+        "heading": "Register entry",
+        "body": "Registered at [register court], [register number]\nVAT ID: [VAT identification number]"
+      },
+      "responsible": {
+        "heading": "Represented by",
+        "body": "[Managing director]"
+      },
+      "disputes": {
+        "heading": "Dispute resolution",
+        "body": "We are neither willing nor obliged to take part in dispute resolution proceedings before a consumer arbitration board."
+      }
+    }
+  }
+}"#;
+
+const COMPLIANCE_MESSAGES_DE: &str = r#"{
+  "consent": {
+    "title": "Cookies auf dieser Website",
+    "description": "Wir verwenden technisch notwendige Cookies, um diese Website zu betreiben. Mit Ihrer Einwilligung laden wir zusätzlich eine datenschutzfreundliche Analyse, um die Nutzung zu verstehen.",
+    "privacyLink": "Datenschutzerklärung",
+    "necessaryOnly": "Nur notwendige",
+    "acceptAll": "Alle akzeptieren"
+  },
+  "legal": {
+    "privacy": {
+      "title": "Datenschutzerklärung",
+      "lastUpdated": "Stand: [Datum]. Dies ist eine Vorlage und keine Rechtsberatung; lassen Sie sie vor der Veröffentlichung prüfen.",
+      "controller": {
+        "heading": "Verantwortlicher",
+        "body": "[Firmenname]\n[Straße und Hausnummer]\n[PLZ und Ort]\n[E-Mail-Adresse]"
+      },
+      "data": {
+        "heading": "Verarbeitete Daten",
+        "body": "Wenn Sie ein Konto anlegen, speichern wir Ihren Namen, Ihre E-Mail-Adresse und Ihr Profilbild, um den Dienst bereitzustellen. Server-Logs enthalten IP-Adressen und werden nach [n] Tagen gelöscht."
+      },
+      "cookies": {
+        "heading": "Cookies",
+        "body": "Technisch notwendige Cookies halten Sie angemeldet und speichern Ihre Sprache und Ihre Cookie-Auswahl. Optionale Cookies werden erst gesetzt, nachdem Sie im Cookie-Banner zugestimmt haben.",
+        "settings": "Cookie-Einstellungen ändern"
+      },
+      "analytics": {
+        "heading": "Analyse",
+        "body": "Wenn Sie alle Cookies akzeptieren, laden wir [Analyseanbieter], um Seitenaufrufe zu messen. Rechtsgrundlage ist Ihre Einwilligung (Art. 6 Abs. 1 lit. a DSGVO), die Sie jederzeit widerrufen können."
+      },
+      "rights": {
+        "heading": "Ihre Rechte",
+        "body": "Sie haben das Recht auf Auskunft, Berichtigung, Löschung und Einschränkung der Verarbeitung Ihrer Daten, auf Datenübertragbarkeit, auf Widerspruch sowie auf Beschwerde bei einer Aufsichtsbehörde."
+      },
+      "contact": {
+        "heading": "Kontakt",
+        "body": "Bei Fragen zum Datenschutz wenden Sie sich an [Datenschutz-E-Mail]."
+      }
+    },
+    "imprint": {
+      "title": "Impressum",
+      "provider": {
+        "heading": "Angaben gemäß § 5 DDG",
+        "body": "[Firmenname]\n[Straße und Hausnummer]\n[PLZ und Ort]"
+      },
+      "contact": {
+        "heading": "Kontakt",
+        "body": "Telefon: [Telefonnummer]\nE-Mail: [E-Mail-Adresse]"
+      },
+      "register": {
+        "heading": "Registereintrag",
+        "body": "Eingetragen beim [Registergericht], [Registernummer]\nUSt-IdNr.: [Umsatzsteuer-Identifikationsnummer]"
+      },
+      "responsible": {
+        "heading": "Vertreten durch",
+        "body": "[Geschäftsführung]"
+      },
+      "disputes": {
+        "heading": "Streitschlichtung",
+        "body": "Wir sind nicht bereit oder verpflichtet, an Streitbeilegungsverfahren vor einer Verbraucherschlichtungsstelle teilzunehmen."
+      }
+    }
+  }
+}"#;
@@ -0,0 +1,59 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use crate::cli::ComponentVariant;
+use crate::templates::embedded;
+use crate::utils::fs::{self, update_file, write_file};
+use crate::utils::naming::{camel_case, kebab_case, pascal_case};
+
+const UI_INDEX_PATH: &str = "src/components/ui/index.ts";
+
+/// Generate a cva-styled component in `src/components/ui/` and export it from the UI
+/// kit index. Returns the path of the new component.
+pub fn generate(project_path: &str, name: &str, variant: ComponentVariant) -> Result<String> {
+    let project = Path::new(project_path);
+    let index_path = project.join(UI_INDEX_PATH);
+    if !fs::path_exists(&index_path) {
+        anyhow::bail!("Components build on the UI kit. Run `t3-mono add ui` first.");
+    }
+
+    let file = kebab_case(name);
+    let component_path = format!("src/components/ui/{}.tsx", file);
+    if fs::path_exists(project.join(&component_path)) {
+        anyhow::bail!("{} already exists", component_path);
+    }
+
+    let template = match variant {
+        ComponentVariant::Card => "card",
+        ComponentVariant::Dialog => "dialog",
+        ComponentVariant::Form => "form",
+    };
+    let vars = [
+        ("Name", pascal_case(name)),
+        ("name", camel_case(name)),
+        ("slot", file.clone()),
+    ];
+    let content = embedded::render_template(&format!("generate/component/{}.tsx", template), &vars)
+        .with_context(|| {
+            format!(
+                "Missing embedded template generate/component/{}.tsx",
+                template
+            )
+        })?;
+    write_file(project_path, &component_path, &content)?;
+
+    let index = fs::read_to_string(&index_path)
+        .with_context(|| format!("Failed to read {}", UI_INDEX_PATH))?;
+    let export = format!("export * from \"./{}\";", file);
+    if !index.lines().any(|line| line == export) {
+        let mut patched = index;
+        if !patched.ends_with('\n') {
+            patched.push('\n');
+        }
+        patched.push_str(&export);
+        patched.push('\n');
+        update_file(project_path, UI_INDEX_PATH, &patched)?;
+    }
+
+    Ok(component_path)
+}
@@ -0,0 +1,216 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use crate::cli::DarkMode;
+use crate::scaffolding::i18n;
+use crate::scaffolding::nav::HEADER_PATH;
+use crate::utils::fs::{self, update_file, write_file};
+
+const LAYOUT_PATH: &str = "src/app/layout.tsx";
+const TAILWIND_CONFIG_PATH: &str = "tailwind.config.ts";
+const PROVIDER_PATH: &str = "src/app/_components/ThemeProvider.tsx";
+/// The stylesheet the layout imports, and the UI kit's theme when it is present
+const STYLESHEETS: &[&str] = &["src/styles/globals.css", "src/app/globals.css"];
+
+const CLASS_DARK_MODE: &str = "  darkMode: \"class\",\n";
+const PROVIDER_IMPORT: &str = "import { ThemeProvider } from \"./_components/ThemeProvider\";\n";
+const PROVIDER_OPEN: &str = "        <ThemeProvider>\n";
+const PROVIDER_CLOSE: &str = "        </ThemeProvider>\n";
+const SWITCHER_IMPORT: &str =
+    "import { LanguageSwitcher } from \"@/app/_components/LanguageSwitcher\";\n";
+const SWITCHER_ELEMENT: &str = "            <LanguageSwitcher />\n";
+
+/// Apply the dark mode strategy once the layout, Header, and stylesheets are final
+pub fn scaffold(project_path: &str, mode: DarkMode) -> Result<()> {
+    for stylesheet in STYLESHEETS {
+        let Ok(content) = fs::read_to_string(Path::new(project_path).join(stylesheet)) else {
+            continue;
+        };
+        let patched = dark_tokens(&content, mode);
+        if patched != content {
+            update_file(project_path, stylesheet, &patched)?;
+        }
+    }
+
+    // Only read by Tailwind v3 tooling, but kept in step with the stylesheets
+    let replacement = match mode {
+        DarkMode::Class => None,
+        DarkMode::Media => Some("  darkMode: \"media\",\n"),
+        DarkMode::None => Some(""),
+    };
+    if let (Some(replacement), Ok(content)) = (
+        replacement,
+        fs::read_to_string(Path::new(project_path).join(TAILWIND_CONFIG_PATH)),
+    ) {
+        update_file(
+            project_path,
+            TAILWIND_CONFIG_PATH,
+            &content.replacen(CLASS_DARK_MODE, replacement, 1),
+        )?;
+    }
+
+    match mode {
+        DarkMode::Class => add_toggle(project_path),
+        DarkMode::Media | DarkMode::None => remove_provider(project_path),
+    }
+}
+
+/// Where the `.dark` token block applies: a class set by next-themes, the OS setting, or nowhere
+fn dark_tokens(content: &str, mode: DarkMode) -> String {
+    let Some(start) = content.find(".dark {\n") else {
+        return content.to_string();
+    };
+    let end = content[start..]
+        .find("\n}\n")
+        .map_or(content.len(), |i| start + i + 3);
+    let block = &content[start..end];
+
+    match mode {
+        // Tailwind v4's `dark:` follows the OS unless told to follow the class
+        DarkMode::Class => match content.find("\n@theme") {
+            Some(theme) if !content.contains("@custom-variant dark") => format!(
+                "{}\n@custom-variant dark (&:where(.dark, .dark *));\n{}",
+                &content[..theme],
+                &content[theme..]
+            ),
+            _ => content.to_string(),
+        },
+        DarkMode::Media => {
+            let tokens: String = block
+                .lines()
+                .skip(1)
+                .take_while(|line| *line != "}")
+                .map(|line| format!("  {}\n", line))
+                .collect();
+            let media = format!(
+                "@media (prefers-color-scheme: dark) {{\n  :root {{\n{}  }}\n}}\n",
+                tokens
+            );
+            format!("{}{}{}", &content[..start], media, &content[end..])
+        }
+        DarkMode::None => {
+            let rest = content[end..].strip_prefix('\n').unwrap_or(&content[end..]);
+            format!("{}{}", &content[..start], rest)
+        }
+    }
+}
+
+/// Theme toggle next to the language switcher
+fn add_toggle(project_path: &str) -> Result<()> {
+    let header = fs::read_to_string(Path::new(project_path).join(HEADER_PATH))
+        .with_context(|| format!("Failed to read {}", HEADER_PATH))?;
+    if header.contains("<ThemeToggle") {
+        return Ok(());
+    }
+
+    write_file(
+        project_path,
+        "src/app/_components/ThemeToggle.tsx",
+        THEME_TOGGLE,
+    )?;
+    let patched = header
+        .replacen(
+            SWITCHER_IMPORT,
+            &format!(
+                "{}import {{ ThemeToggle }} from \"@/app/_components/ThemeToggle\";\n",
+                SWITCHER_IMPORT
+            ),
+            1,
+        )
+        .replacen(
+            SWITCHER_ELEMENT,
+            &format!("            <ThemeToggle />\n{}", SWITCHER_ELEMENT),
+            1,
+        );
+    update_file(project_path, HEADER_PATH, &patched)?;
+
+    i18n::merge_translations(
+        project_path,
+        "messages/en.json",
+        r#"{ "nav": { "toggleTheme": "Toggle dark mode" } }"#,
+    )?;
+    i18n::merge_translations(
+        project_path,
+        "messages/de.json",
+        r#"{ "nav": { "toggleTheme": "Dunkelmodus umschalten" } }"#,
+    )?;
+
+    Ok(())
+}
+
+/// Unwrap the layout from next-themes' provider, which only matters for class switching
+fn remove_provider(project_path: &str) -> Result<()> {
+    let layout = fs::read_to_string(Path::new(project_path).join(LAYOUT_PATH))
+        .with_context(|| format!("Failed to read {}", LAYOUT_PATH))?;
+
+    let mut patched = String::new();
+    let mut inside = false;
+    for line in layout.split_inclusive('\n') {
+        match line {
+            PROVIDER_IMPORT => {}
+            PROVIDER_OPEN => inside = true,
+            PROVIDER_CLOSE => inside = false,
+            _ if inside => patched.push_str(line.strip_prefix("  ").unwrap_or(line)),
+            _ => patched.push_str(line),
+        }
+    }
+    update_file(project_path, LAYOUT_PATH, &patched)?;
+
+    let provider = Path::new(project_path).join(PROVIDER_PATH);
+    if fs::path_exists(&provider) {
+        fs::remove_file(&provider)
+            .with_context(|| format!("Failed to remove {}", PROVIDER_PATH))?;
+    }
+
+    Ok(())
+}
+
+// ============================================================================
+// Embedded Templates
+// ============================================================================
+
+const THEME_TOGGLE: &str = r#""use client";
+
+import { useEffect, useState } from "react";
+import { useTranslations } from "next-intl";
+import { useTheme } from "next-themes";
+
+export function ThemeToggle() {
+  const { resolvedTheme, setTheme } = useTheme();
+  const t = useTranslations("nav");
+  // The resolved theme is only known in the browser; render the light icon until then
+  const [mounted, setMounted] = useState(false);
+  useEffect(() => setMounted(true), []);
+
+  const isDark = mounted && resolvedTheme === "dark";
+
+  return (
+    <button
+      type="button"
+      onClick={() => setTheme(isDark ? "light" : "dark")}
+      className="p-2 rounded-lg text-muted-foreground hover:text-primary hover:bg-muted cursor-pointer transition-colors"
+      aria-label={t("toggleTheme")}
+    >
+      {isDark ? (
+        <svg className="w-5 h-5" fill="none" viewBox="0 0 24 24" stroke="currentColor" aria-hidden="true">
+          <path
+            strokeLinecap="round"
+            strokeLinejoin="round"
+            strokeWidth={2}
+            d="M12 3v1m0 16v1m9-9h-1M4 12H3m15.364 6.364l-.707-.707M6.343 6.343l-.707-.707m12.728 0l-.707.707M6.343 17.657l-.707.707M16 12a4 4 0 11-8 0 4 4 0 018 0z"
+          />
+        </svg>
+      ) : (
+        <svg className="w-5 h-5" fill="none" viewBox="0 0 24 24" stroke="currentColor" aria-hidden="true">
+          <path
+            strokeLinecap="round"
+            strokeLinejoin="round"
+            strokeWidth={2}
+            d="M20.354 15.354A9 9 0 018.646 3.646 9.003 9.003 0 0012 21a9.003 9.003 0 008.354-5.646z"
+          />
+        </svg>
+      )}
+    </button>
+  );
+}
+"#;
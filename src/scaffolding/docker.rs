@@ -0,0 +1,136 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use crate::utils::fs::{self, update_file, write_file};
+
+/// Build the app as a standalone Next.js server and containerize it: `output: "standalone"`
+/// in next.config.js, an app Dockerfile that skips env validation at build time, and an
+/// `app` service next to the database in docker-compose.yml, both probing `/api/health`
+pub fn scaffold(project_path: &str) -> Result<()> {
+    enable_standalone_output(project_path)?;
+    write_file(project_path, "Dockerfile", APP_DOCKERFILE)?;
+    write_file(project_path, ".dockerignore", DOCKERIGNORE)?;
+    add_compose_service(project_path)?;
+
+    Ok(())
+}
+
+fn enable_standalone_output(project_path: &str) -> Result<()> {
+    let config_path = Path::new(project_path).join("next.config.js");
+    let content = fs::read_to_string(&config_path).context("Failed to read next.config.js")?;
+
+    if content.contains("output:") {
+        return Ok(());
+    }
+
+    let patched = content.replacen(
+        "const config = {};",
+        "const config = {\n  // Self-contained server in .next/standalone, used by the Dockerfile\n  output: \"standalone\",\n};",
+        1,
+    );
+    update_file(project_path, "next.config.js", &patched)
+}
+
+fn add_compose_service(project_path: &str) -> Result<()> {
+    let compose_path = Path::new(project_path).join("docker-compose.yml");
+    let content = fs::read_to_string(&compose_path).context("Failed to read docker-compose.yml")?;
+
+    if content.contains("\n  app:\n") {
+        return Ok(());
+    }
+
+    let patched = content.replacen(
+        "\nvolumes:\n",
+        &format!("{}\nvolumes:\n", COMPOSE_APP_SERVICE),
+        1,
+    );
+    update_file(project_path, "docker-compose.yml", &patched)
+}
+
+// ============================================================================
+// Embedded Templates
+// ============================================================================
+
+const APP_DOCKERFILE: &str = r#"FROM node:22-alpine AS base
+RUN apk add --no-cache libc6-compat openssl
+
+# Install dependencies
+FROM base AS deps
+WORKDIR /app
+COPY package.json package-lock.json* ./
+RUN if [ -f package-lock.json ]; then npm ci; else npm install; fi
+
+# Build the standalone server
+FROM base AS builder
+WORKDIR /app
+COPY --from=deps /app/node_modules ./node_modules
+COPY . .
+
+# Secrets aren't available at build time; src/env.js validates them when the server starts
+ARG SKIP_ENV_VALIDATION=1
+ENV SKIP_ENV_VALIDATION=${SKIP_ENV_VALIDATION}
+ENV NEXT_TELEMETRY_DISABLED=1
+
+RUN npx prisma generate && npm run build
+
+# Minimal runtime image
+FROM base AS runner
+WORKDIR /app
+
+ENV NODE_ENV=production
+ENV NEXT_TELEMETRY_DISABLED=1
+ENV PORT=3000
+ENV HOSTNAME=0.0.0.0
+
+RUN addgroup -S -g 1001 nodejs && adduser -S -u 1001 -G nodejs nextjs
+
+COPY --from=builder /app/public ./public
+COPY --from=builder --chown=nextjs:nodejs /app/.next/standalone ./
+COPY --from=builder --chown=nextjs:nodejs /app/.next/static ./.next/static
+
+USER nextjs
+EXPOSE 3000
+
+# Probes /api/health, which also checks the database connection
+HEALTHCHECK --interval=30s --timeout=5s --start-period=20s --retries=3 \
+  CMD wget -qO- http://127.0.0.1:3000/api/health > /dev/null || exit 1
+
+CMD ["node", "server.js"]
+"#;
+
+const DOCKERIGNORE: &str = r#"node_modules
+.next
+.git
+coverage
+restate
+.env
+.env*.local
+.env.production
+"#;
+
+const COMPOSE_APP_SERVICE: &str = r#"
+  app:
+    build:
+      context: .
+      dockerfile: Dockerfile
+      args:
+        SKIP_ENV_VALIDATION: "1"
+    container_name: ${APP_CONTAINER_NAME:-app}
+    restart: unless-stopped
+    env_file:
+      - path: .env.production
+        required: false
+    environment:
+      DATABASE_URL: postgresql://${POSTGRES_USER:-postgres}:${POSTGRES_PASSWORD:-password}@db:5432/${POSTGRES_DB:-app}
+    ports:
+      - "${APP_PORT:-3000}:3000"
+    depends_on:
+      db:
+        condition: service_healthy
+    healthcheck:
+      test: ["CMD-SHELL", "wget -qO- http://127.0.0.1:3000/api/health > /dev/null || exit 1"]
+      interval: 30s
+      timeout: 5s
+      start_period: 20s
+      retries: 3
+"#;
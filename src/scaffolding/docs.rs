@@ -0,0 +1,262 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use crate::cli::{AuthProvider, DarkMode};
+use crate::scaffolding::options::ScaffoldOptions;
+use crate::templates::embedded;
+use crate::utils::fs::{self, update_file, write_file};
+use crate::utils::npm;
+
+const APP_URL: &str = "http://localhost:3000";
+const OLLAMA_URL: &str = "http://localhost:11434";
+const DOCLING_URL: &str = "http://localhost:5001";
+const RESTATE_INGRESS_URL: &str = "http://localhost:8080";
+const RESTATE_ADMIN_URL: &str = "http://localhost:9070";
+const RESTATE_SERVICES_URL: &str = "http://localhost:9082";
+
+/// Guides every project gets
+const BASE_GUIDES: &[&str] = &["PRISMA.md", "THEMING.md", "I18N.md"];
+
+/// A guide that is only written when its extension is selected
+struct ExtensionGuide {
+    extension: &'static str,
+    file: &'static str,
+    /// Line in the README documentation list
+    link: &'static str,
+    /// Line in the README tech stack list
+    stack: &'static str,
+    /// Rows in the README scripts table
+    scripts: &'static [(&'static str, &'static str)],
+}
+
+const EXTENSION_GUIDES: &[ExtensionGuide] = &[
+    ExtensionGuide {
+        extension: "ai",
+        file: "AI.md",
+        link: "- [AI Agents Guide](./AI.md) - Providers, agents, and LLM call logging",
+        stack: "- **AI**: [LangChain](https://js.langchain.com/) agents framework",
+        scripts: &[],
+    },
+    ExtensionGuide {
+        extension: "cmd",
+        file: "COMMAND_ISLAND.md",
+        link: "- [CommandIsland Guide](./COMMAND_ISLAND.md) - Chat, AI tables, and document generation",
+        stack: "- **AI Layer**: CommandIsland (chat, AI tables, docs)",
+        scripts: &[],
+    },
+    ExtensionGuide {
+        extension: "restate",
+        file: "RESTATE.md",
+        link: "- [Restate Guide](./RESTATE.md) - Durable services and the app client",
+        stack: "- **Workflows**: [Restate](https://restate.dev/) durable services",
+        scripts: &[
+            ("restate:up", "Start the Restate stack"),
+            ("restate:dev", "Run the Restate services locally"),
+            ("restate:register", "Register the services with Restate"),
+        ],
+    },
+];
+
+/// Write `docs/` with the base guides plus one guide per selected extension
+pub async fn scaffold(project_path: &str, options: &ScaffoldOptions) -> Result<()> {
+    let guides: Vec<&ExtensionGuide> = EXTENSION_GUIDES
+        .iter()
+        .filter(|guide| is_selected(guide.extension, options))
+        .collect();
+
+    let mut vars = template_vars(&project_name(&options.name), options.auth);
+    vars.push((
+        "extension_stack",
+        render_lines(guides.iter().map(|g| g.stack.to_string())),
+    ));
+    vars.push((
+        "extension_scripts",
+        render_lines(guides.iter().flat_map(|g| g.scripts.iter().map(script_row))),
+    ));
+    vars.push((
+        "extension_guides",
+        render_lines(guides.iter().map(|g| g.link.to_string())),
+    ));
+    vars.extend(database_vars(options.migrations));
+    vars.extend(theming_vars(options.dark_mode));
+
+    write_guide(project_path, "README.md", &vars)?;
+    for file in BASE_GUIDES {
+        // The theming guide covers next-themes, which only class switching uses
+        if *file == "THEMING.md" && options.dark_mode != DarkMode::Class {
+            continue;
+        }
+        write_guide(project_path, file, &vars)?;
+    }
+    for guide in guides {
+        write_guide(project_path, guide.file, &vars)?;
+    }
+
+    Ok(())
+}
+
+/// Add an extension's guide to an existing project and link it from `docs/README.md`.
+/// Projects without a `docs/` folder are left alone.
+pub fn add_guide(project_path: &str, extension: &str) -> Result<()> {
+    let readme_path = Path::new(project_path).join("docs/README.md");
+    let Some(guide) = EXTENSION_GUIDES.iter().find(|g| g.extension == extension) else {
+        return Ok(());
+    };
+    if !fs::path_exists(&readme_path) {
+        return Ok(());
+    }
+
+    let name = npm::read_package_json(project_path)?["name"]
+        .as_str()
+        .map(str::to_string)
+        .unwrap_or_else(|| project_name("."));
+    let vars = template_vars(&name, detect_auth(project_path));
+    write_guide(project_path, guide.file, &vars)?;
+
+    let mut readme = fs::read_to_string(&readme_path)?;
+    insert_after(&mut readme, "- **Linting**:", guide.stack);
+    for row in guide.scripts.iter().map(script_row).rev() {
+        insert_after(&mut readme, "| `npm run test:coverage`", &row);
+    }
+    insert_after(&mut readme, "- [i18n Guide]", guide.link);
+    update_file(project_path, "docs/README.md", &readme)?;
+
+    Ok(())
+}
+
+fn is_selected(extension: &str, options: &ScaffoldOptions) -> bool {
+    match extension {
+        "ai" => options.ai,
+        "cmd" => options.cmd,
+        "restate" => options.restate,
+        _ => false,
+    }
+}
+
+/// Setup step and script rows for `db push` or, with `--migrations`, the migrate workflow
+fn database_vars(migrations: bool) -> Vec<(&'static str, String)> {
+    let (step, command, scripts): (&str, &str, &[(&str, &str)]) = if migrations {
+        (
+            "Apply the migrations",
+            "npm run db:migrate",
+            &[
+                ("db:migrate", "Create and apply a migration in development"),
+                ("db:deploy", "Apply pending migrations (production)"),
+                ("db:reset", "Reset the database and re-apply all migrations"),
+            ],
+        )
+    } else {
+        (
+            "Push the database schema",
+            "npm run db:push",
+            &[
+                ("db:push", "Push Prisma schema to database"),
+                ("db:migrate", "Run database migrations"),
+            ],
+        )
+    };
+
+    vec![
+        ("db_setup_step", step.to_string()),
+        ("db_setup_command", command.to_string()),
+        ("db_scripts", render_lines(scripts.iter().map(script_row))),
+    ]
+}
+
+/// next-themes and its guide only come with class switching
+fn theming_vars(dark_mode: DarkMode) -> Vec<(&'static str, String)> {
+    let class = dark_mode == DarkMode::Class;
+    let line = |text: &str| {
+        if class {
+            format!("{}\n", text)
+        } else {
+            String::new()
+        }
+    };
+    vec![
+        (
+            "theming_stack",
+            line("- **Theming**: [next-themes](https://github.com/pacocoursey/next-themes)"),
+        ),
+        (
+            "theming_guide",
+            line("- [Theming Guide](./THEMING.md) - Dark/light mode configuration"),
+        ),
+    ]
+}
+
+fn template_vars(project_name: &str, auth: AuthProvider) -> Vec<(&'static str, String)> {
+    let (provider, link, secret) = match auth {
+        AuthProvider::BetterAuth => (
+            "Better Auth",
+            "[Better Auth](https://better-auth.com/)",
+            "BETTER_AUTH_SECRET",
+        ),
+        AuthProvider::NextAuth => (
+            "NextAuth",
+            "[NextAuth.js](https://next-auth.js.org/)",
+            "NEXTAUTH_SECRET",
+        ),
+    };
+
+    vec![
+        ("project_name", project_name.to_string()),
+        ("auth_provider", provider.to_string()),
+        ("auth_provider_link", link.to_string()),
+        ("auth_secret_env", secret.to_string()),
+        ("app_url", APP_URL.to_string()),
+        ("ollama_url", OLLAMA_URL.to_string()),
+        ("docling_url", DOCLING_URL.to_string()),
+        ("restate_ingress_url", RESTATE_INGRESS_URL.to_string()),
+        ("restate_admin_url", RESTATE_ADMIN_URL.to_string()),
+        ("restate_services_url", RESTATE_SERVICES_URL.to_string()),
+    ]
+}
+
+fn write_guide(project_path: &str, file: &str, vars: &[(&str, String)]) -> Result<()> {
+    let content = embedded::render_template(&format!("docs/{}", file), vars)
+        .with_context(|| format!("Missing embedded template: docs/{}", file))?;
+    write_file(project_path, &format!("docs/{}", file), &content)
+}
+
+/// Project directory name, resolving `.` to the current directory
+fn project_name(name: &str) -> String {
+    let path = if name == "." {
+        std::env::current_dir().unwrap_or_default()
+    } else {
+        Path::new(name).to_path_buf()
+    };
+
+    path.file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| "my-app".to_string())
+}
+
+fn detect_auth(project_path: &str) -> AuthProvider {
+    if fs::path_exists(Path::new(project_path).join("src/app/api/auth/[...nextauth]")) {
+        AuthProvider::NextAuth
+    } else {
+        AuthProvider::BetterAuth
+    }
+}
+
+fn script_row(&(script, description): &(&str, &str)) -> String {
+    format!("| `npm run {}` | {} |", script, description)
+}
+
+/// Join lines for a list placeholder; each line keeps its trailing newline
+fn render_lines(lines: impl Iterator<Item = String>) -> String {
+    lines.map(|line| format!("{}\n", line)).collect()
+}
+
+fn insert_after(content: &mut String, anchor: &str, line: &str) {
+    if content.contains(line) {
+        return;
+    }
+    if let Some(start) = content.find(anchor) {
+        let end = content[start..]
+            .find('\n')
+            .map_or(content.len(), |i| start + i + 1);
+        content.insert_str(end, &format!("{}\n", line));
+    }
+}
@@ -0,0 +1,152 @@
+use anyhow::{Context, Result};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+use crate::scaffolding::manifest::{self, Manifest};
+use crate::scaffolding::options::ScaffoldOptions;
+use crate::scaffolding::project;
+use crate::templates::resolver;
+use crate::utils::output::Progress;
+
+/// How a file compares to what `create` wrote and to what the current templates produce
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FileState {
+    /// Matches the scaffold-time content and the current templates
+    Unchanged,
+    /// Edited since it was generated
+    UserModified { template_changed: bool },
+    /// Deleted since it was generated
+    Deleted,
+    /// Untouched, and the current templates produce different content
+    TemplateUpdated,
+    /// New in the current templates
+    TemplateAdded,
+    /// Untouched, and no longer produced by the current templates
+    TemplateRemoved,
+}
+
+pub struct FileDrift {
+    pub path: String,
+    pub state: FileState,
+}
+
+/// Drift of a project against its manifest and a render of the current templates
+pub struct Drift {
+    pub manifest: Manifest,
+    pub files: Vec<FileDrift>,
+    render: Render,
+}
+
+impl Drift {
+    /// Files in one state, in path order
+    pub fn in_state(&self, matches: impl Fn(FileState) -> bool) -> Vec<&FileDrift> {
+        self.files
+            .iter()
+            .filter(|file| matches(file.state))
+            .collect()
+    }
+
+    /// Content the current templates produce for `path`
+    pub fn rendered(&self, path: &str) -> Result<Vec<u8>> {
+        self.render.read(path)
+    }
+}
+
+/// A project rendered by the current templates into a temporary directory that is
+/// removed on drop
+pub struct Render {
+    root: PathBuf,
+    dir: PathBuf,
+}
+
+impl Render {
+    pub fn path(&self) -> String {
+        self.dir.to_string_lossy().to_string()
+    }
+
+    /// Content of a rendered file
+    pub fn read(&self, path: &str) -> Result<Vec<u8>> {
+        std::fs::read(self.dir.join(path))
+            .with_context(|| format!("{} is not in the current templates", path))
+    }
+}
+
+impl Drop for Render {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.root);
+    }
+}
+
+/// Render a project with `options`, without running migrations; `label` keeps
+/// several renders in one run apart
+pub async fn render(options: &ScaffoldOptions, init_git: bool, label: &str) -> Result<Render> {
+    let root = std::env::temp_dir().join(format!("t3-mono-{}-{}", label, std::process::id()));
+    if root.exists() {
+        std::fs::remove_dir_all(&root)?;
+    }
+    let render = Render {
+        dir: root.join("project"),
+        root,
+    };
+    std::fs::create_dir_all(&render.dir)?;
+
+    project::scaffold(
+        &render.path(),
+        options,
+        &Progress::hidden(),
+        init_git,
+        false,
+    )
+    .await
+    .context("Failed to render the current templates")?;
+    Ok(render)
+}
+
+/// Compare `project_path` against its manifest and a fresh render of the current templates
+pub async fn analyze(project_path: &str) -> Result<Drift> {
+    // The render goes to a temporary directory, but overrides belong to the project
+    resolver::set_project_root(Path::new(project_path));
+    let manifest = manifest::read(project_path)?;
+    let current = manifest::hash_files(project_path)?;
+
+    // Rendered with the recorded options, so the project name in docs and package.json matches
+    let render = render(&manifest.options, manifest.git, "render").await?;
+    let rendered = manifest::hash_files(&render.path())?;
+    let mut drift = Drift {
+        manifest,
+        files: Vec::new(),
+        render,
+    };
+
+    let paths: BTreeSet<&String> = drift.manifest.files.keys().chain(rendered.keys()).collect();
+    let mut files = Vec::new();
+    for path in paths {
+        let recorded = drift.manifest.files.get(path);
+        let now = current.get(path);
+        let template = rendered.get(path);
+
+        let state = match (recorded, now) {
+            (Some(_), None) => FileState::Deleted,
+            (Some(recorded), Some(now)) if now != recorded => FileState::UserModified {
+                template_changed: template != Some(recorded),
+            },
+            (Some(recorded), Some(_)) => match template {
+                Some(template) if template == recorded => FileState::Unchanged,
+                Some(_) => FileState::TemplateUpdated,
+                None => FileState::TemplateRemoved,
+            },
+            (None, None) => FileState::TemplateAdded,
+            (None, Some(now)) if Some(now) == template => FileState::Unchanged,
+            (None, Some(_)) => FileState::UserModified {
+                template_changed: true,
+            },
+        };
+        files.push(FileDrift {
+            path: path.clone(),
+            state,
+        });
+    }
+    drift.files = files;
+
+    Ok(drift)
+}
@@ -0,0 +1,385 @@
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::utils::fs::{self, update_file, write_file};
+
+const ENV_JS_PATH: &str = "src/env.js";
+
+/// A dotenv file t3-mono lists variables in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnvFile {
+    /// Every variable with a placeholder; copied to `.env`
+    Example,
+    /// Committed local defaults that `next dev` loads, without secrets
+    Development,
+    /// Every variable with production placeholders; filled in as `.env.production` on the server
+    ProductionExample,
+}
+
+pub const ENV_FILES: [EnvFile; 3] = [
+    EnvFile::Example,
+    EnvFile::Development,
+    EnvFile::ProductionExample,
+];
+
+impl EnvFile {
+    pub fn path(self) -> &'static str {
+        match self {
+            EnvFile::Example => ".env.example",
+            EnvFile::Development => ".env.development",
+            EnvFile::ProductionExample => ".env.production.example",
+        }
+    }
+
+    /// First lines of a new file
+    fn header(self) -> &'static str {
+        match self {
+            EnvFile::Example => "",
+            EnvFile::Development => DEVELOPMENT_HEADER,
+            EnvFile::ProductionExample => PRODUCTION_HEADER,
+        }
+    }
+
+    /// What `var` is set to in this file; `None` leaves it out
+    fn value(self, var: &EnvVar) -> Option<&'static str> {
+        let example = var.example?;
+        match self {
+            EnvFile::Example => Some(example),
+            EnvFile::Development => {
+                Some(example).filter(|example| !example.is_empty() && !var.secret)
+            }
+            EnvFile::ProductionExample => {
+                Some(
+                    var.production
+                        .unwrap_or(if var.secret { "" } else { example }),
+                )
+            }
+        }
+    }
+}
+
+/// Where a variable is validated and exposed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnvScope {
+    Server,
+    Client,
+}
+
+/// A single environment variable validated by `src/env.js`
+#[derive(Debug)]
+pub struct EnvVar {
+    pub name: &'static str,
+    pub scope: EnvScope,
+    /// Zod schema expression, e.g. `z.string().url()`
+    pub schema: &'static str,
+    /// Placeholder written to `.env.example`; `None` keeps the variable out of every env file
+    pub example: Option<&'static str>,
+    /// Kept out of `.env.development` and blank in `.env.production.example`
+    pub secret: bool,
+    /// Placeholder in `.env.production.example` when the example is a local value
+    pub production: Option<&'static str>,
+}
+
+impl EnvVar {
+    pub const fn server(name: &'static str, schema: &'static str) -> Self {
+        Self {
+            name,
+            scope: EnvScope::Server,
+            schema,
+            example: Some(""),
+            secret: false,
+            production: None,
+        }
+    }
+
+    pub const fn client(name: &'static str, schema: &'static str) -> Self {
+        Self {
+            name,
+            scope: EnvScope::Client,
+            schema,
+            example: Some(""),
+            secret: false,
+            production: None,
+        }
+    }
+
+    pub const fn example(mut self, value: &'static str) -> Self {
+        self.example = Some(value);
+        self
+    }
+
+    /// Validate the variable without listing it in `.env.example`
+    pub const fn no_example(mut self) -> Self {
+        self.example = None;
+        self
+    }
+
+    pub const fn secret(mut self) -> Self {
+        self.secret = true;
+        self
+    }
+
+    pub const fn production(mut self, value: &'static str) -> Self {
+        self.production = Some(value);
+        self
+    }
+}
+
+/// Environment variables contributed by one scaffolder
+#[derive(Debug)]
+pub struct EnvFragment {
+    pub section: &'static str,
+    pub vars: &'static [EnvVar],
+}
+
+/// Register a scaffolder's variables in `src/env.js` and the env files
+pub fn register(project_path: &str, fragment: &EnvFragment) -> Result<()> {
+    add_to_env_js(project_path, fragment)?;
+    for file in ENV_FILES {
+        add_to_env_file(project_path, file, fragment)?;
+    }
+    Ok(())
+}
+
+/// Start `.env.development` and `.env.production.example` for a new project; projects
+/// created before they existed keep using `.env` alone
+pub fn create_env_files(project_path: &str) -> Result<()> {
+    for file in [EnvFile::Development, EnvFile::ProductionExample] {
+        write_file(project_path, file.path(), file.header())?;
+    }
+    Ok(())
+}
+
+/// Set `name` to `value` in `file`, or remove it with `None`, where the file lists it
+pub fn set_value(project_path: &str, file: EnvFile, name: &str, value: Option<&str>) -> Result<()> {
+    let path = Path::new(project_path).join(file.path());
+    let Ok(content) = fs::read_to_string(&path) else {
+        return Ok(());
+    };
+    let prefix = format!("{}=", name);
+    let mut patched: String = content
+        .lines()
+        .filter_map(|line| match (line.starts_with(&prefix), value) {
+            (false, _) => Some(line.to_string()),
+            (true, Some(value)) => Some(format!("{}\"{}\"", prefix, value)),
+            (true, None) => None,
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    patched.push('\n');
+    update_file(project_path, file.path(), &patched)
+}
+
+/// Merge a scaffolder's variables into `src/env.js`, adding the schema entry and
+/// the matching `runtimeEnv` line for every variable not defined yet
+fn add_to_env_js(project_path: &str, fragment: &EnvFragment) -> Result<()> {
+    let env_path = Path::new(project_path).join(ENV_JS_PATH);
+    if !fs::path_exists(&env_path) {
+        return Ok(());
+    }
+
+    let mut content = fs::read_to_string(&env_path)?;
+
+    for (scope, block) in [(EnvScope::Server, "server"), (EnvScope::Client, "client")] {
+        let missing: Vec<&EnvVar> = fragment
+            .vars
+            .iter()
+            .filter(|var| var.scope == scope && !block_defines(&content, block, var.name))
+            .collect();
+        if missing.is_empty() {
+            continue;
+        }
+
+        // Group the fragment's entries under a comment naming where they came from
+        let mut lines = vec![format!("    // {}", fragment.section)];
+        lines.extend(
+            missing
+                .iter()
+                .map(|var| format!("    {}: {},", var.name, var.schema)),
+        );
+        insert_into_block(&mut content, block, &lines.join("\n"))
+            .with_context(|| format!("Could not find the `{}` block in {}", block, ENV_JS_PATH))?;
+
+        for var in missing {
+            if block_defines(&content, "runtimeEnv", var.name) {
+                continue;
+            }
+            insert_into_block(
+                &mut content,
+                "runtimeEnv",
+                &format!("    {}: process.env.{},", var.name, var.name),
+            )
+            .with_context(|| format!("Could not find the `runtimeEnv` block in {}", ENV_JS_PATH))?;
+        }
+    }
+
+    update_file(project_path, ENV_JS_PATH, &content)?;
+
+    Ok(())
+}
+
+/// Append a section for the fragment to `file`, skipping variables that are already
+/// listed so extensions sharing a variable don't duplicate it. `.env.example` is created
+/// when missing; the other files only exist in projects that started with them.
+fn add_to_env_file(project_path: &str, file: EnvFile, fragment: &EnvFragment) -> Result<()> {
+    let env_path = Path::new(project_path).join(file.path());
+    let mut content = if fs::path_exists(&env_path) {
+        fs::read_to_string(&env_path)?
+    } else if file == EnvFile::Example {
+        String::new()
+    } else {
+        return Ok(());
+    };
+
+    let lines: Vec<String> = fragment
+        .vars
+        .iter()
+        .filter(|var| !example_defines(&content, var.name))
+        .filter_map(|var| {
+            file.value(var)
+                .map(|value| format!("{}=\"{}\"", var.name, value))
+        })
+        .collect();
+    if lines.is_empty() {
+        return Ok(());
+    }
+
+    if !content.is_empty() {
+        content.push('\n');
+    }
+    content.push_str(&format!("# {}\n{}\n", fragment.section, lines.join("\n")));
+
+    update_file(project_path, file.path(), &content)?;
+
+    Ok(())
+}
+
+fn example_defines(content: &str, name: &str) -> bool {
+    let prefix = format!("{}=", name);
+    content
+        .lines()
+        .map(|line| line.trim_start_matches('#').trim_start())
+        .any(|line| line.starts_with(&prefix))
+}
+
+/// A variable `src/env.js` validates, with its Zod schema expression as written
+#[derive(Debug)]
+pub struct SchemaEntry {
+    pub name: String,
+    pub scope: EnvScope,
+    pub schema: String,
+}
+
+/// The server and client variables in the project's `src/env.js`
+pub fn read_schema(project_path: &str) -> Result<Vec<SchemaEntry>> {
+    let content = fs::read_to_string(Path::new(project_path).join(ENV_JS_PATH))
+        .with_context(|| format!("Failed to read {}", ENV_JS_PATH))?;
+
+    let mut entries = Vec::new();
+    for (scope, block) in [(EnvScope::Server, "server"), (EnvScope::Client, "client")] {
+        let (start, end) = block_range(&content, block)
+            .with_context(|| format!("Could not find the `{}` block in {}", block, ENV_JS_PATH))?;
+        for line in content[start..end].lines().skip(1) {
+            let Some((name, schema)) = line.trim().split_once(": ") else {
+                continue;
+            };
+            if name.starts_with("//")
+                || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+            {
+                continue;
+            }
+            entries.push(SchemaEntry {
+                name: name.to_string(),
+                scope,
+                schema: schema.trim_end_matches(',').to_string(),
+            });
+        }
+    }
+
+    Ok(entries)
+}
+
+/// The env files `next dev` loads, lowest precedence first
+pub const DEVELOPMENT_FILES: [&str; 4] = [
+    ".env",
+    ".env.development",
+    ".env.local",
+    ".env.development.local",
+];
+
+/// The variables `next dev` sees from the project's env files, and the files that exist
+pub fn read_development(
+    project_path: &str,
+) -> Result<(Vec<&'static str>, HashMap<String, String>)> {
+    let mut found = Vec::new();
+    let mut values = HashMap::new();
+    for file in DEVELOPMENT_FILES {
+        let path = Path::new(project_path).join(file);
+        if fs::path_exists(&path) {
+            values.extend(read_dotenv(&path)?);
+            found.push(file);
+        }
+    }
+    Ok((found, values))
+}
+
+/// `KEY=value` pairs from a dotenv file; quotes are removed, and `#` starts a comment
+/// outside quotes
+pub fn read_dotenv(path: &Path) -> Result<Vec<(String, String)>> {
+    let content =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+
+    let mut pairs = Vec::new();
+    for line in content.lines() {
+        let line = line.trim();
+        let line = line.strip_prefix("export ").unwrap_or(line);
+        if line.starts_with('#') {
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let value = value.trim();
+        let value = match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => value[1..].split(quote).next().unwrap_or_default(),
+            _ => value.split(" #").next().unwrap_or_default().trim_end(),
+        };
+        pairs.push((key.trim().to_string(), value.to_string()));
+    }
+
+    Ok(pairs)
+}
+
+/// Locate the body of a top-level `createEnv` block such as `server: { ... }`
+fn block_range(content: &str, block: &str) -> Option<(usize, usize)> {
+    let start = content.find(&format!("\n  {}: {{", block))? + 1;
+    let end = start + content[start..].find("\n  },")?;
+    Some((start, end))
+}
+
+fn block_defines(content: &str, block: &str, name: &str) -> bool {
+    block_range(content, block)
+        .map(|(start, end)| content[start..end].contains(&format!("\n    {}:", name)))
+        .unwrap_or(false)
+}
+
+fn insert_into_block(content: &mut String, block: &str, line: &str) -> Option<()> {
+    let (_, end) = block_range(content, block)?;
+    content.insert_str(end, &format!("\n{}", line));
+    Some(())
+}
+
+// ============================================================================
+// Embedded Templates
+// ============================================================================
+
+const DEVELOPMENT_HEADER: &str = r#"# Local defaults for `npm run dev`, committed so a fresh clone starts without setup.
+# No secrets here: put them and personal overrides in .env.local, which git ignores.
+# Next.js loads, highest precedence first: .env.development.local, .env.local,
+# .env.development, .env (see docs/README.md).
+"#;
+
+const PRODUCTION_HEADER: &str = r#"# Production variables. Copy to .env.production on the server (git and the Docker
+# build ignore it) or set them in your host's dashboard, filling in the blanks you use.
+"#;
@@ -0,0 +1,214 @@
+use anyhow::Result;
+use std::path::Path;
+
+use crate::scaffolding::nav::{self, NavEntry};
+use crate::scaffolding::trpc;
+use crate::utils::fs::write_file;
+use crate::utils::plan::{self, Op};
+
+const NAV: NavEntry = NavEntry {
+    href: "/posts",
+    key: "posts",
+    en: "Posts",
+    de: "Beiträge",
+};
+
+/// Scaffold the example `post` router, model, and page
+pub async fn scaffold(project_path: &str) -> Result<()> {
+    // Zod schemas shared by the router, the form, and any route handlers
+    write_file(project_path, "src/schemas/post.ts", POST_SCHEMA)?;
+
+    // Write the router and register it in the app router
+    write_file(project_path, "src/server/api/routers/post.ts", POST_ROUTER)?;
+    trpc::register_router(
+        project_path,
+        "post",
+        "postRouter",
+        "@/server/api/routers/post",
+    )?;
+
+    // Write the page (server component) and the create form (client component)
+    write_file(project_path, "src/app/posts/page.tsx", POSTS_PAGE)?;
+    write_file(
+        project_path,
+        "src/app/posts/_components/CreatePost.tsx",
+        CREATE_POST_COMPONENT,
+    )?;
+    nav::register(project_path, &NAV)?;
+
+    // Append the Post model to the Prisma schema
+    plan::apply(Op::PatchPrisma {
+        path: Path::new(project_path).join("prisma/schema.prisma"),
+        blocks: PRISMA_POST_MODEL.to_string(),
+    })?;
+
+    Ok(())
+}
+
+// ============================================================================
+// Embedded Templates
+// ============================================================================
+
+const POST_SCHEMA: &str = r#"import { z } from "zod";
+
+// Input and output schemas for posts, imported by the tRPC router, client forms, and
+// route handlers so every entry point validates the same way
+
+export const postSchema = z.object({
+  id: z.number().int(),
+  name: z.string(),
+  createdAt: z.date(),
+  updatedAt: z.date(),
+});
+
+export const createPostSchema = z.object({
+  name: z.string().trim().min(1, "Title is required").max(256),
+});
+
+const limit = z.number().int().min(1).max(100);
+
+export const listPostsSchema = z.object({
+  limit: limit.default(20),
+});
+
+/** `listPostsSchema` for query strings, where every value arrives as a string */
+export const listPostsQuerySchema = z.object({
+  limit: z.coerce.number().pipe(limit).default(20),
+});
+
+export type Post = z.infer<typeof postSchema>;
+export type CreatePostInput = z.infer<typeof createPostSchema>;
+export type ListPostsInput = z.input<typeof listPostsSchema>;
+"#;
+
+const POST_ROUTER: &str = r#"import { z } from "zod";
+
+import { createPostSchema, listPostsSchema, postSchema } from "@/schemas/post";
+import { createTRPCRouter, publicProcedure } from "@/server/api/trpc";
+
+export const postRouter = createTRPCRouter({
+  create: publicProcedure
+    .input(createPostSchema)
+    .output(postSchema)
+    .mutation(async ({ ctx, input }) => {
+      return ctx.db.post.create({
+        data: { name: input.name },
+      });
+    }),
+
+  list: publicProcedure
+    .input(listPostsSchema.optional())
+    .output(z.array(postSchema))
+    .query(async ({ ctx, input }) => {
+      return ctx.db.post.findMany({
+        orderBy: { createdAt: "desc" },
+        take: input?.limit ?? 20,
+      });
+    }),
+});
+"#;
+
+const POSTS_PAGE: &str = r#"import { api, HydrateClient } from "@/trpc/server";
+import { CreatePost } from "./_components/CreatePost";
+
+export default async function PostsPage() {
+  // Runs on the server through the tRPC RSC caller -- no HTTP round trip
+  const posts = await api.post.list();
+
+  return (
+    <HydrateClient>
+      <main className="mx-auto flex min-h-screen w-full max-w-2xl flex-col gap-8 px-4 py-16">
+        <div>
+          <h1 className="text-3xl font-bold">Posts</h1>
+          <p className="mt-2 text-muted-foreground">
+            Example tRPC router backed by Prisma. See{" "}
+            <code>src/server/api/routers/post.ts</code>.
+          </p>
+        </div>
+
+        <CreatePost />
+
+        {posts.length === 0 ? (
+          <p className="text-muted-foreground">No posts yet. Create the first one above.</p>
+        ) : (
+          <ul className="flex flex-col gap-2">
+            {posts.map((post) => (
+              <li key={post.id} className="rounded-lg border border-border px-4 py-3">
+                <p className="font-medium">{post.name}</p>
+                <p className="text-xs text-muted-foreground">
+                  {post.createdAt.toLocaleString()}
+                </p>
+              </li>
+            ))}
+          </ul>
+        )}
+      </main>
+    </HydrateClient>
+  );
+}
+"#;
+
+const CREATE_POST_COMPONENT: &str = r#""use client";
+
+import { useState } from "react";
+import { useRouter } from "next/navigation";
+
+import { createPostSchema } from "@/schemas/post";
+import { api } from "@/trpc/react";
+
+export function CreatePost() {
+  const router = useRouter();
+  const [name, setName] = useState("");
+  const isValid = createPostSchema.safeParse({ name }).success;
+
+  const createPost = api.post.create.useMutation({
+    onSuccess: () => {
+      setName("");
+      // Re-render the server component so the new post shows up in the list
+      router.refresh();
+    },
+  });
+
+  return (
+    <form
+      onSubmit={(e) => {
+        e.preventDefault();
+        if (isValid) {
+          createPost.mutate({ name });
+        }
+      }}
+      className="flex gap-2"
+    >
+      <input
+        type="text"
+        placeholder="Title"
+        value={name}
+        onChange={(e) => setName(e.target.value)}
+        className="flex-1 rounded-lg border border-border bg-background px-4 py-2"
+      />
+      <button
+        type="submit"
+        disabled={createPost.isPending || !isValid}
+        className="rounded-lg bg-primary px-4 py-2 font-medium text-primary-foreground disabled:opacity-50"
+      >
+        {createPost.isPending ? "Creating..." : "Create"}
+      </button>
+    </form>
+  );
+}
+"#;
+
+const PRISMA_POST_MODEL: &str = r#"
+// ============================================================================
+// Example Models
+// ============================================================================
+
+model Post {
+  id        Int      @id @default(autoincrement())
+  name      String
+  createdAt DateTime @default(now())
+  updatedAt DateTime @updatedAt
+
+  @@index([name])
+}
+"#;
@@ -0,0 +1,363 @@
+use anyhow::Result;
+use std::path::Path;
+
+use crate::scaffolding::nav::{self, NavEntry};
+use crate::scaffolding::trpc;
+use crate::utils::fs::{self, write_file};
+use crate::utils::npm;
+
+const NAV: NavEntry = NavEntry {
+    href: "/settings/profile",
+    key: "profile",
+    en: "Profile",
+    de: "Profil",
+};
+
+pub const FORMS_DEPENDENCIES: &[(&str, &str)] = &[
+    ("react-hook-form", "^7.66.0"),
+    ("@hookform/resolvers", "^5.2.2"),
+];
+
+/// Scaffold typed form helpers on react-hook-form + zod, wired to the UI kit,
+/// plus an example profile settings form backed by a `profile` tRPC router
+pub fn scaffold(project_path: &str) -> Result<()> {
+    if !fs::path_exists(Path::new(project_path).join("src/components/ui/index.ts")) {
+        anyhow::bail!("The forms extension builds on the UI kit. Run `t3-mono add ui` first.");
+    }
+
+    // Form helpers
+    write_file(project_path, "src/components/forms/form.tsx", FORM)?;
+    write_file(project_path, "src/components/forms/fields.tsx", FIELDS)?;
+    write_file(project_path, "src/components/forms/index.ts", FORMS_INDEX)?;
+
+    // Example: profile settings, validated by the same schema on client and server
+    write_file(project_path, "src/schemas/profile.ts", PROFILE_SCHEMA)?;
+    write_file(
+        project_path,
+        "src/server/api/routers/profile.ts",
+        PROFILE_ROUTER,
+    )?;
+    trpc::register_router(
+        project_path,
+        "profile",
+        "profileRouter",
+        "@/server/api/routers/profile",
+    )?;
+    write_file(
+        project_path,
+        "src/app/settings/profile/page.tsx",
+        PROFILE_PAGE,
+    )?;
+    write_file(
+        project_path,
+        "src/app/settings/profile/ProfileForm.tsx",
+        PROFILE_FORM,
+    )?;
+    nav::register(project_path, &NAV)?;
+
+    npm::add_dependencies(project_path, FORMS_DEPENDENCIES, &[])?;
+
+    Ok(())
+}
+
+// ============================================================================
+// Embedded Templates
+// ============================================================================
+
+const FORM: &str = r#""use client";
+
+import { zodResolver } from "@hookform/resolvers/zod";
+import type * as React from "react";
+import {
+  type FieldValues,
+  FormProvider,
+  type SubmitHandler,
+  type UseFormProps,
+  type UseFormReturn,
+  useForm,
+} from "react-hook-form";
+import type { z } from "zod";
+
+/** `useForm` preconfigured with a zod schema; values are typed from the schema */
+export function useZodForm<TSchema extends z.ZodType<FieldValues, FieldValues>>(
+  schema: TSchema,
+  options?: Omit<UseFormProps<z.input<TSchema>, unknown, z.output<TSchema>>, "resolver">,
+) {
+  return useForm<z.input<TSchema>, unknown, z.output<TSchema>>({
+    ...options,
+    resolver: zodResolver(schema),
+  });
+}
+
+interface FormProps<TFieldValues extends FieldValues, TOutput extends FieldValues>
+  extends Omit<React.ComponentProps<"form">, "onSubmit"> {
+  form: UseFormReturn<TFieldValues, unknown, TOutput>;
+  onSubmit: SubmitHandler<TOutput>;
+}
+
+/** Provides the form to the field components and submits only validated values */
+export function Form<TFieldValues extends FieldValues, TOutput extends FieldValues>({
+  form,
+  onSubmit,
+  children,
+  ...props
+}: FormProps<TFieldValues, TOutput>) {
+  return (
+    <FormProvider {...form}>
+      <form noValidate onSubmit={form.handleSubmit(onSubmit)} {...props}>
+        {children}
+      </form>
+    </FormProvider>
+  );
+}
+"#;
+
+const FIELDS: &str = r#""use client";
+
+import type * as React from "react";
+import { Controller, useFormContext } from "react-hook-form";
+import {
+  Input,
+  Label,
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+  Textarea,
+} from "@/components/ui";
+
+interface FieldProps {
+  name: string;
+  label: string;
+  description?: string;
+}
+
+/** Label, control, and the field's description or validation message */
+export function Field({ name, label, description, children }: FieldProps & { children: React.ReactNode }) {
+  const { getFieldState, formState } = useFormContext();
+  const { error } = getFieldState(name, formState);
+
+  return (
+    <div className="grid gap-2">
+      <Label htmlFor={name}>{label}</Label>
+      {children}
+      {error?.message ? (
+        <p id={`${name}-message`} className="text-sm text-destructive">
+          {error.message}
+        </p>
+      ) : description ? (
+        <p id={`${name}-message`} className="text-sm text-muted-foreground">
+          {description}
+        </p>
+      ) : null}
+    </div>
+  );
+}
+
+function useFieldAria(name: string) {
+  const { getFieldState, formState } = useFormContext();
+  const { invalid } = getFieldState(name, formState);
+  return { "aria-invalid": invalid, "aria-describedby": `${name}-message` };
+}
+
+export function InputField({
+  name,
+  label,
+  description,
+  ...props
+}: FieldProps & Omit<React.ComponentProps<typeof Input>, "name">) {
+  const { register } = useFormContext();
+
+  return (
+    <Field name={name} label={label} description={description}>
+      <Input id={name} {...useFieldAria(name)} {...props} {...register(name)} />
+    </Field>
+  );
+}
+
+export function TextareaField({
+  name,
+  label,
+  description,
+  ...props
+}: FieldProps & Omit<React.ComponentProps<typeof Textarea>, "name">) {
+  const { register } = useFormContext();
+
+  return (
+    <Field name={name} label={label} description={description}>
+      <Textarea id={name} {...useFieldAria(name)} {...props} {...register(name)} />
+    </Field>
+  );
+}
+
+export interface SelectOption {
+  value: string;
+  label: string;
+}
+
+export function SelectField({
+  name,
+  label,
+  description,
+  options,
+  placeholder,
+}: FieldProps & { options: SelectOption[]; placeholder?: string }) {
+  const { control } = useFormContext();
+  const aria = useFieldAria(name);
+
+  return (
+    <Field name={name} label={label} description={description}>
+      <Controller
+        name={name}
+        control={control}
+        render={({ field }) => (
+          <Select value={field.value} onValueChange={field.onChange}>
+            <SelectTrigger id={name} onBlur={field.onBlur} {...aria}>
+              <SelectValue placeholder={placeholder} />
+            </SelectTrigger>
+            <SelectContent>
+              {options.map((option) => (
+                <SelectItem key={option.value} value={option.value}>
+                  {option.label}
+                </SelectItem>
+              ))}
+            </SelectContent>
+          </Select>
+        )}
+      />
+    </Field>
+  );
+}
+"#;
+
+const FORMS_INDEX: &str = r#"export * from "./fields";
+export * from "./form";
+"#;
+
+const PROFILE_SCHEMA: &str = r#"import { z } from "zod";
+
+export const LOCALES = ["en", "de"] as const;
+
+/** Shared by the profile form and `profile.update`, so both validate the same way */
+export const profileSchema = z.object({
+  name: z.string().trim().min(1, "Name is required").max(100),
+  image: z.union([z.url("Enter a valid URL"), z.literal("")]),
+  locale: z.enum(LOCALES),
+});
+
+export type ProfileInput = z.infer<typeof profileSchema>;
+"#;
+
+const PROFILE_ROUTER: &str = r#"import { cookies } from "next/headers";
+import { profileSchema } from "@/schemas/profile";
+import { createTRPCRouter, protectedProcedure } from "@/server/api/trpc";
+
+export const profileRouter = createTRPCRouter({
+  update: protectedProcedure.input(profileSchema).mutation(async ({ ctx, input }) => {
+    const user = await ctx.db.user.update({
+      where: { id: ctx.userId },
+      data: { name: input.name, image: input.image || null },
+      select: { id: true, name: true, image: true },
+    });
+
+    // The locale lives in the cookie read by src/i18n/request.ts
+    (await cookies()).set("locale", input.locale, {
+      path: "/",
+      maxAge: 60 * 60 * 24 * 365,
+      sameSite: "lax",
+    });
+
+    return user;
+  }),
+});
+"#;
+
+const PROFILE_PAGE: &str = r#"import { cookies } from "next/headers";
+import { redirect } from "next/navigation";
+
+import { Header } from "@/app/_components/Header";
+import { getSession, SIGN_IN_PATH } from "@/server/session";
+import { api } from "@/trpc/server";
+import { ProfileForm } from "./ProfileForm";
+
+export default async function ProfileSettingsPage() {
+  const session = await getSession();
+  if (!session) {
+    redirect(SIGN_IN_PATH);
+  }
+
+  const me = await api.auth.me();
+  const locale = (await cookies()).get("locale")?.value === "de" ? "de" : "en";
+
+  return (
+    <div className="min-h-screen flex flex-col bg-background">
+      <Header />
+
+      <main className="flex-1 max-w-xl mx-auto px-4 sm:px-6 lg:px-8 py-8 w-full">
+        <h1 className="text-2xl font-semibold">Profile</h1>
+        <p className="mt-1 mb-6 text-sm text-muted-foreground">
+          Update your name, avatar, and language.
+        </p>
+        <ProfileForm
+          defaultValues={{ name: me?.name ?? "", image: me?.image ?? "", locale }}
+        />
+      </main>
+    </div>
+  );
+}
+"#;
+
+const PROFILE_FORM: &str = r#""use client";
+
+import { useRouter } from "next/navigation";
+import { Form, InputField, SelectField, useZodForm } from "@/components/forms";
+import { Button } from "@/components/ui";
+import { type ProfileInput, profileSchema } from "@/schemas/profile";
+import { onMutationError } from "@/trpc/errors";
+import { api } from "@/trpc/react";
+
+const localeOptions = [
+  { value: "en", label: "English" },
+  { value: "de", label: "Deutsch" },
+];
+
+export function ProfileForm({ defaultValues }: { defaultValues: ProfileInput }) {
+  const router = useRouter();
+  const form = useZodForm(profileSchema, { defaultValues });
+
+  const update = api.profile.update.useMutation({
+    onSuccess: (_user, input) => {
+      form.reset(input);
+      // Re-render server components with the new name and locale
+      router.refresh();
+    },
+    onError: onMutationError({
+      setFieldError: (field, message) => form.setError(field as keyof ProfileInput, { message }),
+    }),
+  });
+
+  return (
+    <Form form={form} onSubmit={(values) => update.mutate(values)} className="grid gap-6">
+      <InputField name="name" label="Name" autoComplete="name" />
+      <InputField
+        name="image"
+        label="Avatar URL"
+        type="url"
+        placeholder="https://"
+        description="Leave empty to use your initials."
+      />
+      <SelectField name="locale" label="Language" options={localeOptions} />
+
+      <div className="flex items-center gap-3">
+        <Button type="submit" disabled={update.isPending || !form.formState.isDirty}>
+          {update.isPending ? "Saving..." : "Save changes"}
+        </Button>
+        {update.isSuccess && !form.formState.isDirty && (
+          <span className="text-sm text-muted-foreground">Saved</span>
+        )}
+      </div>
+    </Form>
+  );
+}
+"#;
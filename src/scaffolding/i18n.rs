@@ -0,0 +1,46 @@
+use anyhow::Result;
+use std::path::Path;
+
+use crate::utils::fs;
+use crate::utils::plan::{self, Op};
+
+/// Merge the namespaces in `additions` into a `messages/*.json` file, replacing the
+/// keys it already has
+pub fn merge_translations(project_path: &str, relative_path: &str, additions: &str) -> Result<()> {
+    plan::apply(Op::MergeJson {
+        path: Path::new(project_path).join(relative_path),
+        value: serde_json::from_str(additions)?,
+        overwrite: true,
+    })
+}
+
+/// Every `messages/*.json` file, `messages/en.json` first
+pub fn locale_files(project_path: &str) -> Result<Vec<String>> {
+    let mut files = Vec::new();
+    for path in fs::list_dir(Path::new(project_path).join("messages"))? {
+        if path.extension().is_some_and(|ext| ext == "json") {
+            files.push(format!(
+                "messages/{}",
+                path.file_name().unwrap_or_default().to_string_lossy()
+            ));
+        }
+    }
+    files.sort_by_key(|file| (file != "messages/en.json", file.clone()));
+    Ok(files)
+}
+
+/// Set one `namespace.key` message in a `messages/*.json` file, keeping the rest
+/// of the namespace
+pub fn set_message(
+    project_path: &str,
+    relative_path: &str,
+    namespace: &str,
+    key: &str,
+    value: &str,
+) -> Result<()> {
+    plan::apply(Op::MergeJson {
+        path: Path::new(project_path).join(relative_path),
+        value: serde_json::json!({ namespace: { key: value } }),
+        overwrite: true,
+    })
+}
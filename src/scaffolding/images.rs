@@ -0,0 +1,318 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use crate::scaffolding::env::{self, EnvFile, EnvFragment, EnvVar};
+use crate::scaffolding::options::ScaffoldOptions;
+use crate::utils::fs::{self, update_file, write_file};
+
+const HOSTS_PATH: &str = "src/lib/image-hosts.js";
+
+const ENV: EnvFragment = EnvFragment {
+    section: "Images",
+    vars: &[
+        EnvVar::client("NEXT_PUBLIC_CDN_URL", "z.string().url().optional()")
+            .example("")
+            .production("https://cdn.example.com"),
+    ],
+};
+
+/// A host next/image may load from: a hostname, optionally starting with `*.` (one
+/// subdomain) or `**.` (any number of them)
+pub fn parse_host(value: &str) -> Result<String, String> {
+    let host = value.trim().to_ascii_lowercase();
+    let name = host
+        .strip_prefix("**.")
+        .or_else(|| host.strip_prefix("*."))
+        .unwrap_or(&host);
+    let valid = name.split('.').count() >= 2
+        && name.split('.').all(|label| {
+            !label.is_empty()
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        });
+    if valid {
+        Ok(host)
+    } else {
+        Err(format!("'{}' is not a hostname; use e.g. images.example.com or *.example.com, without https:// or a path", value))
+    }
+}
+
+/// The CDN's base URL: https, or http for a local host, without a query
+pub fn parse_cdn_url(value: &str) -> Result<String, String> {
+    let url = reqwest::Url::parse(value)
+        .map_err(|_| format!("'{}' is not a URL; use e.g. https://cdn.example.com", value))?;
+    let local = matches!(url.host_str(), Some("localhost" | "127.0.0.1"));
+    if url.scheme() != "https" && !(url.scheme() == "http" && local) {
+        return Err(format!("'{}' must use https", value));
+    }
+    if url.query().is_some() || url.fragment().is_some() {
+        return Err(format!("'{}' must not have a query or fragment", value));
+    }
+    Ok(value.trim_end_matches('/').to_string())
+}
+
+/// Whether the project's next/image hosts are configured
+pub fn is_configured(project_path: &str) -> bool {
+    fs::path_exists(Path::new(project_path).join(HOSTS_PATH))
+}
+
+/// Allow remote images in next.config.js: the `hosts`, the CDN at `NEXT_PUBLIC_CDN_URL`,
+/// and, with cmd, the S3 bucket its presigned URLs point at. Writes `cdnUrl`,
+/// `cdnImageLoader`, and `isAllowedImageUrl` in `src/lib/images.ts`.
+pub fn scaffold(project_path: &str, hosts: &[String], cdn: Option<&str>, s3: bool) -> Result<()> {
+    let hosts = hosts
+        .iter()
+        .map(|host| format!("\"{}\"", host))
+        .collect::<Vec<_>>()
+        .join(", ");
+    write_file(
+        project_path,
+        HOSTS_PATH,
+        &IMAGE_HOSTS.replace("{{hosts}}", &hosts),
+    )?;
+    write_file(project_path, "src/lib/images.ts", IMAGES_HELPER)?;
+    write_test(project_path, s3)?;
+
+    env::register(project_path, &ENV)?;
+    if let Some(cdn) = cdn {
+        for file in [EnvFile::Example, EnvFile::ProductionExample] {
+            env::set_value(project_path, file, "NEXT_PUBLIC_CDN_URL", Some(cdn))?;
+        }
+    }
+
+    configure_next(project_path)
+}
+
+/// Allow the cmd extension's S3 bucket in a project created without image options
+pub fn add_s3(project_path: &str) -> Result<()> {
+    if is_configured(project_path) {
+        // The bucket pattern is always there; only the presigned URL test is missing
+        let test = fs::read_to_string(Path::new(project_path).join("src/lib/images.test.ts"))
+            .unwrap_or_default();
+        if !test.is_empty() && !test.contains("presigned") {
+            write_test(project_path, true)?;
+        }
+        return Ok(());
+    }
+    scaffold(project_path, &[], None, true)
+}
+
+/// The helper's test; with `s3`, also against URLs presigned the way cmd does
+fn write_test(project_path: &str, s3: bool) -> Result<()> {
+    let (imports, tests) = if s3 { (S3_IMPORTS, S3_TESTS) } else { ("", "") };
+    let content = IMAGES_TEST
+        .replace("{{s3_imports}}", imports)
+        .replace("{{s3_tests}}", tests);
+    write_file(project_path, "src/lib/images.test.ts", &content)
+}
+
+/// Read the patterns from `src/lib/image-hosts.js` in next.config.js
+fn configure_next(project_path: &str) -> Result<()> {
+    let config_path = Path::new(project_path).join("next.config.js");
+    let content = fs::read_to_string(&config_path).context("Failed to read next.config.js")?;
+
+    if content.contains("remotePatterns") {
+        return Ok(());
+    }
+
+    let patched = content.replacen(
+        "import \"./src/env.js\";\n",
+        "import \"./src/env.js\";\nimport { remotePatterns } from \"./src/lib/image-hosts.js\";\n",
+        1,
+    );
+    // Empty in a new project; the Dockerfile step has already added `output` when it ran
+    let patched = if patched.contains("const config = {};") {
+        patched.replacen(
+            "const config = {};",
+            "const config = {\n  images: { remotePatterns },\n};",
+            1,
+        )
+    } else {
+        patched.replacen(
+            "const config = {\n",
+            "const config = {\n  images: { remotePatterns },\n",
+            1,
+        )
+    };
+    update_file(project_path, "next.config.js", &patched)
+}
+
+/// Whether the create options call for image configuration
+pub fn requested(options: &ScaffoldOptions) -> bool {
+    options.cmd || !options.image_hosts.is_empty() || options.image_cdn.is_some()
+}
+
+// ============================================================================
+// Embedded Templates
+// ============================================================================
+
+const IMAGE_HOSTS: &str = r#"/**
+ * Where next/image may load remote images from. next.config.js passes these to
+ * `images.remotePatterns`, and `isAllowedImageUrl` in src/lib/images.ts checks URLs
+ * against them. Add hosts to `hosts`; `*.` and `**.` match one or more subdomains.
+ */
+
+const hosts = [{{hosts}}];
+
+/**
+ * @typedef {{ protocol?: "http" | "https"; hostname: string; port?: string; pathname?: string }} RemotePattern
+ */
+
+/** @param {string} url @returns {RemotePattern} Every path under `url` */
+function under(url) {
+  const { protocol, hostname, port, pathname } = new URL(url);
+  return {
+    protocol: protocol === "http:" ? "http" : "https",
+    hostname,
+    port,
+    pathname: `${pathname.replace(/\/$/, "")}/**`,
+  };
+}
+
+/**
+ * The hosts presigned S3 URLs use: virtual-hosted style, or path style for bucket names
+ * with dots. us-east-1 also has the global endpoint.
+ * @param {string} bucket @param {string} region @returns {RemotePattern[]}
+ */
+function s3(bucket, region) {
+  const endpoints = region === "us-east-1" ? ["s3.amazonaws.com", "s3.us-east-1.amazonaws.com"] : [`s3.${region}.amazonaws.com`];
+  return endpoints.map((endpoint) =>
+    bucket.includes(".")
+      ? { protocol: "https", hostname: endpoint, pathname: `/${bucket}/**` }
+      : { protocol: "https", hostname: `${bucket}.${endpoint}`, pathname: "/**" },
+  );
+}
+
+/** @type {RemotePattern[]} */
+export const remotePatterns = [
+  ...hosts.map((hostname) => /** @type {RemotePattern} */ ({ protocol: "https", hostname, pathname: "/**" })),
+  // The CDN in front of stored files
+  ...(process.env.NEXT_PUBLIC_CDN_URL ? [under(process.env.NEXT_PUBLIC_CDN_URL)] : []),
+  // The upload bucket, as the S3 client is configured (region defaults like it does)
+  ...(process.env.AWS_S3_BUCKET_NAME ? s3(process.env.AWS_S3_BUCKET_NAME, process.env.AWS_REGION || "us-east-1") : []),
+];
+"#;
+
+const IMAGES_HELPER: &str = r#"import type { ImageLoader } from "next/image";
+import { remotePatterns } from "./image-hosts.js";
+
+/**
+ * URL of a stored file on the CDN, e.g. `cdnUrl(attachment.s3Key)`. Throws when
+ * NEXT_PUBLIC_CDN_URL isn't set, so callers fall back to presigned URLs explicitly.
+ */
+export function cdnUrl(key: string) {
+  const base = process.env.NEXT_PUBLIC_CDN_URL;
+  if (!base) {
+    throw new Error("NEXT_PUBLIC_CDN_URL is not set");
+  }
+  return new URL(key.replace(/^\/+/, ""), base.endsWith("/") ? base : `${base}/`).toString();
+}
+
+/**
+ * Lets the CDN resize instead of Next.js: `<Image loader={cdnImageLoader} src={key} ... />`.
+ * Sends `w` and `q` query parameters (imgix, ImageKit, Bunny); adjust for your CDN.
+ */
+export const cdnImageLoader: ImageLoader = ({ src, width, quality }) => {
+  const url = new URL(/^https?:\/\//.test(src) ? src : cdnUrl(src));
+  url.searchParams.set("w", String(width));
+  url.searchParams.set("q", String(quality ?? 75));
+  return url.toString();
+};
+
+// Leaves `*` alone for the wildcards below
+const escape = (text: string) => text.replace(/[.+?^${}()|[\]\\]/g, "\\$&");
+
+/** `*.` is one subdomain, `**.` at the start any number of them */
+function hostMatches(pattern: string, hostname: string) {
+  const source = escape(pattern).replace(/^\*\*\\\.|\*/g, (token) => (token === "*" ? "[^.]+" : "(?:[^.]+\\.)+"));
+  return new RegExp(`^${source}$`).test(hostname);
+}
+
+/** `*` is one path segment, `**` any number of them */
+function pathMatches(pattern: string, pathname: string) {
+  const source = escape(pattern).replace(/\/\*\*$|\*\*|\*/g, (token) =>
+    token === "/**" ? "(?:/.*)?" : token === "**" ? ".*" : "[^/]+",
+  );
+  return new RegExp(`^${source}$`).test(pathname);
+}
+
+/**
+ * Whether next/image accepts `url` with the configured patterns. Where the S3 variables
+ * aren't set, as in the browser, bucket URLs count as not allowed.
+ */
+export function isAllowedImageUrl(url: string) {
+  let parsed: URL;
+  try {
+    parsed = new URL(url);
+  } catch {
+    return false;
+  }
+  return remotePatterns.some(
+    (pattern) =>
+      (!pattern.protocol || `${pattern.protocol}:` === parsed.protocol) &&
+      (!pattern.port || pattern.port === parsed.port) &&
+      hostMatches(pattern.hostname, parsed.hostname) &&
+      pathMatches(pattern.pathname ?? "/**", parsed.pathname),
+  );
+}
+"#;
+
+const IMAGES_TEST: &str = r#"// @vitest-environment node
+{{s3_imports}}import { afterEach, describe, expect, it, vi } from "vitest";
+
+// The patterns are read from the environment when image-hosts.js loads, so every case imports it fresh
+async function loadImages(env: Record<string, string> = {}) {
+  vi.resetModules();
+  for (const [name, value] of Object.entries(env)) {
+    vi.stubEnv(name, value);
+  }
+  return import("./images");
+}
+
+afterEach(() => {
+  vi.unstubAllEnvs();
+});
+
+describe("isAllowedImageUrl", () => {
+  it("allows files under the CDN and nothing else", async () => {
+    const { cdnUrl, isAllowedImageUrl } = await loadImages({ NEXT_PUBLIC_CDN_URL: "https://cdn.example.com/files" });
+
+    expect(isAllowedImageUrl(cdnUrl("uploads/photo.png"))).toBe(true);
+    expect(isAllowedImageUrl("https://cdn.example.com/other/photo.png")).toBe(false);
+    expect(isAllowedImageUrl("https://evil.example.com/files/photo.png")).toBe(false);
+    expect(isAllowedImageUrl("not a url")).toBe(false);
+  });
+
+  it("resizes through the CDN", async () => {
+    const { cdnImageLoader } = await loadImages({ NEXT_PUBLIC_CDN_URL: "https://cdn.example.com" });
+
+    expect(cdnImageLoader({ src: "/uploads/photo.png", width: 640 })).toBe(
+      "https://cdn.example.com/uploads/photo.png?w=640&q=75",
+    );
+  });
+{{s3_tests}}});
+"#;
+
+const S3_IMPORTS: &str = r#"import { GetObjectCommand, S3Client } from "@aws-sdk/client-s3";
+import { getSignedUrl } from "@aws-sdk/s3-request-presigner";
+"#;
+
+// Presigned like the cmd chat and docs routers do, so a change in how the SDK builds
+// URLs shows up here before next/image rejects them
+const S3_TESTS: &str = r#"
+  it.each([
+    ["uploads", "eu-central-1"],
+    ["uploads", "us-east-1"],
+    ["my.uploads", "eu-central-1"],
+  ])("allows presigned URLs of bucket %s in %s", async (bucket, region) => {
+    const { isAllowedImageUrl } = await loadImages({ AWS_S3_BUCKET_NAME: bucket, AWS_REGION: region });
+    const client = new S3Client({ region, credentials: { accessKeyId: "test", secretAccessKey: "test" } });
+    const url = await getSignedUrl(client, new GetObjectCommand({ Bucket: bucket, Key: "chat-attachments/t1/photo.png" }), {
+      expiresIn: 60,
+    });
+
+    expect(isAllowedImageUrl(url)).toBe(true);
+    expect(isAllowedImageUrl(url.replace(bucket, "someone-elses-bucket"))).toBe(false);
+  });
+"#;
@@ -0,0 +1,317 @@
+use anyhow::{Context, Result};
+use reqwest::Client;
+use serde_json::{Map, Value};
+use std::path::Path;
+use std::time::Duration;
+
+use crate::scaffolding::env;
+use crate::utils::fs::{self, update_file, write_file};
+use crate::utils::naming::camel_case;
+
+const SOURCE_MESSAGES: &str = "messages/en.json";
+const DICTIONARY_PATH: &str = "src/types/dictionary.ts";
+const SWITCHER_PATH: &str = "src/app/_components/LanguageSwitcher.tsx";
+const AI_CORE_PATH: &str = "src/components/ai/core";
+
+/// Same default model as the AI extension's ModelRegistry.anthropic.sonnet
+const TRANSLATION_MODEL: &str = "claude-sonnet-4-20250514";
+const TODO_PREFIX: &str = "TODO: ";
+
+/// Native names for the language switcher; other codes fall back to the code itself
+const LANGUAGE_NAMES: &[(&str, &str)] = &[
+    ("ar", "العربية"),
+    ("cs", "Čeština"),
+    ("da", "Dansk"),
+    ("de", "Deutsch"),
+    ("el", "Ελληνικά"),
+    ("en", "English"),
+    ("es", "Español"),
+    ("fi", "Suomi"),
+    ("fr", "Français"),
+    ("he", "עברית"),
+    ("hi", "हिन्दी"),
+    ("hu", "Magyar"),
+    ("it", "Italiano"),
+    ("ja", "日本語"),
+    ("ko", "한국어"),
+    ("nl", "Nederlands"),
+    ("no", "Norsk"),
+    ("pl", "Polski"),
+    ("pt", "Português"),
+    ("ro", "Română"),
+    ("ru", "Русский"),
+    ("sv", "Svenska"),
+    ("tr", "Türkçe"),
+    ("uk", "Українська"),
+    ("zh", "中文"),
+];
+
+/// What `generate locale` changed, for the summary
+pub struct GeneratedLocale {
+    pub files: Vec<String>,
+    /// Files that did not match the scaffolded layout and need the locale added by hand
+    pub skipped: Vec<String>,
+}
+
+/// `fr`, `pt-BR`: a lowercase language code with an optional uppercase region
+pub fn is_valid_code(code: &str) -> bool {
+    let (language, region) = match code.split_once('-') {
+        Some((language, region)) => (language, Some(region)),
+        None => (code, None),
+    };
+    (2..=3).contains(&language.len())
+        && language.chars().all(|c| c.is_ascii_lowercase())
+        && region.is_none_or(|region| {
+            region.len() == 2 && region.chars().all(|c| c.is_ascii_uppercase())
+        })
+}
+
+/// Read `messages/en.json`, the source every locale is copied from
+pub fn source_messages(project_path: &str) -> Result<Value> {
+    let path = Path::new(project_path).join(SOURCE_MESSAGES);
+    let content = fs::read_to_string(&path).with_context(|| {
+        format!(
+            "Failed to read {}. Locales need the i18n setup of a t3-mono app.",
+            SOURCE_MESSAGES
+        )
+    })?;
+    serde_json::from_str(&content).with_context(|| format!("{} is not valid JSON", SOURCE_MESSAGES))
+}
+
+/// Copy of `messages` with every string prefixed `TODO: `
+pub fn todo_messages(messages: &Value) -> Value {
+    match messages {
+        Value::String(text) => Value::String(format!("{}{}", TODO_PREFIX, text)),
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(key, value)| (key.clone(), todo_messages(value)))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+/// Translate `messages` into `code` with the Anthropic model the AI extension uses.
+/// Strings the model leaves out keep their `TODO: ` copy.
+pub async fn machine_translate(project_path: &str, code: &str, messages: &Value) -> Result<Value> {
+    if !fs::path_exists(Path::new(project_path).join(AI_CORE_PATH)) {
+        anyhow::bail!("Machine translation uses the AI extension. Run `t3-mono add ai` first.");
+    }
+    let api_key = env_var(project_path, "ANTHROPIC_API_KEY")
+        .context("Set ANTHROPIC_API_KEY in your environment or .env.local to translate")?;
+
+    let client = Client::builder()
+        .timeout(Duration::from_secs(180))
+        .build()
+        .context("Failed to create HTTP client")?;
+    let body = serde_json::json!({
+        "model": TRANSLATION_MODEL,
+        "max_tokens": 16000,
+        "system": "You translate UI message catalogs for web apps. Reply with only the translated JSON object: keep every key and the nesting unchanged, translate only the string values, and keep placeholders such as {name} as they are.",
+        "messages": [{
+            "role": "user",
+            "content": format!(
+                "Translate this English message catalog into the locale '{}':\n\n{}",
+                code,
+                serde_json::to_string_pretty(messages)?
+            ),
+        }],
+    });
+
+    let response = client
+        .post("https://api.anthropic.com/v1/messages")
+        .header("x-api-key", api_key)
+        .header("anthropic-version", "2023-06-01")
+        .json(&body)
+        .send()
+        .await
+        .context("Failed to reach the Anthropic API")?;
+    let status = response.status();
+    let reply: Value = response
+        .json()
+        .await
+        .context("Invalid response from the Anthropic API")?;
+    if !status.is_success() {
+        anyhow::bail!(
+            "Translation failed ({}): {}",
+            status,
+            reply["error"]["message"]
+                .as_str()
+                .unwrap_or("unknown error")
+        );
+    }
+
+    let text = reply["content"][0]["text"].as_str().unwrap_or_default();
+    let json = text
+        .trim()
+        .trim_start_matches("```json")
+        .trim_start_matches("```")
+        .trim_end_matches("```")
+        .trim();
+    let translated: Value =
+        serde_json::from_str(json).context("The model did not reply with a JSON object")?;
+
+    Ok(overlay(messages, &translated))
+}
+
+/// Keep the structure of `source`, taking strings from `translated` where present
+fn overlay(source: &Value, translated: &Value) -> Value {
+    match (source, translated) {
+        (Value::String(_), Value::String(text)) => Value::String(text.clone()),
+        (Value::Object(map), Value::Object(other)) => Value::Object(
+            map.iter()
+                .map(|(key, value)| {
+                    let value = match other.get(key) {
+                        Some(translated) => overlay(value, translated),
+                        None => todo_messages(value),
+                    };
+                    (key.clone(), value)
+                })
+                .collect(),
+        ),
+        (source, _) => todo_messages(source),
+    }
+}
+
+/// Write `messages/<code>.json`, label the locale in every message file, and add it
+/// to `dictionary.ts` and the LanguageSwitcher
+pub fn add(project_path: &str, code: &str, mut messages: Value) -> Result<GeneratedLocale> {
+    let project = Path::new(project_path);
+    let messages_path = format!("messages/{}.json", code);
+    if fs::path_exists(project.join(&messages_path)) {
+        anyhow::bail!("{} already exists", messages_path);
+    }
+
+    let label_key = camel_case(code);
+    let language = code.split('-').next().unwrap_or(code);
+    let label = LANGUAGE_NAMES
+        .iter()
+        .find(|(known, _)| *known == language)
+        .map_or_else(|| code.to_uppercase(), |(_, name)| name.to_string());
+
+    let mut files = Vec::new();
+    let mut skipped = Vec::new();
+
+    set_language_label(&mut messages, &label_key, &label);
+    write_file(
+        project_path,
+        &messages_path,
+        &format!("{}\n", serde_json::to_string_pretty(&messages)?),
+    )?;
+    files.push(messages_path.clone());
+
+    for path in fs::list_dir(project.join("messages")).context("Failed to read messages/")? {
+        let relative = format!(
+            "messages/{}",
+            path.file_name().unwrap_or_default().to_string_lossy()
+        );
+        if path.extension().is_none_or(|ext| ext != "json") || relative == messages_path {
+            continue;
+        }
+        let mut existing: Value = serde_json::from_str(&fs::read_to_string(&path)?)
+            .with_context(|| format!("{} is not valid JSON", relative))?;
+        set_language_label(&mut existing, &label_key, &label);
+        update_file(
+            project_path,
+            &relative,
+            &format!("{}\n", serde_json::to_string_pretty(&existing)?),
+        )?;
+        files.push(relative);
+    }
+
+    let literal = format!("\"{}\"", code);
+    let dictionary =
+        read(project, DICTIONARY_PATH).and_then(|content| patch_dictionary(&content, &literal));
+    let switcher = read(project, SWITCHER_PATH)
+        .and_then(|content| patch_switcher(&content, &literal, &label_key));
+    for (path, patched) in [(DICTIONARY_PATH, dictionary), (SWITCHER_PATH, switcher)] {
+        match patched {
+            Some(content) => {
+                update_file(project_path, path, &content)?;
+                files.push(path.to_string());
+            }
+            None => skipped.push(path.to_string()),
+        }
+    }
+
+    Ok(GeneratedLocale { files, skipped })
+}
+
+fn read(project: &Path, relative_path: &str) -> Option<String> {
+    fs::read_to_string(project.join(relative_path)).ok()
+}
+
+/// Add the locale's switcher label under the `language` namespace
+fn set_language_label(messages: &mut Value, key: &str, label: &str) {
+    if let Some(root) = messages.as_object_mut() {
+        let language = root
+            .entry("language")
+            .or_insert_with(|| Value::Object(Map::new()));
+        if let Some(language) = language.as_object_mut() {
+            language.insert(key.to_string(), Value::String(label.to_string()));
+        }
+    }
+}
+
+/// `export const locales = ["de", "en"] as const;` gains the new code
+fn patch_dictionary(content: &str, literal: &str) -> Option<String> {
+    let start = content.find("export const locales = [")?;
+    let close = start + content[start..].find("] as const")?;
+    let mut patched = content.to_string();
+    patched.insert_str(close, &format!(", {}", literal));
+    Some(patched)
+}
+
+/// Extend the Locale union, the label and flag maps, and the rendered list
+fn patch_switcher(content: &str, literal: &str, label_key: &str) -> Option<String> {
+    let key = if literal.contains('-') {
+        literal.to_string()
+    } else {
+        literal.trim_matches('"').to_string()
+    };
+    let flag = literal.trim_matches('"').to_uppercase();
+
+    let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+
+    let union = lines
+        .iter()
+        .position(|line| line.starts_with("type Locale = "))?;
+    let line = &lines[union];
+    lines[union] = format!("{} | {};", line.strip_suffix(';')?, literal);
+
+    for (opening, entry) in [
+        (
+            "const localeLabels: Record<Locale, string> = {",
+            format!("    {}: t(\"{}\"),", key, label_key),
+        ),
+        (
+            "const localeFlags: Record<Locale, string> = {",
+            format!("    {}: \"{}\",", key, flag),
+        ),
+    ] {
+        let start = lines.iter().position(|line| line.trim() == opening)?;
+        let close = start + lines[start..].iter().position(|line| line.trim() == "};")?;
+        lines.insert(close, entry);
+    }
+
+    let list = lines
+        .iter()
+        .position(|line| line.contains("] as const).map((locale)"))?;
+    let at = lines[list].find("] as const")?;
+    lines[list].insert_str(at, &format!(", {}", literal));
+
+    let mut patched = lines.join("\n");
+    patched.push('\n');
+    Some(patched)
+}
+
+/// Look up `key` in the process environment, then in the env files `next dev` loads
+fn env_var(project_path: &str, key: &str) -> Option<String> {
+    if let Ok(value) = std::env::var(key) {
+        if !value.is_empty() {
+            return Some(value);
+        }
+    }
+    let (_, values) = env::read_development(project_path).ok()?;
+    values.get(key).filter(|value| !value.is_empty()).cloned()
+}
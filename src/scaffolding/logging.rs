@@ -0,0 +1,85 @@
+use anyhow::Result;
+
+use crate::scaffolding::env::{self, EnvFragment, EnvVar};
+use crate::scaffolding::trpc;
+use crate::utils::fs::write_file;
+use crate::utils::npm;
+
+const LOGGING_ENV: EnvFragment = EnvFragment {
+    section: "Logging",
+    vars: &[EnvVar::server(
+        "LOG_LEVEL",
+        r#"z.enum(["fatal", "error", "warn", "info", "debug", "trace", "silent"]).optional()"#,
+    )
+    .example("info")],
+};
+
+pub const LOGGING_DEPENDENCIES: &[(&str, &str)] = &[("pino", "^10.1.0")];
+pub const LOGGING_DEV_DEPENDENCIES: &[(&str, &str)] = &[("pino-pretty", "^13.1.2")];
+
+/// Scaffold the shared pino logger in `src/server/logger.ts` and wire it into tRPC:
+/// request-scoped `ctx.log` tagged with the request id, and per-procedure timings
+pub fn scaffold(project_path: &str) -> Result<()> {
+    write_file(project_path, "src/server/logger.ts", LOGGER)?;
+    trpc::add_logging_middleware(project_path)?;
+
+    env::register(project_path, &LOGGING_ENV)?;
+    npm::add_dependencies(project_path, LOGGING_DEPENDENCIES, LOGGING_DEV_DEPENDENCIES)?;
+    npm::add_scripts(
+        project_path,
+        &[("dev:pretty", "next dev --turbopack | pino-pretty")],
+    )?;
+
+    Ok(())
+}
+
+// ============================================================================
+// Embedded Templates
+// ============================================================================
+
+const LOGGER: &str = r#"import pino from "pino";
+
+const LEVELS = ["fatal", "error", "warn", "info", "debug", "trace", "silent"] as const;
+type Level = (typeof LEVELS)[number];
+
+export const REQUEST_ID_HEADER = "x-request-id";
+
+// Read directly like DATABASE_URL in db.ts, so scripts and tests without a validated env still log
+function resolveLevel(): Level {
+  const level = process.env.LOG_LEVEL as Level | undefined;
+  if (level && LEVELS.includes(level)) {
+    return level;
+  }
+  if (process.env.NODE_ENV === "test") {
+    return "silent";
+  }
+  return process.env.NODE_ENV === "production" ? "info" : "debug";
+}
+
+/**
+ * Shared JSON logger for server code. Prefer `ctx.log` inside tRPC procedures, which
+ * carries the request id; use `npm run dev:pretty` for readable output locally.
+ */
+export const logger = pino({
+  level: resolveLevel(),
+  redact: ["headers.authorization", "headers.cookie", "*.password", "*.token"],
+});
+
+export type Logger = pino.Logger;
+
+/** Reuse an upstream request id (proxy, load balancer, client) or start a new one */
+export function getRequestId(headers: Headers): string {
+  const incoming = headers.get(REQUEST_ID_HEADER);
+  // Only trust short, plain ids so callers can't inject arbitrary content into logs
+  if (incoming && /^[\w.-]{1,128}$/.test(incoming)) {
+    return incoming;
+  }
+  return crypto.randomUUID();
+}
+
+/** Child logger whose entries all carry the request id */
+export function createRequestLogger(headers: Headers): { requestId: string; log: Logger } {
+  const requestId = getRequestId(headers);
+  return { requestId, log: logger.child({ requestId }) };
+}
+"#;
@@ -0,0 +1,382 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use crate::cli::DatabaseProvider;
+use crate::scaffolding::database;
+use crate::scaffolding::env::{self, EnvFragment, EnvVar};
+use crate::scaffolding::i18n;
+use crate::utils::fs::{self, update_file, write_file};
+use crate::utils::npm;
+use crate::utils::plan::{self, Op};
+
+const PROXY_PATH: &str = "src/proxy.ts";
+
+const MAINTENANCE_ENV: EnvFragment = EnvFragment {
+    section: "Maintenance",
+    vars: &[EnvVar::server("MAINTENANCE_MODE", r#"z.enum(["on", "off"]).optional()"#).example("")],
+};
+
+pub const MAINTENANCE_DEV_DEPENDENCIES: &[(&str, &str)] = &[("tsx", "^4.21.0")];
+
+// Node loads the later env files over the earlier ones, like `next dev` does
+const MAINTENANCE_SCRIPTS: &[(&str, &str)] = &[(
+    "maintenance",
+    "tsx --env-file-if-exists=.env --env-file-if-exists=.env.development --env-file-if-exists=.env.local scripts/maintenance.ts",
+)];
+
+/// Scaffold runtime flags in the database (`src/server/runtime-config.ts`) and maintenance
+/// mode on top of them: `src/proxy.ts` answers every page with the localized `/maintenance`
+/// page and tRPC calls with a 503 while the `maintenance` flag or `MAINTENANCE_MODE=on` is set
+pub fn scaffold(project_path: &str) -> Result<()> {
+    let schema_path = Path::new(project_path).join("prisma/schema.prisma");
+    let schema = fs::read_to_string(&schema_path).context("Failed to read prisma/schema.prisma")?;
+    let models = if schema.contains("provider = \"sqlite\"") {
+        database::adapt_schema(PRISMA_RUNTIME_FLAG_MODEL, DatabaseProvider::Sqlite)
+    } else {
+        PRISMA_RUNTIME_FLAG_MODEL.to_string()
+    };
+    plan::apply(Op::PatchPrisma {
+        path: schema_path,
+        blocks: models,
+    })?;
+
+    write_file(project_path, "src/server/runtime-config.ts", RUNTIME_CONFIG)?;
+    write_file(project_path, "src/server/maintenance.ts", MAINTENANCE)?;
+    write_file(
+        project_path,
+        "src/server/maintenance.test.ts",
+        MAINTENANCE_TEST,
+    )?;
+    write_file(
+        project_path,
+        "src/app/maintenance/page.tsx",
+        MAINTENANCE_PAGE,
+    )?;
+    write_file(project_path, "scripts/maintenance.ts", MAINTENANCE_SCRIPT)?;
+    patch_proxy(project_path)?;
+
+    env::register(project_path, &MAINTENANCE_ENV)?;
+    i18n::merge_translations(project_path, "messages/en.json", MESSAGES_EN)?;
+    i18n::merge_translations(project_path, "messages/de.json", MESSAGES_DE)?;
+    npm::add_scripts(project_path, MAINTENANCE_SCRIPTS)?;
+    npm::add_dependencies(project_path, &[], MAINTENANCE_DEV_DEPENDENCIES)?;
+
+    Ok(())
+}
+
+/// Check maintenance first in the proxy, and run it for tRPC calls too
+fn patch_proxy(project_path: &str) -> Result<()> {
+    let proxy_path = Path::new(project_path).join(PROXY_PATH);
+    let content = fs::read_to_string(&proxy_path)
+        .with_context(|| format!("Failed to read {}", PROXY_PATH))?;
+
+    if content.contains("maintenanceResponse") {
+        return Ok(());
+    }
+
+    // With --security-headers the locale proxy is `routeLocale`, called by the CSP proxy
+    let (signature, async_signature) =
+        if content.contains(&format!("{}{}", PROXY_DOC, ROUTE_LOCALE_SIGNATURE)) {
+            (
+                ROUTE_LOCALE_SIGNATURE,
+                "async function routeLocale(request: NextRequest) {\n",
+            )
+        } else {
+            (
+                PROXY_SIGNATURE,
+                "export async function proxy(request: NextRequest) {\n",
+            )
+        };
+    let start_with_maintenance = format!(
+        "{}{}{}",
+        MAINTENANCE_DOC, async_signature, MAINTENANCE_CHECK
+    );
+
+    let patched = content
+        .replacen(
+            PROXY_IMPORT,
+            &format!(
+                "{}import {{ maintenanceResponse }} from \"@/server/maintenance\";\n",
+                PROXY_IMPORT
+            ),
+            1,
+        )
+        .replacen(
+            &format!("{}{}", PROXY_DOC, signature),
+            &start_with_maintenance,
+            1,
+        )
+        .replacen(PROXY_MATCHER, PROXY_MATCHER_WITH_TRPC, 1);
+
+    if !patched.contains(&start_with_maintenance) || !patched.contains(PROXY_MATCHER_WITH_TRPC) {
+        anyhow::bail!("Could not find the locale proxy in {}; call maintenanceResponse() at the top of it manually", PROXY_PATH);
+    }
+
+    update_file(project_path, PROXY_PATH, &patched)
+}
+
+// ============================================================================
+// Embedded Templates
+// ============================================================================
+
+const PROXY_IMPORT: &str =
+    "import { isLocale, LOCALE_COOKIE, negotiateLocale } from \"@/i18n/locale\";\n";
+
+const PROXY_DOC: &str = "/** Pick the locale from Accept-Language on the first visit and remember it in the cookie */\n";
+
+const PROXY_SIGNATURE: &str = "export function proxy(request: NextRequest) {\n";
+
+const ROUTE_LOCALE_SIGNATURE: &str = "function routeLocale(request: NextRequest) {\n";
+
+const MAINTENANCE_DOC: &str = r#"/**
+ * Serve the maintenance page while maintenance mode is on; otherwise pick the locale from
+ * Accept-Language on the first visit and remember it in the cookie
+ */
+"#;
+
+const MAINTENANCE_CHECK: &str = r#"  const maintenance = await maintenanceResponse(request);
+  if (maintenance) {
+    return maintenance;
+  }
+  // tRPC calls only come through for the maintenance check
+  if (request.nextUrl.pathname.startsWith("/api/")) {
+    return NextResponse.next();
+  }
+
+"#;
+
+const PROXY_MATCHER: &str = r#"  // Pages only: API routes, Next.js internals, and static files keep their locale-free responses
+  matcher: ["/((?!api|_next|.*\\..*).*)"],
+"#;
+
+const PROXY_MATCHER_WITH_TRPC: &str = r#"  // Pages, and tRPC for maintenance mode only: other API routes, Next.js internals, and static
+  // files keep their locale-free responses
+  matcher: ["/((?!api|_next|.*\\..*).*)", "/api/trpc/:path*"],
+"#;
+
+const PRISMA_RUNTIME_FLAG_MODEL: &str = r#"
+/// Switched at runtime without a deploy; see src/server/runtime-config.ts
+model RuntimeFlag {
+  key       String   @id
+  enabled   Boolean  @default(false)
+  message   String?  @db.Text
+  updatedAt DateTime @updatedAt
+}
+"#;
+
+const RUNTIME_CONFIG: &str = r#"import { db } from "@/server/db";
+
+export interface RuntimeFlagState {
+  enabled: boolean;
+  message: string | null;
+}
+
+const OFF: RuntimeFlagState = { enabled: false, message: null };
+
+// src/proxy.ts reads flags on every request, so each server keeps them for a few seconds
+const CACHE_MS = 10_000;
+const cache = new Map<string, { state: RuntimeFlagState; expiresAt: number }>();
+
+/**
+ * A flag from the RuntimeFlag table, switched without a deploy: maintenance mode, or a kill
+ * switch such as `if ((await getFlag("uploads-disabled")).enabled) ...`. Missing flags are
+ * off, and so is every flag while the database can't be reached.
+ */
+export async function getFlag(key: string): Promise<RuntimeFlagState> {
+  const cached = cache.get(key);
+  if (cached && cached.expiresAt > Date.now()) {
+    return cached.state;
+  }
+
+  let state = OFF;
+  try {
+    const flag = await db.runtimeFlag.findUnique({ where: { key } });
+    if (flag) {
+      state = { enabled: flag.enabled, message: flag.message };
+    }
+  } catch (error) {
+    console.error(`Could not read runtime flag "${key}"`, error);
+  }
+  cache.set(key, { state, expiresAt: Date.now() + CACHE_MS });
+  return state;
+}
+
+/** Switch a flag; other servers pick it up when their cached copy expires */
+export async function setFlag(key: string, enabled: boolean, message: string | null = null) {
+  await db.runtimeFlag.upsert({
+    where: { key },
+    create: { key, enabled, message },
+    update: { enabled, message },
+  });
+  cache.delete(key);
+}
+"#;
+
+const MAINTENANCE: &str = r#"import { type NextRequest, NextResponse } from "next/server";
+import { getFlag, type RuntimeFlagState } from "@/server/runtime-config";
+
+export const MAINTENANCE_FLAG = "maintenance";
+
+// Reachable during maintenance: the page itself and the health check of the container
+const ALWAYS_AVAILABLE = ["/maintenance", "/api/health"];
+
+/**
+ * Whether maintenance mode is on. `MAINTENANCE_MODE=on` or `off` overrides the flag, e.g.
+ * while the database itself is being migrated. Read directly like DATABASE_URL in db.ts,
+ * so the proxy doesn't load the validated env.
+ */
+export async function getMaintenance(): Promise<RuntimeFlagState> {
+  const override = process.env.MAINTENANCE_MODE;
+  if (override === "on" || override === "off") {
+    return { enabled: override === "on", message: null };
+  }
+  return getFlag(MAINTENANCE_FLAG);
+}
+
+/** The 503 to answer `request` with while maintenance mode is on, or null to go on */
+export async function maintenanceResponse(request: NextRequest): Promise<NextResponse | null> {
+  const { pathname } = request.nextUrl;
+  if (ALWAYS_AVAILABLE.some((path) => pathname === path || pathname.startsWith(`${path}/`))) {
+    return null;
+  }
+  if (!(await getMaintenance()).enabled) {
+    return null;
+  }
+
+  const headers = { "Retry-After": "300" };
+  if (pathname.startsWith("/api/")) {
+    return NextResponse.json({ error: "Service unavailable for maintenance" }, { status: 503, headers });
+  }
+  // Request headers the proxy set, such as a CSP nonce, reach the page too
+  return NextResponse.rewrite(new URL("/maintenance", request.url), {
+    status: 503,
+    headers,
+    request: { headers: request.headers },
+  });
+}
+"#;
+
+const MAINTENANCE_TEST: &str = r#"// @vitest-environment node
+import { NextRequest } from "next/server";
+import { afterEach, beforeEach, describe, expect, it, vi } from "vitest";
+
+// Keep the test off the real database; shared so every fresh import below sees the same mock
+const { findUnique } = vi.hoisted(() => ({ findUnique: vi.fn() }));
+vi.mock("@/server/db", () => ({
+  db: { runtimeFlag: { findUnique, upsert: vi.fn() } },
+}));
+
+// Flags are cached per module, so every case starts from a fresh import
+async function loadMaintenance() {
+  vi.resetModules();
+  return import("./maintenance");
+}
+
+function request(path: string) {
+  return new NextRequest(new URL(path, "http://localhost:3000"));
+}
+
+beforeEach(() => {
+  findUnique.mockReset();
+});
+
+afterEach(() => {
+  vi.unstubAllEnvs();
+});
+
+describe("maintenanceResponse", () => {
+  it("lets requests through while the flag is off", async () => {
+    findUnique.mockResolvedValue(null);
+    const { maintenanceResponse } = await loadMaintenance();
+
+    expect(await maintenanceResponse(request("/posts"))).toBeNull();
+  });
+
+  it("serves the maintenance page and turns tRPC calls away while the flag is on", async () => {
+    findUnique.mockResolvedValue({ enabled: true, message: null });
+    const { maintenanceResponse } = await loadMaintenance();
+
+    const page = await maintenanceResponse(request("/posts"));
+    expect(page?.status).toBe(503);
+    expect(page?.headers.get("x-middleware-rewrite")).toBe("http://localhost:3000/maintenance");
+
+    const api = await maintenanceResponse(request("/api/trpc/post.list"));
+    expect(api?.status).toBe(503);
+    expect(await maintenanceResponse(request("/api/health"))).toBeNull();
+    // Cached between requests
+    expect(findUnique).toHaveBeenCalledTimes(1);
+  });
+
+  it("follows MAINTENANCE_MODE over the flag", async () => {
+    vi.stubEnv("MAINTENANCE_MODE", "on");
+    findUnique.mockRejectedValue(new Error("database is down"));
+    const { maintenanceResponse } = await loadMaintenance();
+
+    expect((await maintenanceResponse(request("/")))?.status).toBe(503);
+    expect(findUnique).not.toHaveBeenCalled();
+  });
+});
+"#;
+
+const MAINTENANCE_PAGE: &str = r#"import type { Metadata } from "next";
+import { redirect } from "next/navigation";
+import { getTranslations } from "next-intl/server";
+import { getMaintenance } from "@/server/maintenance";
+
+export const dynamic = "force-dynamic";
+
+export async function generateMetadata(): Promise<Metadata> {
+  const t = await getTranslations("maintenance");
+  return { title: t("title"), robots: { index: false } };
+}
+
+/** src/proxy.ts shows this page at every URL while maintenance mode is on */
+export default async function MaintenancePage() {
+  const maintenance = await getMaintenance();
+  if (!maintenance.enabled) {
+    redirect("/");
+  }
+  const t = await getTranslations("maintenance");
+
+  return (
+    <main className="min-h-screen flex flex-col items-center justify-center gap-4 px-4 text-center">
+      <h1 className="text-2xl font-semibold">{t("title")}</h1>
+      <p className="max-w-md text-muted-foreground">{maintenance.message ?? t("description")}</p>
+      <p className="text-sm text-muted-foreground">{t("retry")}</p>
+    </main>
+  );
+}
+"#;
+
+const MAINTENANCE_SCRIPT: &str = r#"import { db } from "../src/server/db";
+import { MAINTENANCE_FLAG } from "../src/server/maintenance";
+import { setFlag } from "../src/server/runtime-config";
+
+// Usage: npm run maintenance -- on ["message shown instead of the default text"]
+//        npm run maintenance -- off
+const [mode, message] = process.argv.slice(2);
+if (mode !== "on" && mode !== "off") {
+  console.error('Usage: npm run maintenance -- on|off ["message"]');
+  process.exit(1);
+}
+
+await setFlag(MAINTENANCE_FLAG, mode === "on", message ?? null);
+console.log(`Maintenance mode ${mode}; servers pick it up within 10 seconds`);
+await db.$disconnect();
+"#;
+
+const MESSAGES_EN: &str = r#"{
+  "maintenance": {
+    "title": "Down for maintenance",
+    "description": "We're making some improvements and will be back shortly.",
+    "retry": "Please try again in a few minutes."
+  }
+}"#;
+
+const MESSAGES_DE: &str = r#"{
+  "maintenance": {
+    "title": "Wartungsarbeiten",
+    "description": "Wir nehmen gerade Verbesserungen vor und sind gleich wieder da.",
+    "retry": "Bitte versuchen Sie es in ein paar Minuten erneut."
+  }
+}"#;
@@ -0,0 +1,170 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::io::Read;
+use std::path::Path;
+
+use crate::scaffolding::options::ScaffoldOptions;
+use crate::templates::remote;
+use crate::utils::fs::{resolved_file_type, update_file};
+use crate::utils::plan::{Content, Plan};
+
+pub const MANIFEST_PATH: &str = ".t3mono.json";
+
+/// Directories never tracked: tool output and files generated by other CLIs
+const IGNORED_DIRS: &[&str] = &[
+    ".git",
+    "node_modules",
+    ".next",
+    "prisma/migrations",
+    ".t3mono-backup",
+];
+
+/// What `create` generated: the options to re-render the templates with, and the
+/// hash of every file as it was written
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Manifest {
+    /// CLI version that wrote the files
+    pub version: String,
+    /// Whether `create` initialized git (it writes `.gitignore`)
+    pub git: bool,
+    pub options: ScaffoldOptions,
+    /// Project-relative path to sha256 of the generated content
+    pub files: BTreeMap<String, String>,
+}
+
+/// Write the manifest for a project freshly created in `project_path`, hashing the files
+/// its write plan writes; the manifest itself goes into the plan too
+pub fn record(project_path: &str, options: &ScaffoldOptions, git: bool, plan: &Plan) -> Result<()> {
+    let mut files = BTreeMap::new();
+    plan.each_file(Path::new(project_path), |path, content| {
+        let ignored = IGNORED_DIRS
+            .iter()
+            .any(|dir| path.starts_with(&format!("{}/", dir)));
+        if path != MANIFEST_PATH && !ignored {
+            files.insert(path, hash_content(content)?);
+        }
+        Ok(())
+    })?;
+    let manifest = Manifest {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        git,
+        options: options.clone(),
+        files,
+    };
+    write(project_path, &manifest)
+}
+
+pub fn read(project_path: &str) -> Result<Manifest> {
+    let path = Path::new(project_path).join(MANIFEST_PATH);
+    if !path.exists() {
+        anyhow::bail!(
+            "No {} found. Run this from the root of a project created by a t3-mono version that records one.",
+            MANIFEST_PATH
+        );
+    }
+    let content = std::fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read {}", MANIFEST_PATH))?;
+    serde_json::from_str(&content)
+        .with_context(|| format!("{} is not a valid manifest", MANIFEST_PATH))
+}
+
+/// Read a manifest from a project directory, a manifest file, or an http(s) URL
+pub async fn load(source: &str) -> Result<Manifest> {
+    let content = if source.starts_with("https://") || source.starts_with("http://") {
+        remote::client()?
+            .get(source)
+            .send()
+            .await
+            .map_err(|e| e.without_url())
+            .with_context(|| format!("Failed to fetch {}", source))?
+            .error_for_status()?
+            .text()
+            .await?
+    } else {
+        let mut path = Path::new(source).to_path_buf();
+        if path.is_dir() {
+            path = path.join(MANIFEST_PATH);
+        }
+        std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?
+    };
+    serde_json::from_str(&content).with_context(|| format!("{} is not a valid manifest", source))
+}
+
+pub fn write(project_path: &str, manifest: &Manifest) -> Result<()> {
+    let content = serde_json::to_string_pretty(manifest)?;
+    update_file(project_path, MANIFEST_PATH, &format!("{}\n", content))
+}
+
+/// Hex sha256 of `content`
+pub fn hash(content: &[u8]) -> String {
+    Sha256::digest(content)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// [`hash`] of planned content, reading files in chunks instead of whole
+fn hash_content(content: &Content) -> Result<String> {
+    let mut hasher = Sha256::new();
+    match content {
+        Content::Bytes(bytes) => hasher.update(bytes),
+        Content::File(source) => {
+            let mut file = std::fs::File::open(source)
+                .with_context(|| format!("Failed to read {}", source.display()))?;
+            let mut buffer = [0; 64 * 1024];
+            loop {
+                match file.read(&mut buffer)? {
+                    0 => break,
+                    read => hasher.update(&buffer[..read]),
+                }
+            }
+        }
+    }
+    Ok(hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect())
+}
+
+/// Hash every tracked file under `project_path`, keyed by its `/`-separated relative path
+pub fn hash_files(project_path: &str) -> Result<BTreeMap<String, String>> {
+    let mut files = BTreeMap::new();
+    collect(Path::new(project_path), "", &mut files)?;
+    Ok(files)
+}
+
+fn collect(root: &Path, relative: &str, files: &mut BTreeMap<String, String>) -> Result<()> {
+    let dir = root.join(relative);
+    for entry in
+        std::fs::read_dir(&dir).with_context(|| format!("Failed to read {}", dir.display()))?
+    {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().to_string();
+        let path = if relative.is_empty() {
+            name
+        } else {
+            format!("{}/{}", relative, name)
+        };
+
+        let kind = entry.file_type()?;
+        if kind.is_dir() {
+            if !IGNORED_DIRS.contains(&path.as_str()) {
+                collect(root, &path, files)?;
+            }
+        } else if kind.is_symlink()
+            && !resolved_file_type(&entry).is_some_and(|kind| kind.is_file())
+        {
+            // t3-mono never generates links; one to a directory could loop, a broken one has no content
+            continue;
+        } else if path != MANIFEST_PATH {
+            let content = std::fs::read(entry.path())?;
+            files.insert(path, hash(&content));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use anyhow::{Context, Result};
+use std::process::Command;
+
+use crate::cli::DatabaseProvider;
+use crate::scaffolding::database;
+use crate::utils::fs::write_file;
+
+/// Baseline directory name recommended by Prisma; sorts before timestamped migrations
+const INIT_MIGRATION_DIR: &str = "prisma/migrations/0_init";
+
+/// Prisma CLI used for the diff, matching the `prisma` devDependency
+const PRISMA_CLI: &str = "prisma@7";
+
+/// Generate the initial migration from the final `prisma/schema.prisma`.
+///
+/// Uses `prisma migrate diff` against an empty database, so no database has to be
+/// reachable. Returns `false` when the Prisma CLI couldn't be run (e.g. offline);
+/// `npm run db:migrate -- --name init` then creates the migration instead.
+pub fn scaffold(project_path: &str, database: DatabaseProvider) -> Result<bool> {
+    let output = Command::new(if cfg!(windows) { "npx.cmd" } else { "npx" })
+        .args([
+            "--yes",
+            PRISMA_CLI,
+            "migrate",
+            "diff",
+            "--from-empty",
+            "--to-schema",
+            "prisma/schema.prisma",
+            "--script",
+        ])
+        .current_dir(project_path)
+        .output();
+
+    let sql = match output {
+        Ok(output) if output.status.success() => String::from_utf8(output.stdout)
+            .context("prisma migrate diff produced invalid UTF-8")?,
+        _ => return Ok(false),
+    };
+
+    write_file(
+        project_path,
+        &format!("{}/migration.sql", INIT_MIGRATION_DIR),
+        &sql,
+    )?;
+    let lock = MIGRATION_LOCK.replace("{{provider}}", database::prisma_provider(database));
+    write_file(project_path, "prisma/migrations/migration_lock.toml", &lock)?;
+
+    Ok(true)
+}
+
+// ============================================================================
+// Embedded Templates
+// ============================================================================
+
+const MIGRATION_LOCK: &str = r#"# Please do not edit this file manually
+# It should be added in your version-control system (e.g., Git)
+provider = "{{provider}}"
+"#;
@@ -1,7 +1,57 @@
+pub mod agent;
 pub mod ai;
+pub mod analytics;
+pub mod assistant;
 pub mod better_auth;
+pub mod biome;
+pub mod bundle_analyzer;
+pub mod chat_stream;
+pub mod ci;
 pub mod cmd;
+pub mod cms;
+pub mod compliance;
+pub mod component;
+pub mod dark_mode;
+pub mod database;
+pub mod docker;
+pub mod docs;
+pub mod drift;
+pub mod env;
+pub mod example;
+pub mod forms;
+pub mod i18n;
+pub mod images;
+pub mod locale;
+pub mod logging;
+pub mod maintenance;
+pub mod manifest;
+pub mod migrations;
+pub mod nav;
 pub mod next_auth;
+pub mod nix;
+pub mod options;
+pub mod page;
+pub mod perf;
+pub mod pooling;
+pub mod privacy;
+pub mod project;
+pub mod resource;
+pub mod rest;
 pub mod restate;
+pub mod restate_kafka;
+pub mod search;
+pub mod security_headers;
+pub mod seed;
+pub mod seo;
+pub mod settings;
+pub mod setup_db;
+pub mod skills;
+pub mod soft_delete;
 pub mod t3;
+pub mod tailwind;
+pub mod tasks;
+pub mod terraform;
+pub mod trpc;
 pub mod ui;
+pub mod webhooks;
+pub mod workflow;
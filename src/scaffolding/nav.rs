@@ -0,0 +1,62 @@
+use anyhow::Result;
+use std::path::Path;
+
+use crate::scaffolding::i18n;
+use crate::utils::fs::{self, update_file};
+
+pub const HEADER_PATH: &str = "src/app/_components/Header.tsx";
+const TODO_PREFIX: &str = "TODO: ";
+
+/// A Header link an extension contributes, labelled by `nav.<key>` in the messages
+pub struct NavEntry {
+    pub href: &'static str,
+    pub key: &'static str,
+    pub en: &'static str,
+    pub de: &'static str,
+}
+
+/// Add `entry` to the Header's nav items and its label to every `messages/*.json`; other
+/// languages get the English label marked TODO. A Header that no longer has
+/// `defaultNavItems` is left alone.
+pub fn register(project_path: &str, entry: &NavEntry) -> Result<()> {
+    for file in i18n::locale_files(project_path)? {
+        let label = match file.as_str() {
+            "messages/en.json" => entry.en.to_string(),
+            "messages/de.json" => entry.de.to_string(),
+            _ => format!("{}{}", TODO_PREFIX, entry.en),
+        };
+        i18n::set_message(project_path, &file, "nav", entry.key, &label)?;
+    }
+
+    let header = fs::read_to_string(Path::new(project_path).join(HEADER_PATH))
+        .ok()
+        .and_then(|content| add_item(&content, entry.href, entry.key));
+    if let Some(content) = header {
+        update_file(project_path, HEADER_PATH, &content)?;
+    }
+
+    Ok(())
+}
+
+/// Append `{ href, labelKey }` to the Header's `defaultNavItems`
+pub fn add_item(content: &str, href: &str, label_key: &str) -> Option<String> {
+    let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+    let start = lines
+        .iter()
+        .position(|line| line.trim() == "const defaultNavItems: NavItem[] = [")?;
+    let close = start + lines[start..].iter().position(|line| line.trim() == "];")?;
+    if lines[start..close]
+        .iter()
+        .any(|line| line.contains(&format!("href: \"{}\"", href)))
+    {
+        return Some(content.to_string());
+    }
+    lines.insert(
+        close,
+        format!("  {{ href: \"{}\", labelKey: \"{}\" }},", href, label_key),
+    );
+
+    let mut patched = lines.join("\n");
+    patched.push('\n');
+    Some(patched)
+}
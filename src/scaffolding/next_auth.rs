@@ -1,31 +1,83 @@
-use anyhow::Result;
+use crate::cli::SessionStrategy;
+use crate::scaffolding::env::{self, EnvFragment, EnvVar};
+use crate::scaffolding::trpc;
 use crate::utils::fs::write_file;
+use crate::utils::plan::{self, Op};
+use anyhow::Result;
+use std::path::Path;
+
+const ENV: EnvFragment = EnvFragment {
+    section: "NextAuth",
+    vars: &[
+        EnvVar::server(
+            "NEXTAUTH_SECRET",
+            r#"process.env.NODE_ENV === "production" ? z.string().min(32) : z.string().optional()"#,
+        )
+        .example("your-secret-key-min-32-chars-here")
+        .secret(),
+        EnvVar::server("NEXTAUTH_URL", "z.string().url().optional()")
+            .example("http://localhost:3000")
+            .production("https://example.com"),
+    ],
+};
+
+const OAUTH_ENV: EnvFragment = EnvFragment {
+    section: "OAuth Providers (optional)",
+    vars: &[
+        EnvVar::server("GITHUB_CLIENT_ID", "z.string().optional()"),
+        EnvVar::server("GITHUB_CLIENT_SECRET", "z.string().optional()"),
+    ],
+};
 
-/// Scaffold NextAuth (v4) integration
-pub async fn scaffold(project_path: &str) -> Result<()> {
-    // Write auth configuration
-    write_file(project_path, "src/server/auth.ts", AUTH_CONFIG)?;
+/// Scaffold NextAuth (v4) integration with the given session strategy
+pub async fn scaffold(project_path: &str, session: SessionStrategy) -> Result<()> {
+    // Write auth configuration; NextAuth only signs in with credentials under the JWT strategy
+    let (auth_config, get_session_doc) = match session {
+        SessionStrategy::Jwt => (AUTH_CONFIG, GET_SESSION_DOC_JWT),
+        SessionStrategy::Database => (AUTH_CONFIG_DATABASE, GET_SESSION_DOC_DATABASE),
+    };
+    write_file(project_path, "src/server/auth.ts", auth_config)?;
 
     // Write auth API route
-    write_file(project_path, "src/app/api/auth/[...nextauth]/route.ts", AUTH_ROUTE)?;
+    write_file(
+        project_path,
+        "src/app/api/auth/[...nextauth]/route.ts",
+        AUTH_ROUTE,
+    )?;
 
     // Write auth client
     write_file(project_path, "src/lib/auth-client.ts", AUTH_CLIENT)?;
 
+    // Account menu in the Header
+    write_file(project_path, "src/app/_components/UserMenu.tsx", USER_MENU)?;
+    write_file(
+        project_path,
+        "src/app/_components/UserMenu.test.tsx",
+        USER_MENU_TEST,
+    )?;
+
+    // Server-side session helper, used by tRPC and the dashboard
+    let session_helper = SESSION_HELPER.replace("{{get_session_doc}}", get_session_doc);
+    write_file(project_path, "src/server/session.ts", &session_helper)?;
+    trpc::add_session_support(project_path)?;
+
     // Write session provider wrapper
-    write_file(project_path, "src/components/providers/session-provider.tsx", SESSION_PROVIDER)?;
+    write_file(
+        project_path,
+        "src/components/providers/session-provider.tsx",
+        SESSION_PROVIDER,
+    )?;
 
-    // Append NextAuth models to Prisma schema
-    append_to_prisma_schema(project_path)?;
+    // Validate the auth secrets and list them in .env.example
+    env::register(project_path, &ENV)?;
+    env::register(project_path, &OAUTH_ENV)?;
 
-    Ok(())
-}
+    // Append NextAuth models to Prisma schema
+    plan::apply(Op::PatchPrisma {
+        path: Path::new(project_path).join("prisma/schema.prisma"),
+        blocks: PRISMA_AUTH_MODELS.to_string(),
+    })?;
 
-fn append_to_prisma_schema(project_path: &str) -> Result<()> {
-    let schema_path = std::path::Path::new(project_path).join("prisma/schema.prisma");
-    let mut content = std::fs::read_to_string(&schema_path)?;
-    content.push_str(PRISMA_AUTH_MODELS);
-    std::fs::write(schema_path, content)?;
     Ok(())
 }
 
@@ -91,7 +143,7 @@ export const authOptions: NextAuthOptions = {
       ...session,
       user: {
         ...session.user,
-        id: token.sub,
+        id: token.sub ?? "",
       },
     }),
     jwt: ({ token, user }) => {
@@ -106,6 +158,44 @@ export const authOptions: NextAuthOptions = {
 export const getServerAuthSession = () => getServerSession(authOptions);
 "#;
 
+const AUTH_CONFIG_DATABASE: &str = r#"import { PrismaAdapter } from "@auth/prisma-adapter";
+import { type NextAuthOptions, getServerSession } from "next-auth";
+import GithubProvider from "next-auth/providers/github";
+import { db } from "@/server/db";
+
+export const authOptions: NextAuthOptions = {
+  adapter: PrismaAdapter(db),
+  providers: [
+    GithubProvider({
+      clientId: process.env.GITHUB_CLIENT_ID ?? "",
+      clientSecret: process.env.GITHUB_CLIENT_SECRET ?? "",
+    }),
+  ],
+  session: {
+    // A Session row per sign-in, so sessions can be listed and revoked server-side.
+    // The credentials provider needs the JWT strategy and isn't available here.
+    strategy: "database",
+    maxAge: 60 * 60 * 24 * 30, // 30 days
+    updateAge: 60 * 60 * 24, // 1 day
+  },
+  pages: {
+    signIn: "/auth/signin",
+  },
+  callbacks: {
+    // `user` is the User row the adapter loaded with the session; there is no token
+    session: ({ session, user }) => ({
+      ...session,
+      user: {
+        ...session.user,
+        id: user.id,
+      },
+    }),
+  },
+};
+
+export const getServerAuthSession = () => getServerSession(authOptions);
+"#;
+
 const AUTH_ROUTE: &str = r#"import NextAuth from "next-auth";
 import { authOptions } from "@/server/auth";
 
@@ -132,7 +222,185 @@ export function useAuth() {
 }
 "#;
 
-const SESSION_PROVIDER: &str = r#"\"use client\";
+const USER_MENU: &str = r#""use client";
+
+import Image from "next/image";
+import Link from "next/link";
+import { useEffect, useRef, useState } from "react";
+import { useTranslations } from "next-intl";
+import { SessionProvider } from "@/components/providers/session-provider";
+import { signIn, signOut, useSession } from "@/lib/auth-client";
+
+function initials(name: string) {
+  const letters = name
+    .split(/\s+/)
+    .filter(Boolean)
+    .slice(0, 2)
+    .map((part) => part[0]?.toUpperCase())
+    .join("");
+  return letters || "?";
+}
+
+// useSession reads the session from a SessionProvider, so the menu brings its own
+export function UserMenu() {
+  return (
+    <SessionProvider>
+      <AccountMenu />
+    </SessionProvider>
+  );
+}
+
+function AccountMenu() {
+  const t = useTranslations("nav");
+  const { data: session, status } = useSession();
+  const [isOpen, setIsOpen] = useState(false);
+  const menuRef = useRef<HTMLDivElement>(null);
+
+  // Close menu when clicking outside
+  useEffect(() => {
+    function handleClickOutside(event: MouseEvent) {
+      if (menuRef.current && !menuRef.current.contains(event.target as Node)) {
+        setIsOpen(false);
+      }
+    }
+
+    if (isOpen) {
+      document.addEventListener("mousedown", handleClickOutside);
+    }
+
+    return () => {
+      document.removeEventListener("mousedown", handleClickOutside);
+    };
+  }, [isOpen]);
+
+  if (status === "loading") {
+    return <div className="h-9 w-9 rounded-full bg-muted animate-pulse" aria-hidden="true" />;
+  }
+
+  if (!session) {
+    return (
+      <button
+        type="button"
+        onClick={() => signIn()}
+        className="px-3 py-2 rounded-lg text-sm font-medium text-muted-foreground hover:text-primary hover:bg-muted cursor-pointer transition-colors"
+      >
+        {t("signIn")}
+      </button>
+    );
+  }
+
+  const { user } = session;
+  const name = user.name || user.email || "";
+
+  function handleSignOut() {
+    setIsOpen(false);
+    void signOut({ callbackUrl: "/" });
+  }
+
+  return (
+    <div className="relative" ref={menuRef}>
+      <button
+        type="button"
+        onClick={() => setIsOpen(!isOpen)}
+        className="rounded-full cursor-pointer focus:outline-none focus-visible:ring-2 focus-visible:ring-primary"
+        aria-label={t("account")}
+        aria-expanded={isOpen}
+      >
+        {user.image ? (
+          <Image
+            src={user.image}
+            alt=""
+            width={36}
+            height={36}
+            unoptimized
+            className="h-9 w-9 rounded-full object-cover"
+          />
+        ) : (
+          <span className="flex h-9 w-9 items-center justify-center rounded-full bg-primary text-sm font-medium text-primary-foreground">
+            {initials(name)}
+          </span>
+        )}
+      </button>
+
+      {isOpen && (
+        <div className="absolute right-0 mt-2 w-56 bg-card rounded-xl border border-border/50 shadow-lg py-2 z-50">
+          <div className="px-4 py-2 border-b border-border/50">
+            <p className="text-sm font-medium truncate">{name}</p>
+            {user.name && <p className="text-xs text-muted-foreground truncate">{user.email}</p>}
+          </div>
+          <Link
+            href="/settings"
+            onClick={() => setIsOpen(false)}
+            className="block px-4 py-2.5 text-sm font-medium text-muted-foreground hover:text-primary hover:bg-muted transition-colors"
+          >
+            {t("settings")}
+          </Link>
+          <button
+            type="button"
+            onClick={handleSignOut}
+            className="block w-full text-left px-4 py-2.5 text-sm font-medium text-muted-foreground hover:text-primary hover:bg-muted cursor-pointer transition-colors"
+          >
+            {t("signOut")}
+          </button>
+        </div>
+      )}
+    </div>
+  );
+}
+"#;
+
+const USER_MENU_TEST: &str = r#"import { fireEvent, render, screen } from "@testing-library/react";
+import { NextIntlClientProvider } from "next-intl";
+import { describe, expect, it, vi } from "vitest";
+import messages from "../../../messages/en.json";
+import { UserMenu } from "./UserMenu";
+
+const auth = vi.hoisted(() => ({
+  useSession: vi.fn(),
+  signIn: vi.fn(),
+  signOut: vi.fn(),
+}));
+
+vi.mock("@/lib/auth-client", () => auth);
+vi.mock("@/components/providers/session-provider", () => ({
+  SessionProvider: ({ children }: { children: React.ReactNode }) => children,
+}));
+
+function renderMenu() {
+  return render(
+    <NextIntlClientProvider locale="en" messages={messages}>
+      <UserMenu />
+    </NextIntlClientProvider>,
+  );
+}
+
+describe("UserMenu", () => {
+  it("offers sign-in when signed out", () => {
+    auth.useSession.mockReturnValue({ data: null, status: "unauthenticated" });
+    renderMenu();
+
+    fireEvent.click(screen.getByRole("button", { name: messages.nav.signIn }));
+    expect(auth.signIn).toHaveBeenCalled();
+  });
+
+  it("shows the user, a settings link, and signs out", () => {
+    auth.useSession.mockReturnValue({
+      data: { user: { name: "Ada Lovelace", email: "ada@example.com", image: null } },
+      status: "authenticated",
+    });
+    renderMenu();
+
+    fireEvent.click(screen.getByRole("button", { name: messages.nav.account }));
+    expect(screen.getByText("Ada Lovelace")).toBeInTheDocument();
+    expect(screen.getByRole("link", { name: messages.nav.settings })).toHaveAttribute("href", "/settings");
+
+    fireEvent.click(screen.getByRole("button", { name: messages.nav.signOut }));
+    expect(auth.signOut).toHaveBeenCalledWith({ callbackUrl: "/" });
+  });
+});
+"#;
+
+const SESSION_PROVIDER: &str = r#""use client";
 
 import { SessionProvider as NextAuthSessionProvider } from "next-auth/react";
 
@@ -141,6 +409,36 @@ export function SessionProvider({ children }: { children: React.ReactNode }) {
 }
 "#;
 
+const SESSION_HELPER: &str = r#"import "server-only";
+
+import { type DefaultSession } from "next-auth";
+import { getServerAuthSession } from "@/server/auth";
+
+declare module "next-auth" {
+  interface Session {
+    user: DefaultSession["user"] & { id: string };
+  }
+}
+
+/** Where protected pages send anonymous visitors (matches `pages.signIn` in auth.ts) */
+export const SIGN_IN_PATH = "/auth/signin";
+
+{{get_session_doc}}
+export async function getSession() {
+  return getServerAuthSession();
+}
+
+export type AppSession = NonNullable<Awaited<ReturnType<typeof getSession>>>;
+"#;
+
+const GET_SESSION_DOC_JWT: &str = "/** Current NextAuth session, or `null` when signed out */";
+
+const GET_SESSION_DOC_DATABASE: &str = r#"/**
+ * Current NextAuth session, or `null` when signed out. Database sessions are read from the
+ * Session table, so check them here and in tRPC rather than in src/proxy.ts, which runs
+ * before a database is reachable.
+ */"#;
+
 const PRISMA_AUTH_MODELS: &str = r#"
 // ============================================================================
 // NextAuth Models
@@ -0,0 +1,61 @@
+use anyhow::Result;
+
+use crate::utils::fs::write_file;
+
+/// Scaffold a Nix flake dev shell and the direnv hook that loads it
+pub fn scaffold(project_path: &str) -> Result<()> {
+    write_file(project_path, "flake.nix", FLAKE_NIX)?;
+    write_file(project_path, ".envrc", ENVRC)?;
+
+    Ok(())
+}
+
+// ============================================================================
+// Embedded Templates
+// ============================================================================
+
+const FLAKE_NIX: &str = r#"{
+  description = "Development environment";
+
+  inputs = {
+    nixpkgs.url = "github:NixOS/nixpkgs/nixos-unstable";
+    flake-utils.url = "github:numtide/flake-utils";
+  };
+
+  outputs = { nixpkgs, flake-utils, ... }:
+    flake-utils.lib.eachDefaultSystem (system:
+      let
+        pkgs = nixpkgs.legacyPackages.${system};
+      in
+      {
+        devShells.default = pkgs.mkShell {
+          packages = with pkgs; [
+            # Node.js (ships npm)
+            nodejs_22
+
+            # Postgres client tooling (psql, pg_dump) for the local database
+            postgresql
+
+            # Needed by Prisma and native npm modules
+            openssl
+          ];
+
+          shellHook = ''
+            # Keep globally installed npm binaries inside the project
+            export NPM_CONFIG_PREFIX="$PWD/.direnv/npm-global"
+            export PATH="$PWD/node_modules/.bin:$NPM_CONFIG_PREFIX/bin:$PATH"
+          '';
+        };
+      });
+}
+"#;
+
+const ENVRC: &str = r#"# Loads the Nix dev shell from flake.nix -- run `direnv allow` once to enable
+use flake
+
+# Load local environment variables when present, in the order `next dev` does
+dotenv_if_exists .env
+dotenv_if_exists .env.development
+dotenv_if_exists .env.local
+dotenv_if_exists .env.development.local
+"#;
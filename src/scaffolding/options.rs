@@ -0,0 +1,144 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::cli::{
+    AuthProvider, BiomeProfile, ChatStream, CmdModule, DarkMode, DatabaseProvider, Iac,
+    LlmProvider, Pooling, SessionStrategy, StorageBackend, TailwindPlugin, TaskRunner,
+    TsStrictness, VectorStore,
+};
+use crate::scaffolding::cmd;
+
+/// Everything the scaffolders need to know about the project being created
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScaffoldOptions {
+    /// Project name, also used as the target directory
+    pub name: String,
+    pub auth: AuthProvider,
+    /// NextAuth session strategy; Better Auth always keeps sessions in the database
+    #[serde(default)]
+    pub session: SessionStrategy,
+    pub ai: bool,
+    pub ui: bool,
+    pub restate: bool,
+    /// Kafka broker and a Kafka-triggered workflow in the Restate stack
+    #[serde(default)]
+    pub restate_kafka: bool,
+    pub cmd: bool,
+    /// Streaming transport for CommandIsland chat replies (requires `cmd`)
+    pub cmd_stream: Option<ChatStream>,
+    /// CommandIsland model, search, storage, and modes; the defaults when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cmd_settings: Option<CmdSettings>,
+    /// Generate the `/dashboard/analytics` charts page (requires `ui`)
+    pub analytics: bool,
+    /// Generate the example `post` router and page
+    pub example: bool,
+    /// Generate the `/api/v1/posts` REST endpoint (requires `example`)
+    pub rest: bool,
+    /// Generate `prisma/seed.ts` with demo data
+    pub seed: bool,
+    /// The project's own models, each with zod schemas, a CRUD router, and pages
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub models: Vec<DomainModel>,
+    /// Render the OpenGraph image from the site config
+    #[serde(default)]
+    pub seo: bool,
+    /// Standalone Next.js output with an app Dockerfile and compose service
+    pub standalone: bool,
+    /// @next/bundle-analyzer behind `ANALYZE=true` and an `analyze` script
+    #[serde(default)]
+    pub bundle_analyzer: bool,
+    /// Nonce-based CSP from the proxy and static security headers from next.config.js
+    #[serde(default)]
+    pub security_headers: bool,
+    /// Soft deletes and audit columns on the app models via a Prisma client extension
+    pub soft_delete: bool,
+    /// Database behind Prisma; `switch-db` changes it
+    #[serde(default)]
+    pub database: DatabaseProvider,
+    /// Connection pooler in front of the database, if any
+    pub pooling: Option<Pooling>,
+    /// Generate `prisma/migrations/0_init` and switch the db scripts to `prisma migrate`
+    pub migrations: bool,
+    /// Generate a Nix flake and `.envrc` for the dev environment
+    pub nix: bool,
+    /// Infrastructure-as-code module to generate, if any
+    #[serde(default)]
+    pub iac: Option<Iac>,
+    /// Task runner file to generate, if any
+    pub task_runner: Option<TaskRunner>,
+    /// Extra compiler checks written to tsconfig.json
+    pub ts_strictness: TsStrictness,
+    /// Minimum coverage percentage enforced by `npm run test:coverage`
+    pub coverage_threshold: Option<u8>,
+    /// Tailwind plugins installed and loaded from globals.css
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tailwind_plugins: Vec<TailwindPlugin>,
+    /// How dark mode is switched, if at all
+    #[serde(default)]
+    pub dark_mode: DarkMode,
+    /// Lint rule set written to biome.jsonc
+    #[serde(default)]
+    pub biome_profile: BiomeProfile,
+    /// Hosts next/image may load remote images from
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub image_hosts: Vec<String>,
+    /// Base URL of the CDN serving uploads
+    #[serde(default)]
+    pub image_cdn: Option<String>,
+    /// Languages added next to en and de, with TODO-marked messages
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub locales: Vec<String>,
+}
+
+/// A model as `generate resource` takes it: a name and `name:type` fields
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DomainModel {
+    pub name: String,
+    /// `name:type`, or `name:type?` for an optional column, e.g. `price:float`
+    pub fields: Vec<String>,
+}
+
+/// What `src/server/chat/config.ts` is generated with
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CmdSettings {
+    #[serde(default)]
+    pub provider: LlmProvider,
+    /// Model id; the provider's default when unset
+    #[serde(default)]
+    pub model: Option<String>,
+    #[serde(default)]
+    pub vectors: VectorStore,
+    #[serde(default)]
+    pub storage: StorageBackend,
+    /// Modes offered next to the chat
+    #[serde(default = "all_modules")]
+    pub modules: Vec<CmdModule>,
+}
+
+impl Default for CmdSettings {
+    fn default() -> Self {
+        Self {
+            provider: LlmProvider::default(),
+            model: None,
+            vectors: VectorStore::default(),
+            storage: StorageBackend::default(),
+            modules: all_modules(),
+        }
+    }
+}
+
+impl CmdSettings {
+    pub fn model(&self) -> &str {
+        self.model
+            .as_deref()
+            .unwrap_or(cmd::models(self.provider)[0])
+    }
+}
+
+fn all_modules() -> Vec<CmdModule> {
+    vec![CmdModule::Tables, CmdModule::Docs]
+}
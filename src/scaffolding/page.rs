@@ -0,0 +1,164 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use crate::scaffolding::i18n;
+use crate::scaffolding::nav::{self, HEADER_PATH};
+use crate::templates::embedded;
+use crate::utils::fs::{self, update_file, write_file};
+use crate::utils::naming::{camel_case, is_valid_name, kebab_case, pascal_case};
+
+const SESSION_PATH: &str = "src/server/session.ts";
+const TODO_PREFIX: &str = "TODO: ";
+
+/// What `generate page` changed, for the summary
+pub struct GeneratedPage {
+    pub url: String,
+    pub files: Vec<String>,
+    /// Files that did not match the scaffolded layout and need the nav entry added by hand
+    pub skipped: Vec<String>,
+}
+
+/// `reports` or `settings/billing`: kebab-case path segments under `src/app/`
+pub fn normalize_route(route: &str) -> Result<String> {
+    let segments: Vec<&str> = route.trim_matches('/').split('/').collect();
+    if segments.iter().any(|segment| !is_valid_name(segment)) {
+        anyhow::bail!(
+            "Invalid route '{}'; use path segments of letters, digits, '-' or '_', e.g. reports or settings/billing",
+            route
+        );
+    }
+    Ok(segments
+        .iter()
+        .map(|segment| kebab_case(segment))
+        .collect::<Vec<_>>()
+        .join("/"))
+}
+
+/// Generate a server-component page with a Header nav entry, optionally guarded by the
+/// session and translated through its own message namespace
+pub fn generate(
+    project_path: &str,
+    route: &str,
+    protected: bool,
+    translated: bool,
+) -> Result<GeneratedPage> {
+    let project = Path::new(project_path);
+    let page_path = format!("src/app/{}/page.tsx", route);
+    if fs::path_exists(project.join(&page_path)) {
+        anyhow::bail!("{} already exists", page_path);
+    }
+    if protected && !fs::path_exists(project.join(SESSION_PATH)) {
+        anyhow::bail!(
+            "--protected needs {} from the auth setup of a t3-mono app",
+            SESSION_PATH
+        );
+    }
+    let locale_files = i18n::locale_files(project_path)
+        .context("Failed to read messages/. Pages need the i18n setup of a t3-mono app.")?;
+
+    let slug = route.replace('/', "-");
+    let name = pascal_case(&slug);
+    let namespace = camel_case(&slug);
+    let title = title_case(route.rsplit('/').next().unwrap_or(route));
+    let description = format!("Content for /{} goes here.", route);
+
+    let mut imports = Vec::new();
+    let mut setup = Vec::new();
+    if protected {
+        imports.push("import { redirect } from \"next/navigation\";");
+        setup.push(
+            "  const session = await getSession();\n  if (!session) {\n    redirect(SIGN_IN_PATH);\n  }\n",
+        );
+    }
+    if translated {
+        imports.push("import { getTranslations } from \"next-intl/server\";");
+        setup.push("  const t = await getTranslations(\"{{namespace}}\");\n");
+    }
+    let mut import_block = imports.join("\n");
+    if !import_block.is_empty() {
+        import_block.push_str("\n\n");
+    }
+    import_block.push_str("import { Header } from \"@/app/_components/Header\";\n");
+    if protected {
+        import_block.push_str("import { getSession, SIGN_IN_PATH } from \"@/server/session\";\n");
+    }
+
+    let mut setup = setup.join("\n");
+    if !setup.is_empty() {
+        setup.push('\n');
+    }
+
+    let (heading, body) = if translated {
+        (
+            "{t(\"title\")}".to_string(),
+            "{t(\"description\")}".to_string(),
+        )
+    } else {
+        (title.clone(), description.clone())
+    };
+    let vars = [
+        ("imports", import_block),
+        ("setup", setup),
+        ("heading", heading),
+        ("description", body),
+        ("namespace", namespace.clone()),
+        ("Name", name),
+    ];
+    let content = embedded::render_template("generate/page/page.tsx", &vars)
+        .context("Missing embedded template generate/page/page.tsx")?;
+    write_file(project_path, &page_path, &content)?;
+
+    let mut files = vec![page_path];
+    let mut skipped = Vec::new();
+
+    // The nav label always needs a message; the page namespace only when translated
+    for file in &locale_files {
+        let todo = |text: &str| {
+            if file == "messages/en.json" {
+                text.to_string()
+            } else {
+                format!("{}{}", TODO_PREFIX, text)
+            }
+        };
+        i18n::set_message(project_path, file, "nav", &namespace, &todo(&title))?;
+        if translated {
+            i18n::set_message(project_path, file, &namespace, "title", &todo(&title))?;
+            i18n::set_message(
+                project_path,
+                file,
+                &namespace,
+                "description",
+                &todo(&description),
+            )?;
+        }
+        files.push(file.clone());
+    }
+
+    let url = format!("/{}", route);
+    let header = fs::read_to_string(project.join(HEADER_PATH))
+        .ok()
+        .and_then(|content| nav::add_item(&content, &url, &namespace));
+    match header {
+        Some(content) => {
+            update_file(project_path, HEADER_PATH, &content)?;
+            files.push(HEADER_PATH.to_string());
+        }
+        None => skipped.push(HEADER_PATH.to_string()),
+    }
+
+    Ok(GeneratedPage {
+        url,
+        files,
+        skipped,
+    })
+}
+
+/// `billing-history` -> `Billing history`
+fn title_case(segment: &str) -> String {
+    let words = kebab_case(segment).replace('-', " ");
+    let mut chars = words.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
@@ -0,0 +1,215 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use crate::scaffolding::env::{self, EnvFragment, EnvVar};
+use crate::scaffolding::trpc;
+use crate::utils::fs::{self, update_file, write_file};
+
+const PERF_ENV: EnvFragment = EnvFragment {
+    section: "Performance",
+    vars: &[
+        EnvVar::server(
+            "SLOW_PROCEDURE_MS",
+            "z.coerce.number().int().positive().optional()",
+        )
+        .example("500"),
+        EnvVar::server(
+            "SLOW_QUERY_MS",
+            "z.coerce.number().int().positive().optional()",
+        )
+        .example("100"),
+    ],
+};
+
+/// Scaffold procedure timing: a Prisma client extension counting and timing queries in
+/// `src/server/perf.ts`, and a tRPC middleware reporting each procedure's duration and
+/// query count, with warnings above `SLOW_PROCEDURE_MS` and `SLOW_QUERY_MS`
+pub fn scaffold(project_path: &str) -> Result<()> {
+    write_file(project_path, "src/server/perf.ts", PERF)?;
+    write_file(project_path, "src/server/perf.test.ts", PERF_TEST)?;
+    extend_db_client(project_path)?;
+    trpc::add_timing_middleware(project_path)?;
+
+    env::register(project_path, &PERF_ENV)?;
+
+    Ok(())
+}
+
+/// Apply the extension in `src/server/db.ts`, typing the global cache as the extended client
+fn extend_db_client(project_path: &str) -> Result<()> {
+    let db_path = Path::new(project_path).join("src/server/db.ts");
+    let content = fs::read_to_string(&db_path).context("Failed to read src/server/db.ts")?;
+
+    if content.contains("queryTiming") {
+        return Ok(());
+    }
+
+    let patched = content
+        .replacen(
+            "import { PrismaClient } from \"@prisma/client\";\n",
+            "import { PrismaClient } from \"@prisma/client\";\nimport { queryTiming } from \"@/server/perf\";\n",
+            1,
+        )
+        .replacen(
+            "prisma: PrismaClient | undefined;",
+            "prisma: ReturnType<typeof createPrismaClient> | undefined;",
+            1,
+        );
+    // Directly on the client, ahead of soft deletes, search sync, or Accelerate
+    let patched = if patched.contains("\t}).$extends(") {
+        patched.replacen("\t}).$extends(", "\t}).$extends(queryTiming).$extends(", 1)
+    } else {
+        patched.replacen("\t});\n}\n", "\t}).$extends(queryTiming);\n}\n", 1)
+    };
+
+    if !patched.contains(".$extends(queryTiming)") {
+        anyhow::bail!("Could not find createPrismaClient() in src/server/db.ts; apply `queryTiming` with `$extends` manually");
+    }
+
+    update_file(project_path, "src/server/db.ts", &patched)
+}
+
+// ============================================================================
+// Embedded Templates
+// ============================================================================
+
+const PERF: &str = r#"import { AsyncLocalStorage } from "node:async_hooks";
+import { Prisma } from "@prisma/client";
+
+/** What timing needs from a logger; pino's `ctx.log` fits */
+export interface PerfLog {
+  debug(details: object, message: string): void;
+  warn(details: object, message: string): void;
+}
+
+// Without the logging extension: warnings everywhere but in tests, timings in development
+const consoleLog: PerfLog = {
+  debug(details, message) {
+    if (process.env.NODE_ENV === "development") console.debug(`[perf] ${message}`, details);
+  },
+  warn(details, message) {
+    if (process.env.NODE_ENV !== "test") console.warn(`[perf] ${message}`, details);
+  },
+};
+
+// Read directly like DATABASE_URL in db.ts, so scripts and tests without a validated env still work
+function threshold(name: string, fallback: number) {
+  const value = Number(process.env[name]);
+  return Number.isFinite(value) && value > 0 ? value : fallback;
+}
+
+export const SLOW_PROCEDURE_MS = threshold("SLOW_PROCEDURE_MS", 500);
+export const SLOW_QUERY_MS = threshold("SLOW_QUERY_MS", 100);
+
+interface QueryStats {
+  count: number;
+  durationMs: number;
+  log: PerfLog;
+}
+
+// The procedure a query runs for, so concurrent requests keep separate counts
+const currentProcedure = new AsyncLocalStorage<QueryStats>();
+
+/** Count a query against the running procedure, warning when it was slow */
+export function recordQuery(model: string | undefined, operation: string, durationMs: number) {
+  const stats = currentProcedure.getStore();
+  if (stats) {
+    stats.count += 1;
+    stats.durationMs += durationMs;
+  }
+  if (durationMs >= SLOW_QUERY_MS) {
+    (stats?.log ?? consoleLog).warn({ model, operation, durationMs: Math.round(durationMs) }, "slow query");
+  }
+}
+
+/** Prisma client extension timing every query, including raw ones */
+export const queryTiming = Prisma.defineExtension({
+  name: "query-timing",
+  query: {
+    async $allOperations({ model, operation, args, query }) {
+      const startedAt = performance.now();
+      try {
+        return await query(args);
+      } finally {
+        recordQuery(model, operation, performance.now() - startedAt);
+      }
+    },
+  },
+});
+
+/**
+ * Run a procedure, then report its duration and the queries it made: at `debug`, or at
+ * `warn` above SLOW_PROCEDURE_MS. The tRPC `timing` middleware calls this for every procedure.
+ */
+export async function timeProcedure<T extends { ok: boolean }>(
+  { path, type, log = consoleLog }: { path: string; type: string; log?: PerfLog },
+  run: () => Promise<T>,
+): Promise<T> {
+  const stats: QueryStats = { count: 0, durationMs: 0, log };
+  const startedAt = performance.now();
+  const result = await currentProcedure.run(stats, run);
+
+  const timing = {
+    path,
+    type,
+    ok: result.ok,
+    durationMs: Math.round(performance.now() - startedAt),
+    queries: stats.count,
+    queryMs: Math.round(stats.durationMs),
+  };
+  if (timing.durationMs >= SLOW_PROCEDURE_MS) {
+    log.warn(timing, "slow procedure");
+  } else {
+    log.debug(timing, "procedure timing");
+  }
+
+  return result;
+}
+"#;
+
+const PERF_TEST: &str = r#"// @vitest-environment node
+import { describe, expect, it, vi } from "vitest";
+import { recordQuery, SLOW_PROCEDURE_MS, SLOW_QUERY_MS, timeProcedure } from "./perf";
+
+function fakeLog() {
+  return { debug: vi.fn(), warn: vi.fn() };
+}
+
+describe("timeProcedure", () => {
+  it("counts the queries made while the procedure runs", async () => {
+    const log = fakeLog();
+
+    await timeProcedure({ path: "post.list", type: "query", log }, async () => {
+      recordQuery("Post", "findMany", 3);
+      await Promise.resolve();
+      recordQuery("Post", "count", 2);
+      return { ok: true };
+    });
+    recordQuery("Post", "findMany", 1);
+
+    expect(log.debug).toHaveBeenCalledWith(
+      expect.objectContaining({ path: "post.list", ok: true, queries: 2, queryMs: 5 }),
+      "procedure timing",
+    );
+    expect(log.warn).not.toHaveBeenCalled();
+  });
+
+  it("warns about slow procedures and queries", async () => {
+    const log = fakeLog();
+    vi.useFakeTimers({ toFake: ["performance"] });
+
+    await timeProcedure({ path: "post.create", type: "mutation", log }, async () => {
+      recordQuery("Post", "create", SLOW_QUERY_MS);
+      vi.advanceTimersByTime(SLOW_PROCEDURE_MS);
+      return { ok: false };
+    });
+    vi.useRealTimers();
+
+    expect(log.warn).toHaveBeenCalledWith(expect.objectContaining({ model: "Post", operation: "create" }), "slow query");
+    expect(log.warn).toHaveBeenCalledWith(
+      expect.objectContaining({ path: "post.create", ok: false, queries: 1 }),
+      "slow procedure",
+    );
+  });
+});
+"#;
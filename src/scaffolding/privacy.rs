@@ -0,0 +1,474 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use crate::scaffolding::env::{self, EnvFragment, EnvVar};
+use crate::scaffolding::{i18n, trpc};
+use crate::utils::fs::{self, update_file, write_file};
+use crate::utils::npm;
+
+const SETTINGS_PAGE_PATH: &str = "src/app/settings/page.tsx";
+
+const PRIVACY_ENV: EnvFragment = EnvFragment {
+    section: "Privacy",
+    vars: &[EnvVar::server(
+        "ACCOUNT_DELETION_GRACE_DAYS",
+        "z.coerce.number().int().nonnegative().optional()",
+    )
+    .example("30")],
+};
+
+pub const PRIVACY_DEV_DEPENDENCIES: &[(&str, &str)] = &[("tsx", "^4.21.0")];
+
+// Node loads the later env files over the earlier ones, like `next dev` does
+const PRIVACY_SCRIPTS: &[(&str, &str)] = &[(
+    "privacy:purge",
+    "tsx --env-file-if-exists=.env --env-file-if-exists=.env.development --env-file-if-exists=.env.local scripts/purge-accounts.ts",
+)];
+
+/// Scaffold the data subject rights: a `privacy` tRPC router exporting everything stored
+/// about the user as JSON, found by walking the Prisma relations from `User`, and account
+/// deletion after `ACCOUNT_DELETION_GRACE_DAYS`, carried out by `npm run privacy:purge`.
+/// Both live in a new section of `/settings`.
+pub fn scaffold(project_path: &str) -> Result<()> {
+    add_deletion_column(project_path)?;
+
+    write_file(project_path, "src/server/privacy.ts", PRIVACY)?;
+    write_file(project_path, "src/server/privacy.test.ts", PRIVACY_TEST)?;
+    write_file(
+        project_path,
+        "src/server/api/routers/privacy.ts",
+        PRIVACY_ROUTER,
+    )?;
+    trpc::register_router(
+        project_path,
+        "privacy",
+        "privacyRouter",
+        "@/server/api/routers/privacy",
+    )?;
+    write_file(
+        project_path,
+        "src/app/settings/_components/PrivacySection.tsx",
+        PRIVACY_SECTION,
+    )?;
+    mount_in_settings(project_path)?;
+    write_file(project_path, "scripts/purge-accounts.ts", PURGE_SCRIPT)?;
+
+    env::register(project_path, &PRIVACY_ENV)?;
+    i18n::merge_translations(project_path, "messages/en.json", MESSAGES_EN)?;
+    i18n::merge_translations(project_path, "messages/de.json", MESSAGES_DE)?;
+    npm::add_scripts(project_path, PRIVACY_SCRIPTS)?;
+    npm::add_dependencies(project_path, &[], PRIVACY_DEV_DEPENDENCIES)?;
+
+    Ok(())
+}
+
+/// Add `deletionRequestedAt` to the User model, whichever auth provider wrote it
+fn add_deletion_column(project_path: &str) -> Result<()> {
+    let schema_path = Path::new(project_path).join("prisma/schema.prisma");
+    let mut content =
+        fs::read_to_string(&schema_path).context("Failed to read prisma/schema.prisma")?;
+
+    if content.contains("deletionRequestedAt") {
+        return Ok(());
+    }
+
+    let Some(end) = content
+        .find("model User {")
+        .and_then(|start| content[start..].find("\n}").map(|offset| start + offset))
+    else {
+        anyhow::bail!("Could not find the User model in prisma/schema.prisma");
+    };
+    content.insert_str(end, DELETION_COLUMN);
+
+    update_file(project_path, "prisma/schema.prisma", &content)
+}
+
+/// Render the privacy section below the other settings
+fn mount_in_settings(project_path: &str) -> Result<()> {
+    let page_path = Path::new(project_path).join(SETTINGS_PAGE_PATH);
+    let content = fs::read_to_string(&page_path)
+        .with_context(|| format!("Failed to read {}", SETTINGS_PAGE_PATH))?;
+
+    if content.contains("<PrivacySection") {
+        return Ok(());
+    }
+
+    let patched = content
+        .replacen(
+            "import { EmailForm } from \"./_components/EmailForm\";\n",
+            "import { EmailForm } from \"./_components/EmailForm\";\nimport { PrivacySection } from \"./_components/PrivacySection\";\n",
+            1,
+        )
+        .replacen("      </main>", &format!("{}      </main>", PRIVACY_SETTINGS_SECTION), 1);
+
+    if !patched.contains("<PrivacySection />") {
+        anyhow::bail!("Could not find the settings page's <main> in {}; render <PrivacySection /> there manually", SETTINGS_PAGE_PATH);
+    }
+
+    update_file(project_path, SETTINGS_PAGE_PATH, &patched)
+}
+
+// ============================================================================
+// Embedded Templates
+// ============================================================================
+
+const DELETION_COLUMN: &str = "\n\n  /// Set when the user asked to delete the account; `npm run privacy:purge` deletes it after the grace period\n  deletionRequestedAt DateTime?";
+
+const PRIVACY_SETTINGS_SECTION: &str = r#"
+        <section className="rounded-xl border border-border p-6">
+          <h2 className="text-lg font-semibold">{t("privacy.title")}</h2>
+          <p className="mt-1 mb-4 text-sm text-muted-foreground">{t("privacy.description")}</p>
+          <PrivacySection />
+        </section>
+"#;
+
+const PRIVACY: &str = r#"import { Prisma } from "@prisma/client";
+import { env } from "@/env";
+import { db } from "@/server/db";
+
+const DAY_MS = 24 * 60 * 60 * 1000;
+
+/** Days between a deletion request and the purge, during which the user can cancel it */
+export const DELETION_GRACE_DAYS = env.ACCOUNT_DELETION_GRACE_DAYS ?? 30;
+
+// Credentials are the user's data too, but handing them out only helps whoever stole the session
+const SECRET_FIELDS: ReadonlySet<string> = new Set(["password", "token", "accessToken", "refreshToken", "idToken", "secret"]);
+
+/** What the walk needs from a model in `Prisma.dmmf` */
+export interface DataModel {
+  name: string;
+  fields: readonly { name: string; kind: string; type: string }[];
+}
+
+/**
+ * A nested `include` reaching every model related to `model`, e.g. a user's chat threads
+ * with their messages. Each path stops before a model it already passed, so back relations
+ * aren't followed.
+ */
+export function relationInclude(
+  models: readonly DataModel[],
+  model: string,
+  path: ReadonlySet<string> = new Set([model]),
+): Record<string, unknown> {
+  const include: Record<string, unknown> = {};
+  for (const field of models.find((candidate) => candidate.name === model)?.fields ?? []) {
+    if (field.kind !== "object" || path.has(field.type)) {
+      continue;
+    }
+    const nested = relationInclude(models, field.type, new Set([...path, field.type]));
+    include[field.name] = Object.keys(nested).length > 0 ? { include: nested } : true;
+  }
+  return include;
+}
+
+/** Models with a `userId` column but no relation to User, e.g. webhook endpoints */
+export function ownedModels(models: readonly DataModel[], user = "User"): string[] {
+  return models
+    .filter(
+      (model) =>
+        model.name !== user &&
+        model.fields.some((field) => field.name === "userId" && field.kind === "scalar") &&
+        !model.fields.some((field) => field.kind === "object" && field.type === user),
+    )
+    .map((model) => model.name);
+}
+
+/** Drop credential fields at any depth */
+export function redact(value: unknown): unknown {
+  if (Array.isArray(value)) {
+    return value.map(redact);
+  }
+  // Plain records only; Dates and Decimals serialize themselves
+  if (value !== null && typeof value === "object" && Object.getPrototypeOf(value) === Object.prototype) {
+    return Object.fromEntries(
+      Object.entries(value)
+        .filter(([key]) => !SECRET_FIELDS.has(key))
+        .map(([key, field]) => [key, redact(field)]),
+    );
+  }
+  return value;
+}
+
+interface Delegate {
+  findUnique(args: object): Promise<unknown>;
+  findMany(args: object): Promise<unknown[]>;
+  deleteMany(args: object): Promise<unknown>;
+}
+
+// The walk only knows model names, so it reaches the client's delegates by name
+function delegate(client: object, model: string): Delegate {
+  const found = (client as Record<string, Delegate | undefined>)[model.charAt(0).toLowerCase() + model.slice(1)];
+  if (!found) {
+    throw new Error(`The Prisma client has no ${model} model`);
+  }
+  return found;
+}
+
+function withRelations(model: string) {
+  const include = relationInclude(Prisma.dmmf.datamodel.models, model);
+  return Object.keys(include).length > 0 ? { include } : {};
+}
+
+/**
+ * Everything stored about the user as JSON-ready data: the user with every related record,
+ * and the records of models that only keep a `userId`. New models are included as soon as
+ * they relate to User.
+ */
+export async function exportUserData(userId: string) {
+  const user = await delegate(db, "User").findUnique({ where: { id: userId }, ...withRelations("User") });
+  const owned = await Promise.all(
+    ownedModels(Prisma.dmmf.datamodel.models).map(async (model) => [
+      model,
+      await delegate(db, model).findMany({ where: { userId }, ...withRelations(model) }),
+    ]),
+  );
+
+  return redact({ exportedAt: new Date(), user, ...Object.fromEntries(owned) }) as Record<string, unknown>;
+}
+
+export function deletionDate(requestedAt: Date): Date {
+  return new Date(requestedAt.getTime() + DELETION_GRACE_DAYS * DAY_MS);
+}
+
+/** Schedule the account's deletion; returns when it happens */
+export async function requestDeletion(userId: string) {
+  const requestedAt = new Date();
+  await db.user.update({ where: { id: userId }, data: { deletionRequestedAt: requestedAt } });
+  return { deletesAt: deletionDate(requestedAt) };
+}
+
+export async function cancelDeletion(userId: string) {
+  await db.user.update({ where: { id: userId }, data: { deletionRequestedAt: null } });
+  return { deletesAt: null };
+}
+
+/**
+ * Delete the accounts whose grace period is over, returning how many. Relations to User
+ * cascade; models that only keep a `userId` are cleared in the same transaction.
+ */
+export async function purgeDeletedAccounts(now = new Date()) {
+  const cutoff = new Date(now.getTime() - DELETION_GRACE_DAYS * DAY_MS);
+  const due = await db.user.findMany({ where: { deletionRequestedAt: { lte: cutoff } }, select: { id: true } });
+  const owned = ownedModels(Prisma.dmmf.datamodel.models);
+
+  for (const { id } of due) {
+    await db.$transaction(async (tx) => {
+      for (const model of owned) {
+        await delegate(tx, model).deleteMany({ where: { userId: id } });
+      }
+      await tx.user.delete({ where: { id } });
+    });
+  }
+  return due.length;
+}
+"#;
+
+const PRIVACY_TEST: &str = r#"// @vitest-environment node
+import { describe, expect, it, vi } from "vitest";
+import { type DataModel, DELETION_GRACE_DAYS, deletionDate, ownedModels, redact, relationInclude } from "./privacy";
+
+// Keep the test off the real database
+vi.mock("@/server/db", () => ({ db: {} }));
+vi.mock("@/env", () => ({ env: {} }));
+
+function relation(name: string, type: string) {
+  return { name, kind: "object", type };
+}
+
+function column(name: string) {
+  return { name, kind: "scalar", type: "String" };
+}
+
+const models: DataModel[] = [
+  { name: "User", fields: [column("id"), relation("accounts", "Account"), relation("chatThreads", "ChatThread")] },
+  { name: "Account", fields: [column("userId"), relation("user", "User")] },
+  { name: "ChatThread", fields: [column("userId"), relation("user", "User"), relation("messages", "ChatMessage")] },
+  { name: "ChatMessage", fields: [column("threadId"), relation("thread", "ChatThread")] },
+  { name: "WebhookEndpoint", fields: [column("userId"), relation("deliveries", "WebhookDelivery")] },
+  { name: "WebhookDelivery", fields: [column("endpointId"), relation("endpoint", "WebhookEndpoint")] },
+];
+
+describe("relationInclude", () => {
+  it("follows relations down without walking back up", () => {
+    expect(relationInclude(models, "User")).toEqual({
+      accounts: true,
+      chatThreads: { include: { messages: true } },
+    });
+  });
+});
+
+describe("ownedModels", () => {
+  it("finds models that keep a userId without a relation", () => {
+    expect(ownedModels(models)).toEqual(["WebhookEndpoint"]);
+  });
+});
+
+describe("redact", () => {
+  it("drops credentials at any depth and keeps dates", () => {
+    const createdAt = new Date("2026-01-01T00:00:00Z");
+
+    expect(
+      redact({ email: "a@example.com", createdAt, accounts: [{ providerId: "github", accessToken: "gho_1", password: "hash" }] }),
+    ).toEqual({ email: "a@example.com", createdAt, accounts: [{ providerId: "github" }] });
+  });
+});
+
+describe("deletionDate", () => {
+  it("waits out the grace period", () => {
+    const requestedAt = new Date("2026-01-01T00:00:00Z");
+
+    expect(DELETION_GRACE_DAYS).toBe(30);
+    expect(deletionDate(requestedAt)).toEqual(new Date("2026-01-31T00:00:00Z"));
+  });
+});
+"#;
+
+const PRIVACY_ROUTER: &str = r#"import { createTRPCRouter, protectedProcedure } from "@/server/api/trpc";
+import { cancelDeletion, deletionDate, exportUserData, requestDeletion } from "@/server/privacy";
+
+export const privacyRouter = createTRPCRouter({
+  // A mutation, so the export is only built when asked for and never cached
+  exportData: protectedProcedure.mutation(({ ctx }) => exportUserData(ctx.userId)),
+
+  deletionStatus: protectedProcedure.query(async ({ ctx }) => {
+    const user = await ctx.db.user.findUnique({
+      where: { id: ctx.userId },
+      select: { deletionRequestedAt: true },
+    });
+    return { deletesAt: user?.deletionRequestedAt ? deletionDate(user.deletionRequestedAt) : null };
+  }),
+
+  // The account stays usable until the purge, so the user can sign in and cancel
+  requestDeletion: protectedProcedure.mutation(({ ctx }) => requestDeletion(ctx.userId)),
+
+  cancelDeletion: protectedProcedure.mutation(({ ctx }) => cancelDeletion(ctx.userId)),
+});
+"#;
+
+const PRIVACY_SECTION: &str = r#""use client";
+
+import { useLocale, useTranslations } from "next-intl";
+
+import { api } from "@/trpc/react";
+
+function download(data: unknown, filename: string) {
+  const url = URL.createObjectURL(new Blob([JSON.stringify(data, null, 2)], { type: "application/json" }));
+  const link = document.createElement("a");
+  link.href = url;
+  link.download = filename;
+  link.click();
+  URL.revokeObjectURL(url);
+}
+
+/** Download everything stored about the account, or schedule its deletion */
+export function PrivacySection() {
+  const t = useTranslations("settings.privacy");
+  const locale = useLocale();
+  const utils = api.useUtils();
+  const status = api.privacy.deletionStatus.useQuery();
+  const refresh = { onSuccess: () => utils.privacy.deletionStatus.invalidate() };
+
+  const exportData = api.privacy.exportData.useMutation({
+    onSuccess: (data) => download(data, "account-data.json"),
+  });
+  const requestDeletion = api.privacy.requestDeletion.useMutation(refresh);
+  const cancelDeletion = api.privacy.cancelDeletion.useMutation(refresh);
+  const deletesAt = status.data?.deletesAt;
+  const error = exportData.error ?? requestDeletion.error ?? cancelDeletion.error;
+
+  return (
+    <div className="grid gap-6">
+      <div className="flex flex-wrap items-center justify-between gap-3">
+        <p className="text-sm">{t("exportHint")}</p>
+        <button
+          type="button"
+          onClick={() => exportData.mutate()}
+          disabled={exportData.isPending}
+          className="rounded-lg border border-border px-4 py-2 text-sm font-medium disabled:opacity-50"
+        >
+          {exportData.isPending ? t("exporting") : t("export")}
+        </button>
+      </div>
+
+      <div className="flex flex-wrap items-center justify-between gap-3 border-t border-border pt-6">
+        {deletesAt ? (
+          <>
+            <p className="text-sm text-destructive">
+              {t("scheduled", { date: deletesAt.toLocaleDateString(locale, { dateStyle: "long" }) })}
+            </p>
+            <button
+              type="button"
+              onClick={() => cancelDeletion.mutate()}
+              disabled={cancelDeletion.isPending}
+              className="rounded-lg border border-border px-4 py-2 text-sm font-medium disabled:opacity-50"
+            >
+              {t("cancel")}
+            </button>
+          </>
+        ) : (
+          <>
+            <p className="text-sm">{t("deleteHint")}</p>
+            <button
+              type="button"
+              onClick={() => {
+                if (window.confirm(t("confirm"))) {
+                  requestDeletion.mutate();
+                }
+              }}
+              disabled={status.isPending || requestDeletion.isPending}
+              className="rounded-lg bg-destructive px-4 py-2 text-sm font-medium text-white disabled:opacity-50"
+            >
+              {t("delete")}
+            </button>
+          </>
+        )}
+      </div>
+
+      {error && <p className="text-sm text-destructive">{error.message}</p>}
+    </div>
+  );
+}
+"#;
+
+const PURGE_SCRIPT: &str = r#"import { db } from "../src/server/db";
+import { DELETION_GRACE_DAYS, purgeDeletedAccounts } from "../src/server/privacy";
+
+// Usage: npm run privacy:purge, e.g. daily from cron or a scheduled job
+const deleted = await purgeDeletedAccounts();
+console.log(`Deleted ${deleted} account(s) requested more than ${DELETION_GRACE_DAYS} days ago`);
+await db.$disconnect();
+"#;
+
+const MESSAGES_EN: &str = r#"{
+  "settings": {
+    "privacy": {
+      "title": "Your data",
+      "description": "Download what we store about you, or delete your account.",
+      "exportHint": "A JSON file with your profile and everything you created.",
+      "export": "Download my data",
+      "exporting": "Preparing...",
+      "deleteHint": "Your account and its data are deleted after a grace period, during which you can still cancel.",
+      "delete": "Delete account",
+      "confirm": "Delete your account and all of its data? You can cancel until the deletion date.",
+      "scheduled": "Your account will be deleted on {date}.",
+      "cancel": "Keep my account"
+    }
+  }
+}"#;
+
+const MESSAGES_DE: &str = r#"{
+  "settings": {
+    "privacy": {
+      "title": "Ihre Daten",
+      "description": "Laden Sie herunter, was wir über Sie speichern, oder löschen Sie Ihr Konto.",
+      "exportHint": "Eine JSON-Datei mit Ihrem Profil und allem, was Sie erstellt haben.",
+      "export": "Meine Daten herunterladen",
+      "exporting": "Wird vorbereitet...",
+      "deleteHint": "Ihr Konto und seine Daten werden nach einer Frist gelöscht, in der Sie den Vorgang noch abbrechen können.",
+      "delete": "Konto löschen",
+      "confirm": "Ihr Konto und alle seine Daten löschen? Bis zum Löschdatum können Sie den Vorgang abbrechen.",
+      "scheduled": "Ihr Konto wird am {date} gelöscht.",
+      "cancel": "Konto behalten"
+    }
+  }
+}"#;
@@ -0,0 +1,312 @@
+use anyhow::Result;
+
+use crate::cli::{AuthProvider, Iac};
+use crate::scaffolding::options::ScaffoldOptions;
+use crate::scaffolding::{
+    ai, analytics, assistant, better_auth, biome, bundle_analyzer, chat_stream, ci, cmd, dark_mode,
+    database, docker, docs, example, images, locale, migrations, next_auth, nix, pooling, resource,
+    rest, restate, restate_kafka, security_headers, seed, seo, settings, skills, soft_delete, t3,
+    tailwind, tasks, terraform, ui,
+};
+use crate::utils::fs;
+use crate::utils::lang::Msg;
+use crate::utils::output::Progress;
+use crate::utils::plan::{self, Plan};
+
+/// Write every file of a new project into `project_path`,
+/// reporting steps on `pb`. `run_migrations` controls whether the Prisma CLI generates
+/// the initial migration; returns whether it did.
+pub async fn scaffold(
+    project_path: &str,
+    options: &ScaffoldOptions,
+    pb: &Progress,
+    init_git: bool,
+    run_migrations: bool,
+) -> Result<bool> {
+    prepare(project_path, options, pb, init_git)
+        .await?
+        .execute()
+        .await?;
+    finish(project_path, options, pb, init_git, run_migrations)
+}
+
+/// Run every step of a new project into a write plan without touching the disk; the
+/// plan is flushed concurrently when executed, so a failed step writes nothing
+pub async fn prepare(
+    project_path: &str,
+    options: &ScaffoldOptions,
+    pb: &Progress,
+    init_git: bool,
+) -> Result<Plan> {
+    let name = project_path;
+    let plan = plan::begin();
+
+    // Step 1: Create directory structure
+    pb.set_message(Msg::CreatingStructure.text());
+    plan::step("structure");
+    fs::create_project_dir(name, options.auth)?;
+    pb.inc(1);
+
+    // Step 2: Scaffold T3 base
+    pb.set_message(Msg::SettingUpT3.text());
+    plan::step("t3");
+    t3::scaffold(name).await?;
+    t3::apply_ts_strictness(name, options.ts_strictness)?;
+    if let Some(threshold) = options.coverage_threshold {
+        t3::apply_coverage_threshold(name, threshold)?;
+    }
+    if let Some(pooling) = options.pooling {
+        pooling::scaffold(name, pooling)?;
+    }
+    pb.inc(1);
+
+    // Step 3: Add authentication
+    plan::step("auth");
+    match options.auth {
+        AuthProvider::BetterAuth => {
+            pb.set_message(Msg::ConfiguringAuth.fill(&[&"Better Auth"]));
+            better_auth::scaffold(name).await?;
+        }
+        AuthProvider::NextAuth => {
+            pb.set_message(Msg::ConfiguringAuth.fill(&[&"NextAuth"]));
+            next_auth::scaffold(name, options.session).await?;
+        }
+    }
+    plan::step("settings");
+    settings::scaffold(name, options.auth)?;
+    pb.inc(1);
+
+    // Step 3b: Add the example router if requested
+    if options.example {
+        pb.set_message(Msg::AddingExample.text());
+        plan::step("example");
+        example::scaffold(name).await?;
+        if options.rest {
+            plan::step("rest");
+            rest::scaffold(name)?;
+        }
+        pb.inc(1);
+    }
+
+    // Step 3c: Generate the project's own models like `generate resource` does
+    if !options.models.is_empty() {
+        pb.set_message(Msg::GeneratingModels.text());
+        plan::step("models");
+        for model in &options.models {
+            resource::generate(name, &model.name, &model.fields)?;
+        }
+        pb.inc(1);
+    }
+
+    // Step 4: Add AI if enabled
+    if options.ai {
+        pb.set_message(Msg::AddingAi.text());
+        plan::step("ai");
+        ai::scaffold(name).await?;
+        pb.inc(1);
+    }
+
+    // Step 5: Add UI if enabled
+    if options.ui {
+        pb.set_message(Msg::AddingUi.text());
+        plan::step("ui");
+        ui::scaffold(name).await?;
+        if options.analytics {
+            plan::step("analytics");
+            analytics::scaffold(name)?;
+        }
+        pb.inc(1);
+    }
+
+    // Step 6: Add Restate if enabled
+    if options.restate {
+        pb.set_message(Msg::AddingRestate.text());
+        plan::step("restate");
+        restate::scaffold(name).await?;
+        if options.restate_kafka {
+            plan::step("restate-kafka");
+            restate_kafka::scaffold(name)?;
+        }
+        pb.inc(1);
+    }
+
+    // Step 6b: Add CommandIsland if enabled
+    if options.cmd {
+        pb.set_message(Msg::AddingCmd.text());
+        plan::step("cmd");
+        cmd::scaffold(name, &options.cmd_settings.clone().unwrap_or_default()).await?;
+        if let Some(transport) = options.cmd_stream {
+            plan::step("chat-stream");
+            chat_stream::scaffold(name, transport)?;
+        }
+        pb.inc(1);
+    }
+
+    // Step 6c: Switch dark mode and render the OpenGraph image once the layout and stylesheets are final
+    pb.set_message(Msg::ConfiguringDarkMode.text());
+    plan::step("dark-mode");
+    dark_mode::scaffold(name, options.dark_mode)?;
+    if options.seo {
+        plan::step("seo");
+        seo::scaffold(name)?;
+    }
+
+    // Step 6d: Add soft deletes and audit columns once all models exist
+    if options.soft_delete {
+        pb.set_message(Msg::AddingSoftDeletes.text());
+        plan::step("soft-delete");
+        soft_delete::scaffold(name)?;
+        pb.inc(1);
+    }
+
+    // Step 6e: Add the seed script if requested
+    if options.seed {
+        pb.set_message(Msg::AddingSeed.text());
+        plan::step("seed");
+        seed::scaffold(name, options)?;
+        pb.inc(1);
+    }
+
+    // Step 6f: Containerize the app if requested
+    if options.standalone {
+        pb.set_message(Msg::AddingDockerfile.text());
+        plan::step("docker");
+        docker::scaffold(name)?;
+        pb.inc(1);
+    }
+
+    // Step 6g: Allow remote images; after the Dockerfile step, which rewrites the empty next.config.js
+    if images::requested(options) {
+        pb.set_message(Msg::ConfiguringImages.text());
+        plan::step("images");
+        images::scaffold(
+            name,
+            &options.image_hosts,
+            options.image_cdn.as_deref(),
+            options.cmd,
+        )?;
+        pb.inc(1);
+    }
+
+    // Step 6h: Wrap next.config.js in the bundle analyzer if requested
+    if options.bundle_analyzer {
+        pb.set_message(Msg::AddingBundleAnalyzer.text());
+        plan::step("bundle-analyzer");
+        bundle_analyzer::scaffold(name)?;
+        pb.inc(1);
+    }
+
+    // Step 6i: Send security headers; after cmd and dark mode, which rewrite the layout
+    if options.security_headers {
+        pb.set_message(Msg::AddingSecurityHeaders.text());
+        plan::step("security-headers");
+        security_headers::scaffold(name)?;
+        pb.inc(1);
+    }
+
+    // Step 6j: Add the infrastructure-as-code module if requested
+    if let Some(iac) = options.iac {
+        pb.set_message(Msg::AddingTerraform.text());
+        plan::step("terraform");
+        match iac {
+            Iac::Terraform => terraform::scaffold(name, options)?,
+        }
+        pb.inc(1);
+    }
+
+    // Step 6k: Add the Nix dev shell if requested
+    if options.nix {
+        pb.set_message(Msg::AddingNix.text());
+        plan::step("nix");
+        nix::scaffold(name)?;
+        pb.inc(1);
+    }
+
+    // Step 6l: Add the task runner file if requested
+    if let Some(runner) = options.task_runner {
+        pb.set_message(Msg::AddingTaskRunner.text());
+        plan::step("tasks");
+        tasks::scaffold(name, runner, options)?;
+        pb.inc(1);
+    }
+
+    // Step 6m: Add the requested languages once every extension's messages exist
+    if !options.locales.is_empty() {
+        pb.set_message(Msg::AddingLanguages.text());
+        plan::step("locales");
+        for code in &options.locales {
+            let messages = locale::todo_messages(&locale::source_messages(name)?);
+            locale::add(name, code, messages)?;
+        }
+        pb.inc(1);
+    }
+
+    // Step 6n: Load the Tailwind plugins from the final stylesheets
+    plan::step("tailwind");
+    tailwind::scaffold(name, &options.tailwind_plugins)?;
+
+    // Step 6o: Lint rules for the profile, relaxed where the extensions generate code
+    plan::step("biome");
+    biome::scaffold(name, options)?;
+
+    // Step 6p: Add the CI workflow
+    pb.set_message(Msg::AddingCi.text());
+    plan::step("ci");
+    ci::scaffold(name)?;
+    pb.inc(1);
+
+    // Step 6q: Write docs and AI assistant context for the selected extensions
+    pb.set_message(Msg::WritingDocs.text());
+    plan::step("docs");
+    docs::scaffold(name, options).await?;
+    plan::step("assistant");
+    assistant::scaffold(name, options)?;
+    pb.inc(1);
+
+    // Step 6r: Move the datasource off PostgreSQL once every model and doc exists
+    plan::step("database");
+    database::scaffold(name, options)?;
+
+    // Step 6s: Compose the Claude skills for the final stack
+    plan::step("skills");
+    skills::sync(name)?;
+
+    // Step 7: Ignore what git shouldn't track
+    if init_git {
+        plan::step("git");
+        fs::write_gitignore(name)?;
+    }
+
+    // Step 8: Final package.json assembly
+    pb.set_message(Msg::FinalizingPackageJson.text());
+    plan::step("package.json");
+    t3::finalize_package_json(name, options)?;
+    pb.inc(1);
+
+    Ok(plan)
+}
+
+/// The steps that need the project on disk: generate the initial migration from the
+/// final schema and initialize git. Returns whether a migration was generated.
+pub fn finish(
+    project_path: &str,
+    options: &ScaffoldOptions,
+    pb: &Progress,
+    init_git: bool,
+    run_migrations: bool,
+) -> Result<bool> {
+    let mut migration_generated = false;
+    if options.migrations && run_migrations {
+        pb.set_message(Msg::GeneratingMigration.text());
+        migration_generated = migrations::scaffold(project_path, options.database)?;
+        pb.inc(1);
+    }
+
+    if init_git {
+        pb.set_message(Msg::InitializingGit.text());
+        fs::init_git(project_path)?;
+        pb.inc(1);
+    }
+
+    Ok(migration_generated)
+}
@@ -0,0 +1,408 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use crate::scaffolding::trpc;
+use crate::templates::embedded;
+use crate::utils::fs::{self, write_file};
+use crate::utils::naming::{self, camel_case, kebab_case, pascal_case, pluralize};
+use crate::utils::plan::{self, Op};
+
+/// Columns every generated model gets
+const RESERVED_FIELDS: &[&str] = &["id", "createdAt", "updatedAt"];
+
+/// Models the auth providers and the example already define
+const RESERVED_MODELS: &[&str] = &[
+    "User",
+    "Account",
+    "Session",
+    "Verification",
+    "VerificationToken",
+    "Post",
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum FieldType {
+    String,
+    Text,
+    Int,
+    Float,
+    Boolean,
+    DateTime,
+}
+
+impl FieldType {
+    fn parse(input: &str) -> Option<Self> {
+        match input.to_lowercase().as_str() {
+            "string" => Some(Self::String),
+            "text" => Some(Self::Text),
+            "int" | "integer" => Some(Self::Int),
+            "float" | "number" => Some(Self::Float),
+            "boolean" | "bool" => Some(Self::Boolean),
+            "datetime" | "date" => Some(Self::DateTime),
+            _ => None,
+        }
+    }
+
+    fn prisma(self) -> &'static str {
+        match self {
+            Self::String | Self::Text => "String",
+            Self::Int => "Int",
+            Self::Float => "Float",
+            Self::Boolean => "Boolean",
+            Self::DateTime => "DateTime",
+        }
+    }
+
+    fn zod(self) -> &'static str {
+        match self {
+            Self::String => "z.string().trim().min(1).max(256)",
+            Self::Text => "z.string()",
+            Self::Int => "z.number().int()",
+            Self::Float => "z.number()",
+            Self::Boolean => "z.boolean()",
+            Self::DateTime => "z.date()",
+        }
+    }
+
+    fn zod_output(self) -> &'static str {
+        match self {
+            Self::String | Self::Text => "z.string()",
+            other => other.zod(),
+        }
+    }
+
+    /// Faker call producing a sample value for the seed script
+    fn faker(self) -> &'static str {
+        match self {
+            Self::String => "faker.lorem.words({ min: 1, max: 4 })",
+            Self::Text => "faker.lorem.paragraphs(2)",
+            Self::Int => "faker.number.int({ min: 0, max: 1000 })",
+            Self::Float => "faker.number.float({ min: 0, max: 1000, fractionDigits: 2 })",
+            Self::Boolean => "faker.datatype.boolean()",
+            Self::DateTime => "faker.date.recent({ days: 30 })",
+        }
+    }
+}
+
+/// One `name:type` (or `name:type?` for an optional column) argument
+#[derive(Debug)]
+struct Field {
+    name: String,
+    ty: FieldType,
+    optional: bool,
+}
+
+impl Field {
+    fn parse(input: &str) -> Result<Self> {
+        let (name, ty) = input.split_once(':').with_context(|| {
+            format!(
+                "Invalid field '{}'; use name:type, e.g. title:string",
+                input
+            )
+        })?;
+        let (ty, optional) = match ty.strip_suffix('?') {
+            Some(ty) => (ty, true),
+            None => (ty, false),
+        };
+
+        let name = camel_case(name);
+        if name.is_empty() || !name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            anyhow::bail!("Invalid field name in '{}'", input);
+        }
+        if RESERVED_FIELDS.contains(&name.as_str()) {
+            anyhow::bail!("'{}' is added to every resource; leave it out", name);
+        }
+        let ty = FieldType::parse(ty).with_context(|| {
+            format!(
+                "Unknown type '{}' in '{}'; use string, text, int, float, boolean, or datetime",
+                ty, input
+            )
+        })?;
+
+        Ok(Self { name, ty, optional })
+    }
+
+    fn zod_input(&self) -> String {
+        if self.optional {
+            format!("{}.nullish()", self.ty.zod())
+        } else {
+            self.ty.zod().to_string()
+        }
+    }
+
+    fn zod_output(&self) -> String {
+        if self.optional {
+            format!("{}.nullable()", self.ty.zod_output())
+        } else {
+            self.ty.zod_output().to_string()
+        }
+    }
+
+    fn faker(&self) -> String {
+        if self.optional {
+            format!("faker.helpers.maybe(() => {}) ?? null", self.ty.faker())
+        } else {
+            self.ty.faker().to_string()
+        }
+    }
+
+    /// JSX rendering the field of `record`
+    fn display(&self, record: &str) -> String {
+        let value = format!("{}.{}", record, self.name);
+        match (self.ty, self.optional) {
+            (FieldType::Boolean, _) => format!("{} ? \"Yes\" : \"No\"", value),
+            (FieldType::DateTime, false) => format!("{}.toLocaleString()", value),
+            (FieldType::DateTime, true) => format!("{}?.toLocaleString() ?? \"—\"", value),
+            (_, true) => format!("{} ?? \"—\"", value),
+            (_, false) => value,
+        }
+    }
+}
+
+/// What `generate resource` wrote, for the summary
+pub struct GeneratedResource {
+    pub model: String,
+    pub files: Vec<String>,
+}
+
+/// Generate a full-stack resource: Prisma model, zod schemas, a CRUD tRPC router
+/// registered in root.ts, and list and detail pages
+pub fn generate(
+    project_path: &str,
+    name: &str,
+    field_args: &[String],
+) -> Result<GeneratedResource> {
+    let model = pascal_case(name);
+    let camel = camel_case(name);
+    let file = kebab_case(name);
+    let route = pluralize(&file);
+    let plural = pascal_case(&route);
+
+    let fields = parse_fields(field_args)?;
+
+    let project = Path::new(project_path);
+    let router_path = format!("src/server/api/routers/{}.ts", file);
+    if fs::path_exists(project.join(&router_path)) {
+        anyhow::bail!("{} already exists", router_path);
+    }
+
+    let schema_path = project.join("prisma/schema.prisma");
+    let schema = fs::read_to_string(&schema_path).context("Failed to read prisma/schema.prisma")?;
+    if schema.contains(&format!("model {} {{", model)) {
+        anyhow::bail!("prisma/schema.prisma already has a {} model", model);
+    }
+    // SQLite has no @db.Text; its strings have no length limit
+    let text_attribute = !schema.contains("provider = \"sqlite\"");
+    let model_block = prisma_model(&model, &fields, text_attribute);
+
+    // Title-like field for links and headings
+    let label = fields
+        .iter()
+        .find(|field| matches!(field.ty, FieldType::String))
+        .map_or_else(
+            || format!("{}.id", camel),
+            |field| {
+                if field.optional {
+                    format!("{c}.{} ?? {c}.id", field.name, c = camel)
+                } else {
+                    format!("{}.{}", camel, field.name)
+                }
+            },
+        );
+
+    let vars = [
+        ("Model", model.clone()),
+        ("model", camel.clone()),
+        ("Models", plural.clone()),
+        ("models", camel_case(&route)),
+        ("file", file.clone()),
+        ("route", route.clone()),
+        ("title", words_title(&route)),
+        ("label", label),
+        (
+            "input_fields",
+            render_lines(&fields, |field| {
+                format!("  {}: {},", field.name, field.zod_input())
+            }),
+        ),
+        (
+            "output_fields",
+            render_lines(&fields, |field| {
+                format!("  {}: {},", field.name, field.zod_output())
+            }),
+        ),
+        (
+            "detail_fields",
+            render_lines(&fields, |field| {
+                format!(
+                "        <dt className=\"text-muted-foreground\">{}</dt>\n        <dd>{{{}}}</dd>",
+                words_title(&field.name),
+                field.display(&camel)
+            )
+            }),
+        ),
+    ];
+
+    let outputs = [
+        ("schema.ts", format!("src/schemas/{}.ts", file)),
+        ("router.ts", router_path),
+        ("list-page.tsx", format!("src/app/{}/page.tsx", route)),
+        (
+            "detail-page.tsx",
+            format!("src/app/{}/[id]/page.tsx", route),
+        ),
+    ];
+
+    let mut files = Vec::new();
+    for (template, destination) in outputs {
+        let content = embedded::render_template(&format!("generate/resource/{}", template), &vars)
+            .with_context(|| format!("Missing embedded template generate/resource/{}", template))?;
+        write_file(project_path, &destination, &content)?;
+        files.push(destination);
+    }
+
+    plan::apply(Op::PatchPrisma {
+        path: schema_path,
+        blocks: model_block,
+    })?;
+    files.push("prisma/schema.prisma".to_string());
+
+    trpc::register_router(
+        project_path,
+        &camel,
+        &format!("{}Router", camel),
+        &format!("@/server/api/routers/{}", file),
+    )?;
+    files.push(trpc::ROOT_PATH.to_string());
+
+    Ok(GeneratedResource { model, files })
+}
+
+/// Check one of `create`'s models before any file is written
+pub fn validate(name: &str, field_args: &[String]) -> Result<()> {
+    validate_name(name)?;
+    if field_args.is_empty() {
+        anyhow::bail!("{} needs at least one field", pascal_case(name));
+    }
+    parse_fields(field_args).map(|_| ())
+}
+
+pub fn validate_name(name: &str) -> Result<()> {
+    if !naming::is_valid_name(name) {
+        anyhow::bail!(
+            "Invalid model name '{}'; use letters, digits, '-' or '_', starting with a letter",
+            name
+        );
+    }
+    if RESERVED_MODELS.contains(&pascal_case(name).as_str()) {
+        anyhow::bail!(
+            "Projects can have a {} model already; pick another name",
+            pascal_case(name)
+        );
+    }
+    Ok(())
+}
+
+/// Seed script lines creating ten sample rows of the model when its table is empty
+pub fn seed_block(name: &str, field_args: &[String]) -> Result<String> {
+    let fields = parse_fields(field_args)?;
+    let route = pluralize(&kebab_case(name));
+    Ok(format!(
+        r#"
+  if ((await db.{model}.count()) === 0) {{
+    await db.{model}.createMany({{
+      data: Array.from({{ length: 10 }}, () => ({{
+{fields}
+      }})),
+    }});
+    console.log("Created 10 sample {plural}");
+  }}
+"#,
+        model = camel_case(name),
+        fields = render_lines(&fields, |field| format!(
+            "        {}: {},",
+            field.name,
+            field.faker()
+        )),
+        plural = route.replace('-', " "),
+    ))
+}
+
+fn parse_fields(field_args: &[String]) -> Result<Vec<Field>> {
+    let fields = field_args
+        .iter()
+        .map(|arg| Field::parse(arg))
+        .collect::<Result<Vec<_>>>()?;
+    if let Some(duplicate) = fields
+        .iter()
+        .enumerate()
+        .find(|(i, field)| fields[..*i].iter().any(|other| other.name == field.name))
+    {
+        anyhow::bail!("Field '{}' is listed twice", duplicate.1.name);
+    }
+    Ok(fields)
+}
+
+fn render_lines(fields: &[Field], line: impl Fn(&Field) -> String) -> String {
+    fields.iter().map(line).collect::<Vec<_>>().join("\n")
+}
+
+/// `blog-posts` -> `Blog posts`, `publishedAt` -> `Published at`
+fn words_title(input: &str) -> String {
+    let words = kebab_case(input).replace('-', " ");
+    let mut chars = words.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Model block with columns aligned the way `prisma format` does
+fn prisma_model(model: &str, fields: &[Field], text_attribute: bool) -> String {
+    let mut columns = vec![(
+        "id".to_string(),
+        "String".to_string(),
+        "@id @default(cuid())".to_string(),
+    )];
+    for field in fields {
+        let ty = format!(
+            "{}{}",
+            field.ty.prisma(),
+            if field.optional { "?" } else { "" }
+        );
+        let attributes = if field.ty == FieldType::Text && text_attribute {
+            "@db.Text"
+        } else {
+            ""
+        };
+        columns.push((field.name.clone(), ty, attributes.to_string()));
+    }
+    columns.push((
+        "createdAt".to_string(),
+        "DateTime".to_string(),
+        "@default(now())".to_string(),
+    ));
+    columns.push((
+        "updatedAt".to_string(),
+        "DateTime".to_string(),
+        "@updatedAt".to_string(),
+    ));
+
+    let name_width = columns
+        .iter()
+        .map(|(name, _, _)| name.len())
+        .max()
+        .unwrap_or(0)
+        + 1;
+    let type_width = columns.iter().map(|(_, ty, _)| ty.len()).max().unwrap_or(0) + 1;
+
+    let mut block = format!("\nmodel {} {{\n", model);
+    for (name, ty, attributes) in columns {
+        let line = format!("  {:<name_width$}{:<type_width$}{}", name, ty, attributes);
+        block.push_str(line.trim_end());
+        block.push('\n');
+    }
+    block.push_str("}\n");
+
+    block
+}
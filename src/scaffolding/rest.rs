@@ -0,0 +1,456 @@
+use anyhow::Result;
+
+use crate::scaffolding::env::{self, EnvFragment, EnvVar};
+use crate::utils::fs::write_file;
+
+const REST_ENV: EnvFragment = EnvFragment {
+    section: "REST API",
+    vars: &[
+        EnvVar::server("API_KEYS", "z.string().optional()"),
+        EnvVar::server(
+            "API_DOCS_ENABLED",
+            r#"z.enum(["true", "false"]).optional()"#,
+        ),
+        EnvVar::server("CORS_ALLOWED_ORIGINS", "z.string().optional()"),
+    ],
+};
+
+/// Scaffold a versioned REST surface next to tRPC: `/api/v1/posts` with zod-validated
+/// input and API-key auth, backed by a post service that the tRPC router shares.
+/// Also serves an OpenAPI document built from the shared zod schemas, rendered with Scalar
+/// at `/api-docs`, and answers cross-origin browser calls from `CORS_ALLOWED_ORIGINS`.
+/// Requires the example `post` router and model.
+pub fn scaffold(project_path: &str) -> Result<()> {
+    write_file(project_path, "src/server/services/post.ts", POST_SERVICE)?;
+    write_file(project_path, "src/server/api/routers/post.ts", POST_ROUTER)?;
+    write_file(project_path, "src/server/rest.ts", REST_HELPERS)?;
+    write_file(project_path, "src/server/cors.ts", CORS)?;
+    write_file(project_path, "src/server/cors.test.ts", CORS_TEST)?;
+    write_file(project_path, "src/app/api/v1/posts/route.ts", POSTS_ROUTE)?;
+    write_file(project_path, "src/server/openapi.ts", OPENAPI_DOCUMENT)?;
+    write_file(
+        project_path,
+        "src/app/api/v1/openapi.json/route.ts",
+        OPENAPI_ROUTE,
+    )?;
+    write_file(project_path, "src/app/api-docs/route.ts", API_DOCS_ROUTE)?;
+    env::register(project_path, &REST_ENV)?;
+
+    Ok(())
+}
+
+// ============================================================================
+// Embedded Templates
+// ============================================================================
+
+const POST_SERVICE: &str = r#"import type { CreatePostInput } from "@/schemas/post";
+import type { db as Db } from "@/server/db";
+
+type Database = typeof Db;
+
+// Business logic shared by the tRPC router and the REST route handlers; callers validate input
+
+export function listPosts(db: Database, { limit }: { limit: number }) {
+  return db.post.findMany({
+    orderBy: { createdAt: "desc" },
+    take: limit,
+  });
+}
+
+export function createPost(db: Database, { name }: CreatePostInput) {
+  return db.post.create({
+    data: { name },
+  });
+}
+"#;
+
+const POST_ROUTER: &str = r#"import { z } from "zod";
+
+import { createPostSchema, listPostsSchema, postSchema } from "@/schemas/post";
+import { createTRPCRouter, publicProcedure } from "@/server/api/trpc";
+import { createPost, listPosts } from "@/server/services/post";
+
+export const postRouter = createTRPCRouter({
+  create: publicProcedure
+    .input(createPostSchema)
+    .output(postSchema)
+    .mutation(async ({ ctx, input }) => {
+      return createPost(ctx.db, input);
+    }),
+
+  list: publicProcedure
+    .input(listPostsSchema.optional())
+    .output(z.array(postSchema))
+    .query(async ({ ctx, input }) => {
+      return listPosts(ctx.db, { limit: input?.limit ?? 20 });
+    }),
+});
+"#;
+
+const REST_HELPERS: &str = r#"import { timingSafeEqual } from "node:crypto";
+import type { z } from "zod";
+import { env } from "@/env";
+
+export type Handler<TContext> = (req: Request, context: TContext) => Promise<Response>;
+
+/** JSON error body in the shape every `/api/v1` endpoint uses */
+export function errorResponse(status: number, code: string, message: string, details?: unknown) {
+  return Response.json({ error: { code, message, details } }, { status });
+}
+
+function apiKeys(): string[] {
+  return (env.API_KEYS ?? "")
+    .split(",")
+    .map((key) => key.trim())
+    .filter(Boolean);
+}
+
+function isValidKey(candidate: string): boolean {
+  const given = Buffer.from(candidate);
+  return apiKeys().some((key) => {
+    const expected = Buffer.from(key);
+    return expected.length === given.length && timingSafeEqual(expected, given);
+  });
+}
+
+/**
+ * Require an API key from `API_KEYS` (comma-separated) in `Authorization: Bearer <key>`
+ * or `x-api-key`. Without configured keys every request is rejected.
+ */
+export function withApiKey<TContext>(handler: Handler<TContext>): Handler<TContext> {
+  return async (req, context) => {
+    const header = req.headers.get("authorization");
+    const key = header?.startsWith("Bearer ") ? header.slice(7) : req.headers.get("x-api-key");
+
+    if (!key || !isValidKey(key)) {
+      return errorResponse(401, "UNAUTHORIZED", "A valid API key is required");
+    }
+
+    return handler(req, context);
+  };
+}
+
+type Parsed<T> = { data: T; error?: never } | { data?: never; error: Response };
+
+/** Validate the JSON body; on failure `error` is a ready-to-return 400 response */
+export async function parseBody<TSchema extends z.ZodType>(
+  req: Request,
+  schema: TSchema,
+): Promise<Parsed<z.output<TSchema>>> {
+  let body: unknown;
+  try {
+    body = await req.json();
+  } catch {
+    return { error: errorResponse(400, "BAD_REQUEST", "Request body must be valid JSON") };
+  }
+  return validate(schema, body);
+}
+
+/** Validate the query string; use `z.coerce` for numbers and booleans */
+export function parseQuery<TSchema extends z.ZodType>(
+  req: Request,
+  schema: TSchema,
+): Parsed<z.output<TSchema>> {
+  return validate(schema, Object.fromEntries(new URL(req.url).searchParams));
+}
+
+function validate<TSchema extends z.ZodType>(schema: TSchema, input: unknown): Parsed<z.output<TSchema>> {
+  const result = schema.safeParse(input);
+  if (!result.success) {
+    return {
+      error: errorResponse(400, "BAD_REQUEST", "Invalid request", result.error.flatten().fieldErrors),
+    };
+  }
+  return { data: result.data };
+}
+"#;
+
+const POSTS_ROUTE: &str = r#"import { createPostSchema, listPostsQuerySchema } from "@/schemas/post";
+import { preflight, withCors } from "@/server/cors";
+import { db } from "@/server/db";
+import { parseBody, parseQuery, withApiKey } from "@/server/rest";
+import { createPost, listPosts } from "@/server/services/post";
+
+/** GET /api/v1/posts?limit=20 */
+export const GET = withCors(
+  withApiKey(async (req) => {
+    const query = parseQuery(req, listPostsQuerySchema);
+    if (query.error) {
+      return query.error;
+    }
+
+    const posts = await listPosts(db, query.data);
+    return Response.json({ data: posts });
+  }),
+);
+
+/** POST /api/v1/posts with `{ "name": "..." }` */
+export const POST = withCors(
+  withApiKey(async (req) => {
+    const body = await parseBody(req, createPostSchema);
+    if (body.error) {
+      return body.error;
+    }
+
+    const post = await createPost(db, body.data);
+    return Response.json({ data: post }, { status: 201 });
+  }),
+);
+
+export const OPTIONS = preflight(["GET", "POST"]);
+"#;
+
+const CORS: &str = r#"import { env } from "@/env";
+import type { Handler } from "@/server/rest";
+
+// Everything withApiKey and parseBody read; the API needs no cookies, so no credentials
+const ALLOWED_HEADERS = "Authorization, Content-Type, X-API-Key";
+const PREFLIGHT_MAX_AGE = 60 * 60 * 24;
+
+/** `CORS_ALLOWED_ORIGINS`, comma-separated like `https://app.example.com`; `*` allows every origin */
+function allowedOrigins(): string[] {
+  return (env.CORS_ALLOWED_ORIGINS ?? "")
+    .split(",")
+    .map((origin) => origin.trim().replace(/\/$/, ""))
+    .filter(Boolean);
+}
+
+/** Whether a browser at `origin` may read the responses; same-origin calls don't need this */
+export function isAllowedOrigin(origin: string | null): origin is string {
+  const origins = allowedOrigins();
+  return origin !== null && (origins.includes("*") || origins.includes(origin));
+}
+
+/** The CORS headers for a request; `Vary` keeps caches from sharing them across origins */
+export function corsHeaders(req: Request): Record<string, string> {
+  const origin = req.headers.get("origin");
+  if (!isAllowedOrigin(origin)) {
+    return { Vary: "Origin" };
+  }
+  return { "Access-Control-Allow-Origin": origin, Vary: "Origin" };
+}
+
+/** Add the CORS headers to every response of `handler`, errors included */
+export function withCors<TContext>(handler: Handler<TContext>): Handler<TContext> {
+  return async (req, context) => {
+    const response = await handler(req, context);
+    for (const [name, value] of Object.entries(corsHeaders(req))) {
+      response.headers.set(name, value);
+    }
+    return response;
+  };
+}
+
+/**
+ * The `OPTIONS` handler answering browsers' preflight requests for a route's `methods`.
+ * Origins that aren't allowed get no CORS headers, so the browser blocks the call.
+ */
+export function preflight(methods: string[]) {
+  return (req: Request) => {
+    const headers = corsHeaders(req);
+    if (headers["Access-Control-Allow-Origin"]) {
+      Object.assign(headers, {
+        "Access-Control-Allow-Methods": [...methods, "OPTIONS"].join(", "),
+        "Access-Control-Allow-Headers": ALLOWED_HEADERS,
+        "Access-Control-Max-Age": String(PREFLIGHT_MAX_AGE),
+      });
+    }
+    return new Response(null, { status: 204, headers });
+  };
+}
+"#;
+
+const CORS_TEST: &str = r#"// @vitest-environment node
+import { describe, expect, it, vi } from "vitest";
+import { preflight, withCors } from "./cors";
+
+vi.mock("@/env", () => ({
+  env: { CORS_ALLOWED_ORIGINS: "https://app.example.com, https://admin.example.com/" },
+}));
+
+function request(origin?: string, method = "GET") {
+  return new Request("http://localhost:3000/api/v1/posts", {
+    method,
+    headers: origin ? { origin } : {},
+  });
+}
+
+describe("withCors", () => {
+  const handler = withCors(async () => Response.json({ error: { code: "UNAUTHORIZED" } }, { status: 401 }));
+
+  it("lets allowed origins read responses, errors included", async () => {
+    const response = await handler(request("https://admin.example.com"), undefined);
+
+    expect(response.status).toBe(401);
+    expect(response.headers.get("access-control-allow-origin")).toBe("https://admin.example.com");
+    expect(response.headers.get("vary")).toBe("Origin");
+  });
+
+  it("leaves other origins and same-origin calls without CORS headers", async () => {
+    const other = await handler(request("https://evil.example.com"), undefined);
+    const sameOrigin = await handler(request(), undefined);
+
+    expect(other.headers.get("access-control-allow-origin")).toBeNull();
+    expect(sameOrigin.headers.get("access-control-allow-origin")).toBeNull();
+  });
+});
+
+describe("preflight", () => {
+  const options = preflight(["GET", "POST"]);
+
+  it("allows the route's methods and the API key headers", () => {
+    const response = options(request("https://app.example.com", "OPTIONS"));
+
+    expect(response.status).toBe(204);
+    expect(response.headers.get("access-control-allow-origin")).toBe("https://app.example.com");
+    expect(response.headers.get("access-control-allow-methods")).toBe("GET, POST, OPTIONS");
+    expect(response.headers.get("access-control-allow-headers")).toContain("X-API-Key");
+  });
+
+  it("answers other origins without allowing anything", () => {
+    const response = options(request("https://evil.example.com", "OPTIONS"));
+
+    expect(response.status).toBe(204);
+    expect(response.headers.get("access-control-allow-methods")).toBeNull();
+  });
+});
+"#;
+
+const OPENAPI_DOCUMENT: &str = r#"import { z } from "zod";
+import { env } from "@/env";
+import { createPostSchema, listPostsQuerySchema, postSchema } from "@/schemas/post";
+
+/** The docs expose every endpoint, so production needs `API_DOCS_ENABLED=true` to serve them */
+export function apiDocsEnabled(): boolean {
+  return env.NODE_ENV !== "production" || env.API_DOCS_ENABLED === "true";
+}
+
+// Dates travel as ISO strings in JSON responses
+function jsonSchema(schema: z.ZodType, io: "input" | "output") {
+  return z.toJSONSchema(schema, {
+    io,
+    unrepresentable: "any",
+    override: (ctx) => {
+      if (ctx.zodSchema._zod.def.type === "date") {
+        ctx.jsonSchema.type = "string";
+        ctx.jsonSchema.format = "date-time";
+      }
+    },
+  });
+}
+
+function queryParameters(schema: z.ZodObject) {
+  const { properties = {}, required = [] } = jsonSchema(schema, "input");
+  return Object.entries(properties).map(([name, property]) => ({
+    name,
+    in: "query",
+    required: required.includes(name),
+    schema: property,
+  }));
+}
+
+const errorSchema = {
+  type: "object",
+  properties: {
+    error: {
+      type: "object",
+      properties: {
+        code: { type: "string" },
+        message: { type: "string" },
+        details: {},
+      },
+      required: ["code", "message"],
+    },
+  },
+};
+
+function json(schema: unknown, description: string) {
+  return { description, content: { "application/json": { schema } } };
+}
+
+const errors = {
+  "400": json(errorSchema, "Invalid request"),
+  "401": json(errorSchema, "Missing or invalid API key"),
+};
+
+/**
+ * OpenAPI 3.1 document for `/api/v1`, generated from the same zod schemas the handlers
+ * validate with. Add an entry to `paths` for every new route handler.
+ */
+export function createOpenApiDocument() {
+  const post = jsonSchema(postSchema, "output");
+
+  return {
+    openapi: "3.1.0",
+    info: { title: "REST API", version: "1.0.0" },
+    servers: [{ url: "/api/v1" }],
+    security: [{ bearer: [] }, { apiKey: [] }],
+    components: {
+      securitySchemes: {
+        bearer: { type: "http", scheme: "bearer" },
+        apiKey: { type: "apiKey", in: "header", name: "x-api-key" },
+      },
+    },
+    paths: {
+      "/posts": {
+        get: {
+          summary: "List posts, newest first",
+          parameters: queryParameters(listPostsQuerySchema),
+          responses: {
+            "200": json({ type: "object", properties: { data: { type: "array", items: post } } }, "Posts"),
+            ...errors,
+          },
+        },
+        post: {
+          summary: "Create a post",
+          requestBody: { required: true, content: { "application/json": { schema: jsonSchema(createPostSchema, "input") } } },
+          responses: {
+            "201": json({ type: "object", properties: { data: post } }, "The created post"),
+            ...errors,
+          },
+        },
+      },
+    },
+  };
+}
+"#;
+
+const OPENAPI_ROUTE: &str = r#"import { preflight, withCors } from "@/server/cors";
+import { apiDocsEnabled, createOpenApiDocument } from "@/server/openapi";
+import { errorResponse } from "@/server/rest";
+
+/** GET /api/v1/openapi.json, readable by API clients generated in the allowed origins */
+export const GET = withCors(async () => {
+  if (!apiDocsEnabled()) {
+    return errorResponse(404, "NOT_FOUND", "Not found");
+  }
+  return Response.json(createOpenApiDocument());
+});
+
+export const OPTIONS = preflight(["GET"]);
+"#;
+
+const API_DOCS_ROUTE: &str = r#"import { apiDocsEnabled } from "@/server/openapi";
+
+// Scalar API reference for /api/v1, loaded from a CDN so the app needs no extra dependency
+const HTML = `<!doctype html>
+<html lang="en">
+  <head>
+    <meta charset="utf-8" />
+    <meta name="viewport" content="width=device-width, initial-scale=1" />
+    <title>API Reference</title>
+  </head>
+  <body>
+    <script id="api-reference" data-url="/api/v1/openapi.json"></script>
+    <script src="https://cdn.jsdelivr.net/npm/@scalar/api-reference"></script>
+  </body>
+</html>`;
+
+/** GET /api-docs */
+export function GET() {
+  if (!apiDocsEnabled()) {
+    return new Response("Not found", { status: 404 });
+  }
+  return new Response(HTML, { headers: { "content-type": "text/html; charset=utf-8" } });
+}
+"#;
@@ -1,8 +1,29 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use std::path::Path;
 
+use crate::scaffolding::env::{self, EnvFragment, EnvVar};
 use crate::templates::embedded;
-use crate::utils::fs::write_file;
+use crate::utils::fs::{self, update_file, write_file};
+use crate::utils::npm;
+
+/// Root package.json scripts for driving the Restate stack
+const RESTATE_SCRIPTS: &[(&str, &str)] = &[
+    (
+        "restate:up",
+        "docker-compose -f restate/docker-compose.yml up -d",
+    ),
+    ("restate:dev", "npm --prefix restate/services run dev"),
+    ("restate:register", "npx t3-mono restate register"),
+];
+
+const ENV: EnvFragment = EnvFragment {
+    section: "Restate",
+    vars: &[
+        EnvVar::server("RESTATE_INGRESS_URL", "z.string().url().optional()")
+            .example("http://localhost:8080")
+            .production(""),
+    ],
+};
 
 /// Scaffold Restate durable workflow services
 pub async fn scaffold(project_path: &str) -> Result<()> {
@@ -10,7 +31,7 @@ pub async fn scaffold(project_path: &str) -> Result<()> {
 
     // Create restate directory structure
     let restate_path = project.join("restate");
-    tokio::fs::create_dir_all(&restate_path).await?;
+    fs::create_dir_all(&restate_path)?;
 
     // Copy embedded Restate templates
     embedded::copy_embedded_dir("restate/", &restate_path).await?;
@@ -18,6 +39,54 @@ pub async fn scaffold(project_path: &str) -> Result<()> {
     // Create restate index/readme for the project
     write_file(project_path, "restate/README.md", RESTATE_README)?;
 
+    // Environment template for running the services outside of docker-compose
+    write_file(
+        project_path,
+        "restate/services/.env.example",
+        SERVICES_ENV_EXAMPLE,
+    )?;
+
+    // Wire the Restate workflow into the root package.json
+    npm::add_scripts(project_path, RESTATE_SCRIPTS)?;
+
+    // Share the service contracts with the Next.js app
+    link_contracts(project_path)?;
+    write_file(project_path, "src/server/restate.ts", RESTATE_CLIENT)?;
+    env::register(project_path, &ENV)?;
+
+    Ok(())
+}
+
+/// Expose `restate/services/src/contracts.ts` to the app as `@restate/contracts`
+/// and keep the services sources out of the app's own type-check
+fn link_contracts(project_path: &str) -> Result<()> {
+    let tsconfig_path = Path::new(project_path).join("tsconfig.json");
+    if !fs::path_exists(&tsconfig_path) {
+        return Ok(());
+    }
+
+    let content = fs::read_to_string(&tsconfig_path)?;
+    let mut tsconfig: serde_json::Value = serde_json::from_str(&content)
+        .context("Failed to parse tsconfig.json (comments are not supported)")?;
+
+    if !tsconfig["compilerOptions"]["paths"].is_object() {
+        tsconfig["compilerOptions"]["paths"] = serde_json::json!({});
+    }
+    tsconfig["compilerOptions"]["paths"]["@restate/contracts"] =
+        serde_json::json!(["./restate/services/src/contracts.ts"]);
+
+    if !tsconfig["exclude"].is_array() {
+        tsconfig["exclude"] = serde_json::json!([]);
+    }
+    if let Some(exclude) = tsconfig["exclude"].as_array_mut() {
+        if !exclude.iter().any(|e| e == "restate") {
+            exclude.push(serde_json::json!("restate"));
+        }
+    }
+
+    let content = serde_json::to_string_pretty(&tsconfig)?;
+    update_file(project_path, "tsconfig.json", &format!("{}\n", content))?;
+
     Ok(())
 }
 
@@ -31,22 +100,26 @@ This project includes Restate for building durable, fault-tolerant workflows.
 
 ## Quick Start
 
+From the project root:
+
 ```bash
 # Start infrastructure (Restate, Ollama, Docling, PostgreSQL)
-docker-compose up -d
+npm run restate:up
 
-# Install dependencies
-cd services && npm install
+# Install service dependencies
+npm --prefix restate/services install
 
 # Start services
-npm run dev
+npm run restate:dev
 
-# Register with Restate
-curl -X POST http://localhost:9070/deployments \
-  -H 'content-type: application/json' \
-  -d '{"uri": "http://host.docker.internal:9082"}'
+# Register the services with Restate
+npm run restate:register
 ```
 
+`restate:register` runs `t3-mono restate register`, which waits for the admin API to
+become healthy and retries the registration while the services are starting. Pass
+`--uri` to register a different endpoint or `--force` to replace an existing deployment.
+
 ## Available Services
 
 ### Embedding Service (port 9082)
@@ -99,27 +172,94 @@ curl -X POST http://localhost:8080/LambdaService/invoke \
 
 ## Environment Variables
 
-Copy `.env.example` to `.env` and configure:
+- `restate/.env.example` configures the docker-compose stack
+- `restate/services/.env.example` configures the services when run locally via `npm run restate:dev`
 
-```bash
-# Restate
+Copy each to `.env` next to it and fill in your values.
+
+## Calling Services from the App
+
+The zod schemas in `services/src/contracts.ts` are shared with the Next.js app through
+the `@restate/contracts` path alias. Use the typed client in `src/server/restate.ts`:
+
+```typescript
+import { callRestate } from "@/server/restate";
+
+const { embedding } = await callRestate("EmbeddingService", "generateEmbedding", {
+  text: "Hello, world!",
+});
+```
+
+Set `RESTATE_INGRESS_URL` when the ingress isn't reachable at `http://localhost:8080`.
+
+## Documentation
+
+- [Best Practices](docs/best-practices.md) - Production patterns and guidelines
+- [Restate Docs](https://docs.restate.dev/) - Official Restate documentation
+"#;
+
+const SERVICES_ENV_EXAMPLE: &str = r#"# Service port
 PORT=9082
 
-# Ollama
+# Ollama (EmbeddingService)
 OLLAMA_ENDPOINT=http://localhost:11434
+EMBEDDING_MODEL=nomic-embed-text
 
-# Docling
+# Docling (ExtractionService)
 DOCLING_ENDPOINT=http://localhost:5000
+EXTRACTION_TIMEOUT=60000
+MAX_FILE_SIZE=104857600
 
-# AWS (optional)
+# AWS (S3 & Lambda, optional)
 AWS_REGION=us-east-1
+AWS_S3_BUCKET_NAME=
 AWS_ACCESS_KEY_ID=
 AWS_SECRET_ACCESS_KEY=
-AWS_S3_BUCKET_NAME=
-```
+PRESIGNED_URL_EXPIRY=3600
 
-## Documentation
+# Retry settings
+MAX_RETRIES=5
+"#;
 
-- [Best Practices](docs/best-practices.md) - Production patterns and guidelines
-- [Restate Docs](https://docs.restate.dev/) - Official Restate documentation
+const RESTATE_CLIENT: &str = r#"import "server-only";
+
+import {
+  restateContracts,
+  type RestateHandler,
+  type RestateInput,
+  type RestateOutput,
+  type RestateService,
+} from "@restate/contracts";
+
+const RESTATE_INGRESS_URL = process.env.RESTATE_INGRESS_URL ?? "http://localhost:8080";
+
+/**
+ * Call a Restate service handler through the ingress, validating the input before
+ * sending and the response after receiving with the shared service contracts.
+ *
+ * @example
+ * const { embedding } = await callRestate("EmbeddingService", "generateEmbedding", { text: "Hello" });
+ */
+export async function callRestate<S extends RestateService, H extends RestateHandler<S>>(
+  service: S,
+  handler: H,
+  input: RestateInput<S, H>,
+): Promise<RestateOutput<S, H>> {
+  const contract = restateContracts[service][handler] as {
+    input: { parse: (data: unknown) => unknown };
+    output: { parse: (data: unknown) => unknown };
+  };
+
+  const response = await fetch(`${RESTATE_INGRESS_URL}/${service}/${handler}`, {
+    method: "POST",
+    headers: { "content-type": "application/json" },
+    body: JSON.stringify(contract.input.parse(input)),
+  });
+
+  if (!response.ok) {
+    throw new Error(`${service}/${handler} failed with ${response.status}: ${await response.text()}`);
+  }
+
+  return contract.output.parse(await response.json()) as RestateOutput<S, H>;
+}
 "#;
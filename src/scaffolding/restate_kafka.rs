@@ -0,0 +1,225 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use crate::utils::fs::{self, update_file, write_file};
+use crate::utils::npm;
+
+const KAFKA_SCRIPTS: &[(&str, &str)] = &[(
+    "restate:subscribe",
+    "npx t3-mono restate subscribe orders OrderEvents/process",
+)];
+
+/// Add a Kafka-triggered workflow to the Restate extension: a single-node Kafka broker in
+/// the compose stack, the cluster in Restate's config, and an `OrderEvents` virtual object
+/// that `restate:subscribe` connects to the `orders` topic. Requires the Restate scaffold.
+pub fn scaffold(project_path: &str) -> Result<()> {
+    write_file(project_path, "restate/restate.toml", RESTATE_CONFIG)?;
+    patch(project_path, "restate/docker-compose.yml", |content| {
+        content
+            .replacen(
+                "      - RESTATE_OBSERVABILITY__LOG_LEVEL=info\n",
+                "      - RESTATE_OBSERVABILITY__LOG_LEVEL=info\n      - RESTATE_CONFIG=/etc/restate/restate.toml\n    volumes:\n      - ./restate.toml:/etc/restate/restate.toml:ro\n",
+                1,
+            )
+            .replacen("\nvolumes:\n", &format!("{}\nvolumes:\n", COMPOSE_KAFKA_SERVICE), 1)
+    })?;
+
+    write_file(
+        project_path,
+        "restate/services/src/order-events.ts",
+        ORDER_EVENTS_SERVICE,
+    )?;
+    patch(
+        project_path,
+        "restate/services/src/contracts.ts",
+        |content| {
+            content
+            .replacen(
+                "// ============================================================================\n// Contract registry",
+                &format!("{}// ============================================================================\n// Contract registry", ORDER_EVENT_CONTRACT),
+                1,
+            )
+            .replacen(
+                "export type GetFunctionInput = z.infer<typeof GetFunctionInputSchema>;\n",
+                "export type GetFunctionInput = z.infer<typeof GetFunctionInputSchema>;\nexport type OrderEvent = z.infer<typeof OrderEventSchema>;\n",
+                1,
+            )
+        },
+    )?;
+    patch(project_path, "restate/services/src/index.ts", |content| {
+        content
+            .replacen(
+                "import { AWSLambdaService } from \"./aws-lambda\";\n",
+                "import { AWSLambdaService } from \"./aws-lambda\";\nimport { OrderEvents } from \"./order-events\";\n",
+                1,
+            )
+            .replacen(
+                "export { EmbeddingService, ExtractionService, AWSS3Service, AWSLambdaService };",
+                "export { EmbeddingService, ExtractionService, AWSS3Service, AWSLambdaService, OrderEvents };",
+                1,
+            )
+            .replacen("    .bind(AWSLambdaService)\n", "    .bind(AWSLambdaService)\n    .bind(OrderEvents)\n", 1)
+            .replacen(
+                "      console.log(\"  - AWSLambdaService (AWS Lambda invocations)\");\n",
+                "      console.log(\"  - AWSLambdaService (AWS Lambda invocations)\");\n      console.log(\"  - OrderEvents (Kafka topic `orders`)\");\n",
+                1,
+            )
+    })?;
+    patch(project_path, "restate/README.md", |content| {
+        content.replacen(
+            "## Documentation\n",
+            &format!("{}## Documentation\n", README_SECTION),
+            1,
+        )
+    })?;
+
+    npm::add_scripts(project_path, KAFKA_SCRIPTS)?;
+
+    Ok(())
+}
+
+fn patch(
+    project_path: &str,
+    relative_path: &str,
+    rewrite: impl FnOnce(String) -> String,
+) -> Result<()> {
+    let content = fs::read_to_string(Path::new(project_path).join(relative_path))
+        .with_context(|| format!("Failed to read {}", relative_path))?;
+    update_file(project_path, relative_path, &rewrite(content))
+}
+
+// ============================================================================
+// Embedded Templates
+// ============================================================================
+
+const RESTATE_CONFIG: &str = r#"# Restate server configuration, mounted into the restate container
+
+# Kafka clusters subscriptions can read from; `npm run restate:subscribe` uses `local`
+[[ingress.kafka-clusters]]
+name = "local"
+brokers = ["PLAINTEXT://kafka:29092"]
+"#;
+
+const COMPOSE_KAFKA_SERVICE: &str = r#"
+  # Single-node Kafka in KRaft mode for the event-driven example (OrderEvents)
+  kafka:
+    image: apache/kafka:3.9.1
+    container_name: kafka
+    ports:
+      - "9092:9092"
+    environment:
+      - KAFKA_NODE_ID=1
+      - KAFKA_PROCESS_ROLES=broker,controller
+      - KAFKA_LISTENERS=INTERNAL://:29092,EXTERNAL://:9092,CONTROLLER://:9093
+      - KAFKA_ADVERTISED_LISTENERS=INTERNAL://kafka:29092,EXTERNAL://localhost:9092
+      - KAFKA_LISTENER_SECURITY_PROTOCOL_MAP=INTERNAL:PLAINTEXT,EXTERNAL:PLAINTEXT,CONTROLLER:PLAINTEXT
+      - KAFKA_INTER_BROKER_LISTENER_NAME=INTERNAL
+      - KAFKA_CONTROLLER_LISTENER_NAMES=CONTROLLER
+      - KAFKA_CONTROLLER_QUORUM_VOTERS=1@kafka:9093
+      - KAFKA_OFFSETS_TOPIC_REPLICATION_FACTOR=1
+      - KAFKA_TRANSACTION_STATE_LOG_REPLICATION_FACTOR=1
+      - KAFKA_TRANSACTION_STATE_LOG_MIN_ISR=1
+      - KAFKA_AUTO_CREATE_TOPICS_ENABLE=true
+    networks:
+      - restate-network
+    restart: unless-stopped
+"#;
+
+const ORDER_EVENT_CONTRACT: &str = r#"// ============================================================================
+// OrderEvents (Kafka topic `orders`)
+// ============================================================================
+
+// Producers validate with the same schema before publishing
+export const OrderEventSchema = z.object({
+  orderId: z.string(),
+  type: z.enum(["created", "paid", "shipped", "cancelled"]),
+  amount: z.number().optional(),
+  occurredAt: z.string().optional(),
+});
+
+"#;
+
+const ORDER_EVENTS_SERVICE: &str = r#"import * as restate from "@restatedev/restate-sdk";
+import type { ObjectContext, ObjectSharedContext } from "@restatedev/restate-sdk";
+import { OrderEventSchema } from "./contracts";
+import type { OrderEvent } from "./contracts";
+
+type OrderState = {
+  status: OrderEvent["type"];
+  history: { type: OrderEvent["type"]; at: string }[];
+};
+
+// Retry configuration for side effects
+const retryConfig = {
+  initialRetryInterval: { milliseconds: 500 },
+  retryIntervalFactor: 2,
+  maxRetryAttempts: 5,
+};
+
+// A virtual object keyed by the Kafka record key (the order id): Restate delivers the
+// records of one key in order, one at a time, and keeps the object's state durable.
+export const OrderEvents = restate.object({
+  name: "OrderEvents",
+  handlers: {
+    process: async (ctx: ObjectContext, event: OrderEvent) => {
+      const parsed = OrderEventSchema.safeParse(event);
+      if (!parsed.success) {
+        // Retrying won't fix a malformed record; log it and move on to the next offset
+        ctx.console.warn({ service: "OrderEvents", key: ctx.key, error: parsed.error.message });
+        return;
+      }
+      const validated = parsed.data;
+
+      const state = (await ctx.get<OrderState>("order")) ?? { status: validated.type, history: [] };
+      const at = validated.occurredAt ?? new Date(await ctx.date.now()).toISOString();
+      state.status = validated.type;
+      state.history.push({ type: validated.type, at });
+      ctx.set("order", state);
+
+      // Side effects run once, even when the handler is retried
+      if (validated.type === "paid") {
+        await ctx.run(
+          "notify-fulfillment",
+          async () => {
+            // Replace with a call to your fulfillment system
+            console.log(`Order ${ctx.key} paid${validated.amount ? ` (${validated.amount})` : ""}`);
+          },
+          retryConfig,
+        );
+      }
+
+      ctx.console.info({ service: "OrderEvents", key: ctx.key, status: state.status });
+    },
+
+    status: restate.handlers.object.shared(async (ctx: ObjectSharedContext) => {
+      return (await ctx.get<OrderState>("order")) ?? null;
+    }),
+  },
+});
+"#;
+
+const README_SECTION: &str = r#"## Kafka Ingress
+
+The stack includes a single-node Kafka broker, and `restate.toml` registers it with
+Restate as the `local` cluster. `OrderEvents` consumes the `orders` topic: records with the
+same key (the order id) are processed in order by the virtual object with that key.
+
+```bash
+# After restate:up, restate:dev, and restate:register
+npm run restate:subscribe
+
+# Publish a record (key:value) with the console producer
+docker exec -it kafka /opt/kafka/bin/kafka-console-producer.sh \
+  --bootstrap-server localhost:9092 --topic orders \
+  --property parse.key=true --property key.separator=:
+order-1:{"orderId":"order-1","type":"paid","amount":42}
+
+# Read the order's state
+curl http://localhost:8080/OrderEvents/order-1/status
+```
+
+`restate:subscribe` runs `t3-mono restate subscribe orders OrderEvents/process`, which skips
+subscriptions that already exist. Subscribe other handlers the same way; the handler
+receives each record's value as its input.
+
+"#;
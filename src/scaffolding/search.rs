@@ -0,0 +1,326 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use crate::cli::SearchBackend;
+use crate::scaffolding::env::{self, EnvFragment, EnvVar};
+use crate::scaffolding::i18n;
+use crate::scaffolding::nav::HEADER_PATH;
+use crate::scaffolding::trpc;
+use crate::templates::embedded;
+use crate::utils::fs::{self, update_file, write_file};
+use crate::utils::npm;
+
+pub const SEARCH_DEV_DEPENDENCIES: &[(&str, &str)] = &[("tsx", "^4.21.0")];
+
+// Node loads the later env files over the earlier ones, like `next dev` does
+const SEARCH_SCRIPTS: &[(&str, &str)] = &[(
+    "search:reindex",
+    "tsx --env-file-if-exists=.env --env-file-if-exists=.env.development --env-file-if-exists=.env.local scripts/search-reindex.ts",
+)];
+
+const MEILISEARCH_ENV: EnvFragment = EnvFragment {
+    section: "Search (Meilisearch)",
+    vars: &[
+        EnvVar::server("MEILISEARCH_HOST", "z.string().url()")
+            .example("http://localhost:7700")
+            .production("https://search.example.com"),
+        EnvVar::server("MEILISEARCH_API_KEY", "z.string().optional()")
+            .example("dev-meilisearch-master-key")
+            .production(""),
+    ],
+};
+
+const TYPESENSE_ENV: EnvFragment = EnvFragment {
+    section: "Search (Typesense)",
+    vars: &[
+        EnvVar::server("TYPESENSE_URL", "z.string().url()")
+            .example("http://localhost:8108")
+            .production("https://search.example.com"),
+        EnvVar::server("TYPESENSE_API_KEY", "z.string().min(1)")
+            .example("dev-typesense-api-key")
+            .production(""),
+    ],
+};
+
+const PALETTE_PATH: &str = "src/components/search/SearchPalette.tsx";
+const CMD_LAYOUT_PATH: &str = "src/app/_components/CommandIslandLayout.tsx";
+
+/// Whether the search extension has been added
+pub fn is_installed(project_path: &str) -> bool {
+    fs::path_exists(Path::new(project_path).join(PALETTE_PATH))
+}
+
+/// Scaffold full-text search: index definitions in `src/server/search/`, a `search` tRPC
+/// router, `npm run search:reindex`, and a ⌘K palette in the Header. Meilisearch and
+/// Typesense are kept in sync by a Prisma extension on `db`; Postgres searches the tables.
+pub async fn scaffold(project_path: &str, backend: SearchBackend) -> Result<()> {
+    let project = Path::new(project_path);
+    let schema = fs::read_to_string(project.join("prisma/schema.prisma"))
+        .context("Failed to read prisma/schema.prisma")?;
+    // Spacing varies, e.g. cmd aligns the datasource's `=` with its `extensions` line
+    let postgres = schema.lines().any(|line| {
+        line.split_whitespace()
+            .eq(["provider", "=", "\"postgresql\""])
+    });
+    if backend == SearchBackend::Postgres && !postgres {
+        anyhow::bail!("The postgres search backend needs a PostgreSQL database; use --backend meilisearch or typesense");
+    }
+
+    embedded::copy_embedded_dir("search/common/", project).await?;
+    let (backend_dir, index_note) = match backend {
+        SearchBackend::Postgres => ("search/postgres/", POSTGRES_NOTE),
+        SearchBackend::Meilisearch => ("search/meilisearch/", ENGINE_NOTE),
+        SearchBackend::Typesense => ("search/typesense/", ENGINE_NOTE),
+    };
+    embedded::copy_embedded_dir(backend_dir, project).await?;
+
+    let indexes = if schema.contains("model Post {") {
+        POST_INDEXES
+    } else {
+        EMPTY_INDEXES
+    };
+    write_file(
+        project_path,
+        "src/server/search/indexes.ts",
+        &indexes.replace("{{note}}", index_note),
+    )?;
+
+    match backend {
+        SearchBackend::Postgres => {}
+        SearchBackend::Meilisearch => {
+            embedded::copy_embedded_dir("search/engine/", project).await?;
+            extend_db_client(project_path)?;
+            env::register(project_path, &MEILISEARCH_ENV)?;
+            add_compose_service(project_path, "meilisearch", COMPOSE_MEILISEARCH_SERVICE)?;
+        }
+        SearchBackend::Typesense => {
+            embedded::copy_embedded_dir("search/engine/", project).await?;
+            extend_db_client(project_path)?;
+            env::register(project_path, &TYPESENSE_ENV)?;
+            add_compose_service(project_path, "typesense", COMPOSE_TYPESENSE_SERVICE)?;
+        }
+    }
+
+    trpc::register_router(
+        project_path,
+        "search",
+        "searchRouter",
+        "@/server/api/routers/search",
+    )?;
+    i18n::merge_translations(project_path, "messages/en.json", MESSAGES_EN)?;
+    i18n::merge_translations(project_path, "messages/de.json", MESSAGES_DE)?;
+    npm::add_scripts(project_path, SEARCH_SCRIPTS)?;
+    npm::add_dependencies(project_path, &[], SEARCH_DEV_DEPENDENCIES)?;
+
+    mount_palette(project_path)?;
+    if fs::path_exists(project.join(CMD_LAYOUT_PATH)) {
+        mount_island(project_path).await?;
+    }
+
+    Ok(())
+}
+
+/// Let the CommandIsland's query mode search the app; also run by the cmd extension when
+/// search was added first, since it rewrites the layout
+pub async fn mount_island(project_path: &str) -> Result<()> {
+    embedded::copy_embedded_dir("search/cmd/", Path::new(project_path)).await?;
+
+    let content = fs::read_to_string(Path::new(project_path).join(CMD_LAYOUT_PATH))
+        .with_context(|| format!("Failed to read {}", CMD_LAYOUT_PATH))?;
+    if content.contains("<IslandSearch />") {
+        return Ok(());
+    }
+    let patched = content
+        .replacen(
+            "import { CommandIsland } from \"@/components/layout/CommandIsland\";\n",
+            "import { CommandIsland } from \"@/components/layout/CommandIsland\";\nimport { IslandSearch } from \"@/components/search/IslandSearch\";\n",
+            1,
+        )
+        .replacen("          <CommandIsland />\n", "          <IslandSearch />\n          <CommandIsland />\n", 1);
+    update_file(project_path, CMD_LAYOUT_PATH, &patched)
+}
+
+/// Put the palette button next to the theme toggle; a Header without it is left alone
+fn mount_palette(project_path: &str) -> Result<()> {
+    let Ok(content) = fs::read_to_string(Path::new(project_path).join(HEADER_PATH)) else {
+        return Ok(());
+    };
+    if content.contains("<SearchPalette") || !content.contains("            <ThemeToggle />\n") {
+        return Ok(());
+    }
+    let patched = content
+        .replacen(
+            "import { ThemeToggle } from \"@/app/_components/ThemeToggle\";\n",
+            "import { ThemeToggle } from \"@/app/_components/ThemeToggle\";\nimport { SearchPalette } from \"@/components/search/SearchPalette\";\n",
+            1,
+        )
+        .replacen(
+            "            <ThemeToggle />\n",
+            "            <SearchPalette navItems={navItems} />\n            <ThemeToggle />\n",
+            1,
+        );
+    update_file(project_path, HEADER_PATH, &patched)?;
+
+    // The palette queries tRPC, which the Header test doesn't provide
+    let test_path = HEADER_PATH.replace(".tsx", ".test.tsx");
+    if let Ok(test) = fs::read_to_string(Path::new(project_path).join(&test_path)) {
+        if !test.contains("SearchPalette") {
+            let anchor =
+                "vi.mock(\"@/app/_components/UserMenu\", () => ({\n  UserMenu: () => null,\n}));\n";
+            let patched = test.replacen(anchor, &format!("{}{}", anchor, HEADER_TEST_MOCK), 1);
+            update_file(project_path, &test_path, &patched)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Apply `searchSync` to the client in `src/server/db.ts`, after soft delete and before Accelerate
+fn extend_db_client(project_path: &str) -> Result<()> {
+    let db_path = Path::new(project_path).join("src/server/db.ts");
+    let content = fs::read_to_string(&db_path).context("Failed to read src/server/db.ts")?;
+
+    if content.contains("searchSync") {
+        return Ok(());
+    }
+
+    let patched = content
+        .replacen(
+            "import { PrismaClient } from \"@prisma/client\";\n",
+            "import { PrismaClient } from \"@prisma/client\";\nimport { searchSync } from \"@/server/search/sync\";\n",
+            1,
+        )
+        .replacen(
+            "prisma: PrismaClient | undefined;",
+            "prisma: ReturnType<typeof createPrismaClient> | undefined;",
+            1,
+        )
+        // Accelerate has to stay the last extension
+        .replacen(").$extends(withAccelerate());", ").$extends(searchSync).$extends(withAccelerate());", 1)
+        .replacen(".$extends(softDelete);\n}\n", ".$extends(softDelete).$extends(searchSync);\n}\n", 1)
+        .replacen(".$extends(queryTiming);\n}\n", ".$extends(queryTiming).$extends(searchSync);\n}\n", 1)
+        .replacen("\t});\n}\n", "\t}).$extends(searchSync);\n}\n", 1);
+
+    if !patched.contains(".$extends(searchSync)") {
+        anyhow::bail!("Could not find createPrismaClient() in src/server/db.ts; apply `searchSync` with `$extends` manually");
+    }
+
+    update_file(project_path, "src/server/db.ts", &patched)
+}
+
+/// Run the search engine next to the database; SQLite projects have no compose file
+fn add_compose_service(project_path: &str, name: &str, service: &str) -> Result<()> {
+    let Ok(content) = fs::read_to_string(Path::new(project_path).join("docker-compose.yml")) else {
+        return Ok(());
+    };
+    if content.contains(&format!("\n  {}:\n", name)) {
+        return Ok(());
+    }
+
+    let patched = content.replacen(
+        "\nvolumes:\n",
+        &format!("{}\nvolumes:\n  {}_data:\n", service, name),
+        1,
+    );
+    update_file(project_path, "docker-compose.yml", &patched)
+}
+
+// ============================================================================
+// Embedded Templates
+// ============================================================================
+
+const POSTGRES_NOTE: &str = "Postgres searches the tables themselves;\n * `npm run search:reindex` adds a GIN index for each one.";
+
+const ENGINE_NOTE: &str = "Writes through `db` keep the search backend in\n * sync (see sync.ts); `npm run search:reindex` rebuilds it from the database.";
+
+const POST_INDEXES: &str = r#"import { db } from "@/server/db";
+import { defineIndex, type SearchIndex } from "./types";
+
+/**
+ * The searchable models, by index name. {{note}}
+ */
+export const searchIndexes: Record<string, SearchIndex> = {
+  posts: defineIndex({
+    model: "Post",
+    fields: ["name"],
+    load: (ids) => db.post.findMany({ where: ids ? { id: { in: ids.map(Number) } } : undefined }),
+    toDocument: (post) => ({ id: String(post.id), title: post.name, href: "/posts" }),
+  }),
+};
+"#;
+
+const EMPTY_INDEXES: &str = r#"// import { db } from "@/server/db";
+// import { defineIndex } from "./types";
+import type { SearchIndex } from "./types";
+
+/**
+ * The searchable models, by index name. {{note}}
+ *
+ * @example
+ * products: defineIndex({
+ *   model: "Product",
+ *   fields: ["name", "description"],
+ *   load: (ids) => db.product.findMany({ where: ids ? { id: { in: ids } } : undefined }),
+ *   toDocument: (product) => ({
+ *     id: product.id,
+ *     title: product.name,
+ *     description: product.description ?? undefined,
+ *     href: `/products/${product.id}`,
+ *   }),
+ * }),
+ */
+export const searchIndexes: Record<string, SearchIndex> = {};
+"#;
+
+const HEADER_TEST_MOCK: &str = r#"
+// The search palette needs tRPC; it only adds a button here
+vi.mock("@/components/search/SearchPalette", () => ({
+  SearchPalette: () => null,
+}));
+"#;
+
+const COMPOSE_MEILISEARCH_SERVICE: &str = r#"
+  meilisearch:
+    image: getmeili/meilisearch:v1.15
+    restart: unless-stopped
+    environment:
+      MEILI_ENV: development
+      MEILI_MASTER_KEY: ${MEILISEARCH_API_KEY:-dev-meilisearch-master-key}
+    ports:
+      - "${MEILISEARCH_PORT:-7700}:7700"
+    volumes:
+      - meilisearch_data:/meili_data
+"#;
+
+const COMPOSE_TYPESENSE_SERVICE: &str = r#"
+  typesense:
+    image: typesense/typesense:29.0
+    restart: unless-stopped
+    command: --data-dir /data --api-key=${TYPESENSE_API_KEY:-dev-typesense-api-key}
+    ports:
+      - "${TYPESENSE_PORT:-8108}:8108"
+    volumes:
+      - typesense_data:/data
+"#;
+
+const MESSAGES_EN: &str = r#"{
+  "search": {
+    "open": "Search",
+    "shortcut": "⌘K",
+    "placeholder": "Search...",
+    "hint": "Type to search pages and content.",
+    "searching": "Searching...",
+    "empty": "No results."
+  }
+}"#;
+
+const MESSAGES_DE: &str = r#"{
+  "search": {
+    "open": "Suchen",
+    "shortcut": "⌘K",
+    "placeholder": "Suchen...",
+    "hint": "Tippen Sie, um Seiten und Inhalte zu durchsuchen.",
+    "searching": "Wird gesucht...",
+    "empty": "Keine Ergebnisse."
+  }
+}"#;
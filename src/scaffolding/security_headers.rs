@@ -0,0 +1,274 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use crate::utils::fs::{self, update_file, write_file};
+
+const HEADERS_PATH: &str = "src/lib/security-headers.js";
+const PROXY_PATH: &str = "src/proxy.ts";
+const LAYOUT_PATH: &str = "src/app/layout.tsx";
+const PROVIDER_PATH: &str = "src/app/_components/ThemeProvider.tsx";
+
+/// Whether the project sends the generated security headers
+pub fn is_configured(project_path: &str) -> bool {
+    fs::path_exists(Path::new(project_path).join(HEADERS_PATH))
+}
+
+/// Send security headers: HSTS, framing, sniffing, and referrer rules from next.config.js
+/// `headers()`, and a Content-Security-Policy with a per-request nonce from `src/proxy.ts`,
+/// passed on to the layout's inline theme script. Relaxed for `next dev`.
+pub fn scaffold(project_path: &str) -> Result<()> {
+    write_file(project_path, HEADERS_PATH, SECURITY_HEADERS)?;
+    write_file(
+        project_path,
+        "src/lib/security-headers.test.ts",
+        SECURITY_HEADERS_TEST,
+    )?;
+    write_file(project_path, "src/lib/nonce.ts", NONCE_HELPER)?;
+    configure_next(project_path)?;
+    patch_proxy(project_path)?;
+    patch_layout(project_path)
+}
+
+/// Pass the nonce to next-themes' inline script; a no-op without the ThemeProvider
+pub fn patch_layout(project_path: &str) -> Result<()> {
+    let layout_path = Path::new(project_path).join(LAYOUT_PATH);
+    let content = fs::read_to_string(&layout_path)
+        .with_context(|| format!("Failed to read {}", LAYOUT_PATH))?;
+
+    if !content.contains("        <ThemeProvider>\n") {
+        return Ok(());
+    }
+
+    // Reading the request headers needs an async layout, where next-intl's hooks don't work
+    let patched = content
+        .replacen(
+            "import { NextIntlClientProvider, useLocale } from \"next-intl\";\n",
+            "import { NextIntlClientProvider } from \"next-intl\";\nimport { getLocale } from \"next-intl/server\";\nimport { getNonce } from \"@/lib/nonce\";\n",
+            1,
+        )
+        .replacen("export default function RootLayout(", "export default async function RootLayout(", 1)
+        .replacen(
+            "  const locale = useLocale();\n",
+            "  const locale = await getLocale();\n  const nonce = await getNonce();\n",
+            1,
+        )
+        .replacen("        <ThemeProvider>\n", "        <ThemeProvider nonce={nonce}>\n", 1);
+    if !patched.contains("const nonce = await getNonce();") {
+        anyhow::bail!("Could not find RootLayout in {}; pass `nonce={{await getNonce()}}` to the ThemeProvider manually", LAYOUT_PATH);
+    }
+    update_file(project_path, LAYOUT_PATH, &patched)?;
+
+    let provider_path = Path::new(project_path).join(PROVIDER_PATH);
+    let provider = fs::read_to_string(&provider_path)
+        .with_context(|| format!("Failed to read {}", PROVIDER_PATH))?;
+    if provider.contains("nonce") {
+        return Ok(());
+    }
+    let patched = provider
+        .replacen(
+            "export function ThemeProvider({ children }: { children: React.ReactNode }) {",
+            "export function ThemeProvider({ children, nonce }: { children: React.ReactNode; nonce?: string }) {",
+            1,
+        )
+        .replacen("      disableTransitionOnChange\n", "      disableTransitionOnChange\n      nonce={nonce}\n", 1);
+    update_file(project_path, PROVIDER_PATH, &patched)
+}
+
+/// Add `headers()` to next.config.js
+fn configure_next(project_path: &str) -> Result<()> {
+    let config_path = Path::new(project_path).join("next.config.js");
+    let content = fs::read_to_string(&config_path).context("Failed to read next.config.js")?;
+
+    if content.contains("securityHeaders") {
+        return Ok(());
+    }
+
+    // After the env import, and the image hosts when configured
+    let anchor = [
+        "import { remotePatterns } from \"./src/lib/image-hosts.js\";\n",
+        "import \"./src/env.js\";\n",
+    ]
+    .into_iter()
+    .find(|line| content.contains(line))
+    .context("Could not find the env import in next.config.js")?;
+    let patched = content.replacen(
+        anchor,
+        &format!(
+            "{}import {{ securityHeaders }} from \"./src/lib/security-headers.js\";\n",
+            anchor
+        ),
+        1,
+    );
+    // Empty in a new project unless the Dockerfile or image steps added settings
+    let patched = if patched.contains("const config = {};") {
+        patched.replacen(
+            "const config = {};",
+            &format!("const config = {{\n{}}};", NEXT_HEADERS),
+            1,
+        )
+    } else {
+        patched.replacen(
+            "const config = {\n",
+            &format!("const config = {{\n{}", NEXT_HEADERS),
+            1,
+        )
+    };
+    update_file(project_path, "next.config.js", &patched)
+}
+
+/// Wrap the locale proxy in one that adds the nonce and the CSP to every page
+fn patch_proxy(project_path: &str) -> Result<()> {
+    let proxy_path = Path::new(project_path).join(PROXY_PATH);
+    let content = fs::read_to_string(&proxy_path)
+        .with_context(|| format!("Failed to read {}", PROXY_PATH))?;
+
+    if content.contains("contentSecurityPolicy") {
+        return Ok(());
+    }
+    let Some(config_start) = content.find("export const config = {") else {
+        anyhow::bail!("Could not find the proxy config in {}", PROXY_PATH);
+    };
+
+    let (proxy, config) = content.split_at(config_start);
+    let proxy = proxy
+        .replacen(
+            "import { isLocale, LOCALE_COOKIE, negotiateLocale } from \"@/i18n/locale\";\n",
+            "import { isLocale, LOCALE_COOKIE, negotiateLocale } from \"@/i18n/locale\";\nimport { contentSecurityPolicy } from \"@/lib/security-headers\";\n",
+            1,
+        )
+        .replacen("export function proxy(request: NextRequest) {\n", "function routeLocale(request: NextRequest) {\n", 1)
+        // The nonce travels in the request headers, so every response passes them on
+        .replace("return NextResponse.next();", "return NextResponse.next({ request: { headers: request.headers } });");
+    if !proxy.contains("function routeLocale(") {
+        anyhow::bail!("Could not find the locale proxy in {}; set the CSP from contentSecurityPolicy() manually", PROXY_PATH);
+    }
+
+    update_file(
+        project_path,
+        PROXY_PATH,
+        &format!("{}{}\n{}", proxy, PROXY_WITH_CSP, config),
+    )
+}
+
+// ============================================================================
+// Embedded Templates
+// ============================================================================
+
+const NEXT_HEADERS: &str = r#"  // Framing, sniffing, and HSTS rules for every route; the CSP comes from src/proxy.ts
+  async headers() {
+    return [{ source: "/:path*", headers: securityHeaders }];
+  },
+"#;
+
+const PROXY_WITH_CSP: &str = r#"/**
+ * Send a Content-Security-Policy with a fresh nonce for every page. Next.js finds the nonce
+ * in the request's CSP header and adds it to its own scripts; `getNonce()` from
+ * src/lib/nonce.ts hands it to server components for theirs.
+ */
+export async function proxy(request: NextRequest) {
+  const nonce = btoa(crypto.randomUUID());
+  const policy = contentSecurityPolicy(nonce);
+  request.headers.set("x-nonce", nonce);
+  request.headers.set("Content-Security-Policy", policy);
+
+  const response = await routeLocale(request);
+  response.headers.set("Content-Security-Policy", policy);
+  return response;
+}
+"#;
+
+const SECURITY_HEADERS: &str = r#"/**
+ * Security headers: the static ones next.config.js sends with every response, and the
+ * Content-Security-Policy src/proxy.ts sends with every page. `next dev` gets what hot
+ * reloading and React's debugging need, and no HSTS, which browsers would keep for localhost.
+ *
+ * Third-party scripts load through the nonce ('strict-dynamic'); add the hosts they or the
+ * browser call to `connect-src`, e.g. an analytics endpoint.
+ */
+
+const isDev = process.env.NODE_ENV === "development";
+
+/** @type {{ key: string; value: string }[]} */
+export const securityHeaders = [
+  { key: "X-Frame-Options", value: "DENY" },
+  { key: "X-Content-Type-Options", value: "nosniff" },
+  { key: "Referrer-Policy", value: "strict-origin-when-cross-origin" },
+  { key: "Permissions-Policy", value: "camera=(), microphone=(), geolocation=(), browsing-topics=()" },
+  ...(isDev ? [] : [{ key: "Strict-Transport-Security", value: "max-age=63072000; includeSubDomains" }]),
+];
+
+/** @param {string} nonce @returns {string} */
+export function contentSecurityPolicy(nonce) {
+  /** @type {Record<string, string[]>} */
+  const directives = {
+    "default-src": ["'self'"],
+    // Nonced scripts may load Next.js's chunks; React uses eval for its debugging in development
+    "script-src": ["'self'", `'nonce-${nonce}'`, "'strict-dynamic'", ...(isDev ? ["'unsafe-eval'"] : [])],
+    // React's style props and next/font set inline styles
+    "style-src": ["'self'", "'unsafe-inline'"],
+    // Avatars from the sign-in providers; next/image serves the rest from 'self'
+    "img-src": ["'self'", "blob:", "data:", "https:"],
+    "font-src": ["'self'", "data:"],
+    // Hot reloading in development
+    "connect-src": ["'self'", ...(isDev ? ["ws:"] : [])],
+    "object-src": ["'none'"],
+    "base-uri": ["'self'"],
+    "form-action": ["'self'"],
+    "frame-ancestors": ["'none'"],
+  };
+
+  const policy = Object.entries(directives).map(([name, sources]) => `${name} ${sources.join(" ")}`);
+  if (!isDev) {
+    policy.push("upgrade-insecure-requests");
+  }
+  return policy.join("; ");
+}
+"#;
+
+const SECURITY_HEADERS_TEST: &str = r#"// @vitest-environment node
+import { afterEach, describe, expect, it, vi } from "vitest";
+
+// isDev is read when the module loads, so every case imports it fresh
+async function loadHeaders(nodeEnv: string) {
+  vi.resetModules();
+  vi.stubEnv("NODE_ENV", nodeEnv);
+  return import("./security-headers.js");
+}
+
+afterEach(() => {
+  vi.unstubAllEnvs();
+});
+
+describe("contentSecurityPolicy", () => {
+  it("only runs scripts carrying the nonce in production", async () => {
+    const { contentSecurityPolicy, securityHeaders } = await loadHeaders("production");
+    const policy = contentSecurityPolicy("abc123");
+
+    expect(policy).toContain("script-src 'self' 'nonce-abc123' 'strict-dynamic';");
+    expect(policy).toContain("frame-ancestors 'none'");
+    expect(policy).not.toContain("unsafe-eval");
+    expect(securityHeaders.map((header) => header.key)).toContain("Strict-Transport-Security");
+  });
+
+  it("relaxes what next dev needs", async () => {
+    const { contentSecurityPolicy, securityHeaders } = await loadHeaders("development");
+    const policy = contentSecurityPolicy("abc123");
+
+    expect(policy).toContain("'unsafe-eval'");
+    expect(policy).toContain("connect-src 'self' ws:");
+    expect(policy).not.toContain("upgrade-insecure-requests");
+    expect(securityHeaders.map((header) => header.key)).not.toContain("Strict-Transport-Security");
+  });
+});
+"#;
+
+const NONCE_HELPER: &str = r#"import { headers } from "next/headers";
+
+/**
+ * The CSP nonce src/proxy.ts made for this request, for inline scripts and
+ * `<Script nonce={await getNonce()}>` in server components. Reading it renders the page per request.
+ */
+export async function getNonce() {
+  return (await headers()).get("x-nonce") ?? undefined;
+}
+"#;
@@ -0,0 +1,143 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use crate::cli::{AuthProvider, SessionStrategy};
+use crate::scaffolding::options::ScaffoldOptions;
+use crate::scaffolding::resource;
+use crate::utils::fs::{self, update_file, write_file};
+use crate::utils::npm;
+
+const SEED_SCRIPTS: &[(&str, &str)] = &[("db:seed", "prisma db seed")];
+
+const SEED_DEV_DEPENDENCIES: &[(&str, &str)] =
+    &[("tsx", "^4.21.0"), ("@faker-js/faker", "^10.3.0")];
+
+/// Scaffold `prisma/seed.ts` with a demo user for the chosen auth provider and
+/// sample rows for the example posts and the project's own models
+pub fn scaffold(project_path: &str, options: &ScaffoldOptions) -> Result<()> {
+    let demo_user = match (options.auth, options.session) {
+        (AuthProvider::BetterAuth, _) => SEED_USER_BETTER_AUTH,
+        (AuthProvider::NextAuth, SessionStrategy::Jwt) => SEED_USER_NEXT_AUTH,
+        (AuthProvider::NextAuth, SessionStrategy::Database) => SEED_USER_NEXT_AUTH_DATABASE,
+    };
+    let mut samples = if options.example {
+        SEED_POSTS.to_string()
+    } else {
+        String::new()
+    };
+    for model in &options.models {
+        samples.push_str(&resource::seed_block(&model.name, &model.fields)?);
+    }
+
+    let content = SEED_TEMPLATE
+        .replace("{{auth_import}}", demo_user.0)
+        .replace("{{seed_user}}", demo_user.1)
+        .replace("{{seed_posts}}", &samples);
+    write_file(project_path, "prisma/seed.ts", &content)?;
+
+    register_seed(project_path)?;
+    npm::add_scripts(project_path, SEED_SCRIPTS)?;
+    npm::add_dependencies(project_path, &[], SEED_DEV_DEPENDENCIES)?;
+
+    Ok(())
+}
+
+/// Point `prisma db seed` at the seed script in prisma.config.ts
+fn register_seed(project_path: &str) -> Result<()> {
+    let config_path = Path::new(project_path).join("prisma.config.ts");
+    let content = fs::read_to_string(&config_path).context("Failed to read prisma.config.ts")?;
+
+    if content.contains("seed:") {
+        return Ok(());
+    }
+
+    let anchor = "    path: \"prisma/migrations\",\n";
+    let patched = content.replacen(
+        anchor,
+        &format!("{}    seed: \"tsx prisma/seed.ts\",\n", anchor),
+        1,
+    );
+    update_file(project_path, "prisma.config.ts", &patched)?;
+
+    Ok(())
+}
+
+// ============================================================================
+// Embedded Templates
+// ============================================================================
+
+const SEED_TEMPLATE: &str = r#"import "./env";
+
+import { faker } from "@faker-js/faker";
+import { db } from "../src/server/db";
+{{auth_import}}
+// Stable output between runs
+faker.seed(42);
+
+const DEMO_EMAIL = "demo@example.com";
+const DEMO_PASSWORD = "demo-password-123";
+
+async function main() {
+{{seed_user}}{{seed_posts}}}
+
+main()
+  .then(async () => {
+    await db.$disconnect();
+  })
+  .catch(async (error) => {
+    console.error(error);
+    await db.$disconnect();
+    process.exit(1);
+  });
+"#;
+
+/// Better Auth hashes the password itself, so the user goes through its sign-up API
+const SEED_USER_BETTER_AUTH: (&str, &str) = (
+    "import { auth } from \"../src/server/auth\";\n",
+    r#"  const existing = await db.user.findUnique({ where: { email: DEMO_EMAIL } });
+  if (!existing) {
+    await auth.api.signUpEmail({
+      body: { email: DEMO_EMAIL, password: DEMO_PASSWORD, name: faker.person.fullName() },
+    });
+  }
+  console.log(`Demo user: ${DEMO_EMAIL} / ${DEMO_PASSWORD}`);
+"#,
+);
+
+/// The NextAuth credentials provider doesn't verify passwords yet (see src/server/auth.ts),
+/// so the demo user only needs to exist
+const SEED_USER_NEXT_AUTH: (&str, &str) = (
+    "",
+    r#"  await db.user.upsert({
+    where: { email: DEMO_EMAIL },
+    update: {},
+    create: { email: DEMO_EMAIL, name: faker.person.fullName(), emailVerified: new Date() },
+  });
+  console.log(`Demo user: ${DEMO_EMAIL} (sign in with the credentials provider)`);
+"#,
+);
+
+/// Database sessions rule out the credentials provider, so the demo user can't sign in;
+/// it is there for data that needs an owner
+const SEED_USER_NEXT_AUTH_DATABASE: (&str, &str) = (
+    "",
+    r#"  await db.user.upsert({
+    where: { email: DEMO_EMAIL },
+    update: {},
+    create: { email: DEMO_EMAIL, name: faker.person.fullName(), emailVerified: new Date() },
+  });
+  console.log(`Demo user: ${DEMO_EMAIL} (sign-in is through GitHub with database sessions)`);
+"#,
+);
+
+const SEED_POSTS: &str = r#"
+  if ((await db.post.count()) === 0) {
+    await db.post.createMany({
+      data: Array.from({ length: 10 }, () => ({
+        name: faker.lorem.sentence({ min: 3, max: 8 }),
+        createdAt: faker.date.recent({ days: 30 }),
+      })),
+    });
+    console.log("Created 10 sample posts");
+  }
+"#;
@@ -0,0 +1,65 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use crate::utils::fs::{self, update_file, write_file};
+
+const LAYOUT_PATH: &str = "src/app/layout.tsx";
+const STATIC_OG_IMAGE: &str =
+    "    images: [{ url: \"/og-image.png\", width: 1200, height: 630 }],\n";
+
+/// Render the OpenGraph image from the site config instead of serving `public/og-image.png`
+pub fn scaffold(project_path: &str) -> Result<()> {
+    write_file(project_path, "src/app/opengraph-image.tsx", OPENGRAPH_IMAGE)?;
+
+    // The file route takes precedence over metadata images, so drop the static one
+    let layout = fs::read_to_string(Path::new(project_path).join(LAYOUT_PATH))
+        .with_context(|| format!("Failed to read {}", LAYOUT_PATH))?;
+    if layout.contains(STATIC_OG_IMAGE) {
+        update_file(
+            project_path,
+            LAYOUT_PATH,
+            &layout.replacen(STATIC_OG_IMAGE, "", 1),
+        )?;
+    }
+
+    Ok(())
+}
+
+// ============================================================================
+// Embedded Templates
+// ============================================================================
+
+const OPENGRAPH_IMAGE: &str = r##"import { ImageResponse } from "next/og";
+import { siteConfig } from "@/lib/site";
+
+export const alt = siteConfig.name;
+export const size = { width: 1200, height: 630 };
+export const contentType = "image/png";
+
+/** Social card for every page; X falls back to it when there is no twitter-image */
+export default function OpengraphImage() {
+  return new ImageResponse(
+    (
+      <div
+        style={{
+          width: "100%",
+          height: "100%",
+          display: "flex",
+          flexDirection: "column",
+          justifyContent: "center",
+          padding: "80px",
+          background: "linear-gradient(135deg, #0a0a0a 0%, #27272a 100%)",
+          color: "#fafafa",
+        }}
+      >
+        <div style={{ fontSize: 80, fontWeight: 700, letterSpacing: "-0.02em" }}>{siteConfig.name}</div>
+        <div style={{ fontSize: 36, marginTop: 24, color: "#a1a1aa" }}>{siteConfig.description}</div>
+        <div style={{ fontSize: 24, marginTop: "auto", color: "#71717a" }}>
+          {new URL(siteConfig.url).host}
+        </div>
+      </div>
+    ),
+    size,
+  );
+}
+"##;
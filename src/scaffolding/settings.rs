@@ -0,0 +1,608 @@
+use anyhow::Result;
+
+use crate::cli::AuthProvider;
+use crate::scaffolding::i18n;
+use crate::scaffolding::trpc;
+use crate::utils::fs::write_file;
+
+/// Scaffold the `/settings` page with its forms and the `user` tRPC router behind them.
+/// Only Better Auth stores passwords, so only its projects get the password form.
+pub fn scaffold(project_path: &str, auth: AuthProvider) -> Result<()> {
+    let password = auth == AuthProvider::BetterAuth;
+    let pick = |with_password: &'static str, without: &'static str| {
+        if password {
+            with_password
+        } else {
+            without
+        }
+    };
+
+    // Inputs shared by the router and the forms
+    let schemas = USER_SCHEMAS.replace("{{password_schema}}", pick(PASSWORD_SCHEMA, ""));
+    write_file(project_path, "src/schemas/user.ts", &schemas)?;
+
+    let router = USER_ROUTER
+        .replace("{{password_imports}}", pick(PASSWORD_ROUTER_IMPORTS, ""))
+        .replace(
+            "{{schema_imports}}",
+            pick(
+                "changePasswordSchema, updateEmailSchema, updateProfileSchema",
+                "updateEmailSchema, updateProfileSchema",
+            ),
+        )
+        .replace("{{email_note}}", pick("", EMAIL_NOTE_NEXT_AUTH))
+        .replace("{{change_password}}", pick(PASSWORD_PROCEDURE, ""));
+    write_file(project_path, "src/server/api/routers/user.ts", &router)?;
+    write_file(
+        project_path,
+        "src/server/api/routers/user.test.ts",
+        USER_ROUTER_TEST,
+    )?;
+    trpc::register_router(
+        project_path,
+        "user",
+        "userRouter",
+        "@/server/api/routers/user",
+    )?;
+
+    // Page and forms
+    let page = SETTINGS_PAGE
+        .replace(
+            "{{password_import}}",
+            pick(
+                "import { PasswordForm } from \"./_components/PasswordForm\";\n",
+                "",
+            ),
+        )
+        .replace(
+            "{{password_section}}",
+            pick(PASSWORD_SECTION, NO_PASSWORD_SECTION),
+        );
+    write_file(project_path, "src/app/settings/page.tsx", &page)?;
+    write_file(
+        project_path,
+        "src/app/settings/_components/Field.tsx",
+        FIELD,
+    )?;
+    write_file(
+        project_path,
+        "src/app/settings/_components/ProfileForm.tsx",
+        PROFILE_FORM,
+    )?;
+    write_file(
+        project_path,
+        "src/app/settings/_components/EmailForm.tsx",
+        EMAIL_FORM,
+    )?;
+    if password {
+        write_file(
+            project_path,
+            "src/app/settings/_components/PasswordForm.tsx",
+            PASSWORD_FORM,
+        )?;
+    }
+
+    i18n::merge_translations(project_path, "messages/en.json", MESSAGES_EN)?;
+    i18n::merge_translations(project_path, "messages/de.json", MESSAGES_DE)?;
+    if password {
+        i18n::merge_translations(project_path, "messages/en.json", PASSWORD_MESSAGES_EN)?;
+        i18n::merge_translations(project_path, "messages/de.json", PASSWORD_MESSAGES_DE)?;
+    }
+
+    Ok(())
+}
+
+// ============================================================================
+// Embedded Templates
+// ============================================================================
+
+const USER_SCHEMAS: &str = r#"import { z } from "zod";
+
+// Inputs of the `user` router, shared with the settings forms so both validate the same way
+
+export const updateProfileSchema = z.object({
+  name: z.string().trim().min(1, "Name is required").max(100),
+  image: z.union([z.url("Enter a valid URL"), z.literal("")]),
+});
+
+export const updateEmailSchema = z.object({
+  email: z.email("Enter a valid email address"),
+});
+{{password_schema}}
+export type UpdateProfileInput = z.infer<typeof updateProfileSchema>;
+export type UpdateEmailInput = z.infer<typeof updateEmailSchema>;
+"#;
+
+const PASSWORD_SCHEMA: &str = r#"
+/** Better Auth's default length limits */
+export const changePasswordSchema = z
+  .object({
+    currentPassword: z.string().min(1, "Enter your current password"),
+    newPassword: z.string().min(8, "Use at least 8 characters").max(128),
+    confirmPassword: z.string(),
+  })
+  .refine((input) => input.newPassword === input.confirmPassword, {
+    message: "The passwords don't match",
+    path: ["confirmPassword"],
+  });
+
+export type ChangePasswordInput = z.infer<typeof changePasswordSchema>;
+"#;
+
+const USER_ROUTER: &str = r#"import { TRPCError } from "@trpc/server";
+{{password_imports}}import { {{schema_imports}} } from "@/schemas/user";
+import { createTRPCRouter, protectedProcedure } from "@/server/api/trpc";
+
+export const userRouter = createTRPCRouter({
+  updateProfile: protectedProcedure.input(updateProfileSchema).mutation(({ ctx, input }) => {
+    return ctx.db.user.update({
+      where: { id: ctx.userId },
+      data: { name: input.name, image: input.image || null },
+      select: { id: true, name: true, image: true },
+    });
+  }),
+
+  // The new address is unverified until your verification flow confirms it{{email_note}}
+  updateEmail: protectedProcedure.input(updateEmailSchema).mutation(async ({ ctx, input }) => {
+    const taken = await ctx.db.user.findFirst({
+      where: { email: input.email, NOT: { id: ctx.userId } },
+      select: { id: true },
+    });
+    if (taken) {
+      throw new TRPCError({ code: "CONFLICT", message: "This email address is already in use" });
+    }
+
+    return ctx.db.user.update({
+      where: { id: ctx.userId },
+      data: { email: input.email, emailVerified: null },
+      select: { id: true, email: true },
+    });
+  }),
+{{change_password}}});
+"#;
+
+const EMAIL_NOTE_NEXT_AUTH: &str = r#";
+  // with JWT sessions, the session keeps the old address until the next sign-in"#;
+
+const PASSWORD_ROUTER_IMPORTS: &str = r#"import { APIError } from "better-auth/api";
+import { auth } from "@/server/auth";
+"#;
+
+const PASSWORD_PROCEDURE: &str = r#"
+  // Better Auth checks the current password. Other sessions stay signed in: revoking them
+  // issues a new session cookie, which needs the nextCookies() plugin in src/server/auth.ts.
+  changePassword: protectedProcedure.input(changePasswordSchema).mutation(async ({ ctx, input }) => {
+    try {
+      await auth.api.changePassword({
+        body: { currentPassword: input.currentPassword, newPassword: input.newPassword },
+        headers: ctx.headers,
+      });
+    } catch (error) {
+      // e.g. a wrong current password, or an account that only signs in with OAuth
+      if (error instanceof APIError) {
+        throw new TRPCError({ code: "BAD_REQUEST", message: error.body?.message ?? error.message, cause: error });
+      }
+      throw error;
+    }
+
+    return { changed: true };
+  }),
+"#;
+
+const USER_ROUTER_TEST: &str = r#"import { beforeEach, describe, expect, it, vi } from "vitest";
+import { createCallerFactory, createTRPCContext } from "@/server/api/trpc";
+import { userRouter } from "@/server/api/routers/user";
+import { db } from "@/server/db";
+import { getSession } from "@/server/session";
+
+// Keep the test off the real database and auth provider
+vi.mock("@/server/db", () => ({
+  db: { user: { findFirst: vi.fn(), update: vi.fn() } },
+}));
+vi.mock("@/server/session", () => ({
+  getSession: vi.fn(),
+}));
+vi.mock("@/server/auth", () => ({
+  auth: { api: { changePassword: vi.fn() } },
+}));
+
+const createCaller = createCallerFactory(userRouter);
+
+function caller() {
+  return createCaller(() => createTRPCContext({ headers: new Headers() }));
+}
+
+describe("user router", () => {
+  beforeEach(() => {
+    vi.resetAllMocks();
+    vi.mocked(getSession).mockResolvedValue({ user: { id: "user_1" } } as never);
+  });
+
+  it("rejects anonymous callers", async () => {
+    vi.mocked(getSession).mockResolvedValue(null);
+
+    await expect(caller().updateProfile({ name: "Demo User", image: "" })).rejects.toMatchObject({
+      code: "UNAUTHORIZED",
+    });
+  });
+
+  it("validates the profile", async () => {
+    await expect(caller().updateProfile({ name: " ", image: "not a url" })).rejects.toMatchObject({
+      code: "BAD_REQUEST",
+    });
+    expect(db.user.update).not.toHaveBeenCalled();
+  });
+
+  it("clears an empty avatar", async () => {
+    await caller().updateProfile({ name: "Demo User", image: "" });
+
+    expect(db.user.update).toHaveBeenCalledWith(
+      expect.objectContaining({ where: { id: "user_1" }, data: { name: "Demo User", image: null } }),
+    );
+  });
+
+  it("refuses an email address another user has", async () => {
+    vi.mocked(db.user.findFirst).mockResolvedValue({ id: "user_2" } as never);
+
+    await expect(caller().updateEmail({ email: "taken@example.com" })).rejects.toMatchObject({
+      code: "CONFLICT",
+    });
+    expect(db.user.update).not.toHaveBeenCalled();
+  });
+});
+"#;
+
+const SETTINGS_PAGE: &str = r#"import { redirect } from "next/navigation";
+import { getTranslations } from "next-intl/server";
+
+import { Header } from "@/app/_components/Header";
+import { getSession, SIGN_IN_PATH } from "@/server/session";
+import { api } from "@/trpc/server";
+import { EmailForm } from "./_components/EmailForm";
+{{password_import}}import { ProfileForm } from "./_components/ProfileForm";
+
+export default async function SettingsPage() {
+  const session = await getSession();
+  if (!session) {
+    redirect(SIGN_IN_PATH);
+  }
+
+  const [t, me] = await Promise.all([getTranslations("settings"), api.auth.me()]);
+
+  return (
+    <div className="min-h-screen flex flex-col bg-background">
+      <Header />
+
+      <main className="flex-1 max-w-xl mx-auto px-4 sm:px-6 lg:px-8 py-8 w-full flex flex-col gap-8">
+        <div>
+          <h1 className="text-2xl font-semibold">{t("title")}</h1>
+          <p className="mt-1 text-sm text-muted-foreground">{t("description")}</p>
+        </div>
+
+        <section className="rounded-xl border border-border p-6">
+          <h2 className="text-lg font-semibold">{t("profile.title")}</h2>
+          <p className="mt-1 mb-4 text-sm text-muted-foreground">{t("profile.description")}</p>
+          <ProfileForm name={me?.name ?? ""} image={me?.image ?? ""} />
+        </section>
+
+        <section className="rounded-xl border border-border p-6">
+          <h2 className="text-lg font-semibold">{t("email.title")}</h2>
+          <p className="mt-1 mb-4 text-sm text-muted-foreground">{t("email.description")}</p>
+          <EmailForm email={me?.email ?? ""} />
+        </section>
+{{password_section}}      </main>
+    </div>
+  );
+}
+"#;
+
+const PASSWORD_SECTION: &str = r#"
+        <section className="rounded-xl border border-border p-6">
+          <h2 className="text-lg font-semibold">{t("password.title")}</h2>
+          <p className="mt-1 mb-4 text-sm text-muted-foreground">{t("password.description")}</p>
+          <PasswordForm />
+        </section>
+"#;
+
+const NO_PASSWORD_SECTION: &str = r#"
+        {/* No password form: the credentials provider in src/server/auth.ts doesn't store
+            passwords yet. Add one here once it verifies hashes. */}
+"#;
+
+const FIELD: &str = r#"import type { InputHTMLAttributes } from "react";
+
+type FieldProps = InputHTMLAttributes<HTMLInputElement> & {
+  label: string;
+  hint?: string;
+  error?: string;
+};
+
+/** Labelled input of the settings forms, with its error or hint below it */
+export function Field({ label, hint, error, ...props }: FieldProps) {
+  return (
+    <label className="grid gap-1.5 text-sm font-medium">
+      {label}
+      <input
+        {...props}
+        aria-invalid={error ? true : undefined}
+        className="rounded-lg border border-border bg-background px-4 py-2 font-normal"
+      />
+      {error ? (
+        <span className="text-xs font-normal text-destructive">{error}</span>
+      ) : (
+        hint && <span className="text-xs font-normal text-muted-foreground">{hint}</span>
+      )}
+    </label>
+  );
+}
+"#;
+
+const PROFILE_FORM: &str = r#""use client";
+
+import { useState } from "react";
+import { useRouter } from "next/navigation";
+import { useTranslations } from "next-intl";
+
+import { updateProfileSchema } from "@/schemas/user";
+import { api } from "@/trpc/react";
+import { Field } from "./Field";
+
+export function ProfileForm(saved: { name: string; image: string }) {
+  const t = useTranslations("settings");
+  const router = useRouter();
+  const [name, setName] = useState(saved.name);
+  const [image, setImage] = useState(saved.image);
+  const parsed = updateProfileSchema.safeParse({ name, image });
+  const isDirty = name !== saved.name || image !== saved.image;
+  const issue = (field: string) =>
+    isDirty ? parsed.error?.issues.find((issue) => issue.path[0] === field)?.message : undefined;
+
+  const update = api.user.updateProfile.useMutation({
+    // Re-render the Header and this page with the new name and avatar
+    onSuccess: () => router.refresh(),
+  });
+
+  return (
+    <form
+      onSubmit={(e) => {
+        e.preventDefault();
+        if (parsed.success) {
+          update.mutate(parsed.data);
+        }
+      }}
+      className="grid gap-4"
+    >
+      <Field
+        label={t("profile.name")}
+        autoComplete="name"
+        value={name}
+        onChange={(e) => setName(e.target.value)}
+        error={issue("name")}
+      />
+      <Field
+        label={t("profile.image")}
+        type="url"
+        placeholder="https://"
+        value={image}
+        onChange={(e) => setImage(e.target.value)}
+        hint={t("profile.imageHint")}
+        error={issue("image")}
+      />
+
+      <div className="flex items-center gap-3">
+        <button
+          type="submit"
+          disabled={update.isPending || !isDirty || !parsed.success}
+          className="rounded-lg bg-primary px-4 py-2 text-sm font-medium text-primary-foreground disabled:opacity-50"
+        >
+          {update.isPending ? t("saving") : t("save")}
+        </button>
+        {update.error && <span className="text-sm text-destructive">{update.error.message}</span>}
+        {update.isSuccess && !isDirty && <span className="text-sm text-muted-foreground">{t("saved")}</span>}
+      </div>
+    </form>
+  );
+}
+"#;
+
+const EMAIL_FORM: &str = r#""use client";
+
+import { useState } from "react";
+import { useRouter } from "next/navigation";
+import { useTranslations } from "next-intl";
+
+import { updateEmailSchema } from "@/schemas/user";
+import { api } from "@/trpc/react";
+import { Field } from "./Field";
+
+export function EmailForm(saved: { email: string }) {
+  const t = useTranslations("settings");
+  const router = useRouter();
+  const [email, setEmail] = useState(saved.email);
+  const parsed = updateEmailSchema.safeParse({ email });
+  const isDirty = email !== saved.email;
+
+  const update = api.user.updateEmail.useMutation({
+    onSuccess: () => router.refresh(),
+  });
+
+  return (
+    <form
+      onSubmit={(e) => {
+        e.preventDefault();
+        if (parsed.success) {
+          update.mutate(parsed.data);
+        }
+      }}
+      className="grid gap-4"
+    >
+      <Field
+        label={t("email.email")}
+        type="email"
+        autoComplete="email"
+        value={email}
+        onChange={(e) => setEmail(e.target.value)}
+        error={isDirty ? parsed.error?.issues[0]?.message : undefined}
+      />
+
+      <div className="flex items-center gap-3">
+        <button
+          type="submit"
+          disabled={update.isPending || !isDirty || !parsed.success}
+          className="rounded-lg bg-primary px-4 py-2 text-sm font-medium text-primary-foreground disabled:opacity-50"
+        >
+          {update.isPending ? t("saving") : t("save")}
+        </button>
+        {update.error && <span className="text-sm text-destructive">{update.error.message}</span>}
+        {update.isSuccess && !isDirty && <span className="text-sm text-muted-foreground">{t("saved")}</span>}
+      </div>
+    </form>
+  );
+}
+"#;
+
+const PASSWORD_FORM: &str = r#""use client";
+
+import { type ChangeEvent, useState } from "react";
+import { useTranslations } from "next-intl";
+
+import { type ChangePasswordInput, changePasswordSchema } from "@/schemas/user";
+import { api } from "@/trpc/react";
+import { Field } from "./Field";
+
+const EMPTY: ChangePasswordInput = { currentPassword: "", newPassword: "", confirmPassword: "" };
+
+export function PasswordForm() {
+  const t = useTranslations("settings");
+  const [values, setValues] = useState(EMPTY);
+  const parsed = changePasswordSchema.safeParse(values);
+  const issue = (field: keyof ChangePasswordInput) =>
+    values[field] ? parsed.error?.issues.find((issue) => issue.path[0] === field)?.message : undefined;
+  const set = (field: keyof ChangePasswordInput) => (e: ChangeEvent<HTMLInputElement>) =>
+    setValues({ ...values, [field]: e.target.value });
+
+  const change = api.user.changePassword.useMutation({
+    onSuccess: () => setValues(EMPTY),
+  });
+
+  return (
+    <form
+      onSubmit={(e) => {
+        e.preventDefault();
+        if (parsed.success) {
+          change.mutate(parsed.data);
+        }
+      }}
+      className="grid gap-4"
+    >
+      <Field
+        label={t("password.current")}
+        type="password"
+        autoComplete="current-password"
+        value={values.currentPassword}
+        onChange={set("currentPassword")}
+      />
+      <Field
+        label={t("password.new")}
+        type="password"
+        autoComplete="new-password"
+        value={values.newPassword}
+        onChange={set("newPassword")}
+        error={issue("newPassword")}
+      />
+      <Field
+        label={t("password.confirm")}
+        type="password"
+        autoComplete="new-password"
+        value={values.confirmPassword}
+        onChange={set("confirmPassword")}
+        error={issue("confirmPassword")}
+      />
+
+      <div className="flex items-center gap-3">
+        <button
+          type="submit"
+          disabled={change.isPending || !parsed.success}
+          className="rounded-lg bg-primary px-4 py-2 text-sm font-medium text-primary-foreground disabled:opacity-50"
+        >
+          {change.isPending ? t("saving") : t("password.submit")}
+        </button>
+        {change.error && <span className="text-sm text-destructive">{change.error.message}</span>}
+        {change.isSuccess && <span className="text-sm text-muted-foreground">{t("password.changed")}</span>}
+      </div>
+    </form>
+  );
+}
+"#;
+
+const MESSAGES_EN: &str = r#"{
+  "settings": {
+    "title": "Settings",
+    "description": "Manage your account.",
+    "save": "Save",
+    "saving": "Saving...",
+    "saved": "Saved",
+    "profile": {
+      "title": "Profile",
+      "description": "Your name and avatar as others see them.",
+      "name": "Name",
+      "image": "Avatar URL",
+      "imageHint": "Leave empty to use your initials."
+    },
+    "email": {
+      "title": "Email address",
+      "description": "The address you sign in with.",
+      "email": "Email"
+    }
+  }
+}"#;
+
+const MESSAGES_DE: &str = r#"{
+  "settings": {
+    "title": "Einstellungen",
+    "description": "Verwalten Sie Ihr Konto.",
+    "save": "Speichern",
+    "saving": "Wird gespeichert...",
+    "saved": "Gespeichert",
+    "profile": {
+      "title": "Profil",
+      "description": "Ihr Name und Avatar, wie andere sie sehen.",
+      "name": "Name",
+      "image": "Avatar-URL",
+      "imageHint": "Leer lassen, um Ihre Initialen zu verwenden."
+    },
+    "email": {
+      "title": "E-Mail-Adresse",
+      "description": "Die Adresse, mit der Sie sich anmelden.",
+      "email": "E-Mail"
+    }
+  }
+}"#;
+
+const PASSWORD_MESSAGES_EN: &str = r#"{
+  "settings": {
+    "password": {
+      "title": "Password",
+      "description": "Choose a new password of at least 8 characters.",
+      "current": "Current password",
+      "new": "New password",
+      "confirm": "Confirm new password",
+      "submit": "Change password",
+      "changed": "Password changed"
+    }
+  }
+}"#;
+
+const PASSWORD_MESSAGES_DE: &str = r#"{
+  "settings": {
+    "password": {
+      "title": "Passwort",
+      "description": "Wählen Sie ein neues Passwort mit mindestens 8 Zeichen.",
+      "current": "Aktuelles Passwort",
+      "new": "Neues Passwort",
+      "confirm": "Neues Passwort bestätigen",
+      "submit": "Passwort ändern",
+      "changed": "Passwort geändert"
+    }
+  }
+}"#;
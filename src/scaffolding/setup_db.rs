@@ -0,0 +1,65 @@
+use anyhow::{Context, Result};
+use console::style;
+use std::process::Command;
+
+use crate::cli::DatabaseProvider;
+use crate::scaffolding::options::ScaffoldOptions;
+use crate::utils::lang::Msg;
+use crate::utils::output::glyph;
+
+/// `--setup-db`: install the dependencies, generate the Prisma client, and bring the
+/// database at DATABASE_URL up to the schema, with npm's and Prisma's output streamed to
+/// the terminal. The first step that fails stops the run with the steps that are left.
+pub fn run(project_path: &str, options: &ScaffoldOptions) -> Result<()> {
+    let steps = steps(options);
+    for (index, args) in steps.iter().enumerate() {
+        let command = format!("npm {}", args.join(" "));
+        println!(
+            "  {} {}",
+            style(glyph("→")).cyan(),
+            Msg::RunningSetup.fill(&[&command])
+        );
+
+        let status = Command::new(if cfg!(windows) { "npm.cmd" } else { "npm" })
+            .args(args)
+            .current_dir(project_path)
+            .status()
+            .with_context(|| Msg::SetupNotStarted.fill(&[&command]))?;
+        if !status.success() {
+            let remaining: Vec<String> = steps[index..]
+                .iter()
+                .map(|args| format!("npm {}", args.join(" ")))
+                .collect();
+            let hint = match (index, options.database) {
+                (0, _) => Msg::InstallFailedHint,
+                (_, DatabaseProvider::Sqlite) => Msg::SqliteFailedHint,
+                _ => Msg::DatabaseFailedHint,
+            };
+            anyhow::bail!(
+                "{}\n  {}\n    {}",
+                Msg::SetupFailed.fill(&[&command, &status]),
+                hint,
+                remaining.join("\n    ")
+            );
+        }
+        println!();
+    }
+
+    println!(
+        "  {} {}",
+        style(glyph("✓")).green().bold(),
+        Msg::DatabaseReady
+    );
+    Ok(())
+}
+
+/// npm arguments of each step; the scripts are the ones the next steps list
+fn steps(options: &ScaffoldOptions) -> Vec<Vec<&'static str>> {
+    let schema = if options.migrations {
+        // Applies prisma/migrations/0_init, or creates it when it couldn't be generated
+        vec!["run", "db:migrate", "--", "--name", "init"]
+    } else {
+        vec!["run", "db:push"]
+    };
+    vec![vec!["install"], vec!["run", "db:generate"], schema]
+}
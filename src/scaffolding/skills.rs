@@ -0,0 +1,400 @@
+use anyhow::Result;
+use std::path::Path;
+
+use crate::scaffolding::manifest;
+use crate::scaffolding::search;
+use crate::utils::fs::{self, update_file, write_file};
+
+const SKILLS_DIR: &str = ".claude/skills";
+const INDEX_FILE: &str = "index.md";
+const MARKER_PREFIX: &str = "<!-- t3-mono:";
+
+/// Hashes of the skills t3-mono wrote before they carried a marker, so they still count as unedited
+const UNMARKED_HASHES: &[&str] = &[
+    // ai.md
+    "b309532c9b53072a3f1bb24b64908d02f617829aec80949a627e663a77dbb096",
+    // commandisland.md
+    "c1c690a0b24a4c542e1b5b98da8d5c798b5ee5af1fb2bf35293585a620b79c7c",
+];
+
+/// A Claude skill an extension ships in `.claude/skills/`
+struct Skill {
+    file: &'static str,
+    /// One line for the index
+    summary: &'static str,
+    installed: fn(&Stack) -> bool,
+    render: fn(&Stack) -> String,
+}
+
+const SKILLS: &[Skill] = &[
+    Skill {
+        file: "ai.md",
+        summary: "LLM agents, chunking, and embeddings with the framework in `src/components/ai/`",
+        installed: |stack| stack.ai,
+        render: render_ai,
+    },
+    Skill {
+        file: "commandisland.md",
+        summary: "The CommandIsland: chat tools, AI tables, AI docs, and the split view",
+        installed: |stack| stack.cmd,
+        render: render_cmd,
+    },
+];
+
+/// The parts of the project the skills describe, read from its files so `create` and
+/// every later `add` render the same skills
+struct Stack {
+    ai: bool,
+    cmd: bool,
+    restate: bool,
+    search: bool,
+    logging: bool,
+    chat_stream: Option<&'static str>,
+    /// `src/server/chat/config.ts`, which projects from before it lack
+    cmd_config: bool,
+}
+
+impl Stack {
+    fn read(project_path: &str) -> Self {
+        let exists = |path: &str| fs::path_exists(Path::new(project_path).join(path));
+        let chat_stream = if exists("src/server/chat/socket.ts") {
+            Some("websocket")
+        } else if exists("src/app/api/chat/stream/route.ts") {
+            Some("sse")
+        } else {
+            None
+        };
+        Self {
+            ai: exists("src/components/ai/index.ts"),
+            cmd: exists("src/app/_components/CommandIslandLayout.tsx"),
+            restate: exists("src/server/restate.ts"),
+            search: search::is_installed(project_path),
+            logging: exists("src/server/logger.ts"),
+            chat_stream,
+            cmd_config: exists("src/server/chat/config.ts"),
+        }
+    }
+}
+
+/// What [`sync`] did with a skill file
+#[derive(Debug, PartialEq, Eq)]
+enum Outcome {
+    Unchanged,
+    Written,
+    /// Edited by the user, so the new version went next to it as `<file>.new`
+    Kept,
+}
+
+/// Write the skills of the installed extensions and the index listing them with the
+/// project's own skills. Skills still as generated are regenerated for the current stack;
+/// edited ones are kept, with the new version next to them as `<file>.new`.
+/// Returns the paths of the kept skills.
+pub fn sync(project_path: &str) -> Result<Vec<String>> {
+    let stack = Stack::read(project_path);
+    let mut kept = Vec::new();
+    let mut entries = Vec::new();
+
+    for skill in SKILLS.iter().filter(|skill| (skill.installed)(&stack)) {
+        let path = format!("{}/{}", SKILLS_DIR, skill.file);
+        if write_generated(project_path, &path, &(skill.render)(&stack))? == Outcome::Kept {
+            kept.push(path);
+        }
+        entries.push((skill.file.to_string(), skill.summary.to_string()));
+    }
+    entries.extend(own_skills(project_path)?);
+    if entries.is_empty() {
+        return Ok(kept);
+    }
+
+    let index_path = format!("{}/{}", SKILLS_DIR, INDEX_FILE);
+    if write_generated(project_path, &index_path, &render_index(&entries))? == Outcome::Kept {
+        kept.push(index_path);
+    }
+    point_claude_md_at_skills(project_path)?;
+
+    Ok(kept)
+}
+
+/// Skills the project added itself, described by their frontmatter or first heading
+fn own_skills(project_path: &str) -> Result<Vec<(String, String)>> {
+    let dir = Path::new(project_path).join(SKILLS_DIR);
+    if !fs::path_exists(&dir) {
+        return Ok(Vec::new());
+    }
+
+    let mut skills = Vec::new();
+    for path in fs::list_dir(&dir)? {
+        let Some(file) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        let generated = file == INDEX_FILE || SKILLS.iter().any(|skill| skill.file == file);
+        if generated || !file.ends_with(".md") {
+            continue;
+        }
+        let content = fs::read_to_string(&path)?;
+        // Folded `description: >` values continue on the next lines, so those fall back to the heading
+        let summary = content
+            .lines()
+            .find_map(|line| {
+                let summary = line
+                    .strip_prefix("description:")
+                    .or_else(|| line.strip_prefix("# "))?
+                    .trim();
+                (!summary.is_empty() && !summary.starts_with(['>', '|'])).then_some(summary)
+            })
+            .unwrap_or("Added to this project")
+            .to_string();
+        skills.push((file.to_string(), summary));
+    }
+    Ok(skills)
+}
+
+/// Write `body` with a marker holding its hash, unless the file there was edited since
+fn write_generated(project_path: &str, path: &str, body: &str) -> Result<Outcome> {
+    let content = format!(
+        "{}\n{} {} Regenerated by t3-mono until you edit this file -->\n",
+        body,
+        MARKER_PREFIX,
+        short_hash(body)
+    );
+    let existing = match fs::read_to_string(Path::new(project_path).join(path)) {
+        Ok(existing) => existing,
+        Err(_) => {
+            write_file(project_path, path, &content)?;
+            return Ok(Outcome::Written);
+        }
+    };
+
+    if existing == content {
+        Ok(Outcome::Unchanged)
+    } else if is_unedited(&existing) {
+        update_file(project_path, path, &content)?;
+        Ok(Outcome::Written)
+    } else {
+        write_file(project_path, &format!("{}.new", path), &content)?;
+        Ok(Outcome::Kept)
+    }
+}
+
+/// Whether a skill is still what t3-mono wrote: its marker matches the rest of the file
+fn is_unedited(content: &str) -> bool {
+    let Some(start) = content.rfind(MARKER_PREFIX) else {
+        return UNMARKED_HASHES.contains(&manifest::hash(content.as_bytes()).as_str());
+    };
+    let (body, marker) = content.split_at(start);
+    let Some(body) = body
+        .strip_suffix('\n')
+        .filter(|_| !marker.trim_end().contains('\n'))
+    else {
+        return false;
+    };
+    marker[MARKER_PREFIX.len()..].split_whitespace().next() == Some(short_hash(body).as_str())
+}
+
+fn short_hash(body: &str) -> String {
+    manifest::hash(body.as_bytes())[..16].to_string()
+}
+
+fn point_claude_md_at_skills(project_path: &str) -> Result<()> {
+    let claude_path = Path::new(project_path).join("CLAUDE.md");
+    if !fs::path_exists(&claude_path) {
+        return Ok(());
+    }
+    let mut claude_md = fs::read_to_string(&claude_path)?;
+    if !claude_md.contains(".claude/skills/") {
+        claude_md.push_str(CLAUDE_SKILLS_NOTE);
+        update_file(project_path, "CLAUDE.md", &claude_md)?;
+    }
+    Ok(())
+}
+
+fn render_index(entries: &[(String, String)]) -> String {
+    let rows: String = entries
+        .iter()
+        .map(|(file, summary)| {
+            format!(
+                "| [{}]({}) | {} |\n",
+                file.trim_end_matches(".md"),
+                file,
+                summary
+            )
+        })
+        .collect();
+    INDEX_SKILL.replace("{{rows}}", &rows)
+}
+
+/// A section naming what the skill connects to in this project, or nothing
+fn in_this_project(notes: &[&str]) -> String {
+    if notes.is_empty() {
+        return String::new();
+    }
+    let bullets: String = notes.iter().map(|note| format!("- {}\n", note)).collect();
+    format!("\n## In This Project\n\n{}", bullets)
+}
+
+fn render_ai(stack: &Stack) -> String {
+    let mut notes = Vec::new();
+    if stack.cmd {
+        notes.push("The CommandIsland chat calls LLMs through `src/server/chat/llm.ts`, not this framework; see `commandisland.md`.");
+    }
+    if stack.restate {
+        notes.push("Run embedding jobs durably through the Restate `EmbeddingService` with `callRestate` from `src/server/restate.ts`.");
+    }
+    if stack.logging {
+        notes.push("Log agent runs with the request's `ctx.log` in tRPC procedures, or `logger` from `src/server/logger.ts` elsewhere.");
+    }
+    format!("{}{}", AI_SKILL, in_this_project(&notes))
+}
+
+fn render_cmd(stack: &Stack) -> String {
+    let mut notes = Vec::new();
+    if stack.cmd_config {
+        notes.push("The provider, model, attachment search, storage, and modes come from `cmdConfig` in `src/server/chat/config.ts`; change them there.");
+    }
+    match stack.chat_stream {
+        Some("websocket") => notes.push("Chat replies stream over a WebSocket served by `server.ts` (`src/server/chat/socket.ts`); `npm run dev` and `npm start` run it."),
+        Some(_) => notes.push("Chat replies stream as server-sent events from `src/app/api/chat/stream/route.ts`."),
+        None => notes.push("Chat replies arrive whole from `chat.sendMessage`; nothing streams."),
+    }
+    if stack.search {
+        notes.push("Query mode also searches the indexes in `src/server/search/indexes.ts`.");
+    }
+    if stack.ai {
+        notes.push("For agents outside the chat, use the AI framework; see `ai.md`.");
+    }
+    format!("{}{}", CMD_SKILL, in_this_project(&notes))
+}
+
+// ============================================================================
+// Embedded Templates
+// ============================================================================
+
+const CLAUDE_SKILLS_NOTE: &str =
+    "\nTask-specific skills live in `.claude/skills/`, listed in `.claude/skills/index.md`.\n";
+
+const INDEX_SKILL: &str = r#"---
+name: index
+description: The skills in this project and when to use each. Read it first when a task touches an extension.
+---
+
+# Project Skills
+
+| Skill | Use it for |
+|-------|------------|
+{{rows}}
+t3-mono regenerates its skills and this index when you add an extension. Skills you edit
+are left alone, with the regenerated version next to them as `<file>.new`. Your own skills
+in this directory are listed here too.
+"#;
+
+const AI_SKILL: &str = r#"---
+name: ai-agents
+description: Build LLM agents, chunking, and embeddings with the LangChain-based framework in src/components/ai.
+---
+
+# AI Agents Skill
+
+This project includes a LangChain-based AI agents framework.
+
+## Available Modules
+
+### Providers (`src/components/ai/core/providers`)
+- Unified interface for Anthropic, OpenAI, Google, Mistral, Ollama
+- Model registry with cost estimation
+- Fallback chains for reliability
+
+### Logging (`src/components/ai/core/logging`)
+- LLM call logging to terminal, database, or file
+- Token counting and cost tracking
+- Usage statistics
+
+### Chunking (`src/components/ai/core/chunking`)
+- Text chunking strategies: character, token, semantic, recursive, markdown
+- Presets for different use cases
+
+### Embedding (`src/components/ai/core/embedding`)
+- Multi-provider embedding generation
+- Batch processing and semantic search
+
+## Usage
+
+```typescript
+import { createLLM, LLMLogger, TextChunker, EmbeddingGenerator } from "@/components/ai";
+
+// Create LLM instance
+const llm = createLLM({
+  provider: "anthropic",
+  model: "claude-sonnet-4-20250514",
+  temperature: 0.7,
+});
+
+// Log calls
+LLMLogger.getInstance().initialize({
+  destinations: ["terminal"]
+});
+
+// Chunk text
+const chunker = new TextChunker({ strategy: "semantic" });
+const chunks = await chunker.chunk(longText);
+
+// Generate embeddings
+const embedder = new EmbeddingGenerator("openai");
+const embeddings = await embedder.embed(texts);
+```
+
+## Environment Variables
+
+Required for AI features:
+- `ANTHROPIC_API_KEY` - For Claude models
+- `OPENAI_API_KEY` - For GPT models and embeddings
+"#;
+
+const CMD_SKILL: &str = r#"---
+skill: commandisland-integration
+description: >
+  Integrate the CommandIsland AI module (floating command bar, AI chat, AI Tables,
+  AI Docs, SplitView panel system) into an existing Next.js + tRPC + Prisma project.
+  The module ships as an export/ directory of source files that get copied, wired,
+  and customized for the target domain.
+prerequisites:
+  - Next.js 16+ (App Router)
+  - tRPC v11 with superjson transformer
+  - Prisma 7+ with PostgreSQL (pgvector extension)
+  - Tailwind CSS 4+ with shadcn/ui
+  - next-intl for translations
+author: prototype-ppap
+version: "1.0"
+---
+
+# CommandIsland AI Module -- Integration Skill
+
+This project includes the CommandIsland AI module with:
+
+## Components
+- **CommandIsland** (`src/components/layout/CommandIsland.tsx`) - Floating command bar with AI/Tables/Docs modes
+- **SplitViewShell** (`src/components/layout/SplitViewShell.tsx`) - Split-panel layout system
+- **ChatPanel** (`src/components/chat/`) - Full AI chat with streaming, file attachments, reference tokens
+- **AITable** (`src/components/tables/`) - AI-powered data tables with agent columns
+- **AIDocGenerator** (`src/components/docs/`) - AI document generation (PDF, Excel, PowerPoint)
+
+## Server
+- **Chat routers** (`src/server/api/routers/chat.ts`) - tRPC endpoints for chat threads
+- **Tables routers** (`src/server/api/routers/tables.ts`) - tRPC endpoints for AI tables
+- **Docs routers** (`src/server/api/routers/docs.ts`) - tRPC endpoints for doc generation
+- **LLM integration** (`src/server/chat/llm.ts`) - Multi-provider LLM with tool calling
+- **Chat tools** (`src/server/chat/chat-tools.ts`) - Database query tools for LLM
+
+## Customization Points
+- `src/server/chat/chat-tools.ts` - Add domain-specific tools the LLM can call
+- `src/server/chat/llm.ts` - Customize the system prompt
+- `src/server/chat/context-builder.ts` - Build entity context for LLM
+- `src/lib/ai-table-agent-presets.ts` - Define AI table agent presets
+- `src/lib/chat-tokens.ts` - Define inline reference token types
+- `src/components/layout/CommandIsland.tsx` - Customize quick suggestions and context entries
+- `public/samples/quarterly-report.pdf` - Sample document for trying uploads; delete it once you have your own
+
+## Environment Variables
+- `ANTHROPIC_API_KEY` - Required for Claude models
+- `OPENAI_API_KEY` - Optional for GPT models
+- `AWS_REGION`, `AWS_S3_BUCKET_NAME`, `AWS_ACCESS_KEY_ID`, `AWS_SECRET_ACCESS_KEY` - For file uploads
+"#;
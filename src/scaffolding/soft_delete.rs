@@ -0,0 +1,363 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use crate::scaffolding::trpc;
+use crate::utils::fs::{self, update_file, write_file};
+
+/// Auth library tables; their adapters expect hard deletes and own the columns
+const AUTH_MODELS: &[&str] = &[
+    "User",
+    "Session",
+    "Account",
+    "Verification",
+    "VerificationToken",
+];
+
+/// Add `deletedAt`, `createdBy`, and `updatedBy` to every mutable app model and install
+/// a Prisma client extension that turns deletes into updates, hides deleted rows, and
+/// stamps the signed-in user on writes.
+///
+/// Run after all other scaffolders have added their models.
+pub fn scaffold(project_path: &str) -> Result<()> {
+    let schema_path = Path::new(project_path).join("prisma/schema.prisma");
+    let schema = fs::read_to_string(&schema_path).context("Failed to read prisma/schema.prisma")?;
+
+    let (patched, models) = add_audit_columns(&schema);
+    update_file(project_path, "prisma/schema.prisma", &patched)?;
+
+    let model_list = models
+        .iter()
+        .map(|model| format!("\"{}\"", model))
+        .collect::<Vec<_>>()
+        .join(", ");
+    write_file(
+        project_path,
+        "src/server/audit.ts",
+        &AUDIT_HELPERS.replace("{{models}}", &model_list),
+    )?;
+    write_file(project_path, "src/server/audit.test.ts", AUDIT_TEST)?;
+    write_file(
+        project_path,
+        "src/server/soft-delete.ts",
+        SOFT_DELETE_EXTENSION,
+    )?;
+
+    extend_db_client(project_path)?;
+    trpc::add_audit_actor(project_path)?;
+
+    Ok(())
+}
+
+/// Add the audit columns and a `deletedAt` index to non-auth models with an `updatedAt`
+/// column; append-only models (messages, chunks) are left alone. Returns the patched
+/// schema and the names of the audited models.
+fn add_audit_columns(schema: &str) -> (String, Vec<String>) {
+    let mut output = String::with_capacity(schema.len());
+    let mut models = Vec::new();
+    let mut current: Option<String> = None;
+    let mut body: Vec<&str> = Vec::new();
+
+    for line in schema.split_inclusive('\n') {
+        if current.is_none() {
+            if let Some(name) = line
+                .strip_prefix("model ")
+                .and_then(|rest| rest.split_whitespace().next())
+            {
+                current = Some(name.to_string());
+                body.clear();
+            }
+            if current.is_none() {
+                output.push_str(line);
+                continue;
+            }
+        }
+
+        if line.trim_end() == "}" {
+            let name = current.take().unwrap_or_default();
+            if should_audit(&name, &body) {
+                output.push_str(&audit_model(&body));
+                models.push(name);
+            } else {
+                output.push_str(&body.concat());
+            }
+            output.push_str(line);
+        } else {
+            body.push(line);
+        }
+    }
+
+    (output, models)
+}
+
+fn should_audit(name: &str, body: &[&str]) -> bool {
+    !AUTH_MODELS.contains(&name)
+        && body
+            .iter()
+            .any(|line| field_name(line) == Some("updatedAt"))
+        && !body
+            .iter()
+            .any(|line| field_name(line) == Some("deletedAt"))
+}
+
+fn field_name(line: &str) -> Option<&str> {
+    line.split_whitespace().next()
+}
+
+/// Model body with the audit fields after `updatedAt`, aligned with it, and the index last
+fn audit_model(body: &[&str]) -> String {
+    let mut content = String::new();
+
+    for line in body {
+        content.push_str(line);
+        if field_name(line) == Some("updatedAt") {
+            let type_column = line.find("DateTime").unwrap_or(12);
+            for (field, ty) in [
+                ("deletedAt", "DateTime?"),
+                ("createdBy", "String?"),
+                ("updatedBy", "String?"),
+            ] {
+                content.push_str(&format!(
+                    "  {:<width$}{}\n",
+                    field,
+                    ty,
+                    width = type_column.saturating_sub(2)
+                ));
+            }
+        }
+    }
+
+    let ends_with_attributes = body
+        .iter()
+        .rev()
+        .find(|line| !line.trim().is_empty())
+        .is_some_and(|line| line.trim_start().starts_with("@@"));
+    if !ends_with_attributes {
+        content.push('\n');
+    }
+    content.push_str("  @@index([deletedAt])\n");
+
+    content
+}
+
+/// Apply the extension in `src/server/db.ts`, typing the global cache as the extended client
+fn extend_db_client(project_path: &str) -> Result<()> {
+    let db_path = Path::new(project_path).join("src/server/db.ts");
+    let content = fs::read_to_string(&db_path).context("Failed to read src/server/db.ts")?;
+
+    if content.contains("softDelete") {
+        return Ok(());
+    }
+
+    let patched = content
+        .replacen(
+            "import { PrismaClient } from \"@prisma/client\";\n",
+            "import { PrismaClient } from \"@prisma/client\";\nimport { softDelete } from \"@/server/soft-delete\";\n",
+            1,
+        )
+        .replacen(
+            "prisma: PrismaClient | undefined;",
+            "prisma: ReturnType<typeof createPrismaClient> | undefined;",
+            1,
+        )
+        .replacen("\t});\n}\n", "\t}).$extends(softDelete);\n}\n", 1)
+        // Accelerate has to stay the last extension
+        .replacen(").$extends(withAccelerate());", ").$extends(softDelete).$extends(withAccelerate());", 1);
+
+    if !patched.contains(".$extends(softDelete)") {
+        anyhow::bail!("Could not find createPrismaClient() in src/server/db.ts; apply `softDelete` with `$extends` manually");
+    }
+
+    update_file(project_path, "src/server/db.ts", &patched)
+}
+
+// ============================================================================
+// Embedded Templates
+// ============================================================================
+
+const AUDIT_HELPERS: &str = r#"import { AsyncLocalStorage } from "node:async_hooks";
+
+/** Models with `deletedAt`, `createdBy`, and `updatedBy` columns, handled by `softDelete` */
+export const AUDITED_MODELS: ReadonlySet<string> = new Set([{{models}}]);
+
+export interface SoftDeletable {
+  deletedAt: Date | null;
+}
+
+export interface Audited extends SoftDeletable {
+  createdBy: string | null;
+  updatedBy: string | null;
+}
+
+type Where = Record<string, unknown> | undefined;
+type Data = Record<string, unknown>;
+
+const actor = new AsyncLocalStorage<string>();
+
+/** Attribute writes made inside `fn` to `userId`; `protectedProcedure` does this per call */
+export function runAsActor<T>(userId: string, fn: () => T): T {
+  return actor.run(userId, fn);
+}
+
+export function currentActor(): string | undefined {
+  return actor.getStore();
+}
+
+export function isDeleted(record: SoftDeletable): boolean {
+  return record.deletedAt !== null;
+}
+
+/** Hide soft-deleted rows unless the query filters on `deletedAt` itself */
+export function excludeDeleted(where: Where): Record<string, unknown> {
+  if (where && "deletedAt" in where) {
+    return where;
+  }
+  return { ...where, deletedAt: null };
+}
+
+/** Explicit values in `data` win over the stamped actor */
+export function stampCreate(data: Data, userId = currentActor()): Data {
+  return userId ? { createdBy: userId, updatedBy: userId, ...data } : data;
+}
+
+export function stampUpdate(data: Data, userId = currentActor()): Data {
+  return userId ? { updatedBy: userId, ...data } : data;
+}
+"#;
+
+const AUDIT_TEST: &str = r#"import { describe, expect, it } from "vitest";
+import {
+  currentActor,
+  excludeDeleted,
+  isDeleted,
+  runAsActor,
+  stampCreate,
+  stampUpdate,
+} from "@/server/audit";
+
+describe("excludeDeleted", () => {
+  it("adds the deletedAt filter", () => {
+    expect(excludeDeleted(undefined)).toEqual({ deletedAt: null });
+    expect(excludeDeleted({ id: 1 })).toEqual({ id: 1, deletedAt: null });
+  });
+
+  it("keeps an explicit deletedAt filter", () => {
+    const where = { deletedAt: { not: null } };
+    expect(excludeDeleted(where)).toBe(where);
+  });
+});
+
+describe("audit stamps", () => {
+  it("leaves data alone without an actor", () => {
+    expect(stampCreate({ name: "a" })).toEqual({ name: "a" });
+    expect(stampUpdate({ name: "a" })).toEqual({ name: "a" });
+  });
+
+  it("stamps the actor of the surrounding call", () => {
+    runAsActor("user_1", () => {
+      expect(currentActor()).toBe("user_1");
+      expect(stampCreate({ name: "a" })).toEqual({ name: "a", createdBy: "user_1", updatedBy: "user_1" });
+      expect(stampUpdate({ name: "b" })).toEqual({ name: "b", updatedBy: "user_1" });
+    });
+    expect(currentActor()).toBeUndefined();
+  });
+
+  it("keeps the actor across awaits", async () => {
+    await runAsActor("user_2", async () => {
+      await Promise.resolve();
+      expect(currentActor()).toBe("user_2");
+    });
+  });
+
+  it("lets explicit values win", () => {
+    expect(stampUpdate({ updatedBy: "system" }, "user_1")).toEqual({ updatedBy: "system" });
+  });
+});
+
+describe("isDeleted", () => {
+  it("checks deletedAt", () => {
+    expect(isDeleted({ deletedAt: null })).toBe(false);
+    expect(isDeleted({ deletedAt: new Date() })).toBe(true);
+  });
+});
+"#;
+
+const SOFT_DELETE_EXTENSION: &str = r#"import { Prisma } from "@prisma/client";
+import { AUDITED_MODELS, excludeDeleted, stampCreate, stampUpdate } from "@/server/audit";
+
+// Filtered so soft-deleted rows behave as if they were gone
+const FILTERED_OPERATIONS = new Set([
+  "findUnique",
+  "findUniqueOrThrow",
+  "findFirst",
+  "findFirstOrThrow",
+  "findMany",
+  "count",
+  "aggregate",
+  "groupBy",
+  "update",
+  "updateMany",
+  "updateManyAndReturn",
+]);
+
+/**
+ * Soft deletes and audit columns for the models in `AUDITED_MODELS`: `delete`/`deleteMany`
+ * set `deletedAt`, reads skip deleted rows (filter on `deletedAt` to see them), and writes
+ * record the current actor in `createdBy`/`updatedBy`.
+ *
+ * Permanent deletes need raw SQL, e.g. `db.$executeRaw`.
+ */
+export const softDelete = Prisma.defineExtension((client) =>
+  client.$extends({
+    name: "soft-delete",
+    query: {
+      $allModels: {
+        async $allOperations({ model, operation, args, query }) {
+          if (!AUDITED_MODELS.has(model)) {
+            return query(args);
+          }
+
+          // biome-ignore lint/suspicious/noExplicitAny: args and delegates differ per model and operation
+          const params = args as any;
+          // biome-ignore lint/suspicious/noExplicitAny: see above
+          const delegate = (client as any)[model.charAt(0).toLowerCase() + model.slice(1)];
+
+          switch (operation) {
+            case "delete":
+              return delegate.update({
+                where: excludeDeleted(params.where),
+                data: stampUpdate({ deletedAt: new Date() }),
+              });
+            case "deleteMany":
+              return delegate.updateMany({
+                where: excludeDeleted(params.where),
+                data: stampUpdate({ deletedAt: new Date() }),
+              });
+            case "create":
+              params.data = stampCreate(params.data);
+              break;
+            case "createMany":
+            case "createManyAndReturn":
+              params.data = Array.isArray(params.data)
+                ? params.data.map((data: Record<string, unknown>) => stampCreate(data))
+                : stampCreate(params.data);
+              break;
+            case "upsert":
+              params.create = stampCreate(params.create);
+              params.update = stampUpdate(params.update);
+              break;
+          }
+
+          if (operation.startsWith("update")) {
+            params.data = stampUpdate(params.data);
+          }
+          if (FILTERED_OPERATIONS.has(operation)) {
+            params.where = excludeDeleted(params.where);
+          }
+
+          return query(params);
+        },
+      },
+    },
+  }),
+);
+"#;
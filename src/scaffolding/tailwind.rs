@@ -0,0 +1,78 @@
+use anyhow::Result;
+use std::path::Path;
+
+use crate::cli::TailwindPlugin;
+use crate::utils::fs::{self, update_file};
+use crate::utils::npm;
+
+/// Stylesheets that may load Tailwind: the one the layout imports, and the UI kit's theme
+const STYLESHEETS: &[&str] = &["src/styles/globals.css", "src/app/globals.css"];
+const TAILWIND_IMPORT: &str = "@import \"tailwindcss\";\n";
+
+/// npm package and the CSS line that loads it with Tailwind v4
+pub fn plugin(plugin: TailwindPlugin) -> (&'static str, &'static str, &'static str) {
+    match plugin {
+        TailwindPlugin::Typography => (
+            "@tailwindcss/typography",
+            "^0.5.19",
+            "@plugin \"@tailwindcss/typography\";",
+        ),
+        // Class strategy, so the plugin's resets don't restyle the UI kit's inputs
+        TailwindPlugin::Forms => (
+            "@tailwindcss/forms",
+            "^0.5.10",
+            "@plugin \"@tailwindcss/forms\" {\n  strategy: \"class\";\n}",
+        ),
+        // A CSS-only replacement for tailwindcss-animate, loaded as a stylesheet
+        TailwindPlugin::Animate => ("tw-animate-css", "^1.4.0", "@import \"tw-animate-css\";"),
+    }
+}
+
+/// devDependencies for the chosen plugins
+pub fn dependencies(plugins: &[TailwindPlugin]) -> Vec<(&'static str, &'static str)> {
+    plugins
+        .iter()
+        .map(|p| plugin(*p))
+        .map(|(name, version, _)| (name, version))
+        .collect()
+}
+
+/// Load the chosen plugins right after `@import "tailwindcss";` in every stylesheet that has it
+pub fn scaffold(project_path: &str, plugins: &[TailwindPlugin]) -> Result<()> {
+    if plugins.is_empty() {
+        return Ok(());
+    }
+
+    for stylesheet in STYLESHEETS {
+        let Ok(content) = fs::read_to_string(Path::new(project_path).join(stylesheet)) else {
+            continue;
+        };
+        let mut directives: Vec<&str> = plugins
+            .iter()
+            .map(|p| plugin(*p).2)
+            .filter(|directive| !content.contains(directive))
+            .collect();
+        // CSS only honors @import before other rules
+        directives.sort_by_key(|directive| !directive.starts_with("@import"));
+        if directives.is_empty() || !content.contains(TAILWIND_IMPORT) {
+            continue;
+        }
+
+        let patched = content.replacen(
+            TAILWIND_IMPORT,
+            &format!("{}{}\n", TAILWIND_IMPORT, directives.join("\n")),
+            1,
+        );
+        update_file(project_path, stylesheet, &patched)?;
+    }
+
+    Ok(())
+}
+
+/// Whether the project's package.json lists the plugin
+pub fn is_installed(project_path: &str, plugin_kind: TailwindPlugin) -> bool {
+    let (name, _, _) = plugin(plugin_kind);
+    npm::read_package_json(project_path).is_ok_and(|pkg| {
+        pkg["dependencies"].get(name).is_some() || pkg["devDependencies"].get(name).is_some()
+    })
+}
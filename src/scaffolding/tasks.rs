@@ -0,0 +1,156 @@
+use anyhow::Result;
+
+use crate::cli::{DatabaseProvider, TaskRunner};
+use crate::scaffolding::options::ScaffoldOptions;
+use crate::utils::fs::write_file;
+
+/// A workflow exposed by the task runner file
+struct Task {
+    name: &'static str,
+    description: &'static str,
+    command: &'static str,
+}
+
+const BASE_TASKS: &[Task] = &[
+    Task {
+        name: "install",
+        description: "Install dependencies",
+        command: "npm install",
+    },
+    Task {
+        name: "dev",
+        description: "Start the dev server",
+        command: "npm run dev",
+    },
+    Task {
+        name: "build",
+        description: "Build for production",
+        command: "npm run build",
+    },
+    Task {
+        name: "analyze",
+        description: "Build and open the bundle treemaps",
+        command: "npm run analyze",
+    },
+    Task {
+        name: "lint",
+        description: "Lint with Biome",
+        command: "npm run lint",
+    },
+    Task {
+        name: "check",
+        description: "Lint and format with Biome",
+        command: "npm run check",
+    },
+    Task {
+        name: "test",
+        description: "Run tests",
+        command: "npm run test",
+    },
+    Task {
+        name: "db-up",
+        description: "Start the local database",
+        command: "./start-database.sh",
+    },
+    Task {
+        name: "db-push",
+        description: "Push the Prisma schema",
+        command: "npm run db:push",
+    },
+    Task {
+        name: "db-migrate",
+        description: "Create and apply a migration",
+        command: "npm run db:migrate",
+    },
+    Task {
+        name: "db-deploy",
+        description: "Apply pending migrations",
+        command: "npm run db:deploy",
+    },
+    Task {
+        name: "db-studio",
+        description: "Open Prisma Studio",
+        command: "npm run db:studio",
+    },
+    Task {
+        name: "docker-build",
+        description: "Build the Docker images",
+        command: "docker compose build",
+    },
+];
+
+const RESTATE_TASKS: &[Task] = &[
+    Task {
+        name: "restate-up",
+        description: "Start the Restate stack",
+        command: "npm run restate:up",
+    },
+    Task {
+        name: "restate-dev",
+        description: "Run the Restate services locally",
+        command: "npm run restate:dev",
+    },
+    Task {
+        name: "restate-register",
+        description: "Register the services with Restate",
+        command: "npm run restate:register",
+    },
+    Task {
+        name: "restate-subscribe",
+        description: "Subscribe OrderEvents to the Kafka topic",
+        command: "npm run restate:subscribe",
+    },
+];
+
+/// Write a justfile or Makefile wrapping the common project workflows
+pub fn scaffold(project_path: &str, runner: TaskRunner, options: &ScaffoldOptions) -> Result<()> {
+    let mut tasks: Vec<&Task> = BASE_TASKS
+        .iter()
+        .filter(|task| match task.name {
+            "db-push" => !options.migrations,
+            "db-deploy" => options.migrations,
+            "analyze" => options.bundle_analyzer,
+            // A SQLite file needs no container
+            "db-up" | "docker-build" => options.database != DatabaseProvider::Sqlite,
+            _ => true,
+        })
+        .collect();
+    if options.restate {
+        tasks.extend(
+            RESTATE_TASKS
+                .iter()
+                .filter(|task| task.name != "restate-subscribe" || options.restate_kafka),
+        );
+    }
+
+    match runner {
+        TaskRunner::Just => write_file(project_path, "justfile", &render_justfile(&tasks)),
+        TaskRunner::Make => write_file(project_path, "Makefile", &render_makefile(&tasks)),
+    }
+}
+
+fn render_justfile(tasks: &[&Task]) -> String {
+    let mut content = String::from("# List available recipes\ndefault:\n    @just --list\n");
+    for task in tasks {
+        content.push_str(&format!(
+            "\n# {}\n{}:\n    {}\n",
+            task.description, task.name, task.command
+        ));
+    }
+    content
+}
+
+fn render_makefile(tasks: &[&Task]) -> String {
+    let names: Vec<&str> = tasks.iter().map(|task| task.name).collect();
+    let mut content = format!(
+        ".DEFAULT_GOAL := help\n.PHONY: help {}\n\nhelp: ## List available targets\n\t@grep -E '^[a-zA-Z_-]+:.*?## ' $(MAKEFILE_LIST) | awk 'BEGIN {{FS = \":.*?## \"}}; {{printf \"  %-18s %s\\n\", $$1, $$2}}'\n",
+        names.join(" ")
+    );
+    for task in tasks {
+        content.push_str(&format!(
+            "\n{}: ## {}\n\t{}\n",
+            task.name, task.description, task.command
+        ));
+    }
+    content
+}
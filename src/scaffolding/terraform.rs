@@ -0,0 +1,465 @@
+use anyhow::Result;
+
+use crate::cli::{AuthProvider, DatabaseProvider};
+use crate::scaffolding::options::ScaffoldOptions;
+use crate::utils::fs::write_file;
+
+const MODULE_DIR: &str = "infra/terraform";
+
+/// How the database instance and its connection string differ per engine
+struct Engine {
+    engine: &'static str,
+    version: &'static str,
+    port: u16,
+    scheme: &'static str,
+    /// Query string Prisma expects on the URL
+    params: &'static str,
+}
+
+const POSTGRES: Engine = Engine {
+    engine: "postgres",
+    version: "17",
+    port: 5432,
+    scheme: "postgresql",
+    params: "?schema=public",
+};
+
+const MYSQL: Engine = Engine {
+    engine: "mysql",
+    version: "8.4",
+    port: 3306,
+    scheme: "mysql",
+    params: "",
+};
+
+/// Scaffold a Terraform module in `infra/terraform/` for the AWS resources the project
+/// expects to exist: the database instance, the upload bucket with CORS for cmd, and a
+/// Secrets Manager entry. Outputs are named after the env variables they fill in.
+pub fn scaffold(project_path: &str, options: &ScaffoldOptions) -> Result<()> {
+    let engine = match options.database {
+        DatabaseProvider::Postgres => Some(&POSTGRES),
+        DatabaseProvider::Mysql => Some(&MYSQL),
+        // The database is a file next to the app
+        DatabaseProvider::Sqlite => None,
+    };
+    let auth_secret = match options.auth {
+        AuthProvider::BetterAuth => "BETTER_AUTH_SECRET",
+        AuthProvider::NextAuth => "NEXTAUTH_SECRET",
+    };
+    // With a pooler in front, the instance is the direct connection the Prisma CLI uses
+    let url_output = if options.pooling.is_some() {
+        "DIRECT_URL"
+    } else {
+        "DATABASE_URL"
+    };
+
+    let mut main = MAIN_TF.to_string();
+    let mut outputs = String::new();
+    let mut secrets = vec![format!(
+        "    {} = random_password.auth_secret.result",
+        auth_secret
+    )];
+    if let Some(engine) = engine {
+        main.push_str(
+            &DATABASE_TF
+                .replace("{{engine}}", engine.engine)
+                .replace("{{engine_version}}", engine.version)
+                .replace("{{port}}", &engine.port.to_string())
+                .replace("{{scheme}}", engine.scheme)
+                .replace("{{params}}", engine.params),
+        );
+        outputs.push_str(&DATABASE_OUTPUT_TF.replace("{{url_output}}", url_output));
+        secrets.insert(0, format!("    {} = local.database_url", url_output));
+    }
+    if options.cmd {
+        main.push_str(UPLOADS_TF);
+        outputs.push_str(UPLOADS_OUTPUT_TF);
+        secrets.push("    AWS_ACCESS_KEY_ID     = aws_iam_access_key.app.id".to_string());
+        secrets.push("    AWS_SECRET_ACCESS_KEY = aws_iam_access_key.app.secret".to_string());
+    }
+    main.push_str(&SECRETS_TF.replace("{{secrets}}", &align_assignments(&secrets).join("\n")));
+    outputs.push_str(&SECRETS_OUTPUT_TF.replace("{{auth_secret}}", auth_secret));
+
+    write_file(
+        project_path,
+        &format!("{}/versions.tf", MODULE_DIR),
+        VERSIONS_TF,
+    )?;
+    write_file(
+        project_path,
+        &format!("{}/variables.tf", MODULE_DIR),
+        &VARIABLES_TF
+            .replace("{{project}}", unscoped(&options.name))
+            .replace("{{db_name}}", &database_name(unscoped(&options.name))),
+    )?;
+    write_file(project_path, &format!("{}/main.tf", MODULE_DIR), &main)?;
+    write_file(
+        project_path,
+        &format!("{}/outputs.tf", MODULE_DIR),
+        &outputs,
+    )?;
+    write_file(
+        project_path,
+        &format!("{}/terraform.tfvars.example", MODULE_DIR),
+        TFVARS_EXAMPLE,
+    )?;
+    write_file(
+        project_path,
+        &format!("{}/.gitignore", MODULE_DIR),
+        GITIGNORE,
+    )?;
+    write_file(project_path, &format!("{}/README.md", MODULE_DIR), README)?;
+
+    Ok(())
+}
+
+/// `app` for `@scope/app`; bucket and instance names can't contain the scope
+fn unscoped(name: &str) -> &str {
+    name.rsplit('/').next().unwrap_or(name)
+}
+
+/// RDS database names are letters, digits, and underscores, starting with a letter
+fn database_name(project: &str) -> String {
+    let name: String = project
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    match name.chars().next() {
+        Some(first) if first.is_ascii_alphabetic() => name,
+        _ => format!("app_{}", name),
+    }
+}
+
+/// Line up the `=` of `KEY = value` lines the way `terraform fmt` does
+fn align_assignments(lines: &[String]) -> Vec<String> {
+    let split: Vec<(&str, &str)> = lines
+        .iter()
+        .filter_map(|line| line.split_once(" = "))
+        .map(|(key, value)| (key.trim_end(), value))
+        .collect();
+    let width = split.iter().map(|(key, _)| key.len()).max().unwrap_or(0);
+    split
+        .iter()
+        .map(|(key, value)| format!("{:width$} = {}", key, value, width = width))
+        .collect()
+}
+
+// ============================================================================
+// Embedded Templates
+// ============================================================================
+
+const VERSIONS_TF: &str = r#"terraform {
+  required_version = ">= 1.6"
+
+  required_providers {
+    aws = {
+      source  = "hashicorp/aws"
+      version = "~> 6.0"
+    }
+    random = {
+      source  = "hashicorp/random"
+      version = "~> 3.6"
+    }
+  }
+}
+"#;
+
+const VARIABLES_TF: &str = r#"variable "project" {
+  description = "Prefix for resource names"
+  type        = string
+  default     = "{{project}}"
+}
+
+variable "environment" {
+  description = "Environment name, appended to resource names"
+  type        = string
+  default     = "production"
+}
+
+variable "region" {
+  description = "AWS region for every resource"
+  type        = string
+  default     = "eu-central-1"
+}
+
+variable "app_url" {
+  description = "Public URL of the app, e.g. https://example.com; allowed by the upload bucket's CORS rules"
+  type        = string
+}
+
+variable "db_name" {
+  description = "Name of the database on the instance"
+  type        = string
+  default     = "{{db_name}}"
+}
+
+variable "db_instance_class" {
+  description = "RDS instance class"
+  type        = string
+  default     = "db.t4g.micro"
+}
+
+variable "db_allocated_storage" {
+  description = "Storage in GiB; RDS grows it up to ten times this on demand"
+  type        = number
+  default     = 20
+}
+
+variable "db_subnet_group_name" {
+  description = "Subnet group for the database; the default VPC's when null"
+  type        = string
+  default     = null
+}
+
+variable "vpc_security_group_ids" {
+  description = "Security groups for the database; they must allow the app to reach its port"
+  type        = list(string)
+  default     = []
+}
+"#;
+
+const MAIN_TF: &str = r#"provider "aws" {
+  region = var.region
+
+  default_tags {
+    tags = {
+      Project     = var.project
+      Environment = var.environment
+    }
+  }
+}
+
+locals {
+  name = "${var.project}-${var.environment}"
+}
+"#;
+
+const DATABASE_TF: &str = r#"
+# ----------------------------------------------------------------------------
+# Database
+# ----------------------------------------------------------------------------
+
+resource "random_password" "database" {
+  length  = 32
+  special = false
+}
+
+resource "aws_db_instance" "main" {
+  identifier            = local.name
+  engine                = "{{engine}}"
+  engine_version        = "{{engine_version}}"
+  instance_class        = var.db_instance_class
+  allocated_storage     = var.db_allocated_storage
+  max_allocated_storage = var.db_allocated_storage * 10
+  storage_encrypted     = true
+
+  db_name  = var.db_name
+  username = "app"
+  password = random_password.database.result
+  port     = {{port}}
+
+  db_subnet_group_name   = var.db_subnet_group_name
+  vpc_security_group_ids = var.vpc_security_group_ids
+  publicly_accessible    = false
+
+  backup_retention_period   = 7
+  deletion_protection       = true
+  skip_final_snapshot       = false
+  final_snapshot_identifier = "${local.name}-final"
+}
+
+locals {
+  database_url = "{{scheme}}://${aws_db_instance.main.username}:${random_password.database.result}@${aws_db_instance.main.endpoint}/${var.db_name}{{params}}"
+}
+"#;
+
+const UPLOADS_TF: &str = r#"
+# ----------------------------------------------------------------------------
+# Upload bucket for cmd documents, read by the browser through presigned URLs
+# ----------------------------------------------------------------------------
+
+resource "aws_s3_bucket" "uploads" {
+  bucket = "${local.name}-uploads"
+}
+
+resource "aws_s3_bucket_public_access_block" "uploads" {
+  bucket = aws_s3_bucket.uploads.id
+
+  block_public_acls       = true
+  block_public_policy     = true
+  ignore_public_acls      = true
+  restrict_public_buckets = true
+}
+
+resource "aws_s3_bucket_server_side_encryption_configuration" "uploads" {
+  bucket = aws_s3_bucket.uploads.id
+
+  rule {
+    apply_server_side_encryption_by_default {
+      sse_algorithm = "AES256"
+    }
+  }
+}
+
+resource "aws_s3_bucket_cors_configuration" "uploads" {
+  bucket = aws_s3_bucket.uploads.id
+
+  cors_rule {
+    allowed_methods = ["GET", "HEAD", "PUT"]
+    allowed_origins = [var.app_url]
+    allowed_headers = ["*"]
+    expose_headers  = ["ETag"]
+    max_age_seconds = 3000
+  }
+}
+
+# The app signs its requests as this user
+resource "aws_iam_user" "app" {
+  name = "${local.name}-app"
+}
+
+resource "aws_iam_user_policy" "uploads" {
+  name = "uploads"
+  user = aws_iam_user.app.name
+
+  policy = jsonencode({
+    Version = "2012-10-17"
+    Statement = [
+      {
+        Effect   = "Allow"
+        Action   = ["s3:ListBucket"]
+        Resource = [aws_s3_bucket.uploads.arn]
+      },
+      {
+        Effect   = "Allow"
+        Action   = ["s3:GetObject", "s3:PutObject", "s3:DeleteObject"]
+        Resource = ["${aws_s3_bucket.uploads.arn}/*"]
+      },
+    ]
+  })
+}
+
+resource "aws_iam_access_key" "app" {
+  user = aws_iam_user.app.name
+}
+"#;
+
+const SECRETS_TF: &str = r#"
+# ----------------------------------------------------------------------------
+# Secrets, stored under one Secrets Manager entry keyed by env variable name
+# ----------------------------------------------------------------------------
+
+resource "random_password" "auth_secret" {
+  length  = 48
+  special = false
+}
+
+resource "aws_secretsmanager_secret" "app" {
+  name = "${local.name}/app"
+}
+
+resource "aws_secretsmanager_secret_version" "app" {
+  secret_id = aws_secretsmanager_secret.app.id
+  secret_string = jsonencode({
+{{secrets}}
+  })
+}
+"#;
+
+const DATABASE_OUTPUT_TF: &str = r#"output "{{url_output}}" {
+  description = "Connection string of the database instance"
+  value       = local.database_url
+  sensitive   = true
+}
+
+"#;
+
+const UPLOADS_OUTPUT_TF: &str = r#"output "AWS_REGION" {
+  description = "Region of the upload bucket"
+  value       = var.region
+}
+
+output "AWS_S3_BUCKET_NAME" {
+  description = "Bucket cmd stores uploaded documents in"
+  value       = aws_s3_bucket.uploads.bucket
+}
+
+output "AWS_ACCESS_KEY_ID" {
+  description = "Access key of the app's IAM user"
+  value       = aws_iam_access_key.app.id
+}
+
+output "AWS_SECRET_ACCESS_KEY" {
+  description = "Secret key of the app's IAM user"
+  value       = aws_iam_access_key.app.secret
+  sensitive   = true
+}
+
+"#;
+
+const SECRETS_OUTPUT_TF: &str = r#"output "{{auth_secret}}" {
+  description = "Session signing secret"
+  value       = random_password.auth_secret.result
+  sensitive   = true
+}
+
+output "secret_arn" {
+  description = "Secrets Manager entry holding every secret above as JSON"
+  value       = aws_secretsmanager_secret.app.arn
+}
+"#;
+
+const TFVARS_EXAMPLE: &str = r#"# Copy to terraform.tfvars (git ignores it) and adjust
+app_url = "https://example.com"
+# region                 = "eu-central-1"
+# db_instance_class      = "db.t4g.micro"
+# db_subnet_group_name   = "my-private-subnets"
+# vpc_security_group_ids = ["sg-0123456789abcdef0"]
+"#;
+
+const GITIGNORE: &str = r#".terraform/
+*.tfstate
+*.tfstate.*
+terraform.tfvars
+crash.log
+"#;
+
+const README: &str = r#"# Infrastructure
+
+A Terraform module for the AWS resources the app expects to exist. Every output is named
+after the env variable it fills in, so the outputs become `.env.production` directly.
+
+## Usage
+
+```bash
+cd infra/terraform
+cp terraform.tfvars.example terraform.tfvars   # set app_url
+terraform init
+terraform apply
+```
+
+Write the outputs into the app's production env file (`jq` required):
+
+```bash
+terraform output -json \
+  | jq -r 'to_entries[] | select(.key | test("^[A-Z]")) | "\(.key)=\"\(.value.value)\""' \
+  >> ../../.env.production
+```
+
+Hosts that read secrets from AWS can use the Secrets Manager entry in `secret_arn` instead;
+it holds the same secrets as JSON keyed by variable name.
+
+## Notes
+
+- The state holds every secret in plain text; use a remote backend with encryption (an S3
+  backend block in `versions.tf`) before sharing it.
+- The database isn't publicly accessible. Run the app and `npm run db:deploy` (or
+  `npx prisma db push`) from inside the VPC, or pass security groups that allow your host.
+- `deletion_protection` is on; turn it off before `terraform destroy`.
+"#;
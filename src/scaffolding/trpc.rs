@@ -0,0 +1,422 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use crate::utils::fs::{self, update_file, write_file};
+
+pub const ROOT_PATH: &str = "src/server/api/root.ts";
+const INIT_PATH: &str = "src/server/api/trpc.ts";
+const ROUTE_PATH: &str = "src/app/api/trpc/[trpc]/route.ts";
+const LOGGER_IMPORT: &str = "import { createRequestLogger } from \"@/server/logger\";";
+const AUDIT_IMPORT: &str = "import { runAsActor } from \"@/server/audit\";";
+const PERF_IMPORT: &str = "import { timeProcedure } from \"@/server/perf\";";
+const PUBLIC_PROCEDURE: &str = "export const publicProcedure = t.procedure";
+const AUTH_NEXT: &str = "  return next({\n    ctx: {\n      session: ctx.session,\n      userId: ctx.session.user.id,\n    },\n  });\n";
+
+/// Wire the session into tRPC: an auth-aware `trpc.ts` with `protectedProcedure`
+/// and an `auth` router whose `me` query only answers signed-in users.
+///
+/// Expects `src/server/session.ts` (written by the auth scaffolders) to export `getSession()`.
+pub fn add_session_support(project_path: &str) -> Result<()> {
+    write_protected_init(project_path)?;
+    write_file(project_path, "src/server/api/routers/auth.ts", AUTH_ROUTER)?;
+    write_file(
+        project_path,
+        "src/server/api/routers/auth.test.ts",
+        AUTH_ROUTER_TEST,
+    )?;
+    register_router(
+        project_path,
+        "auth",
+        "authRouter",
+        "@/server/api/routers/auth",
+    )
+}
+
+/// Overwrite `src/server/api/trpc.ts` with the version that resolves the session,
+/// keeping the logging middleware, audit actor, and timing if they were installed before
+pub fn write_protected_init(project_path: &str) -> Result<()> {
+    let init_path = Path::new(project_path).join(INIT_PATH);
+    let previous = fs::read_to_string(&init_path).unwrap_or_default();
+
+    write_file(project_path, INIT_PATH, TRPC_INIT_WITH_SESSION)?;
+    if previous.contains(LOGGER_IMPORT) {
+        add_logging_middleware(project_path)?;
+    }
+    if previous.contains(AUDIT_IMPORT) {
+        add_audit_actor(project_path)?;
+    }
+    if previous.contains(PERF_IMPORT) {
+        add_timing_middleware(project_path)?;
+    }
+
+    Ok(())
+}
+
+/// Run protected procedures as the signed-in user, so audit columns record who wrote a row.
+///
+/// Expects `src/server/audit.ts` to export `runAsActor()`.
+pub fn add_audit_actor(project_path: &str) -> Result<()> {
+    let init_path = Path::new(project_path).join(INIT_PATH);
+    let mut content =
+        fs::read_to_string(&init_path).with_context(|| format!("Failed to read {}", INIT_PATH))?;
+
+    if content.contains(AUDIT_IMPORT) {
+        return Ok(());
+    }
+    if !content.contains(AUTH_NEXT) {
+        anyhow::bail!(
+            "Could not find enforceAuth in {}; wrap its next() call in runAsActor() manually",
+            INIT_PATH
+        );
+    }
+
+    let position = import_insert_position(&content);
+    content.insert_str(position, &format!("{}\n", AUDIT_IMPORT));
+    let content = content.replacen(AUTH_NEXT, AUTH_NEXT_AS_ACTOR, 1);
+    update_file(project_path, INIT_PATH, &content)
+}
+
+/// Give every procedure a request-scoped `ctx.log` and log its duration and outcome;
+/// the tRPC route echoes the request id back in the `x-request-id` response header.
+///
+/// Expects `src/server/logger.ts` to export `createRequestLogger()`.
+pub fn add_logging_middleware(project_path: &str) -> Result<()> {
+    let init_path = Path::new(project_path).join(INIT_PATH);
+    let content =
+        fs::read_to_string(&init_path).with_context(|| format!("Failed to read {}", INIT_PATH))?;
+
+    if !content.contains(LOGGER_IMPORT) {
+        let patched = add_logging(&content).with_context(|| {
+            format!(
+                "Could not find createTRPCContext and publicProcedure in {}",
+                INIT_PATH
+            )
+        })?;
+        update_file(project_path, INIT_PATH, &patched)?;
+    }
+
+    let route_path = Path::new(project_path).join(ROUTE_PATH);
+    let route = fs::read_to_string(&route_path)
+        .with_context(|| format!("Failed to read {}", ROUTE_PATH))?;
+
+    if !route.contains("responseMeta") {
+        let patched = route.replacen(
+            "    createContext: () => createTRPCContext({ headers: req.headers }),\n",
+            "    createContext: () => createTRPCContext({ headers: req.headers }),\n    responseMeta: ({ ctx }) => ({\n      headers: ctx ? { \"x-request-id\": ctx.requestId } : undefined,\n    }),\n",
+            1,
+        );
+        update_file(project_path, ROUTE_PATH, &patched)?;
+    }
+
+    Ok(())
+}
+
+fn add_logging(content: &str) -> Option<String> {
+    let mut content = content.to_string();
+
+    let position = import_insert_position(&content);
+    content.insert_str(position, &format!("{}\n", LOGGER_IMPORT));
+
+    let context_start = content.find("export const createTRPCContext")?;
+    let return_start = context_start + content[context_start..].find("  return {\n")?;
+    content.insert_str(
+        return_start + "  return {\n".len(),
+        "    requestId,\n    log,\n",
+    );
+    content.insert_str(
+        return_start,
+        "  const { requestId, log } = createRequestLogger(opts.headers);\n\n",
+    );
+
+    content = use_on_public_procedure(&content, LOGGING_MIDDLEWARE, "logging", true)?;
+    // Protected procedures are logged too, including the UNAUTHORIZED rejections
+    content = content.replacen(
+        "t.procedure.use(enforceAuth)",
+        "publicProcedure.use(enforceAuth)",
+        1,
+    );
+    // Slow procedure and query warnings go to the request logger from now on
+    content = content.replacen(TIMING_MIDDLEWARE, TIMING_MIDDLEWARE_LOGGED, 1);
+
+    Some(content)
+}
+
+/// Time every procedure and count its Prisma queries, warning about slow ones through
+/// `ctx.log` when the logging middleware is installed.
+///
+/// Expects `src/server/perf.ts` to export `timeProcedure()`.
+pub fn add_timing_middleware(project_path: &str) -> Result<()> {
+    let init_path = Path::new(project_path).join(INIT_PATH);
+    let content =
+        fs::read_to_string(&init_path).with_context(|| format!("Failed to read {}", INIT_PATH))?;
+
+    if content.contains(PERF_IMPORT) {
+        return Ok(());
+    }
+
+    let mut content = content;
+    let position = import_insert_position(&content);
+    content.insert_str(position, &format!("{}\n", PERF_IMPORT));
+
+    let middleware = if content.contains(LOGGER_IMPORT) {
+        TIMING_MIDDLEWARE_LOGGED
+    } else {
+        TIMING_MIDDLEWARE
+    };
+    let content = use_on_public_procedure(&content, middleware, "timing", false)
+        .with_context(|| format!("Could not find publicProcedure in {}", INIT_PATH))?
+        // Protected procedures are timed too
+        .replacen(
+            "t.procedure.use(enforceAuth)",
+            "publicProcedure.use(enforceAuth)",
+            1,
+        );
+    update_file(project_path, INIT_PATH, &content)
+}
+
+/// Define `middleware` above `publicProcedure` and add it to the procedure's chain, first
+/// (outermost) or last
+fn use_on_public_procedure(
+    content: &str,
+    middleware: &str,
+    name: &str,
+    first: bool,
+) -> Option<String> {
+    let start = content.find(PUBLIC_PROCEDURE)?;
+    let end = start + content[start..].find(";\n")?;
+    let chain = &content[start + PUBLIC_PROCEDURE.len()..end];
+    let chain = if first {
+        format!(".use({}){}", name, chain)
+    } else {
+        format!("{}.use({})", chain, name)
+    };
+
+    // A middleware added earlier already ends in a blank line
+    let separator = if content[..start].ends_with("\n\n") {
+        ""
+    } else {
+        "\n"
+    };
+    let mut content = content.to_string();
+    content.replace_range(
+        start..end + 1,
+        &format!(
+            "{}{}\n{}{};",
+            separator, middleware, PUBLIC_PROCEDURE, chain
+        ),
+    );
+    Some(content)
+}
+
+/// Register a router in `src/server/api/root.ts`, adding its import and its
+/// entry in the `createTRPCRouter({ ... })` call unless already present
+pub fn register_router(project_path: &str, key: &str, ident: &str, module: &str) -> Result<()> {
+    let root_path = Path::new(project_path).join(ROOT_PATH);
+    let content =
+        fs::read_to_string(&root_path).with_context(|| format!("Failed to read {}", ROOT_PATH))?;
+
+    let patched = add_router(&content, key, ident, module).with_context(|| {
+        format!(
+            "Could not find createTRPCRouter({{ ... }}) in {}",
+            ROOT_PATH
+        )
+    })?;
+    update_file(project_path, ROOT_PATH, &patched)?;
+
+    Ok(())
+}
+
+fn add_router(content: &str, key: &str, ident: &str, module: &str) -> Option<String> {
+    let mut content = content.to_string();
+
+    let import = format!("import {{ {} }} from \"{}\";", ident, module);
+    if !content.contains(&import) {
+        let position = import_insert_position(&content);
+        content.insert_str(position, &format!("{}\n", import));
+    }
+
+    let router_start = content.find("createTRPCRouter({")?;
+    let router_end = router_start + content[router_start..].find("\n});")?;
+    let body = &content[router_start..router_end];
+
+    if !body.contains(&format!("{}:", key)) {
+        content.insert_str(router_end, &format!("\n  {}: {},", key, ident));
+    }
+
+    Some(content)
+}
+
+/// Byte offset just after the last (possibly multi-line) import statement
+fn import_insert_position(content: &str) -> usize {
+    let mut position = 0;
+    let mut offset = 0;
+    let mut in_import = false;
+
+    for line in content.split_inclusive('\n') {
+        offset += line.len();
+        if line.starts_with("import ") {
+            in_import = true;
+        }
+        if in_import && line.trim_end().ends_with(';') {
+            position = offset;
+            in_import = false;
+        }
+    }
+
+    position
+}
+
+// ============================================================================
+// Embedded Templates
+// ============================================================================
+
+const AUTH_NEXT_AS_ACTOR: &str = r#"  // Captured so the narrowing survives into the callback
+  const session = ctx.session;
+  // Writes made by the procedure record this user in createdBy/updatedBy
+  return runAsActor(session.user.id, () =>
+    next({
+      ctx: {
+        session,
+        userId: session.user.id,
+      },
+    }),
+  );
+"#;
+
+const LOGGING_MIDDLEWARE: &str = r#"/** Logs every procedure call with its duration; server errors at `error`, the rest at `warn` */
+const logging = t.middleware(async ({ ctx, path, type, next }) => {
+  const startedAt = performance.now();
+  const result = await next();
+  const durationMs = Math.round(performance.now() - startedAt);
+
+  if (result.ok) {
+    ctx.log.info({ path, type, durationMs }, "trpc call");
+  } else {
+    const level = result.error.code === "INTERNAL_SERVER_ERROR" ? "error" : "warn";
+    ctx.log[level]({ path, type, durationMs, code: result.error.code, err: result.error }, "trpc call failed");
+  }
+
+  return result;
+});
+"#;
+
+const TIMING_MIDDLEWARE: &str = r#"/** Times every procedure and counts its database queries; slow ones are logged as warnings */
+const timing = t.middleware(({ path, type, next }) => timeProcedure({ path, type }, next));
+"#;
+
+const TIMING_MIDDLEWARE_LOGGED: &str = r#"/** Times every procedure and counts its database queries; slow ones are logged as warnings */
+const timing = t.middleware(({ ctx, path, type, next }) => timeProcedure({ path, type, log: ctx.log }, next));
+"#;
+
+const TRPC_INIT_WITH_SESSION: &str = r#"import { initTRPC, TRPCError } from "@trpc/server";
+import superjson from "superjson";
+import { ZodError } from "zod";
+import { db } from "@/server/db";
+import { getSession } from "@/server/session";
+
+export const createTRPCContext = async (opts: { headers: Headers }) => {
+  const session = await getSession();
+
+  return {
+    db,
+    session,
+    userId: session?.user?.id,
+    ...opts,
+  };
+};
+
+const t = initTRPC.context<typeof createTRPCContext>().create({
+  transformer: superjson,
+  errorFormatter({ shape, error }) {
+    return {
+      ...shape,
+      data: {
+        ...shape.data,
+        zodError:
+          error.cause instanceof ZodError ? error.cause.flatten() : null,
+      },
+    };
+  },
+});
+
+export const createCallerFactory = t.createCallerFactory;
+export const createTRPCRouter = t.router;
+export const publicProcedure = t.procedure;
+
+const enforceAuth = t.middleware(({ ctx, next }) => {
+  if (!ctx.session?.user?.id) {
+    throw new TRPCError({ code: "UNAUTHORIZED" });
+  }
+  return next({
+    ctx: {
+      session: ctx.session,
+      userId: ctx.session.user.id,
+    },
+  });
+});
+
+export const protectedProcedure = t.procedure.use(enforceAuth);
+"#;
+
+const AUTH_ROUTER: &str = r#"import { createTRPCRouter, protectedProcedure } from "@/server/api/trpc";
+
+export const authRouter = createTRPCRouter({
+  // Throws UNAUTHORIZED for anonymous callers, so `ctx.userId` is always set here
+  me: protectedProcedure.query(({ ctx }) => {
+    return ctx.db.user.findUnique({
+      where: { id: ctx.userId },
+      select: { id: true, name: true, email: true, image: true, createdAt: true },
+    });
+  }),
+});
+"#;
+
+const AUTH_ROUTER_TEST: &str = r#"import { beforeEach, describe, expect, it, vi } from "vitest";
+import { createCallerFactory, createTRPCContext } from "@/server/api/trpc";
+import { authRouter } from "@/server/api/routers/auth";
+import { db } from "@/server/db";
+import { getSession } from "@/server/session";
+
+// Keep the test off the real database and auth provider
+vi.mock("@/server/db", () => ({
+  db: { user: { findUnique: vi.fn() } },
+}));
+vi.mock("@/server/session", () => ({
+  getSession: vi.fn(),
+}));
+
+const createCaller = createCallerFactory(authRouter);
+
+function caller() {
+  return createCaller(() => createTRPCContext({ headers: new Headers() }));
+}
+
+describe("auth router", () => {
+  beforeEach(() => {
+    vi.resetAllMocks();
+  });
+
+  it("rejects anonymous callers", async () => {
+    vi.mocked(getSession).mockResolvedValue(null);
+
+    await expect(caller().me()).rejects.toMatchObject({ code: "UNAUTHORIZED" });
+  });
+
+  it("returns the signed-in user", async () => {
+    const user = {
+      id: "user_1",
+      name: "Demo User",
+      email: "demo@example.com",
+      image: null,
+      createdAt: new Date(),
+    };
+    vi.mocked(getSession).mockResolvedValue({ user: { id: user.id } } as never);
+    vi.mocked(db.user.findUnique).mockResolvedValue(user as never);
+
+    await expect(caller().me()).resolves.toEqual(user);
+    expect(db.user.findUnique).toHaveBeenCalledWith(
+      expect.objectContaining({ where: { id: user.id } }),
+    );
+  });
+});
+"#;
@@ -1,8 +1,32 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use std::path::Path;
 
-use crate::templates::embedded;
-use crate::utils::fs::write_file;
+use crate::scaffolding::nav::{self, NavEntry};
+use crate::scaffolding::trpc;
+use crate::templates::remote;
+use crate::utils::fs::{self, update_file, write_file};
+
+/// npm packages the UI kit imports
+pub const UI_DEPENDENCIES: &[(&str, &str)] = &[
+    ("@floating-ui/react", "^0.27.18"),
+    ("@tanstack/react-table", "^8.21.3"),
+    ("class-variance-authority", "^0.7.1"),
+    ("clsx", "^2.1.1"),
+    ("date-fns", "^4.1.0"),
+    ("lucide-react", "^0.574.0"),
+    ("react-day-picker", "^9.13.2"),
+    ("recharts", "^2.15.4"),
+    ("sonner", "^2.0.7"),
+    ("tailwind-merge", "^3.4.1"),
+    ("next-themes", "^0.4.6"),
+];
+
+const MEMBERS_NAV: NavEntry = NavEntry {
+    href: "/dashboard/members",
+    key: "members",
+    en: "Members",
+    de: "Mitglieder",
+};
 
 /// Scaffold UI component library
 pub async fn scaffold(project_path: &str) -> Result<()> {
@@ -10,10 +34,10 @@ pub async fn scaffold(project_path: &str) -> Result<()> {
 
     // Create UI components directory
     let ui_path = project.join("src/components/ui");
-    tokio::fs::create_dir_all(&ui_path).await?;
+    fs::create_dir_all(&ui_path)?;
 
-    // Copy embedded UI templates
-    embedded::copy_embedded_dir("ui/", &ui_path).await?;
+    // Copy the UI kit from the pinned template ref, or the embedded copy
+    remote::copy_template_dir("ui", "ui/", &ui_path).await?;
 
     // Update globals.css with theme config
     update_globals_css(project_path).await?;
@@ -23,25 +47,221 @@ pub async fn scaffold(project_path: &str) -> Result<()> {
 
     // Create utils directory with hooks (only included with UI)
     let utils_path = project.join("src/utils");
-    tokio::fs::create_dir_all(&utils_path).await?;
+    fs::create_dir_all(&utils_path)?;
     write_file(project_path, "src/utils/use-mobile.ts", USE_MOBILE_HOOK)?;
 
+    // Restyle the error, loading, and not-found boundaries with the kit
+    write_file(project_path, "src/app/error.tsx", APP_ERROR)?;
+    write_file(project_path, "src/app/loading.tsx", APP_LOADING)?;
+    write_file(project_path, "src/app/not-found.tsx", APP_NOT_FOUND)?;
+
+    // Server-side paginated data table example
+    write_file(
+        project_path,
+        "src/server/api/routers/members.ts",
+        MEMBERS_ROUTER,
+    )?;
+    trpc::register_router(
+        project_path,
+        "members",
+        "membersRouter",
+        "@/server/api/routers/members",
+    )?;
+    write_file(
+        project_path,
+        "src/app/dashboard/members/page.tsx",
+        MEMBERS_PAGE,
+    )?;
+    write_file(
+        project_path,
+        "src/app/dashboard/members/MembersTable.tsx",
+        MEMBERS_TABLE,
+    )?;
+    nav::register(project_path, &MEMBERS_NAV)?;
+
+    // Shared mutation error handling: toasts plus field messages
+    write_file(project_path, "src/trpc/errors.ts", TRPC_ERRORS)?;
+    mount_toaster(project_path)?;
+    if fs::path_exists(project.join("src/app/posts/_components/CreatePost.tsx")) {
+        write_file(
+            project_path,
+            "src/app/posts/_components/CreatePost.tsx",
+            CREATE_POST_COMPONENT,
+        )?;
+    }
+
     Ok(())
 }
 
-async fn update_globals_css(project_path: &str) -> Result<()> {
-    let globals_path = Path::new(project_path).join("src/app/globals.css");
+/// Render the sonner `Toaster` once, inside the theme provider so it follows dark mode
+fn mount_toaster(project_path: &str) -> Result<()> {
+    let layout_path = Path::new(project_path).join("src/app/layout.tsx");
+    let content = fs::read_to_string(&layout_path).context("Failed to read src/app/layout.tsx")?;
 
-    // Write the full globals.css with theming
-    tokio::fs::write(&globals_path, GLOBALS_CSS_THEMED).await?;
+    if content.contains("<Toaster") {
+        return Ok(());
+    }
 
-    Ok(())
+    let patched = content
+        .replacen(
+            "import { TRPCReactProvider } from \"@/trpc/react\";\n",
+            "import { Toaster } from \"@/components/ui/sonner\";\nimport { TRPCReactProvider } from \"@/trpc/react\";\n",
+            1,
+        )
+        .replacen(
+            "          </NextIntlClientProvider>",
+            "            <Toaster />\n          </NextIntlClientProvider>",
+            1,
+        );
+    update_file(project_path, "src/app/layout.tsx", &patched)
+}
+
+async fn update_globals_css(project_path: &str) -> Result<()> {
+    // Write the full globals.css with theming
+    write_file(project_path, "src/app/globals.css", GLOBALS_CSS_THEMED)
 }
 
 // ============================================================================
 // Embedded Templates
 // ============================================================================
 
+const TRPC_ERRORS: &str = r#"import { TRPCClientError } from "@trpc/client";
+import { toast } from "sonner";
+import type { AppRouter } from "@/server/api/root";
+
+/** Toast titles per tRPC error code; override per call with `messages` */
+const DEFAULT_MESSAGES: Record<string, string> = {
+  BAD_REQUEST: "Please check your input.",
+  UNAUTHORIZED: "Please sign in to continue.",
+  FORBIDDEN: "You don't have permission to do that.",
+  NOT_FOUND: "That item no longer exists.",
+  CONFLICT: "This was changed in the meantime. Reload and try again.",
+  TOO_MANY_REQUESTS: "Too many requests. Try again in a moment.",
+  INTERNAL_SERVER_ERROR: "Something went wrong on our side. Please try again.",
+};
+
+export type FieldErrors = Record<string, string[] | undefined>;
+
+export interface MutationErrorOptions {
+  /** Show field errors next to their inputs, e.g. via react-hook-form's `setError` */
+  setFieldError?: (field: string, message: string) => void;
+  messages?: Record<string, string>;
+}
+
+function isTRPCError(error: unknown): error is TRPCClientError<AppRouter> {
+  return error instanceof TRPCClientError;
+}
+
+/** Flattened zod field errors added by the errorFormatter in `src/server/api/trpc.ts` */
+export function getFieldErrors(error: unknown): FieldErrors {
+  return isTRPCError(error) ? (error.data?.zodError?.fieldErrors ?? {}) : {};
+}
+
+/**
+ * Route a mutation error to the user: zod field errors go to the form when `setFieldError`
+ * is given, everything else becomes a toast titled by the error code.
+ */
+export function handleMutationError(error: unknown, options: MutationErrorOptions = {}) {
+  const fields = Object.entries(getFieldErrors(error)).flatMap(([field, messages]) =>
+    messages?.[0] ? [[field, messages[0]] as const] : [],
+  );
+
+  if (options.setFieldError && fields.length > 0) {
+    for (const [field, message] of fields) {
+      options.setFieldError(field, message);
+    }
+    return;
+  }
+
+  const code = isTRPCError(error) ? error.data?.code : undefined;
+  const title = (code && (options.messages?.[code] ?? DEFAULT_MESSAGES[code])) || "Something went wrong.";
+  // Validation messages are JSON and internal errors may leak details, so neither is shown as is
+  const description =
+    fields.length > 0
+      ? fields.map(([field, message]) => `${field}: ${message}`).join("\n")
+      : code && code !== "BAD_REQUEST" && code !== "INTERNAL_SERVER_ERROR" && error instanceof Error
+        ? error.message
+        : undefined;
+
+  toast.error(title, { description });
+}
+
+/** `onError` callback for `useMutation`: `api.post.create.useMutation({ onError: onMutationError() })` */
+export function onMutationError(options?: MutationErrorOptions) {
+  return (error: unknown) => handleMutationError(error, options);
+}
+"#;
+
+const CREATE_POST_COMPONENT: &str = r#""use client";
+
+import { useState } from "react";
+import { useRouter } from "next/navigation";
+import { toast } from "sonner";
+
+import { Button, Input } from "@/components/ui";
+import { createPostSchema } from "@/schemas/post";
+import { onMutationError } from "@/trpc/errors";
+import { api } from "@/trpc/react";
+
+export function CreatePost() {
+  const router = useRouter();
+  const [name, setName] = useState("");
+  const [nameError, setNameError] = useState<string>();
+
+  const createPost = api.post.create.useMutation({
+    onSuccess: () => {
+      setName("");
+      toast.success("Post created");
+      // Re-render the server component so the new post shows up in the list
+      router.refresh();
+    },
+    // Zod errors on `name` show under the input; auth, rate limit, and server errors as toasts
+    onError: onMutationError({
+      setFieldError: (field, message) => {
+        if (field === "name") {
+          setNameError(message);
+        }
+      },
+    }),
+  });
+
+  return (
+    <form
+      onSubmit={(e) => {
+        e.preventDefault();
+        // Same schema as the procedure input, so invalid titles never leave the browser
+        const result = createPostSchema.safeParse({ name });
+        if (!result.success) {
+          setNameError(result.error.issues[0]?.message);
+          return;
+        }
+        setNameError(undefined);
+        createPost.mutate(result.data);
+      }}
+      className="flex flex-col gap-2"
+    >
+      <div className="flex gap-2">
+        <Input
+          placeholder="Title"
+          value={name}
+          onChange={(e) => setName(e.target.value)}
+          aria-invalid={nameError ? true : undefined}
+          aria-describedby={nameError ? "post-name-error" : undefined}
+        />
+        <Button type="submit" disabled={createPost.isPending || name.length === 0}>
+          {createPost.isPending ? "Creating..." : "Create"}
+        </Button>
+      </div>
+      {nameError && (
+        <p id="post-name-error" className="text-sm text-destructive">
+          {nameError}
+        </p>
+      )}
+    </form>
+  );
+}
+"#;
+
 const UI_INDEX: &str = r#"// UI Components - Re-exports
 export * from "./accordion";
 export * from "./alert";
@@ -56,6 +276,8 @@ export * from "./chart";
 export * from "./checkbox";
 export * from "./collapsible";
 export * from "./context-menu";
+export * from "./data-table";
+export * from "./data-table-columns";
 export * from "./dialog";
 export * from "./dropdown-menu";
 export * from "./empty";
@@ -105,6 +327,253 @@ export function useIsMobile() {
 }
 "#;
 
+const APP_ERROR: &str = r#""use client";
+
+import { TriangleAlert } from "lucide-react";
+import Link from "next/link";
+import { useTranslations } from "next-intl";
+import { useEffect } from "react";
+import {
+  Button,
+  Empty,
+  EmptyContent,
+  EmptyDescription,
+  EmptyHeader,
+  EmptyMedia,
+  EmptyTitle,
+} from "@/components/ui";
+
+export default function ErrorPage({
+  error,
+  reset,
+}: {
+  error: Error & { digest?: string };
+  reset: () => void;
+}) {
+  const t = useTranslations("errors");
+
+  useEffect(() => {
+    console.error(error);
+  }, [error]);
+
+  return (
+    <main className="min-h-screen flex">
+      <Empty>
+        <EmptyHeader>
+          <EmptyMedia variant="icon">
+            <TriangleAlert />
+          </EmptyMedia>
+          <EmptyTitle>{t("title")}</EmptyTitle>
+          <EmptyDescription>{t("description")}</EmptyDescription>
+        </EmptyHeader>
+        <EmptyContent>
+          <div className="flex gap-3">
+            <Button onClick={reset}>{t("retry")}</Button>
+            <Button variant="outline" asChild>
+              <Link href="/">{t("home")}</Link>
+            </Button>
+          </div>
+          {error.digest && <p className="text-xs text-muted-foreground">{error.digest}</p>}
+        </EmptyContent>
+      </Empty>
+    </main>
+  );
+}
+"#;
+
+const APP_LOADING: &str = r#"import { useTranslations } from "next-intl";
+import { Spinner } from "@/components/ui";
+
+export default function Loading() {
+  const t = useTranslations("errors");
+
+  return (
+    <div className="min-h-screen flex items-center justify-center">
+      <Spinner className="size-8 text-muted-foreground" aria-label={t("loading")} />
+    </div>
+  );
+}
+"#;
+
+const APP_NOT_FOUND: &str = r#"import { FileQuestion } from "lucide-react";
+import Link from "next/link";
+import { useTranslations } from "next-intl";
+import {
+  Button,
+  Empty,
+  EmptyContent,
+  EmptyDescription,
+  EmptyHeader,
+  EmptyMedia,
+  EmptyTitle,
+} from "@/components/ui";
+
+export default function NotFound() {
+  const t = useTranslations("errors");
+
+  return (
+    <main className="min-h-screen flex">
+      <Empty>
+        <EmptyHeader>
+          <EmptyMedia variant="icon">
+            <FileQuestion />
+          </EmptyMedia>
+          <EmptyTitle>{t("notFoundTitle")}</EmptyTitle>
+          <EmptyDescription>{t("notFoundDescription")}</EmptyDescription>
+        </EmptyHeader>
+        <EmptyContent>
+          <Button asChild>
+            <Link href="/">{t("home")}</Link>
+          </Button>
+        </EmptyContent>
+      </Empty>
+    </main>
+  );
+}
+"#;
+
+const MEMBERS_ROUTER: &str = r#"import { z } from "zod";
+import { createTRPCRouter, protectedProcedure } from "@/server/api/trpc";
+
+export const MEMBER_SORT_FIELDS = ["name", "email", "createdAt"] as const;
+
+export const membersRouter = createTRPCRouter({
+  // One page of users, sorted and searched in the database
+  list: protectedProcedure
+    .input(
+      z.object({
+        page: z.number().int().min(0).default(0),
+        pageSize: z.number().int().min(1).max(100).default(10),
+        search: z.string().trim().max(100).optional(),
+        sortBy: z.enum(MEMBER_SORT_FIELDS).default("createdAt"),
+        sortDesc: z.boolean().default(true),
+      }),
+    )
+    .query(async ({ ctx, input }) => {
+      const where = input.search
+        ? {
+            OR: [
+              { name: { contains: input.search, mode: "insensitive" as const } },
+              { email: { contains: input.search, mode: "insensitive" as const } },
+            ],
+          }
+        : {};
+
+      const [rows, total] = await ctx.db.$transaction([
+        ctx.db.user.findMany({
+          where,
+          orderBy: { [input.sortBy]: input.sortDesc ? "desc" : "asc" },
+          skip: input.page * input.pageSize,
+          take: input.pageSize,
+          select: { id: true, name: true, email: true, createdAt: true },
+        }),
+        ctx.db.user.count({ where }),
+      ]);
+
+      return { rows, total };
+    }),
+});
+"#;
+
+const MEMBERS_PAGE: &str = r#"import { redirect } from "next/navigation";
+
+import { Header } from "@/app/_components/Header";
+import { getSession, SIGN_IN_PATH } from "@/server/session";
+import { MembersTable } from "./MembersTable";
+
+export default async function MembersPage() {
+  const session = await getSession();
+  if (!session) {
+    redirect(SIGN_IN_PATH);
+  }
+
+  return (
+    <div className="min-h-screen flex flex-col bg-background">
+      <Header />
+
+      <main className="flex-1 max-w-7xl mx-auto px-4 sm:px-6 lg:px-8 py-8 w-full">
+        <h1 className="text-2xl font-semibold mb-6">Members</h1>
+        <MembersTable />
+      </main>
+    </div>
+  );
+}
+"#;
+
+const MEMBERS_TABLE: &str = r#""use client";
+
+import { keepPreviousData } from "@tanstack/react-query";
+import type { ColumnDef } from "@tanstack/react-table";
+import { Copy } from "lucide-react";
+import {
+  DataTable,
+  rowActionsColumn,
+  sortableHeader,
+  useDataTableState,
+} from "@/components/ui";
+import { api, type RouterInputs, type RouterOutputs } from "@/trpc/react";
+
+type Member = RouterOutputs["members"]["list"]["rows"][number];
+type SortField = NonNullable<RouterInputs["members"]["list"]["sortBy"]>;
+
+const columns: ColumnDef<Member>[] = [
+  {
+    accessorKey: "name",
+    header: sortableHeader("Name"),
+    cell: ({ row }) => row.original.name ?? "—",
+  },
+  {
+    accessorKey: "email",
+    header: sortableHeader("Email"),
+  },
+  {
+    accessorKey: "createdAt",
+    header: sortableHeader("Joined"),
+    cell: ({ row }) => row.original.createdAt.toLocaleDateString(),
+  },
+  rowActionsColumn<Member>([
+    {
+      label: "Copy email",
+      icon: <Copy />,
+      onSelect: (member) => void navigator.clipboard.writeText(member.email),
+    },
+    {
+      label: "Copy ID",
+      icon: <Copy />,
+      onSelect: (member) => void navigator.clipboard.writeText(member.id),
+    },
+  ]),
+];
+
+export function MembersTable() {
+  const state = useDataTableState();
+  const sort = state.sorting[0];
+
+  // The table state becomes the query input; the previous page stays visible while loading
+  const members = api.members.list.useQuery(
+    {
+      page: state.pagination.pageIndex,
+      pageSize: state.pagination.pageSize,
+      search: state.globalFilter || undefined,
+      sortBy: (sort?.id as SortField | undefined) ?? "createdAt",
+      sortDesc: sort?.desc ?? true,
+    },
+    { placeholderData: keepPreviousData },
+  );
+
+  return (
+    <DataTable
+      columns={columns}
+      data={members.data?.rows ?? []}
+      rowCount={members.data?.total ?? 0}
+      state={state}
+      filterPlaceholder="Search by name or email..."
+      isLoading={members.isLoading}
+    />
+  );
+}
+"#;
+
 const GLOBALS_CSS_THEMED: &str = r#"@import "tailwindcss";
 
 @theme inline {
@@ -0,0 +1,300 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use crate::cli::DatabaseProvider;
+use crate::scaffolding::database;
+use crate::scaffolding::env::{self, EnvFragment, EnvVar};
+use crate::scaffolding::i18n;
+use crate::scaffolding::nav::{self, NavEntry};
+use crate::scaffolding::trpc;
+use crate::templates::embedded;
+use crate::utils::fs::{self, update_file};
+use crate::utils::plan::{self, Op};
+
+const ENV: EnvFragment = EnvFragment {
+    section: "Webhooks",
+    vars: &[
+        EnvVar::server("WEBHOOK_WORKER_SECRET", "z.string().min(16).optional()")
+            .example("dev-webhook-worker-secret")
+            .production(""),
+    ],
+};
+
+const NAV: NavEntry = NavEntry {
+    href: "/settings/webhooks",
+    key: "webhooks",
+    en: "Webhooks",
+    de: "Webhooks",
+};
+
+const RESTATE_INDEX: &str = "restate/services/src/index.ts";
+
+/// Whether deliveries go through the Restate extension's services
+pub fn uses_restate(project_path: &str) -> bool {
+    fs::path_exists(Path::new(project_path).join(RESTATE_INDEX))
+}
+
+/// Scaffold webhook delivery: endpoint and delivery models, signed sending with retries,
+/// a `webhook` tRPC router, and `/settings/webhooks` for managing endpoints. With the
+/// Restate extension, a `WebhookDelivery` service drives the retries instead of a scheduler.
+pub async fn scaffold(project_path: &str) -> Result<()> {
+    let project = Path::new(project_path);
+
+    let schema_path = project.join("prisma/schema.prisma");
+    let schema = fs::read_to_string(&schema_path).context("Failed to read prisma/schema.prisma")?;
+    let models = if schema.contains("provider = \"sqlite\"") {
+        database::adapt_schema(PRISMA_WEBHOOK_MODELS, DatabaseProvider::Sqlite)
+    } else {
+        PRISMA_WEBHOOK_MODELS.to_string()
+    };
+    plan::apply(Op::PatchPrisma {
+        path: schema_path,
+        blocks: models,
+    })?;
+
+    embedded::copy_embedded_dir("webhooks/common/", project).await?;
+    if uses_restate(project_path) {
+        embedded::copy_embedded_dir("webhooks/restate/", project).await?;
+        add_restate_service(project_path)?;
+    } else {
+        embedded::copy_embedded_dir("webhooks/direct/", project).await?;
+    }
+
+    trpc::register_router(
+        project_path,
+        "webhook",
+        "webhookRouter",
+        "@/server/api/routers/webhook",
+    )?;
+    env::register(project_path, &ENV)?;
+    i18n::merge_translations(project_path, "messages/en.json", MESSAGES_EN)?;
+    i18n::merge_translations(project_path, "messages/de.json", MESSAGES_DE)?;
+    nav::register(project_path, &NAV)?;
+
+    Ok(())
+}
+
+/// Bind `WebhookDelivery` next to the other services and add its contract
+fn add_restate_service(project_path: &str) -> Result<()> {
+    patch(
+        project_path,
+        "restate/services/src/contracts.ts",
+        |content| {
+            content
+            .replacen(
+                "// ============================================================================\n// Contract registry",
+                &format!("{}// ============================================================================\n// Contract registry", WEBHOOK_DELIVERY_CONTRACT),
+                1,
+            )
+            .replacen(
+                "  AWSLambdaService: {\n    invokeAsync: { input: InvokeAsyncInputSchema, output: InvokeAsyncOutputSchema },\n  },\n",
+                "  AWSLambdaService: {\n    invokeAsync: { input: InvokeAsyncInputSchema, output: InvokeAsyncOutputSchema },\n  },\n  WebhookDelivery: {\n    deliver: { input: WebhookDeliveryInputSchema, output: WebhookDeliveryOutputSchema },\n  },\n",
+                1,
+            )
+            .replacen(
+                "export type GetFunctionInput = z.infer<typeof GetFunctionInputSchema>;\n",
+                "export type GetFunctionInput = z.infer<typeof GetFunctionInputSchema>;\nexport type WebhookDeliveryInput = z.infer<typeof WebhookDeliveryInputSchema>;\nexport type WebhookDeliveryOutput = z.infer<typeof WebhookDeliveryOutputSchema>;\n",
+                1,
+            )
+        },
+    )?;
+    patch(project_path, RESTATE_INDEX, |content| {
+        content
+            .replacen(
+                "import { AWSLambdaService } from \"./aws-lambda\";\n",
+                "import { AWSLambdaService } from \"./aws-lambda\";\nimport { WebhookDelivery } from \"./webhook-delivery\";\n",
+                1,
+            )
+            .replacen(
+                "export { EmbeddingService, ExtractionService, AWSS3Service, AWSLambdaService",
+                "export { EmbeddingService, ExtractionService, AWSS3Service, AWSLambdaService, WebhookDelivery",
+                1,
+            )
+            .replacen("    .bind(AWSLambdaService)\n", "    .bind(AWSLambdaService)\n    .bind(WebhookDelivery)\n", 1)
+            .replacen(
+                "      console.log(\"  - AWSLambdaService (AWS Lambda invocations)\");\n",
+                "      console.log(\"  - AWSLambdaService (AWS Lambda invocations)\");\n      console.log(\"  - WebhookDelivery (webhook retries)\");\n",
+                1,
+            )
+    })?;
+    patch(
+        project_path,
+        "restate/services/.env.example",
+        |mut content| {
+            content.push_str(SERVICES_ENV);
+            content
+        },
+    )
+}
+
+fn patch(
+    project_path: &str,
+    relative_path: &str,
+    rewrite: impl FnOnce(String) -> String,
+) -> Result<()> {
+    let content = fs::read_to_string(Path::new(project_path).join(relative_path))
+        .with_context(|| format!("Failed to read {}", relative_path))?;
+    update_file(project_path, relative_path, &rewrite(content))
+}
+
+// ============================================================================
+// Embedded Templates
+// ============================================================================
+
+const PRISMA_WEBHOOK_MODELS: &str = r#"
+// ============================================================================
+// Webhooks
+// ============================================================================
+
+/// An endpoint a user registered to receive events
+model WebhookEndpoint {
+  id          String   @id @default(cuid())
+  userId      String
+  url         String   @db.Text
+  description String?
+  /// Signs every delivery (Standard Webhooks format)
+  secret      String
+  /// Comma-separated event types, or `*` for all of them
+  events      String   @default("*")
+  enabled     Boolean  @default(true)
+  createdAt   DateTime @default(now())
+  updatedAt   DateTime @updatedAt
+
+  deliveries WebhookDelivery[]
+
+  @@index([userId])
+}
+
+/// One event sent to one endpoint, retried until it succeeds or runs out of attempts
+model WebhookDelivery {
+  id            String          @id @default(cuid())
+  endpointId    String
+  endpoint      WebhookEndpoint @relation(fields: [endpointId], references: [id], onDelete: Cascade)
+  event         String
+  payload       String          @db.Text
+  /// pending, succeeded, or failed
+  status        String          @default("pending")
+  attempts      Int             @default(0)
+  nextAttemptAt DateTime        @default(now())
+  /// HTTP status of the last attempt, if the endpoint answered
+  lastStatus    Int?
+  lastError     String?         @db.Text
+  createdAt     DateTime        @default(now())
+  deliveredAt   DateTime?
+
+  @@index([status, nextAttemptAt])
+  @@index([endpointId, createdAt])
+}
+"#;
+
+const WEBHOOK_DELIVERY_CONTRACT: &str = r#"// ============================================================================
+// WebhookDelivery (retries of the app's webhook deliveries)
+// ============================================================================
+
+export const WebhookDeliveryInputSchema = z.object({
+  deliveryId: z.string(),
+});
+
+export const WebhookDeliveryOutputSchema = z.object({
+  deliveryId: z.string(),
+  delivered: z.boolean(),
+});
+
+"#;
+
+const SERVICES_ENV: &str = r#"
+# Webhooks (WebhookDelivery); the app as the services reach it, e.g.
+# http://host.docker.internal:3000 from the compose stack
+APP_URL=http://localhost:3000
+WEBHOOK_WORKER_SECRET=dev-webhook-worker-secret
+"#;
+
+const MESSAGES_EN: &str = r#"{
+  "webhooks": {
+    "title": "Webhooks",
+    "description": "Send events from this app to your own endpoints. Every request is signed with the endpoint's secret.",
+    "create": {
+      "title": "Add endpoint",
+      "url": "Endpoint URL",
+      "description": "Description",
+      "descriptionHint": "Optional, e.g. what receives the events.",
+      "events": "Events",
+      "allEvents": "All events",
+      "submit": "Add endpoint",
+      "adding": "Adding..."
+    },
+    "secret": {
+      "title": "Signing secret",
+      "hint": "Copy it now and verify deliveries with it; it won't be shown again.",
+      "copy": "Copy",
+      "copied": "Copied"
+    },
+    "endpoints": {
+      "empty": "No endpoints yet.",
+      "enabled": "Enabled",
+      "disabled": "Disabled",
+      "enable": "Enable",
+      "disable": "Disable",
+      "test": "Send test event",
+      "rotate": "Rotate secret",
+      "deliveries": "Deliveries",
+      "hideDeliveries": "Hide deliveries",
+      "delete": "Delete",
+      "confirmDelete": "Delete this endpoint and its delivery history?"
+    },
+    "deliveries": {
+      "loading": "Loading deliveries...",
+      "empty": "No deliveries yet.",
+      "pending": "Pending",
+      "succeeded": "Delivered",
+      "failed": "Failed",
+      "attempts": "{count, plural, one {# attempt} other {# attempts}}",
+      "redeliver": "Redeliver"
+    }
+  }
+}"#;
+
+const MESSAGES_DE: &str = r#"{
+  "webhooks": {
+    "title": "Webhooks",
+    "description": "Senden Sie Ereignisse dieser App an Ihre eigenen Endpunkte. Jede Anfrage wird mit dem Secret des Endpunkts signiert.",
+    "create": {
+      "title": "Endpunkt hinzufügen",
+      "url": "Endpunkt-URL",
+      "description": "Beschreibung",
+      "descriptionHint": "Optional, z. B. was die Ereignisse empfängt.",
+      "events": "Ereignisse",
+      "allEvents": "Alle Ereignisse",
+      "submit": "Endpunkt hinzufügen",
+      "adding": "Wird hinzugefügt..."
+    },
+    "secret": {
+      "title": "Signatur-Secret",
+      "hint": "Kopieren Sie es jetzt und prüfen Sie Zustellungen damit; es wird nicht erneut angezeigt.",
+      "copy": "Kopieren",
+      "copied": "Kopiert"
+    },
+    "endpoints": {
+      "empty": "Noch keine Endpunkte.",
+      "enabled": "Aktiv",
+      "disabled": "Deaktiviert",
+      "enable": "Aktivieren",
+      "disable": "Deaktivieren",
+      "test": "Testereignis senden",
+      "rotate": "Secret erneuern",
+      "deliveries": "Zustellungen",
+      "hideDeliveries": "Zustellungen ausblenden",
+      "delete": "Löschen",
+      "confirmDelete": "Diesen Endpunkt und seine Zustellungen löschen?"
+    },
+    "deliveries": {
+      "loading": "Zustellungen werden geladen...",
+      "empty": "Noch keine Zustellungen.",
+      "pending": "Ausstehend",
+      "succeeded": "Zugestellt",
+      "failed": "Fehlgeschlagen",
+      "attempts": "{count, plural, one {# Versuch} other {# Versuche}}",
+      "redeliver": "Erneut zustellen"
+    }
+  }
+}"#;
@@ -0,0 +1,114 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use crate::cli::WorkflowPattern;
+use crate::templates::embedded;
+use crate::utils::fs::{self, update_file, write_file};
+use crate::utils::naming::{kebab_case, pascal_case};
+
+const SERVICES_SRC: &str = "restate/services/src";
+const SERVICES_INDEX: &str = "restate/services/src/index.ts";
+
+pub struct GeneratedWorkflow {
+    /// Name the workflow is registered under, e.g. `ExpenseApprovalWorkflow`
+    pub service: String,
+    pub files: Vec<String>,
+    /// Files that did not match the scaffolded layout and need the workflow bound by hand
+    pub skipped: Vec<String>,
+}
+
+/// Generate a Restate workflow from `pattern` in `restate/services/src/` and bind it in the
+/// services index
+pub fn generate(
+    project_path: &str,
+    name: &str,
+    pattern: WorkflowPattern,
+) -> Result<GeneratedWorkflow> {
+    let project = Path::new(project_path);
+    if !fs::path_exists(project.join(SERVICES_INDEX)) {
+        anyhow::bail!("Workflows run on the Restate services. Run `t3-mono add restate` first.");
+    }
+
+    let file = kebab_case(name);
+    let path = format!("{}/{}.ts", SERVICES_SRC, file);
+    if fs::path_exists(project.join(&path)) {
+        anyhow::bail!("{} already exists", path);
+    }
+    let service = format!("{}Workflow", pascal_case(name));
+
+    let template = format!("generate/workflow/{}.ts", template_name(pattern));
+    let vars = [
+        ("Name", pascal_case(name)),
+        ("title", file.replace('-', " ")),
+    ];
+    let content = embedded::render_template(&template, &vars)
+        .with_context(|| format!("Missing embedded template {}", template))?;
+    write_file(project_path, &path, &content)?;
+
+    let mut files = vec![path];
+    let mut skipped = Vec::new();
+    let index = fs::read_to_string(project.join(SERVICES_INDEX))
+        .with_context(|| format!("Failed to read {}", SERVICES_INDEX))?;
+    match bind(&index, &service, &file, pattern) {
+        Some(updated) => {
+            update_file(project_path, SERVICES_INDEX, &updated)?;
+            files.push(SERVICES_INDEX.to_string());
+        }
+        None => skipped.push(SERVICES_INDEX.to_string()),
+    }
+
+    Ok(GeneratedWorkflow {
+        service,
+        files,
+        skipped,
+    })
+}
+
+fn template_name(pattern: WorkflowPattern) -> &'static str {
+    match pattern {
+        WorkflowPattern::Saga => "saga",
+        WorkflowPattern::Sequential => "sequential",
+        WorkflowPattern::HumanApproval => "human-approval",
+    }
+}
+
+/// Import, export, and `.bind()` the workflow in the services index, next to the last
+/// service of each; `None` when the index no longer has them
+fn bind(index: &str, service: &str, file: &str, pattern: WorkflowPattern) -> Option<String> {
+    let mut lines: Vec<String> = index.split('\n').map(String::from).collect();
+
+    let import = lines
+        .iter()
+        .rposition(|line| line.starts_with("import {") && line.contains("from \"./"))?;
+    let export = lines
+        .iter()
+        .position(|line| line.starts_with("export { ") && line.ends_with(" };"))?;
+    let bind = lines
+        .iter()
+        .rposition(|line| line.trim_start().starts_with(".bind("))?;
+    let listing = lines
+        .iter()
+        .rposition(|line| line.contains("console.log(\"  - "));
+
+    // From the bottom up, so the earlier positions stay valid
+    if let Some(listing) = listing {
+        let indent = &lines[listing][..lines[listing].len() - lines[listing].trim_start().len()];
+        let entry = format!(
+            "{}console.log(\"  - {} ({} workflow)\");",
+            indent,
+            service,
+            template_name(pattern).replace('-', " ")
+        );
+        lines.insert(listing + 1, entry);
+    }
+    let indent = &lines[bind][..lines[bind].len() - lines[bind].trim_start().len()];
+    let entry = format!("{}.bind({})", indent, service);
+    lines.insert(bind + 1, entry);
+    lines[export] = format!("{}, {} }};", lines[export].trim_end_matches(" };"), service);
+    lines.insert(
+        import + 1,
+        format!("import {{ {} }} from \"./{}\";", service, file),
+    );
+
+    Some(lines.join("\n"))
+}
@@ -1,46 +1,60 @@
+use anyhow::Result;
 use rust_embed::Embed;
+use std::borrow::Cow;
 use std::path::Path;
-use anyhow::Result;
-use tokio::fs;
+
+use crate::templates::resolver;
+use crate::utils::fs::to_slash;
+
+include!(concat!(env!("OUT_DIR"), "/executable_templates.rs"));
 
 #[derive(Embed)]
 #[folder = "templates/"]
 pub struct Templates;
 
-/// Get an embedded template file
+/// Get a template file from the first layer that has it (see `resolver`)
 pub fn get_template(path: &str) -> Option<String> {
-    Templates::get(path).map(|f| String::from_utf8_lossy(&f.data).to_string())
+    resolver::resolve(path).map(|content| match content {
+        Cow::Borrowed(bytes) => String::from_utf8_lossy(bytes).into_owned(),
+        // Valid UTF-8 becomes the String without a copy
+        Cow::Owned(bytes) => String::from_utf8(bytes)
+            .unwrap_or_else(|e| String::from_utf8_lossy(e.as_bytes()).into_owned()),
+    })
+}
+
+/// Get a template with every `{{key}}` placeholder replaced by its value
+pub fn render_template(path: &str, vars: &[(&str, String)]) -> Option<String> {
+    let mut content = get_template(path)?;
+    for (key, value) in vars {
+        content = content.replace(&format!("{{{{{}}}}}", key), value);
+    }
+    Some(content)
+}
+
+/// The copy of a file embedded in the binary, ignoring overrides; borrowed from the binary
+/// in release builds, which embed the template directory
+pub fn embedded_bytes(path: &str) -> Option<Cow<'static, [u8]>> {
+    Templates::get(path).map(|f| f.data)
 }
 
-/// List all files in an embedded directory
+/// Whether the embedded template had an executable bit in the source tree (see build.rs)
+pub fn is_executable(path: &str) -> bool {
+    EXECUTABLE_TEMPLATES.contains(&path)
+}
+
+/// List all files in an embedded directory, as `/`-separated paths whichever separator
+/// `prefix` and the platform use
 pub fn list_templates(prefix: &str) -> Vec<String> {
+    let prefix = to_slash(prefix);
     Templates::iter()
-        .filter(|p| p.starts_with(prefix))
-        .map(|p| p.to_string())
+        .map(|p| to_slash(&p))
+        .filter(|p| p.starts_with(&prefix))
         .collect()
 }
 
-/// Copy embedded templates to a destination directory
+/// Copy a template directory to a destination directory, byte for byte so binary
+/// files survive the copy, with project and user overrides applied; scripts keep their
+/// executable bit
 pub async fn copy_embedded_dir(embedded_prefix: &str, dest_path: &Path) -> Result<()> {
-    let files = list_templates(embedded_prefix);
-
-    for file_path in files {
-        if let Some(content) = get_template(&file_path) {
-            // Remove the prefix to get the relative path
-            let relative_path = file_path.strip_prefix(embedded_prefix)
-                .unwrap_or(&file_path)
-                .trim_start_matches('/');
-
-            let dest_file = dest_path.join(relative_path);
-
-            // Create parent directories
-            if let Some(parent) = dest_file.parent() {
-                fs::create_dir_all(parent).await?;
-            }
-
-            fs::write(&dest_file, content).await?;
-        }
-    }
-
-    Ok(())
+    resolver::copy_dir(embedded_prefix, None, dest_path).await
 }
@@ -1,23 +1,498 @@
 use anyhow::{Context, Result};
-use reqwest::Client;
-use std::path::Path;
+use console::style;
+use git2::{
+    CertificateCheckStatus, Cred, CredentialType, FetchOptions, ObjectType, ProxyOptions,
+    RemoteCallbacks, Repository, Tree,
+};
+use reqwest::{Certificate, Client, NoProxy, Proxy};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tokio::fs;
+use tokio::io::AsyncWriteExt;
 
-use crate::utils::fs::get_cache_dir;
+use crate::scaffolding::manifest;
+use crate::templates::resolver;
+use crate::utils::fs::{get_cache_dir, set_executable};
 
-const RAW_CONTENT_BASE: &str = "https://raw.githubusercontent.com/elijahross/boilerplate_moduls/main";
+const RAW_CONTENT_HOST: &str = "https://raw.githubusercontent.com";
+const GITHUB_API: &str = "https://api.github.com";
+
+/// Repository the templates come from, as `owner/name`
+pub const DEFAULT_REPO: &str = "elijahross/boilerplate_moduls";
+
+/// Environment variable with a GitHub token; fetching then goes through the GitHub API
+pub const TOKEN_ENV: &str = "GITHUB_TOKEN";
+
+/// Environment variable overriding the raw content host
+pub const MIRROR_ENV: &str = "T3_MONO_TEMPLATE_MIRROR";
+
+/// boilerplate_moduls tag, branch, or commit the templates are fetched from, pinned at
+/// build time (see build.rs)
+pub const TEMPLATES_REF: &str = env!("T3_MONO_TEMPLATES_REF");
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// How template directories are downloaded
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum FetchStrategy {
+    /// Raw file URLs, or the GitHub API when a token is set
+    #[default]
+    Http,
+    /// Shallow git fetch of the pinned ref, for networks that allow git but block
+    /// raw.githubusercontent.com
+    Git,
+}
+
+/// Network settings for template fetching, from flags, the environment, and t3mono.toml
+#[derive(Clone, Debug, Default)]
+pub struct FetchSettings {
+    pub strategy: FetchStrategy,
+    /// Clone URL for the git strategy; defaults to the GitHub https URL of the repo
+    pub git_url: Option<String>,
+    /// `owner/name` of a fork to fetch from instead of DEFAULT_REPO
+    pub repo: Option<String>,
+    /// GitHub token; when set, files are listed and downloaded through the GitHub API
+    pub github_token: Option<String>,
+    /// Serves the boilerplate_moduls tree as `<mirror>/<ref>/<path>`, e.g. an Artifactory
+    /// remote or a GitHub Enterprise raw URL
+    pub mirror: Option<String>,
+    /// Proxy for every request; HTTPS_PROXY/HTTP_PROXY are used when unset
+    pub proxy: Option<String>,
+    /// PEM files with extra root certificates, e.g. a TLS-intercepting proxy's CA
+    pub ca_certs: Vec<PathBuf>,
+    /// Skip certificate verification
+    pub insecure: bool,
+    /// How long a cached template directory is reused before it is fetched again
+    pub cache_ttl: Duration,
+    /// Ignore the cache for this run (the fresh fetch is still cached)
+    pub refresh: bool,
+}
+
+/// Cache entries older than this are fetched again unless t3mono.toml says otherwise
+pub const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+static SETTINGS: Mutex<Option<FetchSettings>> = Mutex::new(None);
+
+pub fn configure(mut settings: FetchSettings) -> Result<()> {
+    if let Some(repo) = &settings.repo {
+        let parts: Vec<&str> = repo.split('/').collect();
+        if parts.len() != 2 || parts.iter().any(|part| part.is_empty()) {
+            anyhow::bail!("Template repo must be 'owner/name', got '{}'", repo);
+        }
+    }
+    if let Some(mirror) = &settings.mirror {
+        let mirror = mirror.trim().trim_end_matches('/');
+        if !mirror.starts_with("https://") && !mirror.starts_with("http://") {
+            anyhow::bail!("Template mirror must be an http(s) URL, got '{}'", mirror);
+        }
+        settings.mirror = Some(mirror.to_string());
+    }
+    if settings.insecure {
+        eprintln!();
+        eprintln!(
+            "  {} {}",
+            style("WARNING:").red().bold(),
+            style("--insecure disables TLS certificate verification for template downloads.").red()
+        );
+        eprintln!("  Anyone on the network path can change the files written into your project.");
+        eprintln!(
+            "  Add your proxy's root certificate to [network] ca_certs in t3mono.toml instead."
+        );
+        eprintln!();
+    }
+    *SETTINGS.lock().unwrap_or_else(|e| e.into_inner()) = Some(settings);
+    Ok(())
+}
+
+fn settings() -> FetchSettings {
+    SETTINGS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+        .unwrap_or_else(|| FetchSettings {
+            cache_ttl: DEFAULT_CACHE_TTL,
+            ..FetchSettings::default()
+        })
+}
+
+/// Where templates are fetched from with the current settings; a cache entry from
+/// another source is never reused
+pub fn source() -> String {
+    let settings = settings();
+    match (settings.strategy, &settings.github_token, &settings.mirror) {
+        (FetchStrategy::Git, _, _) => settings
+            .git_url
+            .unwrap_or_else(|| format!("https://github.com/{}.git", repo())),
+        (FetchStrategy::Http, Some(_), None) => format!("{}/repos/{}", GITHUB_API, repo()),
+        (FetchStrategy::Http, _, _) => raw_content_base(),
+    }
+}
+
+/// Where a template directory came from
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TemplateSource {
+    /// boilerplate_moduls at the pinned ref
+    Remote,
+    /// The copy embedded in the binary
+    Embedded,
+}
+
+/// Template directories fetched from boilerplate_moduls, as (remote path, embedded prefix)
+const REMOTE_DIRS: &[(&str, &str)] = &[("ui", "ui/"), ("agents/core", "ai/core/")];
+
+/// Copy a template directory into `dest` from boilerplate_moduls at the pinned ref, or
+/// from the templates embedded in the binary when that ref can't be fetched; project
+/// and user overrides apply on top of either
+pub async fn copy_template_dir(
+    remote_path: &str,
+    embedded_prefix: &str,
+    dest: &Path,
+) -> Result<TemplateSource> {
+    if resolver::is_embedded_only() {
+        resolver::copy_dir(embedded_prefix, None, dest).await?;
+        return Ok(TemplateSource::Embedded);
+    }
+    let (remote_dir, source) = match fetch_cached(remote_path).await {
+        Ok(dir) => (Some(dir), TemplateSource::Remote),
+        Err(err) => {
+            eprintln!(
+                "  {} Using the embedded {} templates: {:#}",
+                style("!").yellow().bold(),
+                remote_path,
+                err
+            );
+            if format!("{:#}", err).contains("certificate") {
+                eprintln!(
+                    "    {}",
+                    style("Behind a TLS-intercepting proxy? Add its root certificate to [network] ca_certs in t3mono.toml").dim()
+                );
+            }
+            (None, TemplateSource::Embedded)
+        }
+    };
+    resolver::copy_dir(embedded_prefix, remote_dir.as_deref(), dest).await?;
+    Ok(source)
+}
+
+/// A single file of a fetched template directory, by its embedded path, when the cache
+/// holds a fresh copy; never fetches
+pub fn cached_file(embedded_path: &str) -> Option<Vec<u8>> {
+    let (remote_path, relative) = REMOTE_DIRS.iter().find_map(|(remote_path, prefix)| {
+        embedded_path
+            .strip_prefix(prefix)
+            .map(|relative| (remote_path, relative))
+    })?;
+    let cached_path = get_cache_dir()
+        .ok()?
+        .join(repo())
+        .join(TEMPLATES_REF)
+        .join(remote_path);
+    if !cache_is_fresh(&cached_path, settings().cache_ttl) {
+        return None;
+    }
+    std::fs::read(cached_path.join(relative)).ok()
+}
+
+fn repo() -> String {
+    settings().repo.unwrap_or_else(|| DEFAULT_REPO.to_string())
+}
+
+fn raw_content_base() -> String {
+    match settings().mirror {
+        Some(mirror) => format!("{}/{}", mirror, TEMPLATES_REF),
+        None => format!("{}/{}/{}", RAW_CONTENT_HOST, repo(), TEMPLATES_REF),
+    }
+}
+
+/// HTTP client with the configured proxy, CA certificates, and timeouts
+pub fn client() -> Result<Client> {
+    let settings = settings();
+    let mut builder = Client::builder()
+        .connect_timeout(CONNECT_TIMEOUT)
+        .timeout(REQUEST_TIMEOUT)
+        .tls_danger_accept_invalid_certs(settings.insecure);
+
+    for proxy in proxies(&settings)? {
+        builder = builder.proxy(proxy);
+    }
+
+    for path in &settings.ca_certs {
+        let pem = std::fs::read(path)
+            .with_context(|| format!("Failed to read CA certificate {}", path.display()))?;
+        let certs = Certificate::from_pem_bundle(&pem)
+            .with_context(|| format!("{} is not a PEM certificate bundle", path.display()))?;
+        builder = builder.tls_certs_merge(certs);
+    }
+
+    builder.build().context("Failed to create HTTP client")
+}
+
+/// The configured proxy, or HTTPS_PROXY/HTTP_PROXY (either case), honoring NO_PROXY
+fn proxies(settings: &FetchSettings) -> Result<Vec<Proxy>> {
+    let env = |name: &str| {
+        std::env::var(name)
+            .or_else(|_| std::env::var(name.to_lowercase()))
+            .ok()
+            .filter(|value| !value.is_empty())
+    };
+
+    let proxies = match &settings.proxy {
+        Some(url) => vec![Proxy::all(url).with_context(|| format!("Invalid proxy URL '{}'", url))?],
+        None => {
+            let mut proxies = Vec::new();
+            if let Some(url) = env("HTTPS_PROXY") {
+                proxies.push(
+                    Proxy::https(&url).with_context(|| format!("Invalid HTTPS_PROXY '{}'", url))?,
+                );
+            }
+            if let Some(url) = env("HTTP_PROXY") {
+                proxies.push(
+                    Proxy::http(&url).with_context(|| format!("Invalid HTTP_PROXY '{}'", url))?,
+                );
+            }
+            proxies
+        }
+    };
+
+    Ok(proxies
+        .into_iter()
+        .map(|proxy| proxy.no_proxy(NoProxy::from_env()))
+        .collect())
+}
 
 /// Fetch a directory from the GitHub repository
-/// Uses direct raw URL fetching for reliability (avoids API rate limits)
+/// Uses direct raw URL fetching unless a token is set, since anonymous API calls are
+/// rate limited to 60 an hour
 pub async fn fetch_directory(remote_path: &str, local_path: &Path) -> Result<()> {
-    let client = Client::new();
+    let settings = settings();
+    if settings.strategy == FetchStrategy::Git {
+        let (remote_path, local_path) = (remote_path.to_string(), local_path.to_path_buf());
+        return tokio::task::spawn_blocking(move || {
+            fetch_with_git(&settings, &remote_path, &local_path)
+        })
+        .await?;
+    }
+
+    let client = client()?;
+    match (&settings.github_token, &settings.mirror) {
+        (Some(token), None) => fetch_with_api(&client, token, remote_path, local_path).await,
+        _ => fetch_known_files(&client, remote_path, local_path).await,
+    }
+}
+
+#[derive(Deserialize)]
+struct GitTree {
+    tree: Vec<GitTreeEntry>,
+    truncated: bool,
+}
+
+#[derive(Deserialize)]
+struct GitTreeEntry {
+    path: String,
+    #[serde(rename = "type")]
+    kind: String,
+    mode: String,
+}
 
-    // Always use direct fetching approach for known file structures
-    // This avoids GitHub API rate limits
-    fetch_known_files(&client, remote_path, local_path).await
+/// git's file mode of an executable blob
+const EXECUTABLE_MODE: &str = "100755";
+
+/// git's file mode of a symlink, whose blob is the link target
+const SYMLINK_MODE: &str = "120000";
+
+/// List `remote_path` with the git trees API and download every file in it, which also
+/// works for private forks the token can read
+async fn fetch_with_api(
+    client: &Client,
+    token: &str,
+    remote_path: &str,
+    local_path: &Path,
+) -> Result<()> {
+    let repo = repo();
+    let tree_url = format!(
+        "{}/repos/{}/git/trees/{}?recursive=1",
+        GITHUB_API, repo, TEMPLATES_REF
+    );
+    let tree: GitTree = github_get(client, token, &tree_url, "application/vnd.github+json")
+        .await?
+        .json()
+        .await
+        .context("Unexpected response from the GitHub trees API")?;
+    if tree.truncated {
+        anyhow::bail!("{} is too large to list in one GitHub API call", repo);
+    }
+
+    let prefix = format!("{}/", remote_path);
+    let files: Vec<(&str, bool)> = tree
+        .tree
+        .iter()
+        .filter(|entry| entry.kind == "blob" && entry.mode != SYMLINK_MODE)
+        .filter_map(|entry| {
+            entry
+                .path
+                .strip_prefix(&prefix)
+                .map(|file| (file, entry.mode == EXECUTABLE_MODE))
+        })
+        .collect();
+    if files.is_empty() {
+        anyhow::bail!(
+            "{} has no {} directory at {}",
+            repo,
+            remote_path,
+            TEMPLATES_REF
+        );
+    }
+
+    // The cached copy keeps each file's executable bit for `resolver::copy_dir`
+    for (file, executable) in files {
+        let url = format!(
+            "{}/repos/{}/contents/{}{}?ref={}",
+            GITHUB_API, repo, prefix, file, TEMPLATES_REF
+        );
+        let response = github_get(client, token, &url, "application/vnd.github.raw").await?;
+        let file_path = local_path.join(file);
+        if let Some(parent) = file_path.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+        download(response, &file_path).await?;
+        if executable {
+            set_executable(&file_path)?;
+        }
+    }
+
+    Ok(())
 }
 
-/// Fetch known files when API rate limit is hit
+async fn github_get(
+    client: &Client,
+    token: &str,
+    url: &str,
+    accept: &str,
+) -> Result<reqwest::Response> {
+    let response = client
+        .get(url)
+        .header("User-Agent", "create-monorepo")
+        .header("Accept", accept)
+        .header("Authorization", format!("Bearer {}", token))
+        .header("X-GitHub-Api-Version", "2022-11-28")
+        .send()
+        .await
+        .map_err(|e| e.without_url())
+        .with_context(|| format!("Failed to fetch {}", url))?;
+
+    match response.status().as_u16() {
+        200..=299 => Ok(response),
+        401 => anyhow::bail!("GitHub rejected the token in {} (401)", TOKEN_ENV),
+        403 | 429 => anyhow::bail!(
+            "GitHub refused {} ({}); the token may be rate limited",
+            url,
+            response.status()
+        ),
+        404 => anyhow::bail!(
+            "{} not found; check the repo, the ref, and that the token can read it",
+            url
+        ),
+        _ => anyhow::bail!("Failed to fetch {} ({})", url, response.status()),
+    }
+}
+
+/// Shallow-fetch the pinned ref into a bare repository in the cache and write out only
+/// `remote_path`, so no other part of the tree is checked out. The fetch itself downloads
+/// the whole tree at the ref: libgit2 has no partial clone filters, so a sparse fetch of
+/// just `remote_path` isn't possible here
+fn fetch_with_git(settings: &FetchSettings, remote_path: &str, local_path: &Path) -> Result<()> {
+    let repo = repo();
+    let url = settings
+        .git_url
+        .clone()
+        .unwrap_or_else(|| format!("https://github.com/{}.git", repo));
+    let git_dir = get_cache_dir()?.join("git").join(format!("{}.git", repo));
+    let repository = match Repository::open_bare(&git_dir) {
+        Ok(repository) => repository,
+        Err(_) => Repository::init_bare(&git_dir)
+            .with_context(|| format!("Failed to create {}", git_dir.display()))?,
+    };
+
+    // ssh URLs authenticate through the ssh agent, https URLs with the GitHub token
+    let token = settings.github_token.clone();
+    let mut callbacks = RemoteCallbacks::new();
+    callbacks.credentials(move |_, username, allowed| {
+        if allowed.contains(CredentialType::SSH_KEY) {
+            Cred::ssh_key_from_agent(username.unwrap_or("git"))
+        } else if let Some(token) = &token {
+            Cred::userpass_plaintext("x-access-token", token)
+        } else {
+            Cred::default()
+        }
+    });
+    if settings.insecure {
+        callbacks.certificate_check(|_, _| Ok(CertificateCheckStatus::CertificateOk));
+    }
+    let mut proxy = ProxyOptions::new();
+    match &settings.proxy {
+        Some(proxy_url) => proxy.url(proxy_url),
+        None => proxy.auto(),
+    };
+    let mut options = FetchOptions::new();
+    options
+        .remote_callbacks(callbacks)
+        .proxy_options(proxy)
+        .depth(1);
+
+    repository
+        .remote_anonymous(&url)?
+        .fetch(&[TEMPLATES_REF], Some(&mut options), None)
+        .with_context(|| format!("Failed to fetch {} from {}", TEMPLATES_REF, url))?;
+
+    let root = repository.find_reference("FETCH_HEAD")?.peel_to_tree()?;
+    let dir = root
+        .get_path(Path::new(remote_path))
+        .with_context(|| {
+            format!(
+                "{} has no {} directory at {}",
+                url, remote_path, TEMPLATES_REF
+            )
+        })?
+        .to_object(&repository)?
+        .peel_to_tree()?;
+    write_tree(&repository, &dir, local_path)
+}
+
+fn write_tree(repository: &Repository, tree: &Tree, dest: &Path) -> Result<()> {
+    std::fs::create_dir_all(dest)?;
+    for entry in tree.iter() {
+        let name = entry.name().context("Template tree has a non UTF-8 path")?;
+        let path = dest.join(name);
+        match entry.kind() {
+            Some(ObjectType::Tree) => write_tree(
+                repository,
+                &entry.to_object(repository)?.peel_to_tree()?,
+                &path,
+            )?,
+            // A link's blob is its target path, not content to write
+            Some(ObjectType::Blob) if entry.filemode() == i32::from(git2::FileMode::Link) => {}
+            Some(ObjectType::Blob) => {
+                std::fs::write(
+                    &path,
+                    entry.to_object(repository)?.peel_to_blob()?.content(),
+                )?;
+                if entry.filemode() == i32::from(git2::FileMode::BlobExecutable) {
+                    set_executable(&path)?;
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Fetch the known files of a directory over plain HTTP
 async fn fetch_known_files(client: &Client, remote_path: &str, local_path: &Path) -> Result<()> {
     fs::create_dir_all(local_path).await?;
 
@@ -47,6 +522,8 @@ async fn fetch_known_files(client: &Client, remote_path: &str, local_path: &Path
             "checkbox.tsx",
             "collapsible.tsx",
             "context-menu.tsx",
+            "data-table.tsx",
+            "data-table-columns.tsx",
             "dialog.tsx",
             "dropdown-menu.tsx",
             "empty.tsx",
@@ -78,8 +555,12 @@ async fn fetch_known_files(client: &Client, remote_path: &str, local_path: &Path
         vec![]
     };
 
+    if files.is_empty() {
+        anyhow::bail!("No known template files under {}", remote_path);
+    }
+
     for file in files {
-        let url = format!("{}/{}/{}", RAW_CONTENT_BASE, remote_path, file);
+        let url = format!("{}/{}/{}", raw_content_base(), remote_path, file);
         let file_path = local_path.join(file);
 
         // Create parent directory if needed
@@ -87,79 +568,135 @@ async fn fetch_known_files(client: &Client, remote_path: &str, local_path: &Path
             fs::create_dir_all(parent).await?;
         }
 
-        match client
+        // A missing file means the ref doesn't match this CLI's layout; fail the whole
+        // directory so callers fall back instead of mixing sources
+        let response = client
             .get(&url)
             .header("User-Agent", "create-monorepo")
             .send()
             .await
-        {
-            Ok(response) if response.status().is_success() => {
-                let content = response.text().await?;
-                fs::write(&file_path, content).await?;
-            }
-            _ => {
-                // File doesn't exist, skip
-            }
+            .map_err(|e| e.without_url())
+            .with_context(|| format!("Failed to fetch {}", url))?;
+        if !response.status().is_success() {
+            anyhow::bail!("Failed to fetch {} ({})", url, response.status());
+        }
+        // Raw URLs carry no file mode; scripts start with a shebang
+        if download(response, &file_path).await? {
+            set_executable(&file_path)?;
         }
     }
 
     Ok(())
 }
 
-/// Fetch a single file from the GitHub repository
-pub async fn fetch_file(remote_path: &str) -> Result<String> {
-    let client = Client::new();
-    let url = format!("{}/{}", RAW_CONTENT_BASE, remote_path);
-
-    let content = client
-        .get(&url)
-        .header("User-Agent", "create-monorepo")
-        .send()
-        .await
-        .context("Failed to fetch file from GitHub")?
-        .text()
-        .await
-        .context("Failed to read file content")?;
+/// Write a response body to `path` as it arrives, so large assets never sit in memory
+/// whole; returns whether the file starts with a shebang
+async fn download(mut response: reqwest::Response, path: &Path) -> Result<bool> {
+    let mut file = fs::File::create(path).await?;
+    let mut head: Vec<u8> = Vec::with_capacity(2);
+    while let Some(chunk) = response.chunk().await? {
+        if head.len() < 2 {
+            head.extend(chunk.iter().take(2 - head.len()));
+        }
+        file.write_all(&chunk).await?;
+    }
+    file.flush().await?;
+    Ok(head == b"#!")
+}
 
-    Ok(content)
+/// Written next to each cached directory as `<dir>.meta.json`
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CacheMeta {
+    /// Seconds since the Unix epoch
+    fetched_at: u64,
+    source: String,
+    /// Relative path to sha256, checked before the cache is reused
+    files: BTreeMap<String, String>,
 }
 
-/// Get cached or fetch remote templates
-pub async fn get_or_fetch_directory(remote_path: &str, local_dest: &Path, use_cache: bool) -> Result<()> {
-    if use_cache {
-        let cache_dir = get_cache_dir()?;
-        let cached_path = cache_dir.join(remote_path);
+fn meta_path(cached_path: &Path) -> PathBuf {
+    let name = cached_path
+        .file_name()
+        .unwrap_or_default()
+        .to_string_lossy();
+    cached_path.with_file_name(format!("{}.meta.json", name))
+}
 
-        if cached_path.exists() {
-            // Copy from cache
-            copy_dir_recursive(&cached_path, local_dest).await?;
-            return Ok(());
-        }
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
 
-        // Fetch and cache
-        fetch_directory(remote_path, &cached_path).await?;
-        copy_dir_recursive(&cached_path, local_dest).await?;
-    } else {
-        fetch_directory(remote_path, local_dest).await?;
+/// Whether the cached directory was fetched from the current source within the TTL
+/// and still has exactly the files it was fetched with
+fn cache_is_fresh(cached_path: &Path, ttl: Duration) -> bool {
+    let Ok(content) = std::fs::read_to_string(meta_path(cached_path)) else {
+        return false;
+    };
+    let Ok(meta) = serde_json::from_str::<CacheMeta>(&content) else {
+        return false;
+    };
+    if now().saturating_sub(meta.fetched_at) >= ttl.as_secs() || meta.source != source() {
+        return false;
     }
+    match manifest::hash_files(&cached_path.to_string_lossy()) {
+        Ok(files) => files == meta.files,
+        Err(_) => false,
+    }
+}
 
-    Ok(())
+/// Age of the cached copy of `remote_path`, if one would be reused on the next run
+pub fn cache_age(remote_path: &str) -> Option<Duration> {
+    let settings = settings();
+    let cached_path = get_cache_dir()
+        .ok()?
+        .join(repo())
+        .join(TEMPLATES_REF)
+        .join(remote_path);
+    if settings.refresh || !cache_is_fresh(&cached_path, settings.cache_ttl) {
+        return None;
+    }
+    let meta: CacheMeta =
+        serde_json::from_str(&std::fs::read_to_string(meta_path(&cached_path)).ok()?).ok()?;
+    Some(Duration::from_secs(now().saturating_sub(meta.fetched_at)))
 }
 
-async fn copy_dir_recursive(src: &Path, dest: &Path) -> Result<()> {
-    fs::create_dir_all(dest).await?;
+/// The cached copy of `remote_path`, fetched first unless a fresh one is cached
+pub async fn fetch_cached(remote_path: &str) -> Result<PathBuf> {
+    let settings = settings();
+    let cache_dir = get_cache_dir()?;
+    // One cache entry per repo and ref, so a new CLI release or a fork never reuses other templates
+    let cached_path = cache_dir.join(repo()).join(TEMPLATES_REF).join(remote_path);
 
-    let mut entries = fs::read_dir(src).await?;
-    while let Some(entry) = entries.next_entry().await? {
-        let file_type = entry.file_type().await?;
-        let dest_path = dest.join(entry.file_name());
+    if !settings.refresh && cache_is_fresh(&cached_path, settings.cache_ttl) {
+        return Ok(cached_path);
+    }
 
-        if file_type.is_dir() {
-            Box::pin(copy_dir_recursive(&entry.path(), &dest_path)).await?;
-        } else {
-            fs::copy(entry.path(), dest_path).await?;
+    // Fetch into a staging directory so a failed fetch never leaves partial files behind
+    let staging_root = cache_dir.join(format!(".staging-{}", std::process::id()));
+    let staging = staging_root.join(remote_path);
+    let fetched = fetch_directory(remote_path, &staging).await;
+    if fetched.is_ok() {
+        let meta = CacheMeta {
+            fetched_at: now(),
+            source: source(),
+            files: manifest::hash_files(&staging.to_string_lossy())?,
+        };
+        let _ = fs::remove_dir_all(&cached_path).await;
+        if let Some(parent) = cached_path.parent() {
+            fs::create_dir_all(parent).await?;
         }
+        fs::rename(&staging, &cached_path).await?;
+        fs::write(
+            meta_path(&cached_path),
+            serde_json::to_string_pretty(&meta)?,
+        )
+        .await?;
     }
+    let _ = fs::remove_dir_all(&staging_root).await;
 
-    Ok(())
+    fetched.map(|()| cached_path)
 }
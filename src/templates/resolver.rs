@@ -0,0 +1,226 @@
+use anyhow::{Context, Result};
+use console::style;
+use std::borrow::Cow;
+use std::collections::{BTreeSet, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+use crate::templates::{embedded, remote};
+use crate::utils::conflict::{self, Resolution};
+use crate::utils::fs::{is_executable, resolved_file_type, to_slash, write_content};
+use crate::utils::output::is_verbose;
+use crate::utils::plan::Content;
+
+/// Directory of template overrides, in or next to the project and in the user config directory
+pub const OVERRIDE_DIR: &str = "templates";
+
+/// Where a template file was found, highest precedence first
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Layer {
+    /// `templates/` in the project, or in the directory that contains it
+    Project,
+    /// `<config dir>/t3-mono/templates/`
+    User,
+    /// boilerplate_moduls at the pinned ref, from the cache
+    Remote,
+    /// The copy embedded in the binary
+    Embedded,
+}
+
+impl Layer {
+    fn label(self) -> &'static str {
+        match self {
+            Layer::Project => "project",
+            Layer::User => "user",
+            Layer::Remote => "remote",
+            Layer::Embedded => "embedded",
+        }
+    }
+}
+
+static EMBEDDED_ONLY: AtomicBool = AtomicBool::new(false);
+
+/// The project templates are resolved for; `None` means the current directory
+static PROJECT_ROOT: Mutex<Option<PathBuf>> = Mutex::new(None);
+
+/// Find project overrides relative to `root`, the directory `create` scaffolds into.
+/// Commands that run inside a project leave it at the current directory.
+pub fn set_project_root(root: &Path) {
+    *PROJECT_ROOT.lock().unwrap_or_else(|e| e.into_inner()) = Some(root.to_path_buf());
+}
+
+/// Ignore override directories and fetched templates, so a render only depends on the
+/// binary; `testing` renders use this
+pub fn set_embedded_only(enabled: bool) {
+    EMBEDDED_ONLY.store(enabled, Ordering::Relaxed);
+}
+
+pub fn is_embedded_only() -> bool {
+    EMBEDDED_ONLY.load(Ordering::Relaxed)
+}
+
+/// The override directories that exist, project first. The project's own `templates/`
+/// wins over the one next to it, which is where `create` finds overrides in the directory
+/// it runs in, so `status` and `sync` inside the project see the same ones.
+pub fn override_dirs() -> Vec<(Layer, PathBuf)> {
+    if is_embedded_only() {
+        return Vec::new();
+    }
+    let root = PROJECT_ROOT
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+        .unwrap_or_else(|| PathBuf::from("."));
+    // Worked out without the file system, since `create` resolves templates before the
+    // project directory exists
+    let parent = match root.parent() {
+        Some(parent) if root.file_name().is_some() && parent.as_os_str().is_empty() => {
+            PathBuf::from(".")
+        }
+        Some(parent) if root.file_name().is_some() => parent.to_path_buf(),
+        _ => root.join(".."),
+    };
+    let user = dirs::config_dir().map(|dir| dir.join("t3-mono").join(OVERRIDE_DIR));
+    [
+        (Layer::Project, Some(root.join(OVERRIDE_DIR))),
+        (Layer::Project, Some(parent.join(OVERRIDE_DIR))),
+        (Layer::User, user),
+    ]
+    .into_iter()
+    .filter_map(|(layer, dir)| dir.filter(|dir| dir.is_dir()).map(|dir| (layer, dir)))
+    .collect()
+}
+
+/// The bytes of template `path` from the first layer that has it
+pub fn resolve(path: &str) -> Option<Cow<'static, [u8]>> {
+    let (layer, content) = lookup(path)?;
+    report(layer, path);
+    Some(content)
+}
+
+fn lookup(path: &str) -> Option<(Layer, Cow<'static, [u8]>)> {
+    for (layer, dir) in override_dirs() {
+        if let Ok(content) = std::fs::read(dir.join(path)) {
+            return Some((layer, Cow::Owned(content)));
+        }
+    }
+    if let Some(content) = remote::cached_file(path).filter(|_| !is_embedded_only()) {
+        return Some((Layer::Remote, Cow::Owned(content)));
+    }
+    embedded::embedded_bytes(path).map(|content| (Layer::Embedded, content))
+}
+
+/// Copy template directory `prefix` to `dest`, taking each file from the first layer that has
+/// it. `remote_dir` is a fetched copy that replaces the embedded one as a whole, so a
+/// directory never mixes the two; overrides apply on top of either.
+pub async fn copy_dir(prefix: &str, remote_dir: Option<&Path>, dest: &Path) -> Result<()> {
+    let prefix = to_slash(prefix);
+    let prefix = prefix.trim_end_matches('/');
+    let overrides = override_dirs();
+
+    let mut files = BTreeSet::new();
+    match remote_dir {
+        Some(dir) => list_files(dir, "", &mut files)?,
+        None => {
+            let dir_prefix = format!("{}/", prefix);
+            files.extend(
+                embedded::list_templates(&dir_prefix)
+                    .iter()
+                    .filter_map(|path| path.strip_prefix(&dir_prefix))
+                    .map(str::to_string),
+            )
+        }
+    }
+    for (_, dir) in &overrides {
+        let dir = dir.join(prefix);
+        if dir.is_dir() {
+            list_files(&dir, "", &mut files)?;
+        }
+    }
+
+    for relative in files {
+        let path = format!("{}/{}", prefix, relative);
+        // Files on disk carry their own mode and are streamed when written; embedded ones
+        // are borrowed from the binary, their mode recorded at build time
+        let read = |layer: Layer, file: PathBuf| {
+            let executable = is_executable(&file);
+            file.is_file()
+                .then_some((layer, Content::File(file), executable))
+        };
+        let overridden = overrides
+            .iter()
+            .find_map(|(layer, dir)| read(*layer, dir.join(&path)));
+        let found = match (overridden, remote_dir) {
+            (Some(found), _) => Some(found),
+            (None, Some(dir)) => read(Layer::Remote, dir.join(&relative)),
+            (None, None) => embedded::embedded_bytes(&path).map(|content| {
+                (
+                    Layer::Embedded,
+                    Content::Bytes(content),
+                    embedded::is_executable(&path),
+                )
+            }),
+        };
+        let Some((layer, content, executable)) = found else {
+            continue;
+        };
+        report(layer, &path);
+
+        let dest_file = match conflict::resolve(&dest.join(&relative), &content)? {
+            Resolution::Write(path) => path,
+            Resolution::Skip => continue,
+        };
+        write_content(&dest_file, content, executable)
+            .with_context(|| format!("Failed to write file: {}", dest_file.display()))?;
+    }
+
+    Ok(())
+}
+
+/// Files under `root` as `/`-separated relative paths. Symlinked directories are followed,
+/// e.g. an override directory linked in from a shared checkout, each at most once.
+fn list_files(root: &Path, relative: &str, files: &mut BTreeSet<String>) -> Result<()> {
+    list_files_once(root, relative, files, &mut HashSet::new())
+}
+
+fn list_files_once(
+    root: &Path,
+    relative: &str,
+    files: &mut BTreeSet<String>,
+    visited: &mut HashSet<PathBuf>,
+) -> Result<()> {
+    let dir = root.join(relative);
+    if !visited.insert(std::fs::canonicalize(&dir)?) {
+        return Ok(());
+    }
+    for entry in std::fs::read_dir(&dir)? {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().to_string();
+        let path = if relative.is_empty() {
+            name
+        } else {
+            format!("{}/{}", relative, name)
+        };
+        match resolved_file_type(&entry) {
+            Some(kind) if kind.is_dir() => list_files_once(root, &path, files, visited)?,
+            Some(_) => {
+                files.insert(path);
+            }
+            // A broken link
+            None => {}
+        }
+    }
+    Ok(())
+}
+
+/// `--verbose`: which layer satisfied each template file
+fn report(layer: Layer, path: &str) {
+    if is_verbose() {
+        eprintln!(
+            "    {} {}",
+            style(format!("{:<8}", layer.label())).dim(),
+            path
+        );
+    }
+}
@@ -0,0 +1,225 @@
+//! Render template combinations for tests, e.g. snapshot tests in forks with their own
+//! templates:
+//!
+//! ```no_run
+//! # async fn example() -> anyhow::Result<()> {
+//! let tree = t3_mono::testing::scaffold("extensions: [ui]\nexample: true").await?;
+//! assert!(tree.contains("src/app/posts/page.tsx"));
+//! tree.assert_snapshot("tests/snapshots/ui-example.txt");
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Renders use the embedded templates only, so they don't depend on the network, the
+//! template cache, or override directories, and they run one at a time because the
+//! scaffolders share global state.
+
+use anyhow::{Context, Result};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::scaffolding::manifest;
+use crate::scaffolding::options::ScaffoldOptions;
+use crate::scaffolding::project;
+use crate::templates::resolver;
+use crate::utils::answers::Answers;
+use crate::utils::diff;
+use crate::utils::fs::is_executable;
+use crate::utils::output::Progress;
+
+/// Rewrites snapshot files instead of comparing against them when set to `1`
+pub const UPDATE_SNAPSHOTS_ENV: &str = "T3_MONO_UPDATE_SNAPSHOTS";
+
+/// Project name when the answers don't have one
+const DEFAULT_NAME: &str = "app";
+
+static RENDER: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+static RENDERS: AtomicUsize = AtomicUsize::new(0);
+
+/// A rendered file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct File {
+    pub content: Vec<u8>,
+    pub executable: bool,
+}
+
+/// The files of a rendered project by `/`-separated relative path. The project stays on
+/// disk under [`FileTree::root`] until the tree is dropped, e.g. to run `tsc` on it.
+#[derive(Debug)]
+pub struct FileTree {
+    root: PathBuf,
+    files: BTreeMap<String, File>,
+}
+
+/// Render a project from answers in `--answers` format, e.g. `extensions: [ai, ui]`; the
+/// name defaults to `app`
+pub async fn scaffold(answers: &str) -> Result<FileTree> {
+    let answers: Answers = serde_yaml_ng::from_str(answers).context("Invalid answers")?;
+    let git = answers.git;
+    let options = answers.into_options(DEFAULT_NAME)?;
+    scaffold_options(&options, git).await
+}
+
+/// Render a project with `options`; `git` adds the `.gitignore` and an empty repository,
+/// which the tree leaves out
+pub async fn scaffold_options(options: &ScaffoldOptions, git: bool) -> Result<FileTree> {
+    let _render = RENDER.lock().await;
+
+    let root = std::env::temp_dir().join(format!(
+        "t3-mono-testing-{}-{}",
+        std::process::id(),
+        RENDERS.fetch_add(1, Ordering::Relaxed)
+    ));
+    if root.exists() {
+        std::fs::remove_dir_all(&root)?;
+    }
+    let mut tree = FileTree {
+        root,
+        files: BTreeMap::new(),
+    };
+    std::fs::create_dir_all(&tree.root)?;
+
+    resolver::set_embedded_only(true);
+    let result = project::scaffold(
+        &tree.root.to_string_lossy(),
+        options,
+        &Progress::hidden(),
+        git,
+        false,
+    )
+    .await;
+    resolver::set_embedded_only(false);
+    result.with_context(|| format!("Failed to render {}", options.name))?;
+
+    collect(&tree.root, "", &mut tree.files)?;
+    Ok(tree)
+}
+
+fn collect(root: &Path, relative: &str, files: &mut BTreeMap<String, File>) -> Result<()> {
+    let dir = root.join(relative);
+    for entry in
+        std::fs::read_dir(&dir).with_context(|| format!("Failed to read {}", dir.display()))?
+    {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().to_string();
+        let path = if relative.is_empty() {
+            name
+        } else {
+            format!("{}/{}", relative, name)
+        };
+
+        if entry.file_type()?.is_dir() {
+            if path != ".git" {
+                collect(root, &path, files)?;
+            }
+        } else {
+            let file = File {
+                content: std::fs::read(entry.path())?,
+                executable: is_executable(&entry.path()),
+            };
+            files.insert(path, file);
+        }
+    }
+    Ok(())
+}
+
+impl FileTree {
+    /// Directory the project was rendered into
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Relative paths of every file, sorted
+    pub fn paths(&self) -> impl Iterator<Item = &str> {
+        self.files.keys().map(String::as_str)
+    }
+
+    pub fn files(&self) -> &BTreeMap<String, File> {
+        &self.files
+    }
+
+    pub fn contains(&self, path: &str) -> bool {
+        self.files.contains_key(path)
+    }
+
+    pub fn get(&self, path: &str) -> Option<&File> {
+        self.files.get(path)
+    }
+
+    /// Content of a text file; panics when it is missing or not UTF-8
+    pub fn text(&self, path: &str) -> &str {
+        let file = self
+            .files
+            .get(path)
+            .unwrap_or_else(|| panic!("{} was not rendered", path));
+        std::str::from_utf8(&file.content).unwrap_or_else(|_| panic!("{} is not UTF-8", path))
+    }
+
+    /// Files whose path starts with `prefix`, e.g. `src/server/`
+    pub fn under<'a>(&'a self, prefix: &'a str) -> impl Iterator<Item = (&'a str, &'a File)> {
+        self.files
+            .iter()
+            .filter(move |(path, _)| path.starts_with(prefix))
+            .map(|(path, file)| (path.as_str(), file))
+    }
+
+    /// One line per file in `sha256sum` format, executables marked with `+x`, so a
+    /// snapshot changes with any file's content, mode, or presence
+    pub fn snapshot(&self) -> String {
+        self.files
+            .iter()
+            .map(|(path, file)| {
+                let mode = if file.executable { " +x" } else { "" };
+                format!("{}  {}{}\n", manifest::hash(&file.content), path, mode)
+            })
+            .collect()
+    }
+
+    /// Compare [`snapshot`](Self::snapshot) with the file at `path`, relative to the
+    /// current directory; with `T3_MONO_UPDATE_SNAPSHOTS=1`, write it instead. A missing
+    /// snapshot fails, so a new case can't pass without anything to compare against.
+    pub fn assert_snapshot(&self, path: impl AsRef<Path>) {
+        let path = path.as_ref();
+        let snapshot = self.snapshot();
+        let update = std::env::var(UPDATE_SNAPSHOTS_ENV).is_ok_and(|value| value == "1");
+        if !update && !path.exists() {
+            panic!(
+                "{} doesn't exist; run with {}=1 to write it",
+                path.display(),
+                UPDATE_SNAPSHOTS_ENV
+            );
+        }
+        if update {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent).unwrap();
+            }
+            std::fs::write(path, &snapshot)
+                .unwrap_or_else(|e| panic!("Failed to write {}: {}", path.display(), e));
+            return;
+        }
+
+        let expected = std::fs::read_to_string(path)
+            .unwrap_or_else(|e| panic!("Failed to read {}: {}", path.display(), e));
+        if expected != snapshot {
+            let diff = diff::unified(
+                &expected,
+                &snapshot,
+                &path.display().to_string(),
+                "rendered",
+            );
+            panic!(
+                "The rendered files don't match {}; rerun with {}=1 to accept them\n{}",
+                path.display(),
+                UPDATE_SNAPSHOTS_ENV,
+                diff
+            );
+        }
+    }
+}
+
+impl Drop for FileTree {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.root);
+    }
+}
@@ -0,0 +1,245 @@
+use anyhow::{Context, Result};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use std::path::Path;
+
+use crate::cli::{
+    AuthProvider, BiomeProfile, ChatStream, DarkMode, DatabaseProvider, Iac, Pooling,
+    SessionStrategy, TailwindPlugin, TaskRunner, TsStrictness,
+};
+use crate::scaffolding::options::{CmdSettings, DomainModel, ScaffoldOptions};
+use crate::scaffolding::{images, locale, resource};
+use crate::utils::naming;
+
+/// Languages every project is generated with
+pub const BUILT_IN_LOCALES: &[&str] = &["en", "de"];
+
+/// Every answer `create` would otherwise take from flags or prompts, read from
+/// `--answers <file>`; keys are the flag names
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct Answers {
+    /// Falls back to the name on the command line
+    pub name: Option<String>,
+    #[serde(default)]
+    pub auth: AuthProvider,
+    #[serde(default)]
+    pub session: SessionStrategy,
+    #[serde(default)]
+    pub database: Database,
+    #[serde(default)]
+    pub package_manager: PackageManager,
+    #[serde(default)]
+    pub extensions: Vec<Extension>,
+    /// Languages besides the built-in en and de, e.g. fr or pt-BR
+    #[serde(default)]
+    pub locales: Vec<String>,
+    #[serde(default = "yes")]
+    pub git: bool,
+    #[serde(default)]
+    pub latest: bool,
+    pub cmd_stream: Option<ChatStream>,
+    /// CommandIsland provider, model, vectors, storage, and modules
+    pub cmd_settings: Option<CmdSettings>,
+    #[serde(default)]
+    pub analytics: bool,
+    #[serde(default)]
+    pub restate_kafka: bool,
+    #[serde(default)]
+    pub example: bool,
+    #[serde(default)]
+    pub rest: bool,
+    #[serde(default)]
+    pub seed: bool,
+    /// Your own models, each with zod schemas, a CRUD router, and pages
+    #[serde(default)]
+    pub models: Vec<DomainModel>,
+    #[serde(default)]
+    pub seo: bool,
+    #[serde(default)]
+    pub standalone: bool,
+    #[serde(default)]
+    pub bundle_analyzer: bool,
+    #[serde(default)]
+    pub security_headers: bool,
+    #[serde(default)]
+    pub soft_delete: bool,
+    pub pooling: Option<Pooling>,
+    #[serde(default)]
+    pub migrations: bool,
+    #[serde(default)]
+    pub nix: bool,
+    pub iac: Option<Iac>,
+    pub task_runner: Option<TaskRunner>,
+    #[serde(default)]
+    pub ts_strictness: TsStrictness,
+    pub coverage_threshold: Option<u8>,
+    #[serde(default)]
+    pub tailwind_plugins: Vec<TailwindPlugin>,
+    #[serde(default)]
+    pub dark_mode: DarkMode,
+    #[serde(default)]
+    pub biome_profile: BiomeProfile,
+    #[serde(default)]
+    pub image_hosts: Vec<String>,
+    pub image_cdn: Option<String>,
+}
+
+/// Generated projects use PostgreSQL through Prisma
+#[derive(Debug, Default, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum Database {
+    #[default]
+    Postgresql,
+}
+
+/// Generated scripts, docs, and CI run npm
+#[derive(Debug, Default, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum PackageManager {
+    #[default]
+    Npm,
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum Extension {
+    Ai,
+    Ui,
+    Restate,
+    Cmd,
+}
+
+fn yes() -> bool {
+    true
+}
+
+/// Read an answers file; YAML, so JSON works too
+pub fn load(path: &Path) -> Result<Answers> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    // yaml errors carry the line and column, so keep them in the message
+    serde_yaml_ng::from_str(&content)
+        .map_err(|e| anyhow::anyhow!("{} is not a valid answers file:\n{}", path.display(), e))
+}
+
+impl Answers {
+    /// Check the same requirements the flags have; `cli_name` is used when the file has no name
+    pub fn into_options(self, cli_name: &str) -> Result<ScaffoldOptions> {
+        let has = |extension: Extension| self.extensions.contains(&extension);
+        let (ai, ui, restate, cmd) = (
+            has(Extension::Ai),
+            has(Extension::Ui),
+            has(Extension::Restate),
+            has(Extension::Cmd),
+        );
+
+        if self.session == SessionStrategy::Database && self.auth != AuthProvider::NextAuth {
+            anyhow::bail!("session requires auth: next-auth for the database strategy");
+        }
+        if self.cmd_stream.is_some() && !cmd {
+            anyhow::bail!("cmd-stream requires the cmd extension");
+        }
+        if self.cmd_settings.is_some() && !cmd {
+            anyhow::bail!("cmd-settings requires the cmd extension");
+        }
+        for (i, model) in self.models.iter().enumerate() {
+            resource::validate(&model.name, &model.fields)
+                .map_err(|e| anyhow::anyhow!("models[{}]: {}", i, e))?;
+            if self.models[..i]
+                .iter()
+                .any(|other| naming::pascal_case(&other.name) == naming::pascal_case(&model.name))
+            {
+                anyhow::bail!("models lists {} twice", naming::pascal_case(&model.name));
+            }
+        }
+        if self.analytics && !ui {
+            anyhow::bail!("analytics requires the ui extension");
+        }
+        if self.restate_kafka && !restate {
+            anyhow::bail!("restate-kafka requires the restate extension");
+        }
+        if self.rest && !self.example {
+            anyhow::bail!("rest requires example: true");
+        }
+        if self
+            .coverage_threshold
+            .is_some_and(|threshold| threshold > 100)
+        {
+            anyhow::bail!("coverage-threshold must be a percentage from 0 to 100");
+        }
+
+        let image_hosts = self
+            .image_hosts
+            .iter()
+            .map(|host| images::parse_host(host).map_err(|e| anyhow::anyhow!("image-hosts: {}", e)))
+            .collect::<Result<Vec<_>>>()?;
+        let image_cdn = self
+            .image_cdn
+            .as_deref()
+            .map(|url| images::parse_cdn_url(url).map_err(|e| anyhow::anyhow!("image-cdn: {}", e)))
+            .transpose()?;
+
+        let database = match self.database {
+            Database::Postgresql => DatabaseProvider::Postgres,
+        };
+        // One choice today; matched so a new one has to be handled here
+        let PackageManager::Npm = self.package_manager;
+
+        let mut locales: Vec<String> = Vec::new();
+        for code in self.locales {
+            if !locale::is_valid_code(&code) {
+                anyhow::bail!(
+                    "Invalid locale '{}'; use a language code such as fr or pt-BR",
+                    code
+                );
+            }
+            if !BUILT_IN_LOCALES.contains(&code.as_str()) && !locales.contains(&code) {
+                locales.push(code);
+            }
+        }
+
+        let name = match self.name {
+            // An explicit name on the command line reuses one answers file for several projects
+            Some(name) if cli_name == "." => name,
+            _ => cli_name.to_string(),
+        };
+
+        Ok(ScaffoldOptions {
+            name,
+            auth: self.auth,
+            session: self.session,
+            ai,
+            ui,
+            restate,
+            restate_kafka: self.restate_kafka,
+            cmd,
+            cmd_stream: self.cmd_stream,
+            cmd_settings: self.cmd_settings,
+            analytics: self.analytics,
+            example: self.example,
+            rest: self.rest,
+            seed: self.seed,
+            models: self.models,
+            seo: self.seo,
+            standalone: self.standalone,
+            bundle_analyzer: self.bundle_analyzer,
+            security_headers: self.security_headers,
+            soft_delete: self.soft_delete,
+            database,
+            pooling: self.pooling,
+            migrations: self.migrations,
+            nix: self.nix,
+            iac: self.iac,
+            task_runner: self.task_runner,
+            ts_strictness: self.ts_strictness,
+            coverage_threshold: self.coverage_threshold,
+            tailwind_plugins: self.tailwind_plugins,
+            dark_mode: self.dark_mode,
+            biome_profile: self.biome_profile,
+            image_hosts,
+            image_cdn,
+            locales,
+        })
+    }
+}
@@ -0,0 +1,110 @@
+use anyhow::{Context, Result};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::templates::remote::FetchStrategy;
+
+/// Project-level config file, read from the current directory
+pub const CONFIG_FILE: &str = "t3mono.toml";
+
+/// Settings from `t3mono.toml`; every section is optional
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub templates: TemplatesConfig,
+    pub network: NetworkConfig,
+    pub npm: NpmConfig,
+    pub hooks: HooksConfig,
+}
+
+/// `[templates]`: where remote templates are fetched from
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct TemplatesConfig {
+    /// `http` (raw files or the GitHub API) or `git` (shallow fetch)
+    pub strategy: FetchStrategy,
+    /// Clone URL for the git strategy
+    pub git_url: Option<String>,
+    /// `owner/name` of a (private) fork of boilerplate_moduls
+    pub repo: Option<String>,
+    /// GitHub token for the API; GITHUB_TOKEN takes precedence
+    pub github_token: Option<String>,
+    /// Hours a cached template directory is reused; 0 fetches on every run
+    pub cache_ttl_hours: Option<u64>,
+    /// Base URL serving the boilerplate_moduls tree as `<mirror>/<ref>/<path>`
+    pub mirror: Option<String>,
+}
+
+/// `[network]`: how template downloads reach the internet
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct NetworkConfig {
+    /// Proxy URL for every request; HTTPS_PROXY/HTTP_PROXY are used when unset
+    pub proxy: Option<String>,
+    /// PEM files with extra trusted root certificates
+    pub ca_certs: Vec<PathBuf>,
+}
+
+/// `[npm]`: how `--latest` resolves dependency versions
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct NpmConfig {
+    /// Registry URL; defaults to registry.npmjs.org
+    pub registry: Option<String>,
+    /// Package name to the semver range `--latest` may resolve within
+    pub ranges: BTreeMap<String, String>,
+}
+
+/// `[hooks]`: shell commands run around scaffolding, in order; the first failure stops the run
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct HooksConfig {
+    /// Before `create`, in the current directory
+    pub pre_create: Vec<String>,
+    /// After `create`, in the new project
+    pub post_create: Vec<String>,
+    /// Extension name to the commands run before `add <extension>`
+    pub pre_add: BTreeMap<String, Vec<String>>,
+    /// Extension name to the commands run after `add <extension>`
+    pub post_add: BTreeMap<String, Vec<String>>,
+}
+
+/// Read `./t3mono.toml`, or `<config dir>/t3-mono/t3mono.toml` when there is none
+pub fn load() -> Result<Config> {
+    match find() {
+        Some(path) => read(&path),
+        None => Ok(Config::default()),
+    }
+}
+
+/// Parse a config file, with relative paths resolved against its directory
+pub fn read(path: &Path) -> Result<Config> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    // toml errors carry the line and column, so keep them in the message
+    let mut config: Config = toml::from_str(&content)
+        .map_err(|e| anyhow::anyhow!("{} is not a valid config file:\n{}", path.display(), e))?;
+
+    // Relative paths are relative to the file that names them
+    if let Some(dir) = path.parent() {
+        for cert in &mut config.network.ca_certs {
+            if cert.is_relative() {
+                *cert = dir.join(&*cert);
+            }
+        }
+    }
+    Ok(config)
+}
+
+/// `./t3mono.toml`, or the one in the user config directory
+pub fn find() -> Option<PathBuf> {
+    let local = PathBuf::from(CONFIG_FILE);
+    if local.exists() {
+        return Some(local);
+    }
+    dirs::config_dir()
+        .map(|dir| dir.join("t3-mono").join(CONFIG_FILE))
+        .filter(|path| path.exists())
+}
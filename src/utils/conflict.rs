@@ -0,0 +1,156 @@
+use anyhow::Result;
+use console::{style, Term};
+use dialoguer::Select;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use crate::utils::plan::Content;
+use crate::utils::{diff, fs};
+
+/// How template writes treat files that already exist with different content. Edits to
+/// existing files, e.g. registering a router or adding dependencies, always apply.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConflictMode {
+    /// Replace existing files (new projects, `add --yes`)
+    Overwrite,
+    /// Ask per file (`add` in a terminal)
+    Prompt,
+    /// Keep existing files (`add --skip-existing`)
+    Skip,
+}
+
+/// What happened to a path during this run; later writes to it follow the first decision
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Decision {
+    Written,
+    Skipped,
+    WrittenAs(PathBuf),
+}
+
+/// Where a write should go
+pub enum Resolution {
+    Write(PathBuf),
+    Skip,
+}
+
+static MODE: Mutex<ConflictMode> = Mutex::new(ConflictMode::Overwrite);
+static DECISIONS: Mutex<BTreeMap<PathBuf, Decision>> = Mutex::new(BTreeMap::new());
+
+pub fn set_mode(mode: ConflictMode) {
+    *MODE.lock().unwrap_or_else(|e| e.into_inner()) = mode;
+}
+
+/// Decide whether a template's `content` may be written to `path`, prompting when the
+/// mode asks for it
+pub fn resolve(path: &Path, content: &Content) -> Result<Resolution> {
+    let mode = *MODE.lock().unwrap_or_else(|e| e.into_inner());
+    if mode == ConflictMode::Overwrite {
+        return Ok(Resolution::Write(path.to_path_buf()));
+    }
+
+    let mut decisions = DECISIONS.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(decision) = decisions.get(path) {
+        return Ok(match decision {
+            Decision::Written => Resolution::Write(path.to_path_buf()),
+            Decision::Skipped => Resolution::Skip,
+            Decision::WrittenAs(other) => Resolution::Write(other.clone()),
+        });
+    }
+
+    let content = content.load()?;
+    let existing = match fs::read(path) {
+        Ok(existing) => existing,
+        Err(_) => {
+            decisions.insert(path.to_path_buf(), Decision::Written);
+            return Ok(Resolution::Write(path.to_path_buf()));
+        }
+    };
+    if existing == *content {
+        return Ok(Resolution::Skip);
+    }
+
+    let decision = match mode {
+        ConflictMode::Skip => Decision::Skipped,
+        _ => prompt(path, &existing, &content)?,
+    };
+    decisions.insert(path.to_path_buf(), decision.clone());
+
+    Ok(match decision {
+        Decision::Written => Resolution::Write(path.to_path_buf()),
+        Decision::Skipped => Resolution::Skip,
+        Decision::WrittenAs(other) => Resolution::Write(other),
+    })
+}
+
+/// Existing files that were kept, and the `.new` copies written next to them
+pub fn summary() -> (Vec<String>, Vec<String>) {
+    let decisions = DECISIONS.lock().unwrap_or_else(|e| e.into_inner());
+    let mut skipped = Vec::new();
+    let mut written_as = Vec::new();
+    for (path, decision) in decisions.iter() {
+        match decision {
+            Decision::Skipped => skipped.push(display(path)),
+            Decision::WrittenAs(other) => written_as.push(display(other)),
+            Decision::Written => {}
+        }
+    }
+    (skipped, written_as)
+}
+
+fn prompt(path: &Path, existing: &[u8], content: &[u8]) -> Result<Decision> {
+    let shown = display(path);
+    if !Term::stdout().is_term() {
+        anyhow::bail!(
+            "{} already exists. Rerun with --yes to overwrite or --skip-existing to keep existing files.",
+            shown
+        );
+    }
+
+    let new_path = PathBuf::from(format!("{}.new", path.display()));
+    let items = [
+        "Overwrite".to_string(),
+        "Skip".to_string(),
+        "Show diff".to_string(),
+        format!("Write as {}", display(&new_path)),
+    ];
+
+    loop {
+        let selection = Select::new()
+            .with_prompt(format!("{} already exists", style(&shown).yellow()))
+            .items(&items)
+            .default(0)
+            .interact()?;
+
+        match selection {
+            0 => return Ok(Decision::Written),
+            1 => return Ok(Decision::Skipped),
+            2 => show_changes(&shown, existing, content),
+            _ => return Ok(Decision::WrittenAs(new_path)),
+        }
+    }
+}
+
+/// Print the colored unified diff from the existing file to the incoming content
+fn show_changes(shown: &str, existing: &[u8], content: &[u8]) {
+    let existing = String::from_utf8_lossy(existing);
+    let content = String::from_utf8_lossy(content);
+    let (inserted, deleted) = diff::stats(&existing, &content);
+
+    println!();
+    println!(
+        "  {} {}",
+        style(shown).yellow(),
+        style(format!("+{} -{}", inserted, deleted)).dim()
+    );
+    println!();
+    print!(
+        "{}",
+        diff::unified(&existing, &content, shown, &format!("{} (new)", shown))
+    );
+    println!();
+}
+
+fn display(path: &Path) -> String {
+    path.strip_prefix(".").unwrap_or(path).display().to_string()
+}
@@ -0,0 +1,100 @@
+use console::style;
+use similar::{ChangeTag, TextDiff};
+
+/// Lines of unchanged context around each change
+const CONTEXT_LINES: usize = 3;
+
+/// Colored unified diff from `old` to `new`, empty when they are identical.
+/// Labels name the two sides in the `---`/`+++` header, e.g. the file and `template`.
+pub fn unified(old: &str, new: &str, old_label: &str, new_label: &str) -> String {
+    let diff = TextDiff::from_lines(old, new);
+    let mut output = String::new();
+
+    let mut unified = diff.unified_diff();
+    unified.context_radius(CONTEXT_LINES);
+    for (index, hunk) in unified.iter_hunks().enumerate() {
+        if index == 0 {
+            output.push_str(&format!(
+                "{}\n",
+                style(format!("--- {}", old_label)).red().bold()
+            ));
+            output.push_str(&format!(
+                "{}\n",
+                style(format!("+++ {}", new_label)).green().bold()
+            ));
+        }
+        output.push_str(&format!("{}\n", style(hunk.header()).cyan()));
+
+        for change in hunk.iter_changes() {
+            let line = change.value().trim_end_matches(['\n', '\r']);
+            let rendered = match change.tag() {
+                ChangeTag::Delete => style(format!("-{}", line)).red().to_string(),
+                ChangeTag::Insert => style(format!("+{}", line)).green().to_string(),
+                ChangeTag::Equal => style(format!(" {}", line)).dim().to_string(),
+            };
+            output.push_str(&rendered);
+            output.push('\n');
+            if change.missing_newline() {
+                output.push_str(&format!(
+                    "{}\n",
+                    style("\\ No newline at end of file").dim()
+                ));
+            }
+        }
+    }
+
+    output
+}
+
+/// Count of inserted and deleted lines, for one-line summaries
+pub fn stats(old: &str, new: &str) -> (usize, usize) {
+    let diff = TextDiff::from_lines(old, new);
+    diff.iter_all_changes()
+        .fold((0, 0), |(inserted, deleted), change| match change.tag() {
+            ChangeTag::Insert => (inserted + 1, deleted),
+            ChangeTag::Delete => (inserted, deleted + 1),
+            ChangeTag::Equal => (inserted, deleted),
+        })
+}
+
+/// Apply the change from `base` to `new` to `current`, a copy of `base` that was edited since.
+/// Each changed hunk, with its context, has to appear exactly once in `current`, as `patch`
+/// without fuzz would apply it; `None` when one doesn't.
+pub fn merge(base: &str, new: &str, current: &str) -> Option<String> {
+    let diff = TextDiff::from_lines(base, new);
+    let old_lines: Vec<&str> = diff.iter_old_slices().collect();
+    let new_lines: Vec<&str> = diff.iter_new_slices().collect();
+    let current_lines: Vec<&str> = current.split_inclusive('\n').collect();
+
+    let mut merged = String::new();
+    let mut cursor = 0;
+    for group in diff.grouped_ops(CONTEXT_LINES) {
+        let (first, last) = (group.first()?, group.last()?);
+        let hunk = &old_lines[first.old_range().start..last.old_range().end];
+        let at = cursor + find_once(&current_lines[cursor..], hunk)?;
+
+        merged.extend(current_lines[cursor..at].iter().copied());
+        merged.extend(
+            new_lines[first.new_range().start..last.new_range().end]
+                .iter()
+                .copied(),
+        );
+        cursor = at + hunk.len();
+    }
+    merged.extend(current_lines[cursor..].iter().copied());
+
+    Some(merged)
+}
+
+fn find_once(lines: &[&str], hunk: &[&str]) -> Option<usize> {
+    if hunk.is_empty() {
+        return None;
+    }
+    let mut found = lines
+        .windows(hunk.len())
+        .enumerate()
+        .filter(|(_, window)| *window == hunk)
+        .map(|(index, _)| index);
+    let first = found.next()?;
+    found.next().is_none().then_some(first)
+}
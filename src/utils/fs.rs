@@ -1,16 +1,19 @@
 use anyhow::{Context, Result};
 use git2::Repository;
+use std::collections::BTreeSet;
 use std::fs;
-use std::path::Path;
+use std::io;
+use std::path::{Path, PathBuf};
 
 use crate::cli::AuthProvider;
+use crate::utils::plan::{self, Content, Op};
 
 /// Create the project directory structure
 pub fn create_project_dir(name: &str, auth_provider: AuthProvider) -> Result<()> {
     let project_path = Path::new(name);
 
     if name != "." {
-        fs::create_dir_all(project_path)
+        create_dir_all(project_path)
             .with_context(|| format!("Failed to create directory: {}", name))?;
     }
 
@@ -32,7 +35,7 @@ pub fn create_project_dir(name: &str, auth_provider: AuthProvider) -> Result<()>
     ];
 
     for dir in dirs {
-        fs::create_dir_all(project_path.join(dir))
+        create_dir_all(project_path.join(dir))
             .with_context(|| format!("Failed to create directory: {}", dir))?;
     }
 
@@ -41,10 +44,16 @@ pub fn create_project_dir(name: &str, auth_provider: AuthProvider) -> Result<()>
 
 /// Initialize a git repository
 pub fn init_git(name: &str) -> Result<()> {
-    let project_path = Path::new(name);
-    Repository::init(project_path).context("Failed to initialize git repository")?;
+    let repository = Repository::init(name).context("Failed to initialize git repository")?;
+    // node_modules and nested app routes easily pass Windows' 260 character path limit
+    if cfg!(windows) {
+        repository.config()?.set_bool("core.longpaths", true)?;
+    }
+    Ok(())
+}
 
-    // Create .gitignore
+/// Write the `.gitignore` that goes with [`init_git`]
+pub fn write_gitignore(name: &str) -> Result<()> {
     let gitignore = r#"# Dependencies
 node_modules/
 .pnpm-store/
@@ -59,6 +68,11 @@ build/
 .env
 .env.local
 .env.*.local
+.env.production
+
+# Nix / direnv
+.direnv/
+result
 
 # IDE
 .idea/
@@ -88,22 +102,178 @@ coverage/
 .nyc_output/
 "#;
 
-    fs::write(project_path.join(".gitignore"), gitignore)?;
+    write(Path::new(name).join(".gitignore"), gitignore)?;
 
     Ok(())
 }
 
-/// Write a file to the project directory
+/// Write a template file to the project directory, resolving conflicts with existing
+/// files according to the current [`conflict`] mode
 pub fn write_file(project_path: &str, relative_path: &str, content: &str) -> Result<()> {
-    let full_path = Path::new(project_path).join(relative_path);
+    plan::apply(Op::WriteFile {
+        path: Path::new(project_path).join(relative_path),
+        content: content.to_string().into(),
+        executable: false,
+    })
+}
 
-    if let Some(parent) = full_path.parent() {
+/// Replace a project file with an edited version of its content, e.g. with a router
+/// registered or an import added. Unlike [`write_file`] this never asks about conflicts:
+/// the edit was worked out from what the file holds, so it keeps the user's changes.
+pub fn update_file(project_path: &str, relative_path: &str, content: &str) -> Result<()> {
+    plan::apply(Op::EditFile {
+        path: Path::new(project_path).join(relative_path),
+        content: content.to_string().into(),
+    })
+}
+
+/// Write generated content as-is, bypassing conflict prompts; templates include binary assets
+pub fn write_bytes(relative_path: &str, content: &[u8]) -> Result<()> {
+    write(relative_path, content)
+        .with_context(|| format!("Failed to write file: {}", relative_path))
+}
+
+// While a write plan is active (see `plan`), the functions below queue writes and removals
+// in it and reads see the queued content, so scaffolders can patch files written by
+// earlier steps before anything reaches the disk.
+
+/// Write `content` to `path`, creating its parent directories
+pub fn write(path: impl AsRef<Path>, content: impl AsRef<[u8]>) -> io::Result<()> {
+    write_with_mode(path.as_ref(), content.as_ref(), false)
+}
+
+/// [`write`], making the file executable when `executable` is set
+pub fn write_with_mode(path: &Path, content: &[u8], executable: bool) -> io::Result<()> {
+    write_content(path, content.to_vec().into(), executable)
+}
+
+/// [`write_with_mode`] for content that needn't be copied first, e.g. embedded assets
+pub fn write_content(path: &Path, content: Content, executable: bool) -> io::Result<()> {
+    if plan::is_active() {
+        plan::record_write(path, content, executable);
+        return Ok(());
+    }
+    if let Some(parent) = path.parent() {
         fs::create_dir_all(parent)?;
     }
+    content.write_to(path)?;
+    if executable {
+        set_executable(path).map_err(io::Error::other)?;
+    }
+    Ok(())
+}
+
+pub fn read(path: impl AsRef<Path>) -> io::Result<Vec<u8>> {
+    match plan::read(path.as_ref()) {
+        Some(Some(content)) => content.load().map(|bytes| bytes.into_owned()),
+        Some(None) => Err(io::Error::from(io::ErrorKind::NotFound)),
+        None => fs::read(path),
+    }
+}
+
+pub fn read_to_string(path: impl AsRef<Path>) -> io::Result<String> {
+    String::from_utf8(read(path)?).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Whether a file or directory exists, counting directories that only queued files create
+pub fn path_exists(path: impl AsRef<Path>) -> bool {
+    let path = path.as_ref();
+    match plan::pending(path) {
+        Some(pending) => pending.is_some(),
+        None => path.exists() || plan::pending_under(path),
+    }
+}
+
+/// Create `path` and its parents; while a plan is active, when it is executed
+pub fn create_dir_all(path: impl AsRef<Path>) -> io::Result<()> {
+    let path = path.as_ref();
+    if plan::record_dir(path) {
+        return Ok(());
+    }
+    fs::create_dir_all(path)
+}
 
-    fs::write(&full_path, content)
-        .with_context(|| format!("Failed to write file: {}", relative_path))?;
+pub fn remove_file(path: impl AsRef<Path>) -> io::Result<()> {
+    let path = path.as_ref();
+    if plan::record_remove(path) {
+        return Ok(());
+    }
+    fs::remove_file(path)
+}
 
+/// Paths of the files directly in `dir`, sorted
+pub fn list_dir(dir: impl AsRef<Path>) -> io::Result<Vec<PathBuf>> {
+    let dir = dir.as_ref();
+    let (written, removed) = plan::pending_in(dir);
+    let mut names = BTreeSet::new();
+    match fs::read_dir(dir) {
+        Ok(entries) => {
+            for entry in entries {
+                let entry = entry?;
+                if !entry.file_type()?.is_dir() {
+                    names.insert(entry.file_name());
+                }
+            }
+        }
+        Err(err) if err.kind() == io::ErrorKind::NotFound && !written.is_empty() => {}
+        Err(err) => return Err(err),
+    }
+    names.extend(
+        written
+            .iter()
+            .filter_map(|path| path.file_name())
+            .map(|name| name.to_os_string()),
+    );
+    for name in removed.iter().filter_map(|path| path.file_name()) {
+        names.remove(name);
+    }
+    Ok(names.into_iter().map(|name| dir.join(name)).collect())
+}
+
+/// The type of a directory entry with symlinks followed; `None` for a broken link
+pub fn resolved_file_type(entry: &fs::DirEntry) -> Option<fs::FileType> {
+    let kind = entry.file_type().ok()?;
+    if kind.is_symlink() {
+        fs::metadata(entry.path())
+            .ok()
+            .map(|metadata| metadata.file_type())
+    } else {
+        Some(kind)
+    }
+}
+
+/// Template and manifest paths are `/`-separated on every platform
+pub fn to_slash(path: &str) -> String {
+    path.replace('\\', "/")
+}
+
+/// Whether a file has an executable bit; always false where files have no modes
+pub fn is_executable(path: &Path) -> bool {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::metadata(path).is_ok_and(|metadata| metadata.permissions().mode() & 0o111 != 0)
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = path;
+        false
+    }
+}
+
+/// Let everyone who can read a file execute it, e.g. `start-database.sh`; a no-op where
+/// files have no modes
+pub fn set_executable(path: &Path) -> Result<()> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut permissions = fs::metadata(path)?.permissions();
+        permissions.set_mode(permissions.mode() | (permissions.mode() & 0o444) >> 2);
+        fs::set_permissions(path, permissions)
+            .with_context(|| format!("Failed to make {} executable", path.display()))?;
+    }
+    #[cfg(not(unix))]
+    let _ = path;
     Ok(())
 }
 
@@ -0,0 +1,91 @@
+use anyhow::{Context, Result};
+use console::style;
+use std::path::Path;
+use std::process::Command;
+use std::sync::Mutex;
+
+use crate::utils::config::HooksConfig;
+use crate::utils::lang::Msg;
+use crate::utils::output::glyph;
+
+static HOOKS: Mutex<Option<HooksConfig>> = Mutex::new(None);
+
+/// Point in a run where `[hooks]` commands execute
+#[derive(Clone, Copy, Debug)]
+pub enum Hook<'a> {
+    PreCreate,
+    PostCreate,
+    PreAdd(&'a str),
+    PostAdd(&'a str),
+}
+
+impl Hook<'_> {
+    fn name(self) -> &'static str {
+        match self {
+            Hook::PreCreate => "pre_create",
+            Hook::PostCreate => "post_create",
+            Hook::PreAdd(_) => "pre_add",
+            Hook::PostAdd(_) => "post_add",
+        }
+    }
+
+    fn commands(self, hooks: &HooksConfig) -> Vec<String> {
+        match self {
+            Hook::PreCreate => hooks.pre_create.clone(),
+            Hook::PostCreate => hooks.post_create.clone(),
+            Hook::PreAdd(extension) => hooks.pre_add.get(extension).cloned().unwrap_or_default(),
+            Hook::PostAdd(extension) => hooks.post_add.get(extension).cloned().unwrap_or_default(),
+        }
+    }
+}
+
+pub fn configure(hooks: HooksConfig) {
+    *HOOKS.lock().unwrap_or_else(|e| e.into_inner()) = Some(hooks);
+}
+
+/// Run the commands configured for `hook` through the shell, one at a time with their
+/// output streamed to the terminal. They run in `project_dir`, or in the current directory
+/// before `create`, with T3_MONO_HOOK, T3_MONO_PROJECT_DIR, and for `add` T3_MONO_EXTENSION
+/// set. The first command that fails stops the run.
+pub fn run(hook: Hook<'_>, project_dir: &str) -> Result<()> {
+    let commands = match HOOKS.lock().unwrap_or_else(|e| e.into_inner()).as_ref() {
+        Some(hooks) => hook.commands(hooks),
+        None => return Ok(()),
+    };
+
+    for command in &commands {
+        println!(
+            "  {} {}",
+            style(glyph("→")).cyan(),
+            Msg::RunningHook.fill(&[&hook.name(), &command])
+        );
+
+        let mut shell = if cfg!(windows) {
+            let mut shell = Command::new("cmd");
+            shell.args(["/C", command]);
+            shell
+        } else {
+            let mut shell = Command::new("sh");
+            shell.args(["-c", command]);
+            shell
+        };
+        if !matches!(hook, Hook::PreCreate) {
+            shell.current_dir(project_dir);
+        }
+        shell
+            .env("T3_MONO_HOOK", hook.name())
+            .env("T3_MONO_PROJECT_DIR", Path::new(project_dir));
+        if let Hook::PreAdd(extension) | Hook::PostAdd(extension) = hook {
+            shell.env("T3_MONO_EXTENSION", extension);
+        }
+
+        let status = shell
+            .status()
+            .with_context(|| Msg::HookNotStarted.fill(&[&hook.name(), &command]))?;
+        if !status.success() {
+            anyhow::bail!(Msg::HookFailed.fill(&[&hook.name(), &command, &status]));
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,378 @@
+use std::fmt::Display;
+use std::sync::OnceLock;
+
+use crate::cli::Lang;
+
+static LANG: OnceLock<Lang> = OnceLock::new();
+
+/// Pick create's output language: `--lang`, then the system locale, then English
+pub fn init(flag: Option<Lang>) {
+    let _ = LANG.set(flag.unwrap_or_else(system_lang));
+}
+
+pub fn current() -> Lang {
+    LANG.get().copied().unwrap_or_default()
+}
+
+/// The first of LC_ALL, LC_MESSAGES, and LANG that is set, e.g. `de_DE.UTF-8`
+fn system_lang() -> Lang {
+    let locale = ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|value| !value.is_empty())
+        .unwrap_or_default();
+    if locale.to_lowercase().starts_with("de") {
+        Lang::De
+    } else {
+        Lang::En
+    }
+}
+
+/// The CLI's own output; `{}` marks where `fill` puts its arguments
+#[derive(Clone, Copy, Debug)]
+pub enum Msg {
+    Error,
+    DirectoryNotEmpty,
+    StandaloneWebsocket,
+    SessionNeedsNextAuth,
+
+    // Summary before scaffolding
+    Creating,
+    WithStack,
+    InDirectory,
+    AiAgents,
+    UiLibrary,
+    AnalyticsPage,
+    RestateWorkflows,
+    RestateKafka,
+    CmdLayer,
+    ChatStreaming,
+    ServerSentEvents,
+    ExamplePostRouter,
+    RestEndpoint,
+    SeedScript,
+    OwnModels,
+    OpenGraphRoute,
+    StandaloneBuild,
+    BundleAnalyzer,
+    SecurityHeaders,
+    ConnectionPooling,
+    DatabaseSessions,
+    SoftDeletes,
+    RemoteImages,
+    ImageCdn,
+    InitialMigration,
+    NixShell,
+    TerraformModule,
+    TaskRunnerFile,
+    CoverageThreshold,
+    TailwindPlugins,
+    DarkModeMedia,
+    LightThemeOnly,
+    BiomeRules,
+    Languages,
+    LatestVersions,
+
+    // Progress
+    CreatingStructure,
+    SettingUpT3,
+    ConfiguringAuth,
+    AddingExample,
+    AddingAi,
+    AddingUi,
+    AddingRestate,
+    AddingCmd,
+    ConfiguringDarkMode,
+    AddingSoftDeletes,
+    AddingSeed,
+    GeneratingModels,
+    AddingDockerfile,
+    ConfiguringImages,
+    AddingBundleAnalyzer,
+    AddingSecurityHeaders,
+    AddingNix,
+    AddingTerraform,
+    AddingTaskRunner,
+    AddingLanguages,
+    AddingCi,
+    WritingDocs,
+    GeneratingMigration,
+    InitializingGit,
+    FinalizingPackageJson,
+    ResolvingLatest,
+
+    // --dry-run
+    DryRun,
+    DryRunFiles,
+    Clobbered,
+
+    // --setup-db
+    RunningSetup,
+    SetupNotStarted,
+    SetupFailed,
+    InstallFailedHint,
+    DatabaseFailedHint,
+    SqliteFailedHint,
+    DatabaseReady,
+
+    // Replay and --latest
+    ManifestVersion,
+    NewestVersions,
+    ResolvedVersions,
+    LookupFailed,
+
+    // Prompts
+    SelectAuth,
+    BetterAuthRecommended,
+    SelectExtensions,
+    AiExtension,
+    UiExtension,
+    RestateExtension,
+    CmdExtension,
+    DefineModels,
+    ModelName,
+    ModelFields,
+    SelectCmdProvider,
+    SelectCmdModel,
+    SelectCmdVectors,
+    PgvectorSearch,
+    KeywordSearch,
+    SelectCmdStorage,
+    AwsS3,
+    S3Compatible,
+    SelectCmdModules,
+    AiTables,
+    AiDocs,
+
+    // Success
+    ProjectCreated,
+    NextSteps,
+    ForRestate,
+    IncludedExtensions,
+    AiAgentsIn,
+    UiComponentsIn,
+    AnalyticsAt,
+    RestateIn,
+    CmdIn,
+    CmdSettingsIn,
+    RoutersIn,
+    SkillIn,
+    ChatSocket,
+    ExampleRouterIn,
+    ExamplePageAt,
+    ModelPagesAt,
+    RestAt,
+    ApiReferenceAt,
+    CorsFrom,
+    TranslateTodos,
+    Docs,
+
+    // [hooks] in t3mono.toml
+    RunningHook,
+    HookNotStarted,
+    HookFailed,
+}
+
+impl Msg {
+    pub fn text(self) -> &'static str {
+        let (en, de) = match self {
+            Msg::Error => ("Error:", "Fehler:"),
+            Msg::DirectoryNotEmpty => (
+                "Directory '{}' already exists and is not empty",
+                "Das Verzeichnis '{}' existiert bereits und ist nicht leer",
+            ),
+            Msg::StandaloneWebsocket => (
+                "--cmd-stream websocket needs the custom server in server.ts, which the standalone image doesn't run; use --cmd-stream sse with --standalone",
+                "--cmd-stream websocket braucht den eigenen Server in server.ts, den das Standalone-Image nicht startet; nutze --cmd-stream sse mit --standalone",
+            ),
+            Msg::SessionNeedsNextAuth => (
+                "--session database is for NextAuth; Better Auth always keeps sessions in the database",
+                "--session database gilt für NextAuth; Better Auth speichert Sessions immer in der Datenbank",
+            ),
+
+            Msg::Creating => ("Creating", "Erstelle"),
+            Msg::WithStack => ("with T3 Stack + {}", "mit T3 Stack + {}"),
+            Msg::InDirectory => ("in {}", "in {}"),
+            Msg::AiAgents => ("LangChain AI agents", "LangChain-KI-Agenten"),
+            Msg::UiLibrary => ("UI component library", "UI-Komponentenbibliothek"),
+            Msg::AnalyticsPage => ("Analytics charts page", "Seite mit Analyse-Diagrammen"),
+            Msg::RestateWorkflows => ("Restate durable workflows", "Dauerhafte Restate-Workflows"),
+            Msg::RestateKafka => ("Kafka-triggered Restate workflow", "Kafka-gesteuerter Restate-Workflow"),
+            Msg::CmdLayer => ("CommandIsland AI layer", "CommandIsland-KI-Ebene"),
+            Msg::ChatStreaming => ("Chat streaming over {}", "Chat-Streaming über {}"),
+            Msg::ServerSentEvents => ("server-sent events", "Server-Sent Events"),
+            Msg::ExamplePostRouter => ("Example post router and page", "Beispiel-Router und -Seite für Posts"),
+            Msg::RestEndpoint => ("REST endpoint at /api/v1/posts", "REST-Endpunkt unter /api/v1/posts"),
+            Msg::SeedScript => ("Database seed script", "Seed-Skript für die Datenbank"),
+            Msg::OwnModels => ("Models {} with CRUD routers and pages", "Modelle {} mit CRUD-Routern und Seiten"),
+            Msg::OpenGraphRoute => ("OpenGraph image route", "Route für das OpenGraph-Bild"),
+            Msg::StandaloneBuild => ("Standalone build with app Dockerfile", "Standalone-Build mit Dockerfile für die App"),
+            Msg::BundleAnalyzer => ("Bundle analyzer (npm run analyze)", "Bundle-Analyse (npm run analyze)"),
+            Msg::SecurityHeaders => ("Security headers with a nonce-based CSP", "Sicherheits-Header mit nonce-basierter CSP"),
+            Msg::ConnectionPooling => ("{} connection pooling", "Connection-Pooling mit {}"),
+            Msg::DatabaseSessions => ("Database sessions", "Sessions in der Datenbank"),
+            Msg::SoftDeletes => ("Soft deletes and audit columns", "Soft Deletes und Audit-Spalten"),
+            Msg::RemoteImages => ("Remote images from {}", "Externe Bilder von {}"),
+            Msg::ImageCdn => ("Uploads served from {}", "Uploads ausgeliefert von {}"),
+            Msg::InitialMigration => ("Initial Prisma migration", "Erste Prisma-Migration"),
+            Msg::NixShell => ("Nix flake dev shell", "Nix-Flake-Entwicklungsumgebung"),
+            Msg::TerraformModule => ("Terraform module for AWS infrastructure", "Terraform-Modul für die AWS-Infrastruktur"),
+            Msg::TaskRunnerFile => ("{} for common workflows", "{} für häufige Abläufe"),
+            Msg::CoverageThreshold => ("{}% coverage threshold", "Mindestabdeckung von {}%"),
+            Msg::TailwindPlugins => ("Tailwind plugins: {}", "Tailwind-Plugins: {}"),
+            Msg::DarkModeMedia => ("Dark mode following the OS setting", "Dunkelmodus nach Systemeinstellung"),
+            Msg::LightThemeOnly => ("Light theme only", "Nur helles Design"),
+            Msg::BiomeRules => ("{} Biome lint rules", "Biome-Regelsatz {}"),
+            Msg::Languages => ("Languages: {}", "Sprachen: {}"),
+            Msg::LatestVersions => ("Latest compatible dependency versions", "Neueste kompatible Abhängigkeitsversionen"),
+
+            Msg::CreatingStructure => ("Creating project structure...", "Lege die Projektstruktur an..."),
+            Msg::SettingUpT3 => ("Setting up T3 stack...", "Richte den T3 Stack ein..."),
+            Msg::ConfiguringAuth => ("Configuring {}...", "Konfiguriere {}..."),
+            Msg::AddingExample => ("Adding example router...", "Füge den Beispiel-Router hinzu..."),
+            Msg::AddingAi => ("Adding AI agents framework...", "Füge das KI-Agenten-Framework hinzu..."),
+            Msg::AddingUi => ("Adding UI components...", "Füge UI-Komponenten hinzu..."),
+            Msg::AddingRestate => ("Adding Restate workflows...", "Füge Restate-Workflows hinzu..."),
+            Msg::AddingCmd => ("Adding CommandIsland AI layer...", "Füge die CommandIsland-KI-Ebene hinzu..."),
+            Msg::ConfiguringDarkMode => ("Configuring dark mode...", "Konfiguriere den Dunkelmodus..."),
+            Msg::AddingSoftDeletes => (
+                "Adding soft deletes and audit columns...",
+                "Füge Soft Deletes und Audit-Spalten hinzu...",
+            ),
+            Msg::AddingSeed => ("Adding database seed script...", "Füge das Seed-Skript hinzu..."),
+            Msg::GeneratingModels => ("Generating your models...", "Erzeuge deine Modelle..."),
+            Msg::AddingDockerfile => ("Adding app Dockerfile...", "Füge das Dockerfile für die App hinzu..."),
+            Msg::ConfiguringImages => ("Configuring remote images...", "Konfiguriere externe Bilder..."),
+            Msg::AddingBundleAnalyzer => ("Adding bundle analyzer...", "Füge die Bundle-Analyse hinzu..."),
+            Msg::AddingSecurityHeaders => ("Adding security headers...", "Füge Sicherheits-Header hinzu..."),
+            Msg::AddingNix => ("Adding Nix flake...", "Füge das Nix-Flake hinzu..."),
+            Msg::AddingTerraform => ("Adding Terraform module...", "Füge das Terraform-Modul hinzu..."),
+            Msg::AddingTaskRunner => ("Adding task runner file...", "Füge die Task-Runner-Datei hinzu..."),
+            Msg::AddingLanguages => ("Adding languages...", "Füge Sprachen hinzu..."),
+            Msg::AddingCi => ("Adding CI workflow...", "Füge den CI-Workflow hinzu..."),
+            Msg::WritingDocs => ("Writing documentation...", "Schreibe die Dokumentation..."),
+            Msg::GeneratingMigration => ("Generating initial migration...", "Erzeuge die erste Migration..."),
+            Msg::InitializingGit => ("Initializing git repository...", "Initialisiere das Git-Repository..."),
+            Msg::FinalizingPackageJson => ("Finalizing package.json...", "Stelle package.json fertig..."),
+            Msg::ResolvingLatest => (
+                "Resolving latest versions from the npm registry...",
+                "Ermittle die neuesten Versionen aus der npm-Registry...",
+            ),
+
+            Msg::DryRun => ("Dry run: nothing is written", "Probelauf: es wird nichts geschrieben"),
+            Msg::DryRunFiles => ("{} files would be written to {}", "{} Dateien würden nach {} geschrieben"),
+            Msg::Clobbered => (
+                "{}: {} replaces what {} wrote there without reading it",
+                "{}: {} ersetzt, was {} dort geschrieben hat, ohne es zu lesen",
+            ),
+
+            Msg::RunningSetup => ("Running {}", "Führe {} aus"),
+            Msg::SetupNotStarted => ("Could not run `{}`; is Node.js installed?", "`{}` konnte nicht ausgeführt werden; ist Node.js installiert?"),
+            Msg::SetupFailed => (
+                "`{}` failed ({}). The project was created; the database isn't set up yet.",
+                "`{}` ist fehlgeschlagen ({}). Das Projekt wurde erstellt, die Datenbank ist aber noch nicht eingerichtet.",
+            ),
+            Msg::InstallFailedHint => (
+                "Fix the error above, then run the remaining steps in the project:",
+                "Behebe den Fehler oben und führe dann die übrigen Schritte im Projekt aus:",
+            ),
+            Msg::DatabaseFailedHint => (
+                "Is the database running? Start it with ./start-database.sh, check DATABASE_URL in .env.development, then run:",
+                "Läuft die Datenbank? Starte sie mit ./start-database.sh, prüfe DATABASE_URL in .env.development und führe dann aus:",
+            ),
+            Msg::SqliteFailedHint => (
+                "Check DATABASE_URL in .env.development, then run:",
+                "Prüfe DATABASE_URL in .env.development und führe dann aus:",
+            ),
+            Msg::DatabaseReady => ("Database is set up", "Datenbank ist eingerichtet"),
+
+            Msg::ManifestVersion => (
+                "{} was created with t3-mono {}; this is {}, so templates and dependency versions may differ. Run {} for an exact copy.",
+                "{} wurde mit t3-mono {} erstellt; dies ist {}, Vorlagen und Abhängigkeitsversionen können sich also unterscheiden. Für eine exakte Kopie: {}",
+            ),
+            Msg::NewestVersions => (
+                "The built-in dependency versions are the newest compatible ones",
+                "Die eingebauten Abhängigkeitsversionen sind die neuesten kompatiblen",
+            ),
+            Msg::ResolvedVersions => ("Resolved {} newer dependency versions", "{} neuere Abhängigkeitsversionen ermittelt"),
+            Msg::LookupFailed => (
+                "Couldn't look up {}; kept the built-in versions",
+                "{} konnte nicht abgefragt werden; die eingebauten Versionen bleiben",
+            ),
+
+            Msg::SelectAuth => ("Select authentication provider", "Authentifizierung auswählen"),
+            Msg::BetterAuthRecommended => ("Better Auth (recommended)", "Better Auth (empfohlen)"),
+            Msg::SelectExtensions => ("Select extensions to include", "Erweiterungen auswählen"),
+            Msg::AiExtension => ("AI Agents (LangChain)", "KI-Agenten (LangChain)"),
+            Msg::UiExtension => ("UI Components", "UI-Komponenten"),
+            Msg::RestateExtension => ("Restate Workflows", "Restate-Workflows"),
+            Msg::CmdExtension => ("CommandIsland AI Layer", "CommandIsland-KI-Ebene"),
+            Msg::DefineModels => ("Define your own data models?", "Eigene Datenmodelle anlegen?"),
+            Msg::ModelName => ("Model name, e.g. product (empty to finish)", "Name des Modells, z. B. product (leer zum Beenden)"),
+            Msg::ModelFields => (
+                "Fields of {}, e.g. title:string price:float notes:text? (string, text, int, float, boolean, datetime)",
+                "Felder von {}, z. B. title:string price:float notes:text? (string, text, int, float, boolean, datetime)",
+            ),
+            Msg::SelectCmdProvider => ("CommandIsland: LLM provider", "CommandIsland: LLM-Anbieter"),
+            Msg::SelectCmdModel => ("CommandIsland: default model", "CommandIsland: Standardmodell"),
+            Msg::SelectCmdVectors => ("CommandIsland: attachment search", "CommandIsland: Suche in Anhängen"),
+            Msg::PgvectorSearch => ("pgvector (similarity and keywords)", "pgvector (Ähnlichkeit und Stichwörter)"),
+            Msg::KeywordSearch => ("Keywords only (no embeddings)", "Nur Stichwörter (ohne Embeddings)"),
+            Msg::SelectCmdStorage => ("CommandIsland: file storage", "CommandIsland: Dateispeicher"),
+            Msg::AwsS3 => ("AWS S3", "AWS S3"),
+            Msg::S3Compatible => ("S3-compatible (MinIO, R2) at AWS_S3_ENDPOINT", "S3-kompatibel (MinIO, R2) unter AWS_S3_ENDPOINT"),
+            Msg::SelectCmdModules => ("CommandIsland: modes next to the chat", "CommandIsland: Modi neben dem Chat"),
+            Msg::AiTables => ("AI tables", "KI-Tabellen"),
+            Msg::AiDocs => ("AI documents", "KI-Dokumente"),
+
+            Msg::ProjectCreated => ("Project created successfully!", "Projekt erfolgreich erstellt!"),
+            Msg::NextSteps => ("Next steps:", "Nächste Schritte:"),
+            Msg::ForRestate => ("For Restate:", "Für Restate:"),
+            Msg::IncludedExtensions => ("Included extensions:", "Enthaltene Erweiterungen:"),
+            Msg::AiAgentsIn => ("AI agents in {}", "KI-Agenten in {}"),
+            Msg::UiComponentsIn => ("UI components in {}", "UI-Komponenten in {}"),
+            Msg::AnalyticsAt => ("Analytics charts at {}", "Analyse-Diagramme unter {}"),
+            Msg::RestateIn => ("Restate workflows in {}", "Restate-Workflows in {}"),
+            Msg::CmdIn => ("CommandIsland AI layer in {}", "CommandIsland-KI-Ebene in {}"),
+            Msg::CmdSettingsIn => ("CommandIsland model, storage, and modes in {}", "Modell, Speicher und Modi der CommandIsland in {}"),
+            Msg::RoutersIn => ("tRPC routers in {}", "tRPC-Router in {}"),
+            Msg::SkillIn => ("Claude skill in {}", "Claude-Skill in {}"),
+            Msg::ChatSocket => ("Chat socket served by {} ({} and {} run it)", "Chat-Socket aus {} ({} und {} starten ihn)"),
+            Msg::ExampleRouterIn => ("Example post router in {}", "Beispiel-Router für Posts in {}"),
+            Msg::ExamplePageAt => ("Example page at {}", "Beispielseite unter {}"),
+            Msg::ModelPagesAt => ("Pages for your models at {}", "Seiten für deine Modelle unter {}"),
+            Msg::RestAt => ("REST endpoint at {} (set {})", "REST-Endpunkt unter {} ({} setzen)"),
+            Msg::ApiReferenceAt => ("API reference at {}", "API-Referenz unter {}"),
+            Msg::CorsFrom => ("Browser calls from other origins in {}", "Browser-Aufrufe von anderen Origins in {}"),
+            Msg::TranslateTodos => ("Translate the {} values in {}", "Übersetze die {}-Werte in {}"),
+            Msg::Docs => ("Docs:", "Doku:"),
+            Msg::RunningHook => ("Running {} hook: {}", "Führe {}-Hook aus: {}"),
+            Msg::HookNotStarted => ("Could not start {} hook `{}`", "{}-Hook `{}` konnte nicht gestartet werden"),
+            Msg::HookFailed => ("{} hook `{}` failed ({})", "{}-Hook `{}` ist fehlgeschlagen ({})"),
+        };
+        match current() {
+            Lang::En => en,
+            Lang::De => de,
+        }
+    }
+
+    /// The text with each `{}` replaced by the next argument
+    pub fn fill(self, args: &[&dyn Display]) -> String {
+        let mut parts = self.text().split("{}");
+        let mut filled = parts.next().unwrap_or_default().to_string();
+        let mut args = args.iter();
+        for part in parts {
+            if let Some(arg) = args.next() {
+                filled.push_str(&arg.to_string());
+            }
+            filled.push_str(part);
+        }
+        filled
+    }
+}
+
+impl Display for Msg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.text())
+    }
+}
@@ -1,2 +1,14 @@
+pub mod answers;
+pub mod config;
+pub mod conflict;
+pub mod diff;
 pub mod fs;
+pub mod hooks;
+pub mod lang;
+pub mod naming;
 pub mod npm;
+pub mod output;
+pub mod plan;
+pub mod registry;
+pub mod telemetry;
+pub mod update;
@@ -0,0 +1,133 @@
+/// Split `BlogPost`, `blog-post`, `blog_post`, or `blog post` into lowercase words
+fn words(input: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut previous: Option<char> = None;
+
+    for c in input.chars() {
+        if !c.is_alphanumeric() {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+        } else {
+            let boundary = c.is_uppercase()
+                && previous.is_some_and(|p| p.is_lowercase() || p.is_ascii_digit());
+            if boundary && !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            current.extend(c.to_lowercase());
+        }
+        previous = Some(c);
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// `blog-post` -> `BlogPost`, for models, components, and types
+pub fn pascal_case(input: &str) -> String {
+    words(input).iter().map(|word| capitalize(word)).collect()
+}
+
+/// `blog-post` -> `blogPost`, for router keys and variables
+pub fn camel_case(input: &str) -> String {
+    let pascal = pascal_case(input);
+    let mut chars = pascal.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// `acme-shop` or `@acme/shop` -> `Acme Shop`, for titles shown to users
+pub fn title_case(input: &str) -> String {
+    let name = input.rsplit('/').next().unwrap_or(input);
+    words(name)
+        .iter()
+        .map(|word| capitalize(word))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// `BlogPost` -> `blog-post`, for file names and routes
+pub fn kebab_case(input: &str) -> String {
+    words(input).join("-")
+}
+
+/// English plural of the last word: `category` -> `categories`, `box` -> `boxes`
+pub fn pluralize(word: &str) -> String {
+    let lower = word.to_lowercase();
+    if lower.ends_with('s')
+        || lower.ends_with('x')
+        || lower.ends_with("ch")
+        || lower.ends_with("sh")
+    {
+        format!("{}es", word)
+    } else if lower.ends_with('y')
+        && !lower.ends_with("ay")
+        && !lower.ends_with("ey")
+        && !lower.ends_with("oy")
+        && !lower.ends_with("uy")
+    {
+        format!("{}ies", &word[..word.len() - 1])
+    } else {
+        format!("{}s", word)
+    }
+}
+
+/// A valid npm package name: lowercase, with path separators and other characters npm
+/// rejects turned into `-`; a `@scope/name` is kept
+pub fn package_name(input: &str) -> String {
+    let clean = |part: &str| -> String {
+        let part: String = part
+            .to_lowercase()
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || "-._~".contains(c) {
+                    c
+                } else {
+                    '-'
+                }
+            })
+            .collect();
+        part.trim_start_matches(['.', '_']).to_string()
+    };
+
+    if let Some((scope, name)) = input
+        .strip_prefix('@')
+        .and_then(|rest| rest.split_once('/'))
+    {
+        let (scope, name) = (clean(scope), clean(name));
+        if !scope.is_empty() && !name.is_empty() {
+            return format!("@{}/{}", scope, name);
+        }
+    }
+    let name = clean(&input.replace(['/', '\\'], "-"));
+    if name.is_empty() {
+        "my-app".to_string()
+    } else {
+        name
+    }
+}
+
+/// A name usable as a TypeScript identifier and file name: letters, digits, `-`, `_`,
+/// starting with a letter
+pub fn is_valid_name(input: &str) -> bool {
+    input
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic())
+        && input
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
@@ -1,6 +1,11 @@
+use anyhow::Result;
 use serde::{Deserialize, Serialize};
-use serde_json::{Map, Value};
+use serde_json::Value;
 use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::utils::fs;
+use crate::utils::plan::{self, Op};
 
 /// Package.json structure
 #[derive(Debug, Serialize, Deserialize)]
@@ -73,3 +78,69 @@ pub fn merge_dependencies(
         }
     }
 }
+
+/// Carry over scripts and dependencies from `other` that `base` doesn't define yet
+pub fn merge_package_json(base: &mut Value, other: &Value) {
+    for section in ["scripts", "dependencies", "devDependencies"] {
+        let Some(entries) = other.get(section).and_then(|s| s.as_object()) else {
+            continue;
+        };
+
+        if !base[section].is_object() {
+            base[section] = Value::Object(Default::default());
+        }
+
+        if let Some(target) = base[section].as_object_mut() {
+            for (name, value) in entries {
+                if !target.contains_key(name) {
+                    target.insert(name.clone(), value.clone());
+                }
+            }
+        }
+    }
+}
+
+/// Read the project's package.json, or an empty object if it hasn't been written yet
+pub fn read_package_json(project_path: &str) -> Result<Value> {
+    let path = Path::new(project_path).join("package.json");
+    if !fs::path_exists(&path) {
+        return Ok(Value::Object(Default::default()));
+    }
+
+    let content = fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&content)?)
+}
+
+/// Add dependencies to the project's package.json, keeping versions the user already pinned
+pub fn add_dependencies(
+    project_path: &str,
+    deps: &[(&str, &str)],
+    dev_deps: &[(&str, &str)],
+) -> Result<()> {
+    let path = Path::new(project_path).join("package.json");
+    let deps = deps.iter().map(|dep| (dep, false));
+    for ((name, version), dev) in deps.chain(dev_deps.iter().map(|dep| (dep, true))) {
+        plan::apply(Op::AddDependency {
+            path: path.clone(),
+            name: name.to_string(),
+            version: version.to_string(),
+            dev,
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Add npm scripts to the project's package.json, keeping scripts the user already defined
+pub fn add_scripts(project_path: &str, scripts: &[(&str, &str)]) -> Result<()> {
+    let scripts: serde_json::Map<String, Value> = scripts
+        .iter()
+        .map(|(name, command)| (name.to_string(), Value::String(command.to_string())))
+        .collect();
+
+    plan::apply(Op::MergeJson {
+        path: Path::new(project_path).join("package.json"),
+        value: serde_json::json!({ "scripts": scripts }),
+        overwrite: false,
+    })
+}
@@ -0,0 +1,89 @@
+use indicatif::{ProgressBar, ProgressStyle};
+use std::borrow::Cow;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+static PLAIN: AtomicBool = AtomicBool::new(false);
+static VERBOSE: AtomicBool = AtomicBool::new(false);
+
+/// `--plain`: no colors, no spinner, and ASCII in place of status glyphs.
+/// `--verbose`: detail lines such as where each template came from, so no spinner either
+pub fn init(plain: bool, verbose: bool) {
+    VERBOSE.store(verbose, Ordering::Relaxed);
+    if plain {
+        PLAIN.store(true, Ordering::Relaxed);
+        console::set_colors_enabled(false);
+        console::set_colors_enabled_stderr(false);
+    }
+}
+
+pub fn is_plain() -> bool {
+    PLAIN.load(Ordering::Relaxed)
+}
+
+pub fn is_verbose() -> bool {
+    VERBOSE.load(Ordering::Relaxed)
+}
+
+/// `symbol`, or a word a screen reader reads out in plain mode
+pub fn glyph(symbol: &'static str) -> &'static str {
+    if !is_plain() {
+        return symbol;
+    }
+    match symbol {
+        "✓" => "ok",
+        "•" => "-",
+        "→" => "->",
+        _ => symbol,
+    }
+}
+
+/// Status of a long-running step: a spinner with the latest message,
+/// or one line per message in plain and verbose mode
+pub enum Progress {
+    Spinner(ProgressBar),
+    Lines,
+    Hidden,
+}
+
+impl Progress {
+    pub fn spinner() -> Self {
+        if is_plain() || is_verbose() {
+            return Progress::Lines;
+        }
+        let pb = ProgressBar::new_spinner();
+        pb.set_style(
+            ProgressStyle::default_spinner()
+                .template("  {spinner:.green} {msg}")
+                .unwrap()
+                .tick_chars("⠋⠙⠹⠸⠼⠴⠦⠧⠇⠏"),
+        );
+        pb.enable_steady_tick(Duration::from_millis(80));
+        Progress::Spinner(pb)
+    }
+
+    /// Prints nothing, for renders the user doesn't watch
+    pub fn hidden() -> Self {
+        Progress::Hidden
+    }
+
+    pub fn set_message(&self, message: impl Into<Cow<'static, str>>) {
+        match self {
+            Progress::Spinner(pb) => pb.set_message(message),
+            Progress::Lines => println!("  {}", message.into()),
+            Progress::Hidden => {}
+        }
+    }
+
+    pub fn inc(&self, delta: u64) {
+        if let Progress::Spinner(pb) = self {
+            pb.inc(delta);
+        }
+    }
+
+    pub fn finish_and_clear(&self) {
+        if let Progress::Spinner(pb) = self {
+            pb.finish_and_clear();
+        }
+    }
+}
@@ -0,0 +1,797 @@
+use anyhow::{Context, Result};
+use futures::stream::{self, TryStreamExt};
+use serde_json::Value;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet};
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Mutex;
+
+use crate::utils::conflict::{self, Resolution};
+use crate::utils::fs::{self, set_executable, to_slash};
+
+/// Writes in flight at once when a plan is flushed; enough to keep a slow disk busy
+/// without running into open-file limits
+const CONCURRENT_WRITES: usize = 32;
+
+/// A change to the project, described instead of performed: [`apply`] works out the
+/// file content from what earlier operations left, and the engine takes care of dry runs,
+/// rollback, conflict checks, and manifest hashes for every scaffolder
+pub enum Op {
+    /// Write a template's `content`, resolving a clash with an existing file per the
+    /// [`conflict`] mode
+    WriteFile {
+        path: PathBuf,
+        content: Content,
+        executable: bool,
+    },
+    /// Replace `path` with `content` worked out from what the file holds, e.g. a router
+    /// registered in root.ts; applied in every conflict mode, like the edits below, since
+    /// it keeps the user's changes
+    EditFile { path: PathBuf, content: Content },
+    /// Merge `value` into the JSON object in `path`, recursing into objects both define;
+    /// `overwrite` decides whether `value` or the file wins where both set a key
+    MergeJson {
+        path: PathBuf,
+        value: Value,
+        overwrite: bool,
+    },
+    /// Append the `model` and `enum` blocks of `blocks` the Prisma schema doesn't define yet
+    PatchPrisma { path: PathBuf, blocks: String },
+    /// Add a dependency to a package.json, keeping a version that is already there
+    AddDependency {
+        path: PathBuf,
+        name: String,
+        version: String,
+        dev: bool,
+    },
+}
+
+/// What a write puts in a file. Embedded templates are borrowed from the binary, and files
+/// from the template cache or an override directory are streamed when the plan is
+/// flushed, so large assets (fonts, sample documents) are never copied into memory first.
+#[derive(Clone, Debug)]
+pub enum Content {
+    Bytes(Cow<'static, [u8]>),
+    /// The content of this file on disk
+    File(PathBuf),
+}
+
+impl Content {
+    /// The bytes, read from disk for [`Content::File`]
+    pub fn load(&self) -> io::Result<Cow<'_, [u8]>> {
+        match self {
+            Content::Bytes(bytes) => Ok(Cow::Borrowed(bytes)),
+            Content::File(source) => std::fs::read(source).map(Cow::Owned),
+        }
+    }
+
+    /// Write to `path` on disk, copying files in chunks
+    pub fn write_to(&self, path: &Path) -> io::Result<()> {
+        match self {
+            Content::Bytes(bytes) => std::fs::write(path, bytes),
+            Content::File(source) => {
+                // Not fs::copy: it would carry over the source's permissions, e.g. read-only
+                // files from a Nix store
+                let mut reader = std::fs::File::open(source)?;
+                io::copy(&mut reader, &mut std::fs::File::create(path)?).map(|_| ())
+            }
+        }
+    }
+
+    async fn write_to_async(&self, path: &Path) -> io::Result<()> {
+        match self {
+            Content::Bytes(bytes) => tokio::fs::write(path, bytes).await,
+            Content::File(source) => {
+                let mut reader = tokio::fs::File::open(source).await?;
+                let mut writer = tokio::fs::File::create(path).await?;
+                tokio::io::copy(&mut reader, &mut writer).await.map(|_| ())
+            }
+        }
+    }
+}
+
+impl From<Vec<u8>> for Content {
+    fn from(bytes: Vec<u8>) -> Self {
+        Content::Bytes(Cow::Owned(bytes))
+    }
+}
+
+impl From<String> for Content {
+    fn from(text: String) -> Self {
+        Content::Bytes(Cow::Owned(text.into_bytes()))
+    }
+}
+
+impl From<Cow<'static, [u8]>> for Content {
+    fn from(bytes: Cow<'static, [u8]>) -> Self {
+        Content::Bytes(bytes)
+    }
+}
+
+/// What an operation did to a file, for dry runs
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Kind {
+    Write,
+    Edit,
+    Merge,
+    PatchPrisma,
+    Dependency(String),
+    Remove,
+}
+
+/// What a plan does to a file when it is flushed
+enum Change {
+    Write { content: Content, executable: bool },
+    Remove,
+}
+
+/// A file one step wrote and a later step replaced without reading it first, so whatever
+/// the first step put there is lost
+#[derive(Clone, Debug)]
+pub struct Clobber {
+    pub path: PathBuf,
+    pub written_by: &'static str,
+    pub replaced_by: &'static str,
+}
+
+#[derive(Default)]
+struct State {
+    changes: BTreeMap<PathBuf, Change>,
+    /// Directories to create even if no file ends up in them
+    dirs: BTreeSet<PathBuf>,
+    /// Operations in the order they were applied, with the step that applied them
+    log: Vec<(PathBuf, &'static str, Kind)>,
+    /// Step that last wrote each path, and whether anything read it since
+    writers: BTreeMap<PathBuf, (&'static str, bool)>,
+    clobbers: Vec<Clobber>,
+    step: &'static str,
+}
+
+/// Changes collected while a plan is active; `None` when writes go straight to disk
+static PLAN: Mutex<Option<State>> = Mutex::new(None);
+
+/// An active write plan: until it is dropped, writes through `utils::fs` are collected in
+/// memory instead of going to disk, and reads through it see them
+pub struct Plan(());
+
+/// Start collecting writes. Changes that are never executed are discarded, which is all a
+/// dry run has to do.
+pub fn begin() -> Plan {
+    *PLAN.lock().unwrap_or_else(|e| e.into_inner()) = Some(State::default());
+    Plan(())
+}
+
+impl Plan {
+    /// Apply the collected changes and stop collecting
+    pub async fn execute(self) -> Result<usize> {
+        flush().await
+    }
+
+    /// Call `f` with the `/`-separated path relative to `root` and the content of each
+    /// file the plan writes there
+    pub fn each_file(
+        &self,
+        root: &Path,
+        mut f: impl FnMut(String, &Content) -> Result<()>,
+    ) -> Result<()> {
+        let root = normalize(root);
+        let plan = PLAN.lock().unwrap_or_else(|e| e.into_inner());
+        for (path, change) in plan.iter().flat_map(|state| &state.changes) {
+            if let (Change::Write { content, .. }, Ok(relative)) =
+                (change, path.strip_prefix(&root))
+            {
+                f(to_slash(&relative.to_string_lossy()), content)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// What the steps did to each path, e.g. `ui: write` or `ai: +3 dependencies`
+    pub fn operations(&self) -> BTreeMap<PathBuf, Vec<String>> {
+        with_state(|state| {
+            let mut operations: BTreeMap<PathBuf, Vec<(&'static str, Kind, usize)>> =
+                BTreeMap::new();
+            for (path, step, kind) in &state.log {
+                let entries = operations.entry(path.clone()).or_default();
+                match entries.last_mut() {
+                    // A step adding several dependencies reads as one operation
+                    Some((last_step, Kind::Dependency(_), count))
+                        if last_step == step && matches!(kind, Kind::Dependency(_)) =>
+                    {
+                        *count += 1
+                    }
+                    Some((last_step, last_kind, _)) if last_step == step && last_kind == kind => {}
+                    _ => entries.push((step, kind.clone(), 1)),
+                }
+            }
+            operations
+                .into_iter()
+                .map(|(path, entries)| {
+                    let described = entries
+                        .into_iter()
+                        .map(|(step, kind, count)| describe(step, &kind, count))
+                        .collect();
+                    (path, described)
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+    }
+
+    /// Files a step replaced without reading what an earlier step wrote there
+    pub fn clobbers(&self) -> Vec<Clobber> {
+        with_state(|state| state.clobbers.clone()).unwrap_or_default()
+    }
+}
+
+impl Drop for Plan {
+    fn drop(&mut self) {
+        *PLAN.lock().unwrap_or_else(|e| e.into_inner()) = None;
+    }
+}
+
+fn describe(step: &str, kind: &Kind, count: usize) -> String {
+    let what = match kind {
+        Kind::Write => "write".to_string(),
+        Kind::Edit => "edit".to_string(),
+        Kind::Merge => "merge".to_string(),
+        Kind::PatchPrisma => "add models".to_string(),
+        Kind::Dependency(name) if count == 1 => format!("+{}", name),
+        Kind::Dependency(_) => format!("+{} dependencies", count),
+        Kind::Remove => "remove".to_string(),
+    };
+    if step.is_empty() {
+        what
+    } else {
+        format!("{}: {}", step, what)
+    }
+}
+
+/// Whether writes are being collected
+pub fn is_active() -> bool {
+    PLAN.lock().unwrap_or_else(|e| e.into_inner()).is_some()
+}
+
+fn with_state<T>(f: impl FnOnce(&mut State) -> T) -> Option<T> {
+    PLAN.lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .as_mut()
+        .map(f)
+}
+
+/// Name the step whose operations follow, for dry runs and clobber reports
+pub fn step(name: &'static str) {
+    with_state(|state| state.step = name);
+}
+
+/// Work out what `op` does to its file and write it: queued when a plan is active,
+/// straight to disk otherwise. Only template writes go through [`conflict::resolve`]; edits
+/// build on whatever the file holds, so each one sees the changes before it.
+pub fn apply(op: Op) -> Result<()> {
+    let (path, content, executable, kind) = match op {
+        Op::WriteFile {
+            path,
+            content,
+            executable,
+        } => {
+            let path = match conflict::resolve(&path, &content)? {
+                Resolution::Write(target) => target,
+                Resolution::Skip => return Ok(()),
+            };
+            (path, content, executable, Kind::Write)
+        }
+        Op::EditFile { path, content } => (path, content, false, Kind::Edit),
+        Op::MergeJson {
+            path,
+            value,
+            overwrite,
+        } => {
+            let mut json = read_json(&path)?;
+            merge_json(&mut json, value, overwrite);
+            (
+                path,
+                serde_json::to_string_pretty(&json)?.into(),
+                false,
+                Kind::Merge,
+            )
+        }
+        Op::PatchPrisma { path, blocks } => {
+            let schema = fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read {}", path.display()))?;
+            let patched = patch_prisma(&schema, &blocks);
+            if patched == schema {
+                return Ok(());
+            }
+            (path, patched.into(), false, Kind::PatchPrisma)
+        }
+        Op::AddDependency {
+            path,
+            name,
+            version,
+            dev,
+        } => {
+            let mut json = read_json(&path)?;
+            let object = json
+                .as_object_mut()
+                .with_context(|| format!("{} is not a JSON object", path.display()))?;
+            for section in ["dependencies", "devDependencies"] {
+                if !object.get(section).is_some_and(Value::is_object) {
+                    object.insert(section.to_string(), Value::Object(Default::default()));
+                }
+            }
+            let section = if dev {
+                "devDependencies"
+            } else {
+                "dependencies"
+            };
+            if let Some(dependencies) = object.get_mut(section).and_then(Value::as_object_mut) {
+                dependencies
+                    .entry(name.clone())
+                    .or_insert(Value::String(version));
+            }
+            (
+                path,
+                serde_json::to_string_pretty(&json)?.into(),
+                false,
+                Kind::Dependency(name),
+            )
+        }
+    };
+
+    if is_active() {
+        record(
+            &path,
+            Change::Write {
+                content,
+                executable,
+            },
+            kind,
+        );
+        return Ok(());
+    }
+    fs::write_content(&path, content, executable)
+        .with_context(|| format!("Failed to write file: {}", path.display()))
+}
+
+/// The JSON in `path`, or an empty object if it hasn't been written yet
+fn read_json(path: &Path) -> Result<Value> {
+    if !fs::path_exists(path) {
+        return Ok(Value::Object(Default::default()));
+    }
+    let content =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    serde_json::from_str(&content).with_context(|| format!("{} is not valid JSON", path.display()))
+}
+
+fn merge_json(base: &mut Value, value: Value, overwrite: bool) {
+    let (Some(base), Value::Object(entries)) = (base.as_object_mut(), value) else {
+        return;
+    };
+    for (key, value) in entries {
+        match base.get_mut(&key) {
+            Some(existing) if existing.is_object() && value.is_object() => {
+                merge_json(existing, value, overwrite)
+            }
+            Some(existing) if overwrite => *existing = value,
+            Some(_) => {}
+            None => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+/// `schema` with the blocks of `blocks` appended whose `model`/`enum` name it doesn't
+/// define yet, so applying the same models twice changes nothing. Blank lines and
+/// comments before a block go with it.
+fn patch_prisma(schema: &str, blocks: &str) -> String {
+    let defined: BTreeSet<&str> = schema.lines().filter_map(block_name).collect();
+    let mut patched = schema.to_string();
+    let mut appended = false;
+    let mut chunk = String::new();
+    let mut name = None;
+    for line in blocks.split_inclusive('\n') {
+        chunk.push_str(line);
+        name = name.or_else(|| block_name(line));
+        if let Some(declared) = name.filter(|_| line.trim_end() == "}") {
+            if !defined.contains(declared) {
+                patched.push_str(&chunk);
+                appended = true;
+            }
+            chunk.clear();
+            name = None;
+        }
+    }
+    if name.is_some_and(|declared| !defined.contains(declared)) || (appended && name.is_none()) {
+        patched.push_str(&chunk);
+    }
+    patched
+}
+
+/// The name `model X {` or `enum X {` declares
+fn block_name(line: &str) -> Option<&str> {
+    let mut words = line.split_whitespace();
+    match (words.next(), words.next(), words.next()) {
+        (Some("model" | "enum"), Some(name), Some("{")) => Some(name),
+        _ => None,
+    }
+}
+
+/// Apply the changes collected so far and keep collecting. Files are written
+/// concurrently; if any write fails, the files already written are restored to what
+/// they were before and new files and directories removed again. Returns how many
+/// files were written.
+pub async fn flush() -> Result<usize> {
+    let (changes, dirs) = match with_state(|state| {
+        (
+            std::mem::take(&mut state.changes),
+            std::mem::take(&mut state.dirs),
+        )
+    }) {
+        Some(taken) => taken,
+        None => return Ok(0),
+    };
+
+    let mut undo = Undo::default();
+    match write_changes(changes, dirs, &mut undo).await {
+        Ok(count) => Ok(count),
+        Err(err) => {
+            undo.rollback();
+            Err(err)
+        }
+    }
+}
+
+/// What a flush changed, to put back if it fails halfway
+#[derive(Default)]
+struct Undo {
+    /// Files with their content before the flush; `None` for files it created
+    files: Vec<(PathBuf, Option<Vec<u8>>)>,
+    /// Directories the flush created, parents first
+    dirs: Vec<PathBuf>,
+}
+
+impl Undo {
+    fn rollback(self) {
+        for (path, original) in self.files {
+            let _ = match original {
+                Some(content) => std::fs::write(&path, content),
+                None => std::fs::remove_file(&path),
+            };
+        }
+        for dir in self.dirs.iter().rev() {
+            let _ = std::fs::remove_dir(dir);
+        }
+    }
+}
+
+async fn write_changes(
+    changes: BTreeMap<PathBuf, Change>,
+    dirs: BTreeSet<PathBuf>,
+    undo: &mut Undo,
+) -> Result<usize> {
+    for path in changes.keys() {
+        undo.files.push((path.clone(), std::fs::read(path).ok()));
+    }
+
+    let mut writes = Vec::new();
+    for (path, change) in changes {
+        match change {
+            Change::Write {
+                content,
+                executable,
+            } => writes.push((path, content, executable)),
+            Change::Remove => match std::fs::remove_file(&path) {
+                Err(err) if err.kind() != std::io::ErrorKind::NotFound => {
+                    return Err(err)
+                        .with_context(|| format!("Failed to remove {}", path.display()));
+                }
+                _ => {}
+            },
+        }
+    }
+
+    let parents = writes
+        .iter()
+        .filter_map(|(path, _, _)| path.parent().map(Path::to_path_buf));
+    let dirs: BTreeSet<PathBuf> = dirs
+        .into_iter()
+        .chain(parents)
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .collect();
+    for dir in &dirs {
+        let mut missing = Vec::new();
+        let mut ancestor = Some(dir.as_path());
+        while let Some(path) =
+            ancestor.filter(|path| !path.as_os_str().is_empty() && !path.exists())
+        {
+            missing.push(path.to_path_buf());
+            ancestor = path.parent();
+        }
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create directory: {}", dir.display()))?;
+        undo.dirs.extend(missing.into_iter().rev());
+    }
+
+    let count = writes.len();
+    stream::iter(writes.into_iter().map(Ok::<_, anyhow::Error>))
+        .try_for_each_concurrent(
+            CONCURRENT_WRITES,
+            |(path, content, executable)| async move {
+                content
+                    .write_to_async(&path)
+                    .await
+                    .with_context(|| format!("Failed to write file: {}", path.display()))?;
+                if executable {
+                    set_executable(&path)?;
+                }
+                Ok(())
+            },
+        )
+        .await?;
+
+    Ok(count)
+}
+
+/// Queue a write in the active plan (see [`is_active`])
+pub fn record_write(path: &Path, content: Content, executable: bool) {
+    record(
+        path,
+        Change::Write {
+            content,
+            executable,
+        },
+        Kind::Write,
+    );
+}
+
+/// Queue a removal; false when no plan is active
+pub fn record_remove(path: &Path) -> bool {
+    record(path, Change::Remove, Kind::Remove)
+}
+
+/// Queue a directory; false when no plan is active
+pub fn record_dir(path: &Path) -> bool {
+    with_state(|state| state.dirs.insert(normalize(path))).is_some()
+}
+
+fn record(path: &Path, change: Change, kind: Kind) -> bool {
+    let path = normalize(path);
+    with_state(|state| {
+        let step = state.step;
+        match &change {
+            Change::Write { .. } => {
+                let previous = state.writers.insert(path.clone(), (step, false));
+                if let Some((written_by, read)) = previous {
+                    // Edits read the file first; a plain write over another step's file drops it
+                    if kind == Kind::Write && !read && written_by != step {
+                        state.clobbers.push(Clobber {
+                            path: path.clone(),
+                            written_by,
+                            replaced_by: step,
+                        });
+                    }
+                }
+            }
+            Change::Remove => {
+                state.writers.remove(&path);
+            }
+        }
+        state.log.push((path.clone(), step, kind));
+        state.changes.insert(path, change);
+    })
+    .is_some()
+}
+
+/// The pending content of `path`: `Some(None)` when it is queued for removal, `None` when
+/// the plan doesn't touch it (or no plan is active)
+pub fn pending(path: &Path) -> Option<Option<Content>> {
+    let plan = PLAN.lock().unwrap_or_else(|e| e.into_inner());
+    match plan.as_ref()?.changes.get(&normalize(path))? {
+        Change::Write { content, .. } => Some(Some(content.clone())),
+        Change::Remove => Some(None),
+    }
+}
+
+/// [`pending`], noting that the current step has seen what earlier steps wrote
+pub fn read(path: &Path) -> Option<Option<Content>> {
+    let path = normalize(path);
+    with_state(|state| {
+        if let Some((_, read)) = state.writers.get_mut(&path) {
+            *read = true;
+        }
+    });
+    pending(&path)
+}
+
+/// Whether a queued write or directory lies under directory `dir`
+pub fn pending_under(dir: &Path) -> bool {
+    let dir = normalize(dir);
+    let plan = PLAN.lock().unwrap_or_else(|e| e.into_inner());
+    plan.as_ref().is_some_and(|state| {
+        state.dirs.iter().any(|queued| queued.starts_with(&dir))
+            || state
+                .changes
+                .range(dir.clone()..)
+                .take_while(|(path, _)| path.starts_with(&dir))
+                .any(|(path, change)| path != &dir && matches!(change, Change::Write { .. }))
+    })
+}
+
+/// Files directly in `dir` that the plan writes and removes
+pub fn pending_in(dir: &Path) -> (Vec<PathBuf>, Vec<PathBuf>) {
+    let dir = normalize(dir);
+    let plan = PLAN.lock().unwrap_or_else(|e| e.into_inner());
+    let (mut written, mut removed) = (Vec::new(), Vec::new());
+    let changes = plan.iter().flat_map(|state| &state.changes);
+    for (path, change) in changes.filter(|(path, _)| path.parent() == Some(dir.as_path())) {
+        match change {
+            Change::Write { .. } => written.push(path.clone()),
+            Change::Remove => removed.push(path.clone()),
+        }
+    }
+    (written, removed)
+}
+
+/// `./app/src/../x` and `app/x` name the same file; only `.` and `..` are resolved, since
+/// the file may not exist yet
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir if normalized.file_name().is_some() => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Plans are process-wide, so tests that begin one take turns
+    static PLAN_LOCK: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
+    /// An empty scratch directory, removed when dropped
+    struct Scratch(PathBuf);
+
+    impl Scratch {
+        fn new(name: &str) -> Self {
+            let dir =
+                std::env::temp_dir().join(format!("t3-mono-plan-{}-{}", name, std::process::id()));
+            let _ = std::fs::remove_dir_all(&dir);
+            std::fs::create_dir_all(&dir).unwrap();
+            Scratch(dir)
+        }
+    }
+
+    impl Drop for Scratch {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn write(path: PathBuf, content: &str) -> Op {
+        Op::WriteFile {
+            path,
+            content: content.to_string().into(),
+            executable: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn flushes_every_file_concurrently() {
+        let _lock = PLAN_LOCK.lock().await;
+        let scratch = Scratch::new("flush");
+        let plan = begin();
+        for i in 0..CONCURRENT_WRITES * 3 {
+            apply(write(
+                scratch.0.join(format!("dir-{}/file-{}.txt", i % 5, i)),
+                &i.to_string(),
+            ))
+            .unwrap();
+        }
+
+        assert_eq!(plan.execute().await.unwrap(), CONCURRENT_WRITES * 3);
+        for i in 0..CONCURRENT_WRITES * 3 {
+            let path = scratch.0.join(format!("dir-{}/file-{}.txt", i % 5, i));
+            assert_eq!(std::fs::read_to_string(path).unwrap(), i.to_string());
+        }
+    }
+
+    #[tokio::test]
+    async fn failed_flush_restores_written_files_and_removes_created_ones() {
+        let _lock = PLAN_LOCK.lock().await;
+        let scratch = Scratch::new("rollback");
+        let existing = scratch.0.join("existing.txt");
+        std::fs::write(&existing, "before").unwrap();
+        // Writing a file over a directory fails once the other writes are under way
+        std::fs::create_dir(scratch.0.join("blocked")).unwrap();
+
+        let plan = begin();
+        apply(write(existing.clone(), "after")).unwrap();
+        apply(write(scratch.0.join("created/nested/new.txt"), "new")).unwrap();
+        for i in 0..CONCURRENT_WRITES {
+            apply(write(
+                scratch.0.join(format!("created/file-{}.txt", i)),
+                "new",
+            ))
+            .unwrap();
+        }
+        apply(write(scratch.0.join("blocked"), "file")).unwrap();
+
+        assert!(plan.execute().await.is_err());
+        assert_eq!(std::fs::read_to_string(&existing).unwrap(), "before");
+        assert!(!scratch.0.join("created").exists());
+        assert!(scratch.0.join("blocked").is_dir());
+    }
+
+    #[tokio::test]
+    async fn dry_run_touches_nothing() {
+        let _lock = PLAN_LOCK.lock().await;
+        let scratch = Scratch::new("dry-run");
+        let package = scratch.0.join("package.json");
+        std::fs::write(&package, "{\n  \"name\": \"app\"\n}").unwrap();
+
+        let plan = begin();
+        apply(write(scratch.0.join("src/new.ts"), "export {};\n")).unwrap();
+        apply(Op::MergeJson {
+            path: package.clone(),
+            value: serde_json::json!({ "private": true }),
+            overwrite: true,
+        })
+        .unwrap();
+        apply(Op::AddDependency {
+            path: package.clone(),
+            name: "zod".into(),
+            version: "^4.0.0".into(),
+            dev: false,
+        })
+        .unwrap();
+        assert!(record_remove(&package));
+        assert!(record_dir(&scratch.0.join("public")));
+        assert_eq!(plan.operations().len(), 2);
+        drop(plan);
+
+        assert_eq!(
+            std::fs::read_to_string(&package).unwrap(),
+            "{\n  \"name\": \"app\"\n}"
+        );
+        assert!(!scratch.0.join("src").exists());
+        assert!(!scratch.0.join("public").exists());
+    }
+
+    #[tokio::test]
+    async fn reports_writes_over_another_steps_unread_file() {
+        let _lock = PLAN_LOCK.lock().await;
+        let scratch = Scratch::new("clobber");
+        let plan = begin();
+
+        step("first");
+        apply(write(scratch.0.join("clobbered.ts"), "first")).unwrap();
+        apply(write(scratch.0.join("read.ts"), "first")).unwrap();
+        apply(write(scratch.0.join("edited.ts"), "first")).unwrap();
+        step("second");
+        apply(write(scratch.0.join("clobbered.ts"), "second")).unwrap();
+        assert!(read(&scratch.0.join("read.ts")).is_some());
+        apply(write(scratch.0.join("read.ts"), "second")).unwrap();
+        apply(Op::EditFile {
+            path: scratch.0.join("edited.ts"),
+            content: "second".to_string().into(),
+        })
+        .unwrap();
+
+        let clobbers = plan.clobbers();
+        assert_eq!(clobbers.len(), 1);
+        assert_eq!(clobbers[0].path, normalize(&scratch.0.join("clobbered.ts")));
+        assert_eq!(
+            (clobbers[0].written_by, clobbers[0].replaced_by),
+            ("first", "second")
+        );
+    }
+}
@@ -0,0 +1,174 @@
+use anyhow::{Context, Result};
+use futures::stream::{self, StreamExt};
+use reqwest::Client;
+use semver::{Version, VersionReq};
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+
+use crate::templates::remote;
+use crate::utils::fs::update_file;
+use crate::utils::npm;
+
+pub const DEFAULT_REGISTRY: &str = "https://registry.npmjs.org";
+
+/// Registry lookups in flight at once
+const CONCURRENCY: usize = 8;
+
+/// How `--latest` resolves versions
+#[derive(Clone, Debug)]
+pub struct LatestSettings {
+    pub registry: String,
+    /// Package name to the range it may move within, replacing the range in package.json
+    pub ranges: BTreeMap<String, String>,
+}
+
+/// One dependency moved to a newer version
+pub struct Upgrade {
+    pub name: String,
+    pub from: String,
+    pub to: String,
+}
+
+#[derive(Default)]
+pub struct Resolution {
+    pub upgrades: Vec<Upgrade>,
+    /// Packages whose versions couldn't be looked up; they keep the frozen version
+    pub failed: Vec<String>,
+}
+
+/// Rewrite the dependencies in `<package_dir>/package.json` to the newest published
+/// versions their ranges allow, keeping each range's `^`/`~`/exact style
+pub async fn resolve_latest(package_dir: &str, settings: &LatestSettings) -> Result<Resolution> {
+    let mut pkg = npm::read_package_json(package_dir)?;
+    let client = remote::client()?;
+
+    let mut wanted: BTreeMap<String, String> = BTreeMap::new();
+    for section in ["dependencies", "devDependencies"] {
+        for (name, spec) in pkg[section].as_object().into_iter().flatten() {
+            if let Some(spec) = spec.as_str() {
+                wanted.insert(name.clone(), spec.to_string());
+            }
+        }
+    }
+
+    let registry = settings.registry.trim_end_matches('/');
+    let lookups: Vec<(String, Result<Vec<Version>>)> = stream::iter(wanted.keys().cloned())
+        .map(|name| {
+            let client = &client;
+            async move {
+                let versions = published_versions(client, registry, &name).await;
+                (name, versions)
+            }
+        })
+        .buffer_unordered(CONCURRENCY)
+        .collect()
+        .await;
+    let published: HashMap<String, Result<Vec<Version>>> = lookups.into_iter().collect();
+
+    let mut resolution = Resolution::default();
+    let mut resolved: BTreeMap<String, String> = BTreeMap::new();
+    for (name, spec) in &wanted {
+        let range = settings.ranges.get(name).unwrap_or(spec);
+        let Some(req) = to_version_req(range) else {
+            continue;
+        };
+        let versions = match published.get(name) {
+            Some(Ok(versions)) => versions,
+            _ => {
+                resolution.failed.push(name.clone());
+                continue;
+            }
+        };
+        let Some(newest) = versions.iter().filter(|version| req.matches(version)).max() else {
+            continue;
+        };
+
+        let prefix = match spec.chars().next() {
+            Some(c @ ('^' | '~')) => c.to_string(),
+            _ => String::new(),
+        };
+        let to = format!("{}{}", prefix, newest);
+        if &to != spec {
+            resolved.insert(name.clone(), to.clone());
+            resolution.upgrades.push(Upgrade {
+                name: name.clone(),
+                from: spec.clone(),
+                to,
+            });
+        }
+    }
+
+    if !resolved.is_empty() {
+        for section in ["dependencies", "devDependencies"] {
+            if let Some(deps) = pkg[section].as_object_mut() {
+                for (name, spec) in deps.iter_mut() {
+                    if let Some(to) = resolved.get(name) {
+                        *spec = serde_json::Value::String(to.clone());
+                    }
+                }
+            }
+        }
+        let content = serde_json::to_string_pretty(&pkg)?;
+        update_file(package_dir, "package.json", &content)?;
+    }
+
+    Ok(resolution)
+}
+
+/// Every non-deprecated version published for `name`
+async fn published_versions(client: &Client, registry: &str, name: &str) -> Result<Vec<Version>> {
+    #[derive(Deserialize)]
+    struct Packument {
+        versions: HashMap<String, PublishedVersion>,
+    }
+
+    #[derive(Deserialize)]
+    struct PublishedVersion {
+        deprecated: Option<serde_json::Value>,
+    }
+
+    // Scoped names keep the `@` but escape the slash
+    let url = format!("{}/{}", registry, name.replace('/', "%2f"));
+    let packument: Packument = client
+        .get(&url)
+        // Abbreviated metadata: versions without readmes
+        .header("Accept", "application/vnd.npm.install-v1+json")
+        .send()
+        .await
+        .map_err(|e| e.without_url())
+        .with_context(|| format!("Failed to fetch {}", url))?
+        .error_for_status()?
+        .json()
+        .await
+        .with_context(|| format!("Unexpected registry response for {}", name))?;
+
+    Ok(packument
+        .versions
+        .into_iter()
+        .filter(|(_, published)| published.deprecated.is_none())
+        .filter_map(|(version, _)| Version::parse(&version).ok())
+        .collect())
+}
+
+/// npm range to a semver requirement: a bare version is an exact pin, and
+/// space-separated comparators (`>=7 <8`) must all match. Tags, URLs, `||`
+/// unions, and workspace specs aren't resolved.
+pub fn to_version_req(range: &str) -> Option<VersionReq> {
+    let range = range.trim();
+    if range.contains("||") || range.contains(':') || range.contains('/') {
+        return None;
+    }
+    if Version::parse(range).is_ok() {
+        return VersionReq::parse(&format!("={}", range)).ok();
+    }
+
+    // `>= 7` is one comparator, `>=7 <8` two
+    let mut comparators: Vec<String> = Vec::new();
+    for token in range.split_whitespace() {
+        match comparators.last_mut() {
+            Some(last) if last.chars().all(|c| "<>=~^".contains(c)) => last.push_str(token),
+            _ => comparators.push(token.to_string()),
+        }
+    }
+    VersionReq::parse(&comparators.join(", ")).ok()
+}
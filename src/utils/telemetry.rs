@@ -0,0 +1,208 @@
+use anyhow::{Context, Result};
+use console::{style, Term};
+use dialoguer::Confirm;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use std::io::IsTerminal;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::cli::{Command, ConfigCommand, GenerateCommand, RestateCommand};
+use crate::scaffolding::manifest;
+
+const SEND_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Where events go. There is no collector by default: telemetry stays off, and nobody is
+/// asked, until a build sets this at compile time or the environment sets it at run time.
+pub const ENDPOINT_ENV: &str = "T3_MONO_TELEMETRY_ENDPOINT";
+
+/// Set to `0`/`false` to turn telemetry off regardless of the saved choice
+pub const TELEMETRY_ENV: &str = "T3_MONO_TELEMETRY";
+
+/// Saved choice; the file existing means the user has been asked
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct State {
+    pub enabled: bool,
+    /// Random id that groups events from one machine, never tied to a person
+    pub anonymous_id: String,
+}
+
+/// One command run; flag names only, never their values, paths, or project names
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Event<'a> {
+    anonymous_id: &'a str,
+    version: &'a str,
+    os: &'a str,
+    arch: &'a str,
+    command: &'a str,
+    flags: &'a [String],
+    success: bool,
+    duration_ms: u128,
+}
+
+pub fn state_path() -> Result<PathBuf> {
+    Ok(dirs::config_dir()
+        .context("Could not determine config directory")?
+        .join("t3-mono")
+        .join("telemetry.json"))
+}
+
+pub fn read_state() -> Option<State> {
+    let content = std::fs::read_to_string(state_path().ok()?).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+pub fn write_state(enabled: bool) -> Result<State> {
+    let state = State {
+        enabled,
+        anonymous_id: read_state()
+            .map(|state| state.anonymous_id)
+            .unwrap_or_else(new_id),
+    };
+    let path = state_path()?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&path, serde_json::to_string_pretty(&state)?)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(state)
+}
+
+/// The collector events are sent to, if any ([`ENDPOINT_ENV`])
+pub fn endpoint() -> Option<String> {
+    std::env::var(ENDPOINT_ENV)
+        .ok()
+        .or_else(|| option_env!("T3_MONO_TELEMETRY_ENDPOINT").map(str::to_string))
+        .filter(|url| !url.is_empty())
+}
+
+/// Why telemetry is off for this process even if it was enabled
+pub fn env_override() -> Option<&'static str> {
+    let set = |name: &str| std::env::var(name).ok().filter(|value| !value.is_empty());
+    if set(TELEMETRY_ENV).is_some_and(|value| value == "0" || value.eq_ignore_ascii_case("false")) {
+        return Some(TELEMETRY_ENV);
+    }
+    if set("DO_NOT_TRACK").is_some_and(|value| value != "0") {
+        return Some("DO_NOT_TRACK");
+    }
+    None
+}
+
+/// Ask once, on the first interactive run in a terminal; the answer defaults to no. Runs
+/// that answer everything up front (`--answers`, `add --yes`) pass `scripted` and are
+/// never stopped by the question.
+pub fn ask_consent_once(scripted: bool) {
+    let term = Term::stderr();
+    if scripted
+        || endpoint().is_none()
+        || read_state().is_some()
+        || env_override().is_some()
+        || std::env::var_os("CI").is_some()
+        || !term.is_term()
+        || !std::io::stdin().is_terminal()
+    {
+        return;
+    }
+
+    let _ = term.write_line("");
+    let _ = term.write_line(
+        "  t3-mono can send anonymous usage data: the command, flag names, success, and duration.",
+    );
+    let _ = term.write_line(&format!(
+        "  No project names, paths, or flag values. Change your mind any time with {}.",
+        style("t3-mono telemetry disable").cyan()
+    ));
+    let enabled = Confirm::new()
+        .with_prompt("  Share anonymous usage data?")
+        .default(false)
+        .interact_on(&term)
+        .unwrap_or(false);
+    let _ = write_state(enabled);
+    let _ = term.write_line("");
+}
+
+/// Send one event when the user opted in; failures are ignored
+pub async fn record(command: &str, flags: &[String], success: bool, duration: Duration) {
+    if env_override().is_some() {
+        return;
+    }
+    let Some(endpoint) = endpoint() else {
+        return;
+    };
+    let Some(state) = read_state().filter(|state| state.enabled) else {
+        return;
+    };
+
+    let event = Event {
+        anonymous_id: &state.anonymous_id,
+        version: env!("CARGO_PKG_VERSION"),
+        os: std::env::consts::OS,
+        arch: std::env::consts::ARCH,
+        command,
+        flags,
+        success,
+        duration_ms: duration.as_millis(),
+    };
+    let Ok(client) = Client::builder().timeout(SEND_TIMEOUT).build() else {
+        return;
+    };
+    let _ = client.post(endpoint).json(&event).send().await;
+}
+
+/// Subcommand path without user-supplied names, e.g. `add ui` or `generate resource`
+pub fn command_name(command: &Option<Command>) -> String {
+    match command {
+        None => "create".to_string(),
+        Some(Command::Add { extension, .. }) => format!("add {}", extension),
+        Some(Command::Generate { generator }) => {
+            let kind = match generator {
+                GenerateCommand::Resource { .. } => "resource",
+                GenerateCommand::Component { .. } => "component",
+                GenerateCommand::Agent { .. } => "agent",
+                GenerateCommand::Locale { .. } => "locale",
+                GenerateCommand::Page { .. } => "page",
+                GenerateCommand::Workflow { .. } => "workflow",
+            };
+            format!("generate {}", kind)
+        }
+        Some(Command::Status { .. }) => "status".to_string(),
+        Some(Command::Rename { .. }) => "rename".to_string(),
+        Some(Command::SwitchAuth { .. }) => "switch-auth".to_string(),
+        Some(Command::SwitchDb { .. }) => "switch-db".to_string(),
+        Some(Command::Envcheck { .. }) => "envcheck".to_string(),
+        Some(Command::Sync { .. }) => "sync".to_string(),
+        Some(Command::Config { action }) => match action {
+            ConfigCommand::Validate { .. } => "config validate".to_string(),
+            ConfigCommand::Schema { .. } => "config schema".to_string(),
+        },
+        Some(Command::Restate { action }) => match action {
+            RestateCommand::Register { .. } => "restate register".to_string(),
+            RestateCommand::Subscribe { .. } => "restate subscribe".to_string(),
+        },
+        Some(Command::Info { .. }) => "info".to_string(),
+        Some(Command::Telemetry { .. }) => "telemetry".to_string(),
+    }
+}
+
+/// Names of the flags on the command line, e.g. `--ui` from `--ui` and `--auth` from `--auth=next-auth`
+pub fn flag_names() -> Vec<String> {
+    let mut flags: Vec<String> = std::env::args()
+        .skip(1)
+        .filter(|arg| arg.starts_with('-') && arg != "-" && arg != "--")
+        .map(|arg| arg.split('=').next().unwrap_or_default().to_string())
+        .collect();
+    flags.sort();
+    flags.dedup();
+    flags
+}
+
+fn new_id() -> String {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or_default();
+    let seed = format!("{}-{}", nanos, std::process::id());
+    manifest::hash(seed.as_bytes())[..32].to_string()
+}
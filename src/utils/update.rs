@@ -0,0 +1,129 @@
+use console::{style, Term};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::task::JoinHandle;
+
+use crate::utils::fs::get_cache_dir;
+
+const LATEST_URL: &str = "https://registry.npmjs.org/t3-mono/latest";
+const CHECK_INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
+/// How long the end of a run may wait for a check that is still in flight
+const WAIT_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Set to any value to skip the check
+pub const NO_UPDATE_CHECK_ENV: &str = "T3_MONO_NO_UPDATE_CHECK";
+
+/// Result of the last check, cached so the registry is asked at most once a day
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UpdateCheck {
+    /// Seconds since the Unix epoch
+    checked_at: u64,
+    latest: Option<String>,
+}
+
+/// Start a background check of the latest published version when the cached one is
+/// older than a day
+pub fn spawn_check() -> Option<JoinHandle<()>> {
+    if !enabled() {
+        return None;
+    }
+    let cached = read_cache().unwrap_or_default();
+    if now().saturating_sub(cached.checked_at) < CHECK_INTERVAL.as_secs() {
+        return None;
+    }
+
+    Some(tokio::spawn(async move {
+        // Offline runs record the attempt too, so they aren't slowed down again for a day
+        let latest = fetch_latest().await.or(cached.latest);
+        let _ = write_cache(&UpdateCheck {
+            checked_at: now(),
+            latest,
+        });
+    }))
+}
+
+/// Print a one-line notice when a newer version is published
+pub async fn notify(check: Option<JoinHandle<()>>) {
+    if !enabled() {
+        return;
+    }
+    if let Some(check) = check {
+        let _ = tokio::time::timeout(WAIT_TIMEOUT, check).await;
+    }
+
+    let Some(latest) = read_cache().and_then(|cache| cache.latest) else {
+        return;
+    };
+    let current = env!("CARGO_PKG_VERSION");
+    if is_newer(&latest, current) {
+        eprintln!(
+            "  {} t3-mono {} is available (you have {}). Run {} to get the latest templates.",
+            style("Update:").yellow().bold(),
+            style(&latest).green(),
+            current,
+            style("npx t3-mono@latest").cyan()
+        );
+        eprintln!();
+    }
+}
+
+fn enabled() -> bool {
+    std::env::var_os(NO_UPDATE_CHECK_ENV).is_none()
+        && std::env::var_os("CI").is_none()
+        && Term::stderr().is_term()
+}
+
+async fn fetch_latest() -> Option<String> {
+    #[derive(Deserialize)]
+    struct Latest {
+        version: String,
+    }
+
+    let client = Client::builder().timeout(WAIT_TIMEOUT).build().ok()?;
+    let response = client
+        .get(LATEST_URL)
+        .send()
+        .await
+        .ok()?
+        .error_for_status()
+        .ok()?;
+    Some(response.json::<Latest>().await.ok()?.version)
+}
+
+/// Compare `major.minor.patch`, ignoring pre-release and build suffixes
+fn is_newer(latest: &str, current: &str) -> bool {
+    let parse = |version: &str| -> Option<(u64, u64, u64)> {
+        let core = version.trim_start_matches('v').split(['-', '+']).next()?;
+        let mut parts = core.split('.').map(|part| part.parse::<u64>().ok());
+        Some((parts.next()??, parts.next()??, parts.next()??))
+    };
+    match (parse(latest), parse(current)) {
+        (Some(latest), Some(current)) => latest > current,
+        _ => false,
+    }
+}
+
+fn cache_path() -> Option<PathBuf> {
+    get_cache_dir()
+        .ok()
+        .map(|dir| dir.join("update-check.json"))
+}
+
+fn read_cache() -> Option<UpdateCheck> {
+    let content = std::fs::read_to_string(cache_path()?).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+fn write_cache(check: &UpdateCheck) -> Option<()> {
+    std::fs::write(cache_path()?, serde_json::to_string_pretty(check).ok()?).ok()
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
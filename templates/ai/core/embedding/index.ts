@@ -14,7 +14,7 @@ import { MistralAIEmbeddings } from "@langchain/mistralai";
 import { GoogleGenerativeAIEmbeddings } from "@langchain/google-genai";
 import { CohereEmbeddings } from "@langchain/cohere";
 import { OllamaEmbeddings } from "@langchain/ollama";
-import { Embeddings } from "@langchain/core/embeddings";
+import type { Embeddings } from "@langchain/core/embeddings";
 
 export type EmbeddingProvider = "openai" | "mistral" | "google" | "cohere" | "ollama";
 
@@ -171,7 +171,7 @@ export class EmbeddingGenerator {
       throw new Error("Embeddings must have the same dimensions");
     }
 
-    const dotProduct = a.reduce((sum, val, i) => sum + val * b[i], 0);
+    const dotProduct = a.reduce((sum, val, i) => sum + val * (b[i] ?? 0), 0);
     const magnitudeA = Math.sqrt(a.reduce((sum, val) => sum + val * val, 0));
     const magnitudeB = Math.sqrt(b.reduce((sum, val) => sum + val * val, 0));
 
@@ -342,7 +342,7 @@ export class SemanticSearch {
 
     this.corpus = documents.map((doc, i) => ({
       text: doc.text,
-      embedding: result.embeddings[i],
+      embedding: result.embeddings[i] ?? [],
       metadata: doc.metadata,
     }));
   }
@@ -11,8 +11,7 @@
  *   LLMLOG=all         - Log to all destinations
  */
 
-import { BaseChatModel } from "@langchain/core/language_models/chat_models";
-import { BaseMessage } from "@langchain/core/messages";
+import type { BaseChatModel } from "@langchain/core/language_models/chat_models";
 import * as winston from "winston";
 import * as fs from "fs";
 import * as path from "path";
@@ -12,7 +12,7 @@ function processChildren(children: React.ReactNode): React.ReactNode {
       return [<React.Fragment key={`c-${ci}`}>{child}</React.Fragment>];
     }
     const segments = parseContent(child);
-    if (segments.length === 1 && segments[0].kind === "text") {
+    if (segments.length === 1 && segments[0]?.kind === "text") {
       return [<React.Fragment key={`t-${ci}`}>{child}</React.Fragment>];
     }
     return segments.map((seg, i) =>
@@ -42,7 +42,7 @@ export const MessageList = memo(function MessageList({
 
   const lastAssistantIndex = useMemo(() => {
     for (let i = messages.length - 1; i >= 0; i--) {
-      if (messages[i].role === "assistant") return i;
+      if (messages[i]?.role === "assistant") return i;
     }
     return -1;
   }, [messages]);
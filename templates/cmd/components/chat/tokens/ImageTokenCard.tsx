@@ -1,5 +1,6 @@
 "use client";
 
+import Image from "next/image";
 import { useEffect, useState } from "react";
 import { useTranslations } from "next-intl";
 import { trpc } from "@/lib/trpc";
@@ -61,11 +62,16 @@ export function ImageTokenCard({ imageId }: ImageTokenCardProps) {
         )}
       </div>
       {image.presignedUrl && (
-        <img
+        // The bucket is in images.remotePatterns (src/lib/image-hosts.js); the size only
+        // reserves space, so unknown dimensions fall back to a 4:3 box
+        <Image
           src={image.presignedUrl}
           alt={image.description || "Document image"}
+          width={image.width ?? 800}
+          height={image.height ?? 600}
+          sizes="(max-width: 768px) 100vw, 600px"
           className="max-w-full rounded border border-border"
-          style={{ maxHeight: 300 }}
+          style={{ maxHeight: 300, width: "auto", height: "auto" }}
         />
       )}
       {image.description && (
@@ -112,8 +112,9 @@ export function AITableAddColumn({ onAdd }: AITableAddColumnProps) {
       // Initialize default values for select fields
       const defaults: Record<string, string> = {};
       for (const field of preset.settings) {
-        if (field.type === "select" && field.options?.[0]) {
-          defaults[field.key] = field.options[0].value;
+        const firstOption = field.options?.[0];
+        if (field.type === "select" && firstOption) {
+          defaults[field.key] = firstOption.value;
         }
       }
       setSettingsValues(defaults);
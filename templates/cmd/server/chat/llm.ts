@@ -86,6 +86,33 @@ interface ChatResponse {
   toolCalls?: { name: string; args: Record<string, any> }[];
 }
 
+/** Callbacks for streaming a reply as it is generated */
+export interface ChatStreamHandlers {
+  onToken: (text: string) => void;
+  /** The streamed text ended in tool calls and isn't part of the final reply; discard it */
+  onReset?: () => void;
+}
+
+/** Text of a message or chunk; Anthropic returns content blocks when tools are bound */
+function textContent(content: unknown): string {
+  if (typeof content === "string") return content;
+  if (!Array.isArray(content)) return "";
+  return content
+    .map((block: any) => (block?.type === "text" || block?.type === "text_delta" ? block.text ?? "" : ""))
+    .join("");
+}
+
+/** Stream one model turn, forwarding text as it arrives, and return the merged message */
+async function streamTurn(model: any, messages: any[], stream: ChatStreamHandlers) {
+  let merged: any;
+  for await (const chunk of await model.stream(messages)) {
+    merged = merged ? merged.concat(chunk) : chunk;
+    const text = textContent(chunk.content);
+    if (text) stream.onToken(text);
+  }
+  return merged;
+}
+
 export async function chatWithTools(
   messages: ChatHistory[],
   submissionContext: string | null,
@@ -94,6 +121,7 @@ export async function chatWithTools(
   attachmentContext?: string,
   threadId?: string,
   regulationCollectionId?: string,
+  stream?: ChatStreamHandlers,
 ): Promise<ChatResponse> {
   const llm = createLLM({
     provider: "anthropic",
@@ -141,16 +169,15 @@ export async function chatWithTools(
     }
 
     const model = isLastIteration ? llm : (llm as any).bindTools(chatTools);
-    const result = await model.invoke(langchainMessages);
+    const result = stream
+      ? await streamTurn(model, langchainMessages, stream)
+      : await model.invoke(langchainMessages);
 
     const usage = result.usage_metadata ?? { input_tokens: 0, output_tokens: 0 };
     totalInputTokens += usage.input_tokens ?? 0;
     totalOutputTokens += usage.output_tokens ?? 0;
 
-    const content =
-      typeof result.content === "string"
-        ? result.content
-        : JSON.stringify(result.content);
+    const content = textContent(result.content);
 
     const toolCalls = (result as any).tool_calls || [];
 
@@ -168,6 +195,8 @@ export async function chatWithTools(
       };
     }
 
+    stream?.onReset?.();
+
     // Execute tool calls
     for (const call of toolCalls) {
       allToolCalls.push({ name: call.name, args: call.args });
@@ -0,0 +1,298 @@
+import { z } from "zod";
+import { TRPCError } from "@trpc/server";
+import { SystemMessage, HumanMessage, AIMessage } from "@langchain/core/messages";
+import { createLLM } from "@/components/ai/core/providers/index";
+import { buildSubmissionContext } from "@/server/chat/context-builder";
+import { chatWithTools, type ChatStreamHandlers } from "@/server/chat/llm";
+import type { db } from "@/server/db";
+
+const viewContextSchema = z.object({
+  submissionId: z.string().optional(),
+  regulationCollectionId: z.string().optional(),
+  findingId: z.string().optional(),
+  documentId: z.string().optional(),
+  chunkId: z.string().optional(),
+  checklistId: z.string().optional(),
+  documentTypeId: z.string().optional(),
+}).optional();
+
+export const sendMessageInput = z.object({
+  threadId: z.string(),
+  content: z.string().min(1),
+  viewContext: viewContextSchema,
+  attachmentIds: z.array(z.string()).optional(),
+});
+
+export type SendMessageInput = z.infer<typeof sendMessageInput>;
+
+/**
+ * Save the user message, run the tool-calling LLM, and save the reply. Shared by the
+ * `chat.sendMessage` procedure and the streaming transports, which pass `stream` to
+ * receive tokens as they are generated.
+ */
+export async function sendChatMessage(
+  prisma: typeof db,
+  userId: string,
+  input: SendMessageInput,
+  stream?: ChatStreamHandlers,
+) {
+  const thread = await prisma.chatThread.findFirst({
+    where: { id: input.threadId, userId },
+  });
+  if (!thread) throw new TRPCError({ code: "NOT_FOUND" });
+
+  // Build attachment metadata for user message
+  let messageMetadata: Record<string, any> | undefined;
+  if (input.attachmentIds && input.attachmentIds.length > 0) {
+    const attachments = await prisma.chatAttachment.findMany({
+      where: { id: { in: input.attachmentIds }, threadId: input.threadId },
+      select: { filename: true },
+    });
+    messageMetadata = { attachments: attachments.map((a) => ({ filename: a.filename })) };
+  }
+
+  // Save user message
+  const userMsg = await prisma.chatMessage.create({
+    data: {
+      threadId: input.threadId,
+      role: "user",
+      content: input.content,
+      metadata: messageMetadata,
+    },
+  });
+
+  // Build context — use viewContext.submissionId as fallback when thread has none
+  const effectiveSubmissionId = thread.submissionId || input.viewContext?.submissionId;
+
+  const submissionContext = effectiveSubmissionId
+    ? await buildSubmissionContext(effectiveSubmissionId)
+    : null;
+
+  // Bind the submission to the thread so future messages work without viewContext
+  if (!thread.submissionId && effectiveSubmissionId) {
+    await prisma.chatThread.update({
+      where: { id: input.threadId },
+      data: { submissionId: effectiveSubmissionId },
+    });
+  }
+
+  // Build focused context from viewContext
+  const focusedContext = await buildFocusedContext(prisma, input.viewContext);
+
+  // Build attachment context from completed attachments on this thread
+  const attachmentContext = await buildAttachmentContext(prisma, input.threadId);
+
+  // Fetch recent history for context window
+  const history = await prisma.chatMessage.findMany({
+    where: { threadId: input.threadId },
+    orderBy: { createdAt: "asc" },
+    take: 20,
+  });
+
+  const chatHistory = history.map((m) => ({
+    role: m.role as "user" | "assistant",
+    content: m.content,
+  }));
+
+  // Call LLM with tools
+  const startMs = Date.now();
+  const response = await chatWithTools(
+    chatHistory,
+    submissionContext,
+    focusedContext,
+    effectiveSubmissionId,
+    attachmentContext || undefined,
+    input.threadId,
+    input.viewContext?.regulationCollectionId,
+    stream,
+  );
+  const latencyMs = Date.now() - startMs;
+
+  // Save assistant message
+  const assistantMsg = await prisma.chatMessage.create({
+    data: {
+      threadId: input.threadId,
+      role: "assistant",
+      content: response.content,
+      metadata: {
+        inputTokens: response.usage.inputTokens,
+        outputTokens: response.usage.outputTokens,
+        latencyMs,
+        model: "claude-sonnet-4-20250514",
+        toolCalls: response.toolCalls,
+      },
+    },
+  });
+
+  // Update thread title from first user message
+  if (!thread.title) {
+    const title =
+      input.content.length > 60
+        ? input.content.slice(0, 57) + "..."
+        : input.content;
+    await prisma.chatThread.update({
+      where: { id: input.threadId },
+      data: { title },
+    });
+  }
+
+  // Touch updatedAt
+  await prisma.chatThread.update({
+    where: { id: input.threadId },
+    data: { updatedAt: new Date() },
+  });
+
+  // Fire-and-forget: generate follow-up suggestions
+  generateFollowUpSuggestions(prisma, assistantMsg.id, input.threadId).catch(() => {});
+
+  return { userMessage: userMsg, assistantMessage: assistantMsg };
+}
+
+// ============================================================================
+// Follow-up suggestion generation
+// ============================================================================
+
+export async function generateFollowUpSuggestions(
+  prisma: typeof db,
+  messageId: string,
+  threadId: string,
+): Promise<string[]> {
+  const history = await prisma.chatMessage.findMany({
+    where: { threadId },
+    orderBy: { createdAt: "asc" },
+    take: 10,
+    select: { role: true, content: true },
+  });
+
+  const llm = createLLM({
+    provider: "anthropic",
+    model: "claude-3-haiku-20240307",
+    temperature: 0.8,
+    maxTokens: 256,
+  });
+
+  const result = await llm.invoke([
+    new SystemMessage(
+      "Generate 2-4 short follow-up questions or actions the user might want to take next, based on the conversation. Return ONLY a JSON array of strings, each 3-8 words. Focus on actionable next steps relevant to PPAP/quality review."
+    ),
+    ...history.map((m) =>
+      m.role === "user" ? new HumanMessage(m.content) : new AIMessage(m.content)
+    ),
+    new HumanMessage("What follow-up questions should I suggest?"),
+  ]);
+
+  const content = typeof result.content === "string" ? result.content : "";
+  let suggestions: string[] = [];
+  try {
+    const match = content.match(/\[[\s\S]*\]/);
+    if (match) suggestions = JSON.parse(match[0]);
+  } catch { /* ignore parse errors */ }
+
+  // Cache on message metadata
+  const message = await prisma.chatMessage.findUnique({
+    where: { id: messageId },
+    select: { metadata: true },
+  });
+  const existing = (message?.metadata as Record<string, unknown>) ?? {};
+  await prisma.chatMessage.update({
+    where: { id: messageId },
+    data: { metadata: { ...existing, followUpSuggestions: suggestions } },
+  });
+
+  return suggestions;
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+async function buildFocusedContext(
+  prisma: any,
+  viewContext?: z.infer<typeof viewContextSchema>,
+): Promise<string> {
+  if (!viewContext) return "";
+
+  const sections: string[] = [];
+
+  if (viewContext.findingId) {
+    const finding = await prisma.finding.findUnique({
+      where: { id: viewContext.findingId },
+      select: { severity: true, category: true, title: true, description: true },
+    });
+    if (finding) {
+      sections.push(
+        `### Currently Viewing: Finding\n[${finding.severity}] ${finding.title}\nCategory: ${finding.category}\n${finding.description}`,
+      );
+    }
+  }
+
+  if (viewContext.documentId) {
+    const doc = await prisma.document.findUnique({
+      where: { id: viewContext.documentId },
+      select: { filename: true, documentType: true, mimeType: true },
+    });
+    if (doc) {
+      sections.push(
+        `### Currently Viewing: Document\n${doc.filename} (${doc.documentType ?? "unclassified"})`,
+      );
+    }
+  }
+
+  if (viewContext.checklistId) {
+    const checklist = await prisma.checklist.findUnique({
+      where: { id: viewContext.checklistId },
+      select: { name: true, ppapLevel: true, _count: { select: { items: true } } },
+    });
+    if (checklist) {
+      sections.push(
+        `### Currently Viewing: Checklist\n${checklist.name} — Level ${checklist.ppapLevel}, ${checklist._count.items} elements`,
+      );
+    }
+  }
+
+  if (viewContext.documentTypeId) {
+    const typeDef = await prisma.documentTypeDefinition.findUnique({
+      where: { id: viewContext.documentTypeId },
+      select: { code: true, label: true, description: true },
+    });
+    if (typeDef) {
+      sections.push(
+        `### Currently Viewing: Document Type\n${typeDef.code}: ${typeDef.label}${typeDef.description ? `\n${typeDef.description}` : ""}`,
+      );
+    }
+  }
+
+  if (viewContext.regulationCollectionId) {
+    const collection = await prisma.regulationCollection.findUnique({
+      where: { id: viewContext.regulationCollectionId },
+      select: {
+        name: true,
+        description: true,
+        _count: { select: { documents: true, requirements: true } },
+      },
+    });
+    if (collection) {
+      sections.push(
+        `### Currently Viewing: Regulation Collection\n${collection.name}${collection.description ? `\n${collection.description}` : ""}\nDocuments: ${collection._count.documents}, Requirements: ${collection._count.requirements}`,
+      );
+    }
+  }
+
+  return sections.join("\n\n");
+}
+
+async function buildAttachmentContext(
+  prisma: any,
+  threadId: string,
+): Promise<string | null> {
+  const attachments = await prisma.chatAttachment.findMany({
+    where: { threadId, processingStatus: "COMPLETED" },
+    select: { id: true, filename: true, mimeType: true },
+  });
+  if (attachments.length === 0) return null;
+
+  const lines = attachments.map(
+    (a: any) => `- ${a.filename} (${a.mimeType}) [id: ${a.id}]`,
+  );
+  return lines.join("\n");
+}
@@ -563,14 +563,15 @@ function renderInlineSpans(doc: any, line: string, opts: { indent?: number }) {
   if (spans.length === 0) return;
 
   // If all plain text, simple render
-  if (spans.length === 1 && !spans[0].bold && !spans[0].italic) {
+  const [first] = spans;
+  if (spans.length === 1 && first && !first.bold && !first.italic) {
     doc.font("Roboto").fontSize(11).fillColor("#000000")
-      .text(spans[0].text, { indent: opts.indent, continued: false });
+      .text(first.text, { indent: opts.indent, continued: false });
     return;
   }
 
   for (let i = 0; i < spans.length; i++) {
-    const span = spans[i];
+    const span = spans[i]!;
     const font = span.bold ? "Roboto-Bold" : span.italic ? "Roboto-Italic" : "Roboto";
     const isLast = i === spans.length - 1;
     doc.font(font).fontSize(11).fillColor("#000000")
@@ -0,0 +1,22 @@
+import { draftMode } from "next/headers";
+import { redirect } from "next/navigation";
+import type { NextRequest } from "next/server";
+import { env } from "@/env";
+
+/**
+ * Turn on draft mode, e.g. from the CMS preview button:
+ * `/api/draft/enable?secret=<CMS_PREVIEW_SECRET>&slug=<post-slug>`
+ */
+export async function GET(request: NextRequest) {
+  const { searchParams } = request.nextUrl;
+  const slug = searchParams.get("slug");
+
+  if (!env.CMS_PREVIEW_SECRET || searchParams.get("secret") !== env.CMS_PREVIEW_SECRET) {
+    return new Response("Invalid preview secret", { status: 401 });
+  }
+
+  (await draftMode()).enable();
+
+  // Only ever redirect within the blog, never to a URL taken from the query string
+  redirect(slug ? `/blog/${encodeURIComponent(slug)}` : "/blog");
+}
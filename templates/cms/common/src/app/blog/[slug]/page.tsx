@@ -0,0 +1,54 @@
+import type { Metadata } from "next";
+import { draftMode } from "next/headers";
+import Link from "next/link";
+import { notFound } from "next/navigation";
+import { getPostBySlug } from "@/server/cms";
+import { PostBody } from "./PostBody";
+
+interface PostPageProps {
+  params: Promise<{ slug: string }>;
+}
+
+export async function generateMetadata({ params }: PostPageProps): Promise<Metadata> {
+  const post = await getPostBySlug((await params).slug);
+  return post ? { title: post.title, description: post.excerpt } : {};
+}
+
+export default async function PostPage({ params }: PostPageProps) {
+  const { slug } = await params;
+  const [post, draft] = await Promise.all([getPostBySlug(slug), draftMode()]);
+
+  if (!post) {
+    notFound();
+  }
+
+  return (
+    <main className="max-w-3xl mx-auto px-4 sm:px-6 py-12 w-full">
+      {draft.isEnabled && (
+        <div className="mb-8 flex items-center justify-between rounded-lg border border-border bg-muted px-4 py-3 text-sm">
+          <span>Previewing unpublished content</span>
+          <a href="/api/draft/disable" className="font-medium underline underline-offset-4">
+            Exit preview
+          </a>
+        </div>
+      )}
+
+      <Link href="/blog" className="text-sm text-muted-foreground hover:text-primary">
+        ← All posts
+      </Link>
+
+      <article className="mt-6">
+        <h1 className="text-3xl font-semibold">{post.title}</h1>
+        {post.publishedAt && (
+          <time
+            dateTime={post.publishedAt.toISOString()}
+            className="mt-2 block text-sm text-muted-foreground"
+          >
+            {post.publishedAt.toLocaleDateString()}
+          </time>
+        )}
+        <PostBody post={post} />
+      </article>
+    </main>
+  );
+}
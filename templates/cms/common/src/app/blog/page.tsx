@@ -0,0 +1,42 @@
+import type { Metadata } from "next";
+import Link from "next/link";
+import { getPosts } from "@/server/cms";
+
+export const metadata: Metadata = {
+  title: "Blog",
+};
+
+export default async function BlogPage() {
+  const posts = await getPosts();
+
+  return (
+    <main className="max-w-3xl mx-auto px-4 sm:px-6 py-12 w-full">
+      <h1 className="text-3xl font-semibold mb-8">Blog</h1>
+
+      {posts.length === 0 ? (
+        <p className="text-muted-foreground">No posts published yet.</p>
+      ) : (
+        <ul className="space-y-8">
+          {posts.map((post) => (
+            <li key={post.slug}>
+              <Link href={`/blog/${post.slug}`} className="group block">
+                <h2 className="text-xl font-medium group-hover:text-primary transition-colors">
+                  {post.title}
+                </h2>
+                {post.publishedAt && (
+                  <time
+                    dateTime={post.publishedAt.toISOString()}
+                    className="text-sm text-muted-foreground"
+                  >
+                    {post.publishedAt.toLocaleDateString()}
+                  </time>
+                )}
+                {post.excerpt && <p className="mt-2 text-muted-foreground">{post.excerpt}</p>}
+              </Link>
+            </li>
+          ))}
+        </ul>
+      )}
+    </main>
+  );
+}
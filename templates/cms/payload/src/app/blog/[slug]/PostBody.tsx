@@ -0,0 +1,15 @@
+import type { Post } from "@/server/cms";
+
+export function PostBody({ post }: { post: Post }) {
+  if (!post.html) {
+    return null;
+  }
+
+  return (
+    <div
+      className="mt-8 space-y-4 leading-7"
+      // biome-ignore lint/security/noDangerouslySetInnerHtml: HTML rendered by your own CMS from rich text
+      dangerouslySetInnerHTML={{ __html: post.html }}
+    />
+  );
+}
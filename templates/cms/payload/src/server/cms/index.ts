@@ -0,0 +1,73 @@
+import "server-only";
+
+import { draftMode } from "next/headers";
+import { z } from "zod";
+import { env } from "@/env";
+
+/*
+ * Reads the Payload REST API of your CMS deployment. Expected `posts` collection:
+ *   title: text, slug: text (unique), excerpt: textarea, publishedAt: date,
+ *   content: richText, plus `lexicalHTMLField({ lexicalFieldName: "content",
+ *   htmlFieldName: "content_html" })` and `versions: { drafts: true }`
+ */
+
+const postSummarySchema = z.object({
+  slug: z.string(),
+  title: z.string(),
+  excerpt: z.string().nullish().transform((value) => value ?? null),
+  publishedAt: z.coerce.date().nullish().transform((value) => value ?? null),
+});
+
+const postSchema = postSummarySchema
+  .extend({ content_html: z.string().nullish() })
+  .transform(({ content_html, ...post }) => ({ ...post, html: content_html ?? null }));
+
+const listResponse = <T extends z.ZodType>(doc: T) => z.object({ docs: z.array(doc) });
+
+export type PostSummary = z.infer<typeof postSummarySchema>;
+export type Post = z.infer<typeof postSchema>;
+
+/** Published content, cached for a minute; drafts with the API key while draft mode is on */
+async function fetchPayload(path: string, params: Record<string, string>): Promise<unknown> {
+  if (!env.PAYLOAD_URL) {
+    throw new Error("PAYLOAD_URL is not set; see .env.example");
+  }
+
+  const { isEnabled: draft } = await draftMode();
+  const url = new URL(`/api/${path}`, env.PAYLOAD_URL);
+  for (const [key, value] of Object.entries(params)) {
+    url.searchParams.set(key, value);
+  }
+
+  const init: RequestInit = draft
+    ? {
+        cache: "no-store",
+        headers: env.PAYLOAD_API_KEY
+          ? { Authorization: `users API-Key ${env.PAYLOAD_API_KEY}` }
+          : undefined,
+      }
+    : { next: { revalidate: 60, tags: ["cms"] } };
+  if (draft) {
+    url.searchParams.set("draft", "true");
+  }
+
+  const response = await fetch(url, init);
+  if (!response.ok) {
+    throw new Error(`Payload request failed: ${response.status} ${response.statusText}`);
+  }
+  return response.json();
+}
+
+export async function getPosts(): Promise<PostSummary[]> {
+  const data = await fetchPayload("posts", { sort: "-publishedAt", limit: "50", depth: "0" });
+  return listResponse(postSummarySchema).parse(data).docs;
+}
+
+export async function getPostBySlug(slug: string): Promise<Post | null> {
+  const data = await fetchPayload("posts", {
+    "where[slug][equals]": slug,
+    limit: "1",
+    depth: "1",
+  });
+  return listResponse(postSchema).parse(data).docs[0] ?? null;
+}
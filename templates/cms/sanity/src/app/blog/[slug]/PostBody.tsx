@@ -0,0 +1,14 @@
+import { PortableText } from "next-sanity";
+import type { Post } from "@/server/cms";
+
+export function PostBody({ post }: { post: Post }) {
+  if (!post.body) {
+    return null;
+  }
+
+  return (
+    <div className="mt-8 space-y-4 leading-7">
+      <PortableText value={post.body} />
+    </div>
+  );
+}
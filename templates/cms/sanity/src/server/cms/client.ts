@@ -0,0 +1,17 @@
+import { createClient } from "next-sanity";
+import { env } from "@/env";
+
+export const apiVersion = "2025-01-01";
+
+export function getSanityClient() {
+  if (!env.NEXT_PUBLIC_SANITY_PROJECT_ID) {
+    throw new Error("NEXT_PUBLIC_SANITY_PROJECT_ID is not set; see .env.example");
+  }
+
+  return createClient({
+    projectId: env.NEXT_PUBLIC_SANITY_PROJECT_ID,
+    dataset: env.NEXT_PUBLIC_SANITY_DATASET,
+    apiVersion,
+    useCdn: true,
+  });
+}
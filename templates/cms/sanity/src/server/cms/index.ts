@@ -0,0 +1,44 @@
+import "server-only";
+
+import { draftMode } from "next/headers";
+import type { PortableTextBlock, QueryParams } from "next-sanity";
+import { z } from "zod";
+import { env } from "@/env";
+import { getSanityClient } from "./client";
+import { POST_BY_SLUG_QUERY, POSTS_QUERY } from "./queries";
+
+const postSummarySchema = z.object({
+  slug: z.string(),
+  title: z.string(),
+  excerpt: z.string().nullable(),
+  publishedAt: z.coerce.date().nullable(),
+});
+
+const postSchema = postSummarySchema.extend({
+  body: z.custom<PortableTextBlock[]>(Array.isArray).nullable(),
+});
+
+export type PostSummary = z.infer<typeof postSummarySchema>;
+export type Post = z.infer<typeof postSchema>;
+
+/** Published content from the CDN, or drafts with the read token while draft mode is on */
+async function fetchSanity(query: string, params: QueryParams = {}): Promise<unknown> {
+  const client = getSanityClient();
+  const { isEnabled: draft } = await draftMode();
+
+  if (draft) {
+    return client
+      .withConfig({ token: env.SANITY_API_READ_TOKEN, perspective: "drafts", useCdn: false })
+      .fetch(query, params, { cache: "no-store" });
+  }
+
+  return client.fetch(query, params, { next: { revalidate: 60, tags: ["cms"] } });
+}
+
+export async function getPosts(): Promise<PostSummary[]> {
+  return z.array(postSummarySchema).parse(await fetchSanity(POSTS_QUERY));
+}
+
+export async function getPostBySlug(slug: string): Promise<Post | null> {
+  return postSchema.nullable().parse(await fetchSanity(POST_BY_SLUG_QUERY, { slug }));
+}
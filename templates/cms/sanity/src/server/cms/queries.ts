@@ -0,0 +1,25 @@
+import { defineQuery } from "next-sanity";
+
+/*
+ * Expected content model in your Sanity Studio, a `post` document with:
+ *   title: string, slug: slug, excerpt: text, publishedAt: datetime, body: array of blocks
+ */
+
+export const POSTS_QUERY = defineQuery(`
+  *[_type == "post" && defined(slug.current)] | order(publishedAt desc) {
+    "slug": slug.current,
+    title,
+    excerpt,
+    publishedAt
+  }
+`);
+
+export const POST_BY_SLUG_QUERY = defineQuery(`
+  *[_type == "post" && slug.current == $slug][0] {
+    "slug": slug.current,
+    title,
+    excerpt,
+    publishedAt,
+    body
+  }
+`);
@@ -0,0 +1,34 @@
+import type { Metadata } from "next";
+import { getLegalTranslator, type LegalPageProps, LOCALES } from "../legal";
+
+const SECTIONS = ["provider", "contact", "register", "responsible", "disputes"] as const;
+
+export const dynamicParams = false;
+
+export function generateStaticParams() {
+  return LOCALES.map((locale) => ({ locale }));
+}
+
+export async function generateMetadata({ params }: LegalPageProps): Promise<Metadata> {
+  const t = await getLegalTranslator((await params).locale);
+  return { title: t("imprint.title") };
+}
+
+export default async function ImprintPage({ params }: LegalPageProps) {
+  const t = await getLegalTranslator((await params).locale);
+
+  return (
+    <main className="max-w-3xl mx-auto px-4 sm:px-6 py-12 w-full">
+      <h1 className="text-3xl font-semibold">{t("imprint.title")}</h1>
+
+      {SECTIONS.map((section) => (
+        <section key={section} className="mt-8">
+          <h2 className="text-xl font-medium">{t(`imprint.${section}.heading`)}</h2>
+          <p className="mt-2 leading-7 text-muted-foreground whitespace-pre-line">
+            {t(`imprint.${section}.body`)}
+          </p>
+        </section>
+      ))}
+    </main>
+  );
+}
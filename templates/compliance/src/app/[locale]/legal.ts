@@ -0,0 +1,18 @@
+import { notFound } from "next/navigation";
+import { createTranslator } from "next-intl";
+
+export const LOCALES = ["en", "de"] as const;
+
+export interface LegalPageProps {
+  params: Promise<{ locale: string }>;
+}
+
+/** Translator for the `legal` namespace in the locale of the URL rather than the cookie */
+export async function getLegalTranslator(locale: string) {
+  if (!(LOCALES as readonly string[]).includes(locale)) {
+    notFound();
+  }
+
+  const messages = (await import(`../../../messages/${locale}.json`)).default;
+  return createTranslator({ locale, messages, namespace: "legal" });
+}
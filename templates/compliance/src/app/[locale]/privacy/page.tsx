@@ -0,0 +1,39 @@
+import type { Metadata } from "next";
+import { ConsentSettingsButton } from "@/components/consent";
+import { getLegalTranslator, type LegalPageProps, LOCALES } from "../legal";
+
+const SECTIONS = ["controller", "data", "cookies", "analytics", "rights", "contact"] as const;
+
+export const dynamicParams = false;
+
+export function generateStaticParams() {
+  return LOCALES.map((locale) => ({ locale }));
+}
+
+export async function generateMetadata({ params }: LegalPageProps): Promise<Metadata> {
+  const t = await getLegalTranslator((await params).locale);
+  return { title: t("privacy.title") };
+}
+
+export default async function PrivacyPage({ params }: LegalPageProps) {
+  const t = await getLegalTranslator((await params).locale);
+
+  return (
+    <main className="max-w-3xl mx-auto px-4 sm:px-6 py-12 w-full">
+      <h1 className="text-3xl font-semibold">{t("privacy.title")}</h1>
+      <p className="mt-2 text-sm text-muted-foreground">{t("privacy.lastUpdated")}</p>
+
+      {SECTIONS.map((section) => (
+        <section key={section} className="mt-8">
+          <h2 className="text-xl font-medium">{t(`privacy.${section}.heading`)}</h2>
+          <p className="mt-2 leading-7 text-muted-foreground whitespace-pre-line">
+            {t(`privacy.${section}.body`)}
+          </p>
+          {section === "cookies" && (
+            <ConsentSettingsButton label={t("privacy.cookies.settings")} className="mt-4" />
+          )}
+        </section>
+      ))}
+    </main>
+  );
+}
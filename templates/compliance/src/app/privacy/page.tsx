@@ -0,0 +1,8 @@
+import { cookies } from "next/headers";
+import { redirect } from "next/navigation";
+
+/** Unprefixed link: send visitors to the version in their current language */
+export default async function PrivacyRedirect() {
+  const locale = (await cookies()).get("locale")?.value === "de" ? "de" : "en";
+  redirect(`/${locale}/privacy`);
+}
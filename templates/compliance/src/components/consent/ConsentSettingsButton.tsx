@@ -0,0 +1,23 @@
+"use client";
+
+import { clearConsent, useConsent } from "./consent";
+
+export function ConsentSettingsButton({ label, className }: { label: string; className?: string }) {
+  const consent = useConsent();
+
+  return (
+    <button
+      type="button"
+      onClick={() => {
+        clearConsent();
+        // Scripts loaded after an earlier "accept all" only go away with a reload
+        if (consent === "all") {
+          window.location.reload();
+        }
+      }}
+      className={`rounded-lg border border-border px-4 py-2 text-sm font-medium hover:bg-muted cursor-pointer ${className ?? ""}`}
+    >
+      {label}
+    </button>
+  );
+}
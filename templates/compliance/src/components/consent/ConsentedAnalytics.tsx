@@ -0,0 +1,22 @@
+"use client";
+
+import Script from "next/script";
+import { env } from "@/env";
+import { useConsent } from "./consent";
+
+/** Loads the analytics script only after the visitor accepted all cookies */
+export function ConsentedAnalytics() {
+  const consent = useConsent();
+
+  if (consent !== "all" || !env.NEXT_PUBLIC_ANALYTICS_SCRIPT_URL) {
+    return null;
+  }
+
+  return (
+    <Script
+      src={env.NEXT_PUBLIC_ANALYTICS_SCRIPT_URL}
+      data-domain={env.NEXT_PUBLIC_ANALYTICS_DOMAIN}
+      strategy="afterInteractive"
+    />
+  );
+}
@@ -0,0 +1,46 @@
+"use client";
+
+import Link from "next/link";
+import { useLocale, useTranslations } from "next-intl";
+import { saveConsent, useConsent } from "./consent";
+
+// Both choices get the same weight; nudging towards "accept" is not valid consent under the GDPR
+const buttonClass =
+  "rounded-lg border border-border px-4 py-2 text-sm font-medium hover:bg-muted cursor-pointer transition-colors";
+
+export function CookieConsent() {
+  const t = useTranslations("consent");
+  const locale = useLocale();
+  const consent = useConsent();
+
+  if (consent !== null) {
+    return null;
+  }
+
+  return (
+    <section
+      aria-label={t("title")}
+      className="fixed inset-x-0 bottom-0 z-50 p-4"
+    >
+      <div className="mx-auto max-w-3xl rounded-xl border border-border bg-card p-4 shadow-lg sm:flex sm:items-center sm:gap-6">
+        <div className="flex-1 text-sm">
+          <p className="font-medium">{t("title")}</p>
+          <p className="mt-1 text-muted-foreground">
+            {t("description")}{" "}
+            <Link href={`/${locale}/privacy`} className="underline underline-offset-4">
+              {t("privacyLink")}
+            </Link>
+          </p>
+        </div>
+        <div className="mt-4 flex gap-2 sm:mt-0">
+          <button type="button" onClick={() => saveConsent("necessary")} className={buttonClass}>
+            {t("necessaryOnly")}
+          </button>
+          <button type="button" onClick={() => saveConsent("all")} className={buttonClass}>
+            {t("acceptAll")}
+          </button>
+        </div>
+      </div>
+    </section>
+  );
+}
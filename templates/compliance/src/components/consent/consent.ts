@@ -0,0 +1,34 @@
+"use client";
+
+import { useSyncExternalStore } from "react";
+
+export type Consent = "all" | "necessary";
+
+const CONSENT_COOKIE = "cookie_consent";
+const CONSENT_EVENT = "cookie-consent-change";
+
+function readConsent(): Consent | null {
+  const match = document.cookie.match(/(?:^|;\s*)cookie_consent=(all|necessary)/);
+  return (match?.[1] as Consent | undefined) ?? null;
+}
+
+export function saveConsent(consent: Consent) {
+  document.cookie = `${CONSENT_COOKIE}=${consent};path=/;max-age=31536000;SameSite=Lax`;
+  window.dispatchEvent(new Event(CONSENT_EVENT));
+}
+
+/** Forget the decision so the banner asks again */
+export function clearConsent() {
+  document.cookie = `${CONSENT_COOKIE}=;path=/;max-age=0;SameSite=Lax`;
+  window.dispatchEvent(new Event(CONSENT_EVENT));
+}
+
+function subscribe(onChange: () => void) {
+  window.addEventListener(CONSENT_EVENT, onChange);
+  return () => window.removeEventListener(CONSENT_EVENT, onChange);
+}
+
+/** `undefined` while rendering on the server, `null` until the visitor has decided */
+export function useConsent(): Consent | null | undefined {
+  return useSyncExternalStore(subscribe, readConsent, () => undefined);
+}
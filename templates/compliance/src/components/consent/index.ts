@@ -0,0 +1,4 @@
+export * from "./ConsentedAnalytics";
+export * from "./ConsentSettingsButton";
+export * from "./CookieConsent";
+export { type Consent, useConsent } from "./consent";
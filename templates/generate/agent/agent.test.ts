@@ -0,0 +1,46 @@
+import { beforeEach, describe, expect, it, vi } from "vitest";
+
+const invoke = vi.fn();
+
+vi.mock("@/components/ai/core/providers", () => ({
+  ModelRegistry: { anthropic: { sonnet: "test-model" } },
+  createLLM: () => ({ invoke, bindTools: () => ({ invoke }) }),
+}));
+vi.mock("@/components/ai/core/logging", () => ({ logLLMCall: vi.fn() }));
+{{test_mocks}}
+import { execute{{Name}}Tool, run{{Name}}Agent, SYSTEM_PROMPT, {{name}}Tools } from "./{{file}}";
+
+describe("{{file}} agent", () => {
+  beforeEach(() => {
+    invoke.mockReset();
+  });
+
+  it("has a system prompt", () => {
+    expect(SYSTEM_PROMPT.length).toBeGreaterThan(0);
+  });
+
+  it("registers its tools", () => {
+    expect({{name}}Tools.map((tool) => tool.name)).toEqual([{{tool_list}}]);
+  });
+
+  it("rejects unknown tools", async () => {
+    await expect(execute{{Name}}Tool("missing", {})).resolves.toEqual({ error: "Unknown tool: missing" });
+  });
+{{test_cases}}
+  it("answers directly when the model calls no tools", async () => {
+    invoke.mockResolvedValueOnce({ content: "Done", tool_calls: [] });
+
+    await expect(run{{Name}}Agent("Hello")).resolves.toEqual({ content: "Done", toolCalls: [] });
+  });
+
+  it("feeds tool results back to the model", async () => {
+    invoke
+      .mockResolvedValueOnce({ content: "", tool_calls: [{ id: "call_1", name: "missing", args: {} }] })
+      .mockResolvedValueOnce({ content: "Done", tool_calls: [] });
+
+    const result = await run{{Name}}Agent("Hello");
+
+    expect(result.toolCalls).toEqual([{ name: "missing", args: {} }]);
+    expect(invoke).toHaveBeenCalledTimes(2);
+  });
+});
@@ -0,0 +1,82 @@
+/**
+ * {{Name}} agent
+ * Tool-calling loop on the shared providers/logging core. Edit SYSTEM_PROMPT and
+ * the tools below, then call run{{Name}}Agent from a router or server action.
+ */
+import { z } from "zod";
+import { AIMessage, HumanMessage, SystemMessage, ToolMessage } from "@langchain/core/messages";
+import { createLLM, ModelRegistry } from "@/components/ai/core/providers";
+import { logLLMCall } from "@/components/ai/core/logging";
+{{tool_imports}}
+{{tool_setup}}const PROVIDER = "anthropic";
+const MODEL = ModelRegistry.anthropic.sonnet;
+const MAX_ITERATIONS = 5;
+
+export const SYSTEM_PROMPT = `You are the {{title}} agent.
+
+TODO: describe the agent's job, the tone it should use, and when it should call each tool.`;
+
+// ============================================================================
+// Tool Definitions (plain objects to avoid TS2589 from LangChain tool())
+// ============================================================================
+
+export const {{name}}Tools: { name: string; description: string; schema: z.ZodType }[] = [
+{{tool_definitions}}];
+
+export async function execute{{Name}}Tool(name: string, args: Record<string, any>): Promise<unknown> {
+  switch (name) {
+{{tool_cases}}    default:
+      return { error: `Unknown tool: ${name}` };
+  }
+}
+
+// ============================================================================
+// Agent
+// ============================================================================
+
+export interface {{Name}}AgentResult {
+  content: string;
+  toolCalls: { name: string; args: Record<string, any> }[];
+}
+
+function textContent(content: unknown): string {
+  if (typeof content === "string") return content;
+  if (Array.isArray(content)) {
+    return content
+      .map((part) => (typeof part === "string" ? part : part?.type === "text" ? part.text : ""))
+      .join("");
+  }
+  return "";
+}
+
+export async function run{{Name}}Agent(input: string): Promise<{{Name}}AgentResult> {
+  const llm = createLLM({ provider: PROVIDER, model: MODEL, temperature: 0.2 });
+  const model = {{name}}Tools.length > 0 ? (llm as any).bindTools({{name}}Tools) : llm;
+
+  const messages: (SystemMessage | HumanMessage | AIMessage | ToolMessage)[] = [
+    new SystemMessage(SYSTEM_PROMPT),
+    new HumanMessage(input),
+  ];
+  const toolCalls: {{Name}}AgentResult["toolCalls"] = [];
+
+  for (let iteration = 0; iteration < MAX_ITERATIONS; iteration++) {
+    const result = await model.invoke(messages);
+    await logLLMCall(PROVIDER, MODEL, messages, result, { agent: "{{file}}", iteration });
+
+    const content = textContent(result.content);
+    const calls = (result as any).tool_calls ?? [];
+    messages.push(new AIMessage({ content, tool_calls: calls }));
+
+    if (calls.length === 0) {
+      return { content, toolCalls };
+    }
+
+    for (const call of calls) {
+      toolCalls.push({ name: call.name, args: call.args });
+      const toolResult = await execute{{Name}}Tool(call.name, call.args);
+      messages.push(new ToolMessage({ content: JSON.stringify(toolResult), tool_call_id: call.id }));
+    }
+  }
+
+  throw new Error(`{{title}} agent stopped after ${MAX_ITERATIONS} iterations without an answer`);
+}
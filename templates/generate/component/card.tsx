@@ -0,0 +1,66 @@
+/**
+ * {{Name}} component - Card layout
+ * Builds on the UI kit Card; add variants to {{name}}Variants
+ */
+import * as React from "react"
+import { cva, type VariantProps } from "class-variance-authority"
+
+import { cn } from "@/utils/utils"
+import {
+  Card,
+  CardContent,
+  CardDescription,
+  CardFooter,
+  CardHeader,
+  CardTitle,
+} from "@/components/ui/card"
+
+const {{name}}Variants = cva("", {
+  variants: {
+    variant: {
+      default: "",
+      muted: "bg-muted/50",
+      outline: "bg-transparent ring-border",
+    },
+  },
+  defaultVariants: {
+    variant: "default",
+  },
+})
+
+interface {{Name}}Props
+  extends Omit<React.ComponentProps<typeof Card>, "title">,
+    VariantProps<typeof {{name}}Variants> {
+  title: React.ReactNode
+  description?: React.ReactNode
+  footer?: React.ReactNode
+}
+
+function {{Name}}({
+  className,
+  variant = "default",
+  title,
+  description,
+  footer,
+  children,
+  ...props
+}: {{Name}}Props) {
+  return (
+    <Card
+      data-slot="{{slot}}"
+      data-variant={variant}
+      className={cn({{name}}Variants({ variant }), className)}
+      {...props}
+    >
+      <CardHeader>
+        <CardTitle>{title}</CardTitle>
+        {description && <CardDescription>{description}</CardDescription>}
+      </CardHeader>
+      {children && <CardContent>{children}</CardContent>}
+      {footer && <CardFooter>{footer}</CardFooter>}
+    </Card>
+  )
+}
+
+export { {{Name}}, {{name}}Variants }
+export type { {{Name}}Props }
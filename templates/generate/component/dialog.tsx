@@ -0,0 +1,77 @@
+/**
+ * {{Name}} component - Dialog with a trigger
+ * Builds on the UI kit Dialog; add variants to {{name}}Variants
+ */
+"use client"
+
+import * as React from "react"
+import { cva, type VariantProps } from "class-variance-authority"
+
+import { cn } from "@/utils/utils"
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogFooter,
+  DialogHeader,
+  DialogTitle,
+  DialogTrigger,
+} from "@/components/ui/dialog"
+
+const {{name}}Variants = cva("", {
+  variants: {
+    tone: {
+      default: "",
+      destructive: "[&_[data-slot=dialog-title]]:text-destructive",
+    },
+  },
+  defaultVariants: {
+    tone: "default",
+  },
+})
+
+interface {{Name}}Props
+  extends Omit<React.ComponentProps<typeof DialogContent>, "title">,
+    VariantProps<typeof {{name}}Variants> {
+  /** Element that opens the dialog, e.g. a Button */
+  trigger: React.ReactElement
+  title: React.ReactNode
+  description?: React.ReactNode
+  footer?: React.ReactNode
+  open?: boolean
+  onOpenChange?: (open: boolean) => void
+}
+
+function {{Name}}({
+  className,
+  tone = "default",
+  trigger,
+  title,
+  description,
+  footer,
+  open,
+  onOpenChange,
+  children,
+  ...props
+}: {{Name}}Props) {
+  return (
+    <Dialog open={open} onOpenChange={onOpenChange}>
+      <DialogTrigger asChild>{trigger}</DialogTrigger>
+      <DialogContent
+        data-tone={tone}
+        className={cn({{name}}Variants({ tone }), className)}
+        {...props}
+      >
+        <DialogHeader>
+          <DialogTitle>{title}</DialogTitle>
+          {description && <DialogDescription>{description}</DialogDescription>}
+        </DialogHeader>
+        {children}
+        {footer && <DialogFooter>{footer}</DialogFooter>}
+      </DialogContent>
+    </Dialog>
+  )
+}
+
+export { {{Name}}, {{name}}Variants }
+export type { {{Name}}Props }
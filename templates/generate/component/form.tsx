@@ -0,0 +1,68 @@
+/**
+ * {{Name}} component - Form layout with a submit button
+ * Pair with useZodForm from @/components/forms for validation
+ */
+"use client"
+
+import * as React from "react"
+import { cva, type VariantProps } from "class-variance-authority"
+
+import { cn } from "@/utils/utils"
+import { Button } from "@/components/ui/button"
+
+const {{name}}Variants = cva("flex gap-4", {
+  variants: {
+    layout: {
+      stacked: "flex-col",
+      inline: "flex-row flex-wrap items-end",
+    },
+  },
+  defaultVariants: {
+    layout: "stacked",
+  },
+})
+
+interface {{Name}}Props
+  extends React.ComponentProps<"form">,
+    VariantProps<typeof {{name}}Variants> {
+  submitLabel?: React.ReactNode
+  /** Disables the submit button while a mutation runs */
+  pending?: boolean
+  /** Form-level error, e.g. from a failed mutation */
+  error?: React.ReactNode
+}
+
+function {{Name}}({
+  className,
+  layout = "stacked",
+  submitLabel = "Save",
+  pending = false,
+  error,
+  children,
+  ...props
+}: {{Name}}Props) {
+  return (
+    <form
+      data-slot="{{slot}}"
+      data-layout={layout}
+      className={cn({{name}}Variants({ layout }), className)}
+      noValidate
+      {...props}
+    >
+      {children}
+      {error && (
+        <p role="alert" className="text-destructive text-sm">
+          {error}
+        </p>
+      )}
+      <div>
+        <Button type="submit" disabled={pending}>
+          {submitLabel}
+        </Button>
+      </div>
+    </form>
+  )
+}
+
+export { {{Name}}, {{name}}Variants }
+export type { {{Name}}Props }
@@ -0,0 +1,13 @@
+{{imports}}
+export default async function {{Name}}Page() {
+{{setup}}  return (
+    <div className="min-h-screen flex flex-col bg-background">
+      <Header />
+
+      <main className="flex-1 max-w-7xl mx-auto px-4 sm:px-6 lg:px-8 py-8 w-full">
+        <h1 className="text-2xl font-semibold mb-6">{{heading}}</h1>
+        <p className="text-muted-foreground">{{description}}</p>
+      </main>
+    </div>
+  );
+}
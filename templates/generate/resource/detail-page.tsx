@@ -0,0 +1,32 @@
+import { TRPCError } from "@trpc/server";
+import Link from "next/link";
+import { notFound } from "next/navigation";
+
+import { api } from "@/trpc/server";
+
+export default async function {{Model}}Page({ params }: { params: Promise<{ id: string }> }) {
+  const { id } = await params;
+  const {{model}} = await api.{{model}}.byId({ id }).catch((error: unknown) => {
+    if (error instanceof TRPCError && error.code === "NOT_FOUND") {
+      notFound();
+    }
+    throw error;
+  });
+
+  return (
+    <main className="mx-auto flex min-h-screen w-full max-w-2xl flex-col gap-8 px-4 py-16">
+      <div>
+        <Link href="/{{route}}" className="text-sm text-muted-foreground hover:underline">
+          &larr; {{title}}
+        </Link>
+        <h1 className="mt-2 text-3xl font-bold">{{{label}}}</h1>
+      </div>
+
+      <dl className="grid grid-cols-[auto_1fr] gap-x-6 gap-y-2">
+{{detail_fields}}
+        <dt className="text-muted-foreground">Created</dt>
+        <dd>{{{model}}.createdAt.toLocaleString()}</dd>
+      </dl>
+    </main>
+  );
+}
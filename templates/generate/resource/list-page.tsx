@@ -0,0 +1,31 @@
+import Link from "next/link";
+
+import { api } from "@/trpc/server";
+
+export default async function {{Models}}Page() {
+  const {{models}} = await api.{{model}}.list();
+
+  return (
+    <main className="mx-auto flex min-h-screen w-full max-w-2xl flex-col gap-8 px-4 py-16">
+      <h1 className="text-3xl font-bold">{{title}}</h1>
+
+      {{{models}}.length === 0 ? (
+        <p className="text-muted-foreground">Nothing here yet.</p>
+      ) : (
+        <ul className="flex flex-col gap-2">
+          {{{models}}.map(({{model}}) => (
+            <li key={{{model}}.id}>
+              <Link
+                href={`/{{route}}/${{{model}}.id}`}
+                className="block rounded-lg border border-border px-4 py-3 hover:bg-muted"
+              >
+                <p className="font-medium">{{{label}}}</p>
+                <p className="text-xs text-muted-foreground">{{{model}}.createdAt.toLocaleString()}</p>
+              </Link>
+            </li>
+          ))}
+        </ul>
+      )}
+    </main>
+  );
+}
@@ -0,0 +1,49 @@
+import { TRPCError } from "@trpc/server";
+import { z } from "zod";
+
+import { create{{Model}}Schema, update{{Model}}Schema, {{model}}Schema } from "@/schemas/{{file}}";
+import { createTRPCRouter, protectedProcedure, publicProcedure } from "@/server/api/trpc";
+
+export const {{model}}Router = createTRPCRouter({
+  list: publicProcedure
+    .input(z.object({ limit: z.number().int().min(1).max(100).default(50) }).optional())
+    .output(z.array({{model}}Schema))
+    .query(async ({ ctx, input }) => {
+      return ctx.db.{{model}}.findMany({
+        orderBy: { createdAt: "desc" },
+        take: input?.limit ?? 50,
+      });
+    }),
+
+  byId: publicProcedure
+    .input(z.object({ id: z.string() }))
+    .output({{model}}Schema)
+    .query(async ({ ctx, input }) => {
+      const record = await ctx.db.{{model}}.findUnique({ where: { id: input.id } });
+      if (!record) {
+        throw new TRPCError({ code: "NOT_FOUND" });
+      }
+      return record;
+    }),
+
+  create: protectedProcedure
+    .input(create{{Model}}Schema)
+    .output({{model}}Schema)
+    .mutation(async ({ ctx, input }) => {
+      return ctx.db.{{model}}.create({ data: input });
+    }),
+
+  update: protectedProcedure
+    .input(update{{Model}}Schema)
+    .output({{model}}Schema)
+    .mutation(async ({ ctx, input: { id, ...data } }) => {
+      return ctx.db.{{model}}.update({ where: { id }, data });
+    }),
+
+  delete: protectedProcedure
+    .input(z.object({ id: z.string() }))
+    .mutation(async ({ ctx, input }) => {
+      await ctx.db.{{model}}.delete({ where: { id: input.id } });
+      return { id: input.id };
+    }),
+});
@@ -0,0 +1,22 @@
+import { z } from "zod";
+
+// Input and output schemas for {{Model}}, shared by the tRPC router, pages, and forms
+
+export const {{model}}Schema = z.object({
+  id: z.string(),
+{{output_fields}}
+  createdAt: z.date(),
+  updatedAt: z.date(),
+});
+
+export const create{{Model}}Schema = z.object({
+{{input_fields}}
+});
+
+export const update{{Model}}Schema = create{{Model}}Schema.partial().extend({
+  id: z.string(),
+});
+
+export type {{Model}} = z.infer<typeof {{model}}Schema>;
+export type Create{{Model}}Input = z.infer<typeof create{{Model}}Schema>;
+export type Update{{Model}}Input = z.infer<typeof update{{Model}}Schema>;
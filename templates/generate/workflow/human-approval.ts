@@ -0,0 +1,97 @@
+import * as restate from "@restatedev/restate-sdk";
+import type { WorkflowContext, WorkflowSharedContext } from "@restatedev/restate-sdk";
+import { z } from "zod";
+
+// Input of one {{title}} run; the workflow id in the URL identifies the run
+export const {{Name}}InputSchema = z.object({
+  // TODO: what the approver needs to decide
+  title: z.string(),
+  requestedBy: z.string(),
+});
+export type {{Name}}Input = z.infer<typeof {{Name}}InputSchema>;
+
+export const {{Name}}DecisionSchema = z.object({
+  approver: z.string(),
+  comment: z.string().optional(),
+});
+export type {{Name}}Decision = z.infer<typeof {{Name}}DecisionSchema> & { approved: boolean };
+
+export type {{Name}}Status = "pending" | "awaiting-approval" | "approved" | "rejected" | "expired";
+
+// How long a request waits for a decision before it expires
+const APPROVAL_TIMEOUT_MS = 7 * 24 * 60 * 60 * 1000;
+
+// Retry configuration for side effects
+const retryConfig = {
+  initialRetryInterval: { milliseconds: 500 },
+  retryIntervalFactor: 2,
+  maxRetryAttempts: 5,
+};
+
+// TODO: replace with your notification channel and the work an approval unlocks
+async function notifyApprovers(id: string, input: {{Name}}Input) {
+  console.log(`Approval requested for ${id}: ${input.title} (by ${input.requestedBy})`);
+}
+
+function parseDecision(input: unknown, approved: boolean): {{Name}}Decision {
+  const parsed = {{Name}}DecisionSchema.safeParse(input);
+  if (!parsed.success) {
+    throw new restate.TerminalError(`Invalid decision: ${parsed.error.message}`);
+  }
+  return { ...parsed.data, approved };
+}
+
+async function onApproved(id: string, decision: {{Name}}Decision) {
+  console.log(`${id} approved by ${decision.approver}`);
+}
+
+// Human approval: `run` suspends on a durable promise until `approve` or `reject` resolves
+// it. While it waits the workflow holds no resources, across restarts and deploys.
+export const {{Name}}Workflow = restate.workflow({
+  name: "{{Name}}Workflow",
+  handlers: {
+    run: async (ctx: WorkflowContext, input: {{Name}}Input) => {
+      const parsed = {{Name}}InputSchema.safeParse(input);
+      if (!parsed.success) {
+        // Retrying won't fix the input
+        throw new restate.TerminalError(`Invalid input: ${parsed.error.message}`);
+      }
+
+      await ctx.run("notify-approvers", () => notifyApprovers(ctx.key, parsed.data), retryConfig);
+      ctx.set<{{Name}}Status>("status", "awaiting-approval");
+
+      let result: {{Name}}Decision;
+      try {
+        result = await ctx.promise<{{Name}}Decision>("decision").get().orTimeout(APPROVAL_TIMEOUT_MS);
+      } catch (e) {
+        if (e instanceof restate.TimeoutError) {
+          ctx.set<{{Name}}Status>("status", "expired");
+          return { status: "expired" as const };
+        }
+        throw e;
+      }
+
+      if (!result.approved) {
+        ctx.set<{{Name}}Status>("status", "rejected");
+        return { status: "rejected" as const, decision: result };
+      }
+
+      await ctx.run("on-approved", () => onApproved(ctx.key, result), retryConfig);
+      ctx.set<{{Name}}Status>("status", "approved");
+      return { status: "approved" as const, decision: result };
+    },
+
+    // Shared handlers run alongside `run`; the first decision wins
+    approve: async (ctx: WorkflowSharedContext, input: z.infer<typeof {{Name}}DecisionSchema>) => {
+      await ctx.promise<{{Name}}Decision>("decision").resolve(parseDecision(input, true));
+    },
+
+    reject: async (ctx: WorkflowSharedContext, input: z.infer<typeof {{Name}}DecisionSchema>) => {
+      await ctx.promise<{{Name}}Decision>("decision").resolve(parseDecision(input, false));
+    },
+
+    status: async (ctx: WorkflowSharedContext) => {
+      return (await ctx.get<{{Name}}Status>("status")) ?? "pending";
+    },
+  },
+});
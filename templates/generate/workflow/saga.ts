@@ -0,0 +1,91 @@
+import * as restate from "@restatedev/restate-sdk";
+import type { WorkflowContext, WorkflowSharedContext } from "@restatedev/restate-sdk";
+import { z } from "zod";
+
+// Input of one {{title}} run; the workflow id in the URL identifies the run
+export const {{Name}}InputSchema = z.object({
+  // TODO: the data the steps need
+  orderId: z.string(),
+  amount: z.number().positive(),
+});
+export type {{Name}}Input = z.infer<typeof {{Name}}InputSchema>;
+
+export type {{Name}}Status = "pending" | "running" | "completed" | "compensating" | "compensated";
+
+// Retry configuration for the steps; a step that still fails after the last attempt
+// throws a TerminalError, which rolls back the steps before it
+const retryConfig = {
+  initialRetryInterval: { milliseconds: 500 },
+  retryIntervalFactor: 2,
+  maxRetryAttempts: 5,
+};
+
+// TODO: replace the step bodies with calls to your systems. Actions and compensations must
+// be idempotent: a compensation can run for an action that never took effect.
+async function reserveInventory(input: {{Name}}Input) {
+  return { reservationId: `reservation-${input.orderId}` };
+}
+
+async function releaseInventory(input: {{Name}}Input) {
+  console.log(`Released inventory for ${input.orderId}`);
+}
+
+async function chargePayment(input: {{Name}}Input) {
+  return { paymentId: `payment-${input.orderId}`, amount: input.amount };
+}
+
+async function refundPayment(input: {{Name}}Input) {
+  console.log(`Refunded payment for ${input.orderId}`);
+}
+
+async function confirm(input: {{Name}}Input) {
+  console.log(`Confirmed ${input.orderId}`);
+}
+
+// Saga: every step registers how to undo it, and a terminal failure runs the undos in
+// reverse order. Restate journals each step, so a rollback survives crashes too.
+export const {{Name}}Workflow = restate.workflow({
+  name: "{{Name}}Workflow",
+  handlers: {
+    run: async (ctx: WorkflowContext, input: {{Name}}Input) => {
+      const parsed = {{Name}}InputSchema.safeParse(input);
+      if (!parsed.success) {
+        // Retrying won't fix the input
+        throw new restate.TerminalError(`Invalid input: ${parsed.error.message}`);
+      }
+      const validated = parsed.data;
+
+      const compensations: (() => Promise<void>)[] = [];
+      ctx.set<{{Name}}Status>("status", "running");
+      try {
+        // Register the undo before the step, so a step that fails halfway is undone too
+        compensations.push(() => ctx.run("release-inventory", () => releaseInventory(validated)));
+        const reservation = await ctx.run("reserve-inventory", () => reserveInventory(validated), retryConfig);
+
+        compensations.push(() => ctx.run("refund-payment", () => refundPayment(validated)));
+        const payment = await ctx.run("charge-payment", () => chargePayment(validated), retryConfig);
+
+        await ctx.run("confirm", () => confirm(validated), retryConfig);
+
+        ctx.set<{{Name}}Status>("status", "completed");
+        return { reservationId: reservation.reservationId, paymentId: payment.paymentId };
+      } catch (e) {
+        // Other errors are retried by Restate and never reach the rollback
+        if (e instanceof restate.TerminalError) {
+          ctx.set<{{Name}}Status>("status", "compensating");
+          for (const compensate of compensations.reverse()) {
+            await compensate();
+          }
+          ctx.set<{{Name}}Status>("status", "compensated");
+          ctx.console.warn({ workflow: "{{Name}}Workflow", id: ctx.key, error: e.message });
+        }
+        throw e;
+      }
+    },
+
+    // Shared handlers run alongside `run`, e.g. to poll progress
+    status: async (ctx: WorkflowSharedContext) => {
+      return (await ctx.get<{{Name}}Status>("status")) ?? "pending";
+    },
+  },
+});
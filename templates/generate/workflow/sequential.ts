@@ -0,0 +1,65 @@
+import * as restate from "@restatedev/restate-sdk";
+import type { WorkflowContext, WorkflowSharedContext } from "@restatedev/restate-sdk";
+import { z } from "zod";
+
+// Input of one {{title}} run; the workflow id in the URL identifies the run
+export const {{Name}}InputSchema = z.object({
+  // TODO: the data the steps need
+  subject: z.string(),
+});
+export type {{Name}}Input = z.infer<typeof {{Name}}InputSchema>;
+
+export type {{Name}}Step = "pending" | "prepare" | "execute" | "finalize" | "done";
+
+// Retry configuration for the steps; after the last attempt the run fails
+const retryConfig = {
+  initialRetryInterval: { milliseconds: 500 },
+  retryIntervalFactor: 2,
+  maxRetryAttempts: 5,
+};
+
+// TODO: replace the step bodies. Each runs once per workflow id: after a crash or a
+// deploy, Restate replays finished steps from the journal and resumes at the next one.
+async function prepare(input: {{Name}}Input) {
+  return { subject: input.subject };
+}
+
+async function execute(prepared: Awaited<ReturnType<typeof prepare>>) {
+  return { ...prepared, executed: true };
+}
+
+async function finalize(executed: Awaited<ReturnType<typeof execute>>) {
+  return { ...executed, finalized: true };
+}
+
+// Sequential steps: each one's result is the next one's input
+export const {{Name}}Workflow = restate.workflow({
+  name: "{{Name}}Workflow",
+  handlers: {
+    run: async (ctx: WorkflowContext, input: {{Name}}Input) => {
+      const parsed = {{Name}}InputSchema.safeParse(input);
+      if (!parsed.success) {
+        // Retrying won't fix the input
+        throw new restate.TerminalError(`Invalid input: ${parsed.error.message}`);
+      }
+
+      ctx.set<{{Name}}Step>("step", "prepare");
+      const prepared = await ctx.run("prepare", () => prepare(parsed.data), retryConfig);
+
+      ctx.set<{{Name}}Step>("step", "execute");
+      const executed = await ctx.run("execute", () => execute(prepared), retryConfig);
+
+      ctx.set<{{Name}}Step>("step", "finalize");
+      const result = await ctx.run("finalize", () => finalize(executed), retryConfig);
+
+      ctx.set<{{Name}}Step>("step", "done");
+      ctx.console.info({ workflow: "{{Name}}Workflow", id: ctx.key, step: "done" });
+      return result;
+    },
+
+    // Shared handlers run alongside `run`, e.g. to poll progress
+    status: async (ctx: WorkflowSharedContext) => {
+      return (await ctx.get<{{Name}}Step>("step")) ?? "pending";
+    },
+  },
+});
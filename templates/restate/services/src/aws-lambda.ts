@@ -1,6 +1,5 @@
 import * as restate from "@restatedev/restate-sdk";
 import { Context } from "@restatedev/restate-sdk";
-import { z } from "zod";
 import {
   LambdaClient,
   InvokeCommand,
@@ -8,6 +7,8 @@ import {
   GetFunctionCommand,
   ListFunctionsCommand,
 } from "@aws-sdk/client-lambda";
+import { InvokeFunctionInputSchema, InvokeAsyncInputSchema, GetFunctionInputSchema } from "./contracts";
+import type { InvokeFunctionInput, InvokeAsyncInput, GetFunctionInput } from "./contracts";
 
 // Configuration
 const config = {
@@ -26,27 +27,6 @@ const retryConfig = {
   maxRetryDuration: { minutes: 1 },
 };
 
-// Schemas
-const InvokeFunctionInputSchema = z.object({
-  functionName: z.string(),
-  payload: z.any(),
-  invocationType: z.enum(["RequestResponse", "Event", "DryRun"]).optional().default("RequestResponse"),
-  logType: z.enum(["None", "Tail"]).optional().default("None"),
-});
-
-const InvokeAsyncInputSchema = z.object({
-  functionName: z.string(),
-  payload: z.any(),
-});
-
-const GetFunctionInputSchema = z.object({
-  functionName: z.string(),
-});
-
-type InvokeFunctionInput = z.infer<typeof InvokeFunctionInputSchema>;
-type InvokeAsyncInput = z.infer<typeof InvokeAsyncInputSchema>;
-type GetFunctionInput = z.infer<typeof GetFunctionInputSchema>;
-
 // Service
 export const AWSLambdaService = restate.service({
   name: "AWSLambdaService",
@@ -1,6 +1,5 @@
 import * as restate from "@restatedev/restate-sdk";
 import { Context } from "@restatedev/restate-sdk";
-import { z } from "zod";
 import {
   S3Client,
   GetObjectCommand,
@@ -12,6 +11,24 @@ import {
 import { getSignedUrl } from "@aws-sdk/s3-request-presigner";
 import * as fs from "fs";
 import * as path from "path";
+import {
+  GetPresignedUrlInputSchema,
+  UploadFileInputSchema,
+  UploadBufferInputSchema,
+  DownloadFileInputSchema,
+  DownloadBufferInputSchema,
+  DeleteFileInputSchema,
+  ListFilesInputSchema,
+} from "./contracts";
+import type {
+  GetPresignedUrlInput,
+  UploadFileInput,
+  UploadBufferInput,
+  DownloadFileInput,
+  DownloadBufferInput,
+  DeleteFileInput,
+  ListFilesInput,
+} from "./contracts";
 
 // Configuration
 const config = {
@@ -32,59 +49,6 @@ const retryConfig = {
   maxRetryDuration: { seconds: 30 },
 };
 
-// Schemas
-const GetPresignedUrlInputSchema = z.object({
-  key: z.string(),
-  bucket: z.string().optional(),
-  expiresIn: z.number().optional(),
-});
-
-const UploadFileInputSchema = z.object({
-  key: z.string(),
-  filePath: z.string(),
-  bucket: z.string().optional(),
-  contentType: z.string().optional(),
-  metadata: z.record(z.string()).optional(),
-});
-
-const UploadBufferInputSchema = z.object({
-  key: z.string(),
-  content: z.string(), // base64
-  bucket: z.string().optional(),
-  contentType: z.string().optional(),
-  metadata: z.record(z.string()).optional(),
-});
-
-const DownloadFileInputSchema = z.object({
-  key: z.string(),
-  bucket: z.string().optional(),
-  destination: z.string(),
-});
-
-const DownloadBufferInputSchema = z.object({
-  key: z.string(),
-  bucket: z.string().optional(),
-});
-
-const DeleteFileInputSchema = z.object({
-  key: z.string(),
-  bucket: z.string().optional(),
-});
-
-const ListFilesInputSchema = z.object({
-  prefix: z.string().optional(),
-  bucket: z.string().optional(),
-  maxKeys: z.number().optional().default(1000),
-});
-
-type GetPresignedUrlInput = z.infer<typeof GetPresignedUrlInputSchema>;
-type UploadFileInput = z.infer<typeof UploadFileInputSchema>;
-type UploadBufferInput = z.infer<typeof UploadBufferInputSchema>;
-type DownloadFileInput = z.infer<typeof DownloadFileInputSchema>;
-type DownloadBufferInput = z.infer<typeof DownloadBufferInputSchema>;
-type DeleteFileInput = z.infer<typeof DeleteFileInputSchema>;
-type ListFilesInput = z.infer<typeof ListFilesInputSchema>;
-
 // Service
 export const AWSS3Service = restate.service({
   name: "AWSS3Service",
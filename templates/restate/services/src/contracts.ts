@@ -0,0 +1,259 @@
+import { z } from "zod";
+
+// Shared service contracts
+//
+// These schemas are the single source of truth for service inputs and outputs.
+// The services validate against them, and the Next.js app imports them through
+// the `@restate/contracts` path alias to call Restate with end-to-end type safety.
+//
+// Only use APIs that behave the same in zod 3 and zod 4, since the app and the
+// services may resolve different zod versions.
+
+// ============================================================================
+// EmbeddingService
+// ============================================================================
+
+export const GenerateEmbeddingsInputSchema = z.object({
+  texts: z.array(z.string()).min(1).max(100),
+  model: z.string().optional(),
+  normalize: z.boolean().optional().default(true),
+  truncate: z.boolean().optional().default(true),
+});
+
+export const GenerateEmbeddingsOutputSchema = z.object({
+  embeddings: z.array(z.array(z.number())),
+  model: z.string(),
+  dimensions: z.number(),
+  processingTime: z.number(),
+});
+
+export const GenerateEmbeddingInputSchema = z.object({
+  text: z.string(),
+  model: z.string().optional(),
+  normalize: z.boolean().optional().default(true),
+  truncate: z.boolean().optional().default(true),
+});
+
+export const GenerateEmbeddingOutputSchema = z.object({
+  embedding: z.array(z.number()),
+  model: z.string(),
+  dimensions: z.number(),
+  processingTime: z.number(),
+});
+
+// ============================================================================
+// ExtractionService
+// ============================================================================
+
+export const DocumentFormatSchema = z.enum(["markdown", "json", "html", "text", "doctran"]);
+
+export const ExtractDocumentInputSchema = z.object({
+  url: z.string(),
+  format: DocumentFormatSchema.optional().default("markdown"),
+  extractImages: z.boolean().optional().default(true),
+  extractTables: z.boolean().optional().default(true),
+  ocrEnabled: z.boolean().optional().default(false),
+});
+
+export const ExtractFromBufferInputSchema = z.object({
+  content: z.string(), // base64
+  filename: z.string(),
+  format: DocumentFormatSchema.optional().default("markdown"),
+  extractImages: z.boolean().optional().default(true),
+  extractTables: z.boolean().optional().default(true),
+  ocrEnabled: z.boolean().optional().default(false),
+});
+
+export const ExtractionResultSchema = z.object({
+  content: z.string(),
+  format: z.string(),
+  metadata: z.object({
+    pageCount: z.number().optional(),
+    images: z
+      .array(
+        z.object({
+          index: z.number(),
+          width: z.number().optional(),
+          height: z.number().optional(),
+          format: z.string().optional(),
+          data: z.string().optional(),
+        }),
+      )
+      .optional(),
+    tables: z
+      .array(
+        z.object({
+          index: z.number(),
+          rows: z.number().optional(),
+          columns: z.number().optional(),
+          content: z.any(),
+        }),
+      )
+      .optional(),
+    language: z.string().optional(),
+    author: z.string().optional(),
+    title: z.string().optional(),
+  }),
+  processingTime: z.number(),
+});
+
+// ============================================================================
+// AWSS3Service
+// ============================================================================
+
+export const GetPresignedUrlInputSchema = z.object({
+  key: z.string(),
+  bucket: z.string().optional(),
+  expiresIn: z.number().optional(),
+});
+
+export const PresignedUrlOutputSchema = z.object({
+  url: z.string(),
+  expiresIn: z.number(),
+});
+
+export const UploadFileInputSchema = z.object({
+  key: z.string(),
+  filePath: z.string(),
+  bucket: z.string().optional(),
+  contentType: z.string().optional(),
+  metadata: z.record(z.string(), z.string()).optional(),
+});
+
+export const UploadBufferInputSchema = z.object({
+  key: z.string(),
+  content: z.string(), // base64
+  bucket: z.string().optional(),
+  contentType: z.string().optional(),
+  metadata: z.record(z.string(), z.string()).optional(),
+});
+
+export const UploadOutputSchema = z.object({
+  key: z.string(),
+  bucket: z.string(),
+  size: z.number(),
+});
+
+export const DownloadFileInputSchema = z.object({
+  key: z.string(),
+  bucket: z.string().optional(),
+  destination: z.string(),
+});
+
+export const DownloadBufferInputSchema = z.object({
+  key: z.string(),
+  bucket: z.string().optional(),
+});
+
+export const DownloadBufferOutputSchema = z.object({
+  content: z.string(), // base64
+  size: z.number(),
+});
+
+export const DeleteFileInputSchema = z.object({
+  key: z.string(),
+  bucket: z.string().optional(),
+});
+
+export const DeleteFileOutputSchema = z.object({
+  key: z.string(),
+  deleted: z.boolean(),
+});
+
+export const ListFilesInputSchema = z.object({
+  prefix: z.string().optional(),
+  bucket: z.string().optional(),
+  maxKeys: z.number().optional().default(1000),
+});
+
+export const ListFilesOutputSchema = z.object({
+  files: z.array(
+    z.object({
+      key: z.string(),
+      size: z.number(),
+      lastModified: z.string(),
+    }),
+  ),
+  count: z.number(),
+});
+
+// ============================================================================
+// AWSLambdaService
+// ============================================================================
+
+export const InvokeFunctionInputSchema = z.object({
+  functionName: z.string(),
+  payload: z.any(),
+  invocationType: z.enum(["RequestResponse", "Event", "DryRun"]).optional().default("RequestResponse"),
+  logType: z.enum(["None", "Tail"]).optional().default("None"),
+});
+
+export const InvokeAsyncInputSchema = z.object({
+  functionName: z.string(),
+  payload: z.any(),
+});
+
+export const InvokeAsyncOutputSchema = z.object({
+  invoked: z.boolean(),
+  functionName: z.string(),
+});
+
+export const GetFunctionInputSchema = z.object({
+  functionName: z.string(),
+});
+
+// ============================================================================
+// Contract registry
+// ============================================================================
+
+export const restateContracts = {
+  EmbeddingService: {
+    generateEmbeddings: { input: GenerateEmbeddingsInputSchema, output: GenerateEmbeddingsOutputSchema },
+    generateEmbedding: { input: GenerateEmbeddingInputSchema, output: GenerateEmbeddingOutputSchema },
+  },
+  ExtractionService: {
+    extractDocument: { input: ExtractDocumentInputSchema, output: ExtractionResultSchema },
+    extractFromBuffer: { input: ExtractFromBufferInputSchema, output: ExtractionResultSchema },
+  },
+  AWSS3Service: {
+    getPresignedUrl: { input: GetPresignedUrlInputSchema, output: PresignedUrlOutputSchema },
+    getUploadPresignedUrl: { input: GetPresignedUrlInputSchema, output: PresignedUrlOutputSchema },
+    uploadBuffer: { input: UploadBufferInputSchema, output: UploadOutputSchema },
+    downloadFileToBuffer: { input: DownloadBufferInputSchema, output: DownloadBufferOutputSchema },
+    deleteFile: { input: DeleteFileInputSchema, output: DeleteFileOutputSchema },
+    listFiles: { input: ListFilesInputSchema, output: ListFilesOutputSchema },
+  },
+  AWSLambdaService: {
+    invokeAsync: { input: InvokeAsyncInputSchema, output: InvokeAsyncOutputSchema },
+  },
+} as const;
+
+export type RestateContracts = typeof restateContracts;
+export type RestateService = keyof RestateContracts;
+export type RestateHandler<S extends RestateService> = keyof RestateContracts[S] & string;
+
+type ContractOf<S extends RestateService, H extends RestateHandler<S>> = RestateContracts[S][H] extends {
+  input: z.ZodTypeAny;
+  output: z.ZodTypeAny;
+}
+  ? RestateContracts[S][H]
+  : never;
+
+export type RestateInput<S extends RestateService, H extends RestateHandler<S>> = z.input<ContractOf<S, H>["input"]>;
+export type RestateOutput<S extends RestateService, H extends RestateHandler<S>> = z.output<ContractOf<S, H>["output"]>;
+
+// Input types used by the service implementations
+export type GenerateEmbeddingsInput = z.infer<typeof GenerateEmbeddingsInputSchema>;
+export type GenerateEmbeddingInput = z.infer<typeof GenerateEmbeddingInputSchema>;
+export type ExtractDocumentInput = z.infer<typeof ExtractDocumentInputSchema>;
+export type ExtractFromBufferInput = z.infer<typeof ExtractFromBufferInputSchema>;
+export type GetPresignedUrlInput = z.infer<typeof GetPresignedUrlInputSchema>;
+export type UploadFileInput = z.infer<typeof UploadFileInputSchema>;
+export type UploadBufferInput = z.infer<typeof UploadBufferInputSchema>;
+export type DownloadFileInput = z.infer<typeof DownloadFileInputSchema>;
+export type DownloadBufferInput = z.infer<typeof DownloadBufferInputSchema>;
+export type DeleteFileInput = z.infer<typeof DeleteFileInputSchema>;
+export type ListFilesInput = z.infer<typeof ListFilesInputSchema>;
+export type InvokeFunctionInput = z.infer<typeof InvokeFunctionInputSchema>;
+export type InvokeAsyncInput = z.infer<typeof InvokeAsyncInputSchema>;
+export type GetFunctionInput = z.infer<typeof GetFunctionInputSchema>;
@@ -1,7 +1,8 @@
 import * as restate from "@restatedev/restate-sdk";
 import { Context } from "@restatedev/restate-sdk";
-import { z } from "zod";
 import ollama from "ollama";
+import { GenerateEmbeddingsInputSchema, GenerateEmbeddingInputSchema } from "./contracts";
+import type { GenerateEmbeddingsInput, GenerateEmbeddingInput } from "./contracts";
 
 // Configuration
 const config = {
@@ -19,24 +20,6 @@ const retryConfig = {
   maxRetryDuration: { seconds: 30 },
 };
 
-// Schemas
-const GenerateEmbeddingsInputSchema = z.object({
-  texts: z.array(z.string()).min(1).max(100),
-  model: z.string().optional(),
-  normalize: z.boolean().optional().default(true),
-  truncate: z.boolean().optional().default(true),
-});
-
-const GenerateEmbeddingInputSchema = z.object({
-  text: z.string(),
-  model: z.string().optional(),
-  normalize: z.boolean().optional().default(true),
-  truncate: z.boolean().optional().default(true),
-});
-
-type GenerateEmbeddingsInput = z.infer<typeof GenerateEmbeddingsInputSchema>;
-type GenerateEmbeddingInput = z.infer<typeof GenerateEmbeddingInputSchema>;
-
 // Utilities
 function normalizeVector(vector: number[]): number[] {
   const magnitude = Math.sqrt(vector.reduce((sum, val) => sum + val * val, 0));
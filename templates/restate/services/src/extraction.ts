@@ -1,7 +1,8 @@
 import * as restate from "@restatedev/restate-sdk";
 import { Context } from "@restatedev/restate-sdk";
-import { z } from "zod";
 import axios from "axios";
+import { ExtractDocumentInputSchema, ExtractFromBufferInputSchema } from "./contracts";
+import type { ExtractDocumentInput, ExtractFromBufferInput } from "./contracts";
 
 // Configuration
 const config = {
@@ -20,29 +21,6 @@ const retryConfig = {
   maxRetryDuration: { minutes: 5 },
 };
 
-// Schemas
-const DocumentFormatSchema = z.enum(["markdown", "json", "html", "text", "doctran"]);
-
-const ExtractDocumentInputSchema = z.object({
-  url: z.string(),
-  format: DocumentFormatSchema.optional().default("markdown"),
-  extractImages: z.boolean().optional().default(true),
-  extractTables: z.boolean().optional().default(true),
-  ocrEnabled: z.boolean().optional().default(false),
-});
-
-const ExtractFromBufferInputSchema = z.object({
-  content: z.string(),
-  filename: z.string(),
-  format: DocumentFormatSchema.optional().default("markdown"),
-  extractImages: z.boolean().optional().default(true),
-  extractTables: z.boolean().optional().default(true),
-  ocrEnabled: z.boolean().optional().default(false),
-});
-
-type ExtractDocumentInput = z.infer<typeof ExtractDocumentInputSchema>;
-type ExtractFromBufferInput = z.infer<typeof ExtractFromBufferInputSchema>;
-
 // Docling Client
 class DoclingClient {
   private baseUrl: string;
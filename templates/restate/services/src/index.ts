@@ -7,6 +7,9 @@ import { AWSLambdaService } from "./aws-lambda";
 // Export services for use in workflows
 export { EmbeddingService, ExtractionService, AWSS3Service, AWSLambdaService };
 
+// Export the shared input/output contracts
+export * from "./contracts";
+
 // Start the server if run directly
 if (require.main === module) {
   const port = parseInt(process.env.PORT || "9082");
@@ -0,0 +1,53 @@
+"use client";
+
+import { useRouter } from "next/navigation";
+import { useTranslations } from "next-intl";
+import { useEffect, useState } from "react";
+
+import { useCommandIsland } from "@/lib/command-island-context";
+import { SearchResults } from "./SearchPalette";
+import { useSearch } from "./useSearch";
+
+/**
+ * Makes the CommandIsland's query mode search the app, with the hits shown above the
+ * island. A page that consumes the query itself takes over until it unmounts.
+ */
+export function IslandSearch() {
+  const t = useTranslations("search");
+  const router = useRouter();
+  const { query, setQuery, hasQueryConsumer, setHasQueryConsumer, placeholder, setPlaceholder } = useCommandIsland();
+  const [active, setActive] = useState(0);
+  const ownPlaceholder = t("placeholder");
+
+  // Claim query mode whenever no page does
+  useEffect(() => {
+    if (!hasQueryConsumer) {
+      setHasQueryConsumer(true);
+      setPlaceholder(ownPlaceholder);
+    }
+  }, [hasQueryConsumer, ownPlaceholder, setHasQueryConsumer, setPlaceholder]);
+
+  const searching = placeholder === ownPlaceholder && query.trim().length > 0;
+  const { hits, isFetching, error } = useSearch(searching ? query : "");
+  if (!searching) {
+    return null;
+  }
+
+  const items = hits.map((hit) => ({ key: `${hit.index}:${hit.id}`, ...hit, kind: "hit" as const }));
+  return (
+    <div className="fixed bottom-28 inset-x-0 z-70 px-4 sm:px-6 pointer-events-none">
+      <div className="pointer-events-auto mx-auto max-w-2xl overflow-hidden rounded-xl border border-border bg-card shadow-lg">
+        <SearchResults
+          items={items}
+          selected={Math.min(active, items.length - 1)}
+          onHover={setActive}
+          onChoose={(item) => {
+            setQuery("");
+            router.push(item.href);
+          }}
+          status={error ? error.message : items.length === 0 ? (isFetching ? t("searching") : t("empty")) : null}
+        />
+      </div>
+    </div>
+  );
+}
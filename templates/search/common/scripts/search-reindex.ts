@@ -0,0 +1,10 @@
+import { db } from "../src/server/db";
+import { reindex } from "../src/server/search/reindex";
+
+// Usage: npm run search:reindex [-- <index>...]
+const names = process.argv.slice(2);
+const counts = await reindex(names.length > 0 ? names : undefined);
+for (const [name, count] of Object.entries(counts)) {
+  console.log(`${name}: ${count} documents`);
+}
+await db.$disconnect();
@@ -0,0 +1,184 @@
+"use client";
+
+import { FileTextIcon, LinkIcon, SearchIcon } from "lucide-react";
+import { useRouter } from "next/navigation";
+import { useTranslations } from "next-intl";
+import { type KeyboardEvent as ReactKeyboardEvent, useEffect, useRef, useState } from "react";
+
+import { useSearch } from "./useSearch";
+
+export interface PaletteItem {
+  key: string;
+  href: string;
+  title: string;
+  description?: string;
+  kind: "page" | "hit";
+}
+
+/**
+ * A search button that opens a command palette, also on ⌘K / Ctrl+K. It jumps to the
+ * Header's pages by name and to hits of the `search` router.
+ */
+export function SearchPalette({ navItems = [] }: { navItems?: { href: string; labelKey: string }[] }) {
+  const t = useTranslations("search");
+  const tNav = useTranslations("nav");
+  const [open, setOpen] = useState(false);
+
+  useEffect(() => {
+    function handleKeyDown(event: KeyboardEvent) {
+      if ((event.metaKey || event.ctrlKey) && event.key.toLowerCase() === "k") {
+        event.preventDefault();
+        setOpen((current) => !current);
+      }
+    }
+    document.addEventListener("keydown", handleKeyDown);
+    return () => document.removeEventListener("keydown", handleKeyDown);
+  }, []);
+
+  const pages = navItems.map((item) => ({ href: item.href, title: tNav(item.labelKey) }));
+
+  return (
+    <>
+      <button
+        type="button"
+        onClick={() => setOpen(true)}
+        className="flex items-center gap-2 p-2 rounded-lg text-muted-foreground hover:text-primary hover:bg-muted cursor-pointer transition-colors"
+        aria-label={t("open")}
+      >
+        <SearchIcon className="w-5 h-5" />
+        <kbd className="hidden md:inline text-xs font-sans">{t("shortcut")}</kbd>
+      </button>
+      {open && <Palette pages={pages} onClose={() => setOpen(false)} />}
+    </>
+  );
+}
+
+function Palette({ pages, onClose }: { pages: { href: string; title: string }[]; onClose: () => void }) {
+  const t = useTranslations("search");
+  const router = useRouter();
+  const inputRef = useRef<HTMLInputElement>(null);
+  const [query, setQuery] = useState("");
+  const [active, setActive] = useState(0);
+  const { hits, isFetching, error } = useSearch(query);
+
+  useEffect(() => inputRef.current?.focus(), []);
+
+  const needle = query.trim().toLowerCase();
+  const items: PaletteItem[] = [
+    ...pages
+      .filter((page) => needle && page.title.toLowerCase().includes(needle))
+      .map((page): PaletteItem => ({ key: `page:${page.href}`, href: page.href, title: page.title, kind: "page" })),
+    ...hits.map((hit): PaletteItem => ({ key: `${hit.index}:${hit.id}`, ...hit, kind: "hit" })),
+  ];
+  const selected = Math.min(active, Math.max(items.length - 1, 0));
+
+  function choose(item: PaletteItem | undefined) {
+    if (item) {
+      onClose();
+      router.push(item.href);
+    }
+  }
+
+  function handleKeyDown(event: ReactKeyboardEvent) {
+    if (event.key === "ArrowDown" || event.key === "ArrowUp") {
+      event.preventDefault();
+      const step = event.key === "ArrowDown" ? 1 : -1;
+      setActive((selected + step + items.length) % Math.max(items.length, 1));
+    } else if (event.key === "Enter") {
+      event.preventDefault();
+      choose(items[selected]);
+    } else if (event.key === "Escape") {
+      onClose();
+    }
+  }
+
+  return (
+    <div className="fixed inset-0 z-50 flex items-start justify-center bg-black/40 px-4 pt-[15vh]" onMouseDown={onClose}>
+      <div
+        role="dialog"
+        aria-modal="true"
+        aria-label={t("open")}
+        className="w-full max-w-xl overflow-hidden rounded-xl border border-border bg-card shadow-lg"
+        onMouseDown={(event) => event.stopPropagation()}
+      >
+        <div className="flex items-center gap-3 border-b border-border px-4">
+          <SearchIcon className="w-5 h-5 shrink-0 text-muted-foreground" />
+          <input
+            ref={inputRef}
+            value={query}
+            onChange={(event) => {
+              setQuery(event.target.value);
+              setActive(0);
+            }}
+            onKeyDown={handleKeyDown}
+            placeholder={t("placeholder")}
+            role="combobox"
+            aria-expanded={items.length > 0}
+            aria-controls="search-palette-results"
+            aria-activedescendant={items[selected] ? `search-palette-${selected}` : undefined}
+            className="h-12 flex-1 bg-transparent text-sm outline-none"
+          />
+          <kbd className="text-xs text-muted-foreground">Esc</kbd>
+        </div>
+
+        <SearchResults
+          items={items}
+          selected={selected}
+          onHover={setActive}
+          onChoose={choose}
+          status={
+            error ? error.message : !needle ? t("hint") : items.length === 0 ? (isFetching ? t("searching") : t("empty")) : null
+          }
+        />
+      </div>
+    </div>
+  );
+}
+
+export function SearchResults({
+  items,
+  selected,
+  onHover,
+  onChoose,
+  status,
+}: {
+  items: PaletteItem[];
+  selected: number;
+  onHover: (index: number) => void;
+  onChoose: (item: PaletteItem) => void;
+  status: string | null;
+}) {
+  if (status) {
+    return <p className="px-4 py-6 text-center text-sm text-muted-foreground">{status}</p>;
+  }
+
+  return (
+    <ul id="search-palette-results" role="listbox" className="max-h-80 overflow-y-auto p-2">
+      {items.map((item, i) => {
+        const Icon = item.kind === "page" ? LinkIcon : FileTextIcon;
+        return (
+          <li
+            key={item.key}
+            id={`search-palette-${i}`}
+            role="option"
+            aria-selected={i === selected}
+            onMouseEnter={() => onHover(i)}
+            onMouseDown={(event) => {
+              event.preventDefault();
+              onChoose(item);
+            }}
+            className={`flex cursor-pointer items-start gap-3 rounded-lg px-3 py-2 text-sm ${
+              i === selected ? "bg-muted text-primary" : "text-foreground"
+            }`}
+          >
+            <Icon className="mt-0.5 w-4 h-4 shrink-0 text-muted-foreground" />
+            <div className="min-w-0">
+              <p className="truncate font-medium">{item.title}</p>
+              {item.description && <p className="truncate text-xs text-muted-foreground">{item.description}</p>}
+            </div>
+          </li>
+        );
+      })}
+    </ul>
+  );
+}
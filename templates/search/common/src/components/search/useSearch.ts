@@ -0,0 +1,26 @@
+"use client";
+
+import { keepPreviousData } from "@tanstack/react-query";
+import { useEffect, useState } from "react";
+
+import { api } from "@/trpc/react";
+
+/** Hits for `query`, fetched once typing pauses */
+export function useSearch(query: string, delay = 200) {
+  const [debounced, setDebounced] = useState(query.trim());
+  useEffect(() => {
+    const timer = setTimeout(() => setDebounced(query.trim()), delay);
+    return () => clearTimeout(timer);
+  }, [query, delay]);
+
+  const result = api.search.find.useQuery(
+    { q: debounced },
+    // Keep the previous hits on screen while the next ones load
+    { enabled: debounced.length > 0, placeholderData: keepPreviousData },
+  );
+  return {
+    hits: debounced ? (result.data ?? []) : [],
+    isFetching: result.isFetching,
+    error: result.error,
+  };
+}
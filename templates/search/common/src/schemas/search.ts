@@ -0,0 +1,12 @@
+import { z } from "zod";
+
+// Input of the `search` router, shared with the command palette
+
+export const searchQuerySchema = z.object({
+  q: z.string().trim().min(1).max(200),
+  /** Only search these indexes; every index by default */
+  indexes: z.array(z.string()).optional(),
+  limit: z.number().int().min(1).max(50).default(8),
+});
+
+export type SearchQuery = z.input<typeof searchQuerySchema>;
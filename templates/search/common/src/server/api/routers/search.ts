@@ -0,0 +1,14 @@
+import { searchQuerySchema } from "@/schemas/search";
+import { createTRPCRouter, protectedProcedure } from "@/server/api/trpc";
+import { backend, searchIndexes } from "@/server/search";
+
+export const searchRouter = createTRPCRouter({
+  // Every signed-in user sees every hit; filter by `ctx` here before indexing private rows
+  find: protectedProcedure.input(searchQuerySchema).query(async ({ input }) => {
+    const indexes = (input.indexes ?? Object.keys(searchIndexes)).filter((name) => name in searchIndexes);
+    if (indexes.length === 0) {
+      return [];
+    }
+    return backend.search(input.q, indexes, input.limit);
+  }),
+});
@@ -0,0 +1,6 @@
+import "server-only";
+
+export { backend } from "./backend";
+export { searchIndexes } from "./indexes";
+export { reindex } from "./reindex";
+export type { SearchDocument, SearchHit, SearchIndex } from "./types";
@@ -0,0 +1,23 @@
+import { backend } from "./backend";
+import { searchIndexes } from "./indexes";
+
+const BATCH_SIZE = 500;
+
+/** Set up the indexes (all of them by default) and send every row to the backend; returns the document counts */
+export async function reindex(names = Object.keys(searchIndexes)) {
+  const counts: Record<string, number> = {};
+  for (const name of names) {
+    const index = searchIndexes[name];
+    if (!index) {
+      throw new Error(`Unknown search index "${name}"; the indexes are ${Object.keys(searchIndexes).join(", ") || "not defined yet"}`);
+    }
+
+    await backend.setup(name);
+    const documents = (await index.load()).map(index.toDocument);
+    for (let i = 0; i < documents.length; i += BATCH_SIZE) {
+      await backend.upsert(name, documents.slice(i, i + BATCH_SIZE));
+    }
+    counts[name] = documents.length;
+  }
+  return counts;
+}
@@ -0,0 +1,38 @@
+import type { Prisma } from "@prisma/client";
+
+/** What the backend stores for a row, and what the palette shows for a hit */
+export interface SearchDocument {
+  id: string;
+  title: string;
+  description?: string;
+  /** Where choosing the hit navigates to */
+  href: string;
+}
+
+export interface SearchHit extends SearchDocument {
+  /** Name of the index the hit came from */
+  index: string;
+}
+
+/** A Prisma model made searchable; register it in `indexes.ts` */
+export interface SearchIndex<Row = unknown> {
+  model: Prisma.ModelName;
+  /** Columns the Postgres backend matches; search engines match the document's title and description */
+  fields: string[];
+  /** Rows with these ids, or every row when reindexing */
+  load(ids?: string[]): Promise<Row[]>;
+  toDocument(row: Row): SearchDocument;
+}
+
+export function defineIndex<Row>(index: SearchIndex<Row>): SearchIndex<Row> {
+  return index;
+}
+
+/** What every backend implements; see `backend.ts` */
+export interface SearchBackend {
+  search(query: string, indexes: string[], limit: number): Promise<SearchHit[]>;
+  /** Create the index or whatever the backend needs to search it */
+  setup(index: string): Promise<void>;
+  upsert(index: string, documents: SearchDocument[]): Promise<void>;
+  remove(index: string, ids: string[]): Promise<void>;
+}
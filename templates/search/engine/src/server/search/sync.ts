@@ -0,0 +1,70 @@
+import { Prisma } from "@prisma/client";
+import { backend } from "./backend";
+import { searchIndexes } from "./indexes";
+
+const TRACKED = new Set([
+  "create",
+  "createManyAndReturn",
+  "update",
+  "updateMany",
+  "updateManyAndReturn",
+  "upsert",
+  "delete",
+  "deleteMany",
+]);
+
+type Row = { id?: unknown };
+type Delegate = { findMany(args: { where: unknown; select: { id: true } }): Promise<Row[]> };
+
+function idsOf(rows: Row | Row[]) {
+  return (Array.isArray(rows) ? rows : [rows]).flatMap((row) => (row?.id === undefined ? [] : [String(row.id)]));
+}
+
+/** Send the rows with `ids` to every index of `model`; rows `load` no longer returns are removed */
+async function sync(model: string, ids: string[], deleted: boolean) {
+  for (const [name, index] of Object.entries(searchIndexes)) {
+    if (index.model !== model || ids.length === 0) {
+      continue;
+    }
+    const documents = deleted ? [] : (await index.load(ids)).map(index.toDocument);
+    const kept = new Set(documents.map((document) => document.id));
+    await backend.upsert(name, documents);
+    await backend.remove(name, ids.filter((id) => !kept.has(id)));
+  }
+}
+
+/**
+ * Keeps the search indexes in step with writes through `db`. A failed sync is logged
+ * instead of failing the write; `npm run search:reindex` catches up. Writes that don't
+ * select `id` and `createMany` aren't tracked, so reindex after bulk imports.
+ */
+export const searchSync = Prisma.defineExtension((client) =>
+  client.$extends({
+    name: "searchSync",
+    query: {
+      $allModels: {
+        async $allOperations({ model, operation, args, query }) {
+          if (!TRACKED.has(operation) || !Object.values(searchIndexes).some((index) => index.model === model)) {
+            return query(args);
+          }
+
+          // Bulk writes don't return the rows, so look up which ones they touch first
+          let affected: string[] = [];
+          if (operation === "updateMany" || operation === "deleteMany") {
+            const delegate = (client as unknown as Record<string, Delegate>)[model.charAt(0).toLowerCase() + model.slice(1)];
+            affected = idsOf(await delegate.findMany({ where: (args as { where?: unknown }).where, select: { id: true } }));
+          }
+
+          const result = await query(args);
+          const ids = affected.length > 0 ? affected : idsOf(result as Row | Row[]);
+          try {
+            await sync(model, ids, operation === "delete" || operation === "deleteMany");
+          } catch (error) {
+            console.error(`[search] Failed to sync ${model} ${ids.join(", ")}:`, error);
+          }
+          return result;
+        },
+      },
+    },
+  }),
+);
@@ -0,0 +1,54 @@
+import { env } from "@/env";
+import type { SearchBackend, SearchDocument, SearchHit } from "./types";
+
+// Meilisearch over its REST API, so no client package is needed. Indexes are named after
+// the entries of `indexes.ts` and match the documents' title and description.
+
+async function meilisearch<T>(path: string, init: { method: string; body?: unknown }): Promise<T> {
+  const response = await fetch(new URL(path, env.MEILISEARCH_HOST), {
+    method: init.method,
+    headers: {
+      "Content-Type": "application/json",
+      ...(env.MEILISEARCH_API_KEY ? { Authorization: `Bearer ${env.MEILISEARCH_API_KEY}` } : {}),
+    },
+    body: init.body === undefined ? undefined : JSON.stringify(init.body),
+  });
+  if (!response.ok) {
+    throw new Error(`Meilisearch ${init.method} ${path} failed with ${response.status}: ${await response.text()}`);
+  }
+  return response.json() as Promise<T>;
+}
+
+type FederatedHit = SearchDocument & { _federation: { indexUid: string } };
+
+export const backend: SearchBackend = {
+  async search(query, indexes, limit) {
+    // One federated search ranks the hits of every index against each other
+    const { hits } = await meilisearch<{ hits: FederatedHit[] }>("/multi-search", {
+      method: "POST",
+      body: { federation: { limit }, queries: indexes.map((indexUid) => ({ indexUid, q: query })) },
+    });
+    return hits.map(({ _federation, ...document }): SearchHit => ({ ...document, index: _federation.indexUid }));
+  },
+
+  // Both requests are queued as tasks; creating an index that exists fails only its own task
+  async setup(name) {
+    await meilisearch("/indexes", { method: "POST", body: { uid: name, primaryKey: "id" } });
+    await meilisearch(`/indexes/${name}/settings`, {
+      method: "PATCH",
+      body: { searchableAttributes: ["title", "description"] },
+    });
+  },
+
+  async upsert(name, documents) {
+    if (documents.length > 0) {
+      await meilisearch(`/indexes/${name}/documents?primaryKey=id`, { method: "POST", body: documents });
+    }
+  },
+
+  async remove(name, ids) {
+    if (ids.length > 0) {
+      await meilisearch(`/indexes/${name}/documents/delete-batch`, { method: "POST", body: ids });
+    }
+  },
+};
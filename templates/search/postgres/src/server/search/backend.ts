@@ -0,0 +1,66 @@
+import { Prisma } from "@prisma/client";
+import { db } from "@/server/db";
+import { searchIndexes } from "./indexes";
+import type { SearchBackend, SearchHit } from "./types";
+
+// Full-text search in the app's own database: the tables are the index, so there is
+// nothing to sync. `setup` adds a GIN index per search index to keep matching fast.
+// Tables are looked up by model name; models with `@@map` need their table name here.
+
+/** The tsvector queries match and the GIN index covers; the two must stay identical */
+function vector(fields: string[]) {
+  const text = fields.map((field) => `coalesce("${field}"::text, '')`).join(" || ' ' || ");
+  return `to_tsvector('simple', ${text})`;
+}
+
+/** Every word as a prefix, so hits show up while typing */
+function toTsQuery(query: string) {
+  return query
+    .split(/[^\p{L}\p{N}]+/u)
+    .filter(Boolean)
+    .map((word) => `${word}:*`)
+    .join(" & ");
+}
+
+async function searchIndex(name: string, tsquery: string, limit: number) {
+  const index = searchIndexes[name];
+  const matches = await db.$queryRaw<{ id: unknown; rank: number }[]>`
+    SELECT id, ts_rank(${Prisma.raw(vector(index.fields))}, q) AS rank
+    FROM ${Prisma.raw(`"${index.model}"`)}, to_tsquery('simple', ${tsquery}) q
+    WHERE ${Prisma.raw(vector(index.fields))} @@ q
+    ORDER BY rank DESC
+    LIMIT ${limit}`;
+
+  const rows = await index.load(matches.map((match) => String(match.id)));
+  const documents = new Map(rows.map(index.toDocument).map((document) => [document.id, document]));
+  return matches.flatMap(({ id, rank }) => {
+    const document = documents.get(String(id));
+    return document ? [{ hit: { ...document, index: name } satisfies SearchHit, rank }] : [];
+  });
+}
+
+export const backend: SearchBackend = {
+  async search(query, indexes, limit) {
+    const tsquery = toTsQuery(query);
+    if (!tsquery) {
+      return [];
+    }
+    const results = await Promise.all(indexes.map((name) => searchIndex(name, tsquery, limit)));
+    return results
+      .flat()
+      .sort((a, b) => b.rank - a.rank)
+      .slice(0, limit)
+      .map(({ hit }) => hit);
+  },
+
+  // Drop the index by hand after changing `fields`; IF NOT EXISTS keeps the old one
+  async setup(name) {
+    const index = searchIndexes[name];
+    await db.$executeRawUnsafe(
+      `CREATE INDEX IF NOT EXISTS "${index.model}_${name}_search_idx" ON "${index.model}" USING GIN (${vector(index.fields)})`,
+    );
+  },
+
+  async upsert() {},
+  async remove() {},
+};
@@ -0,0 +1,103 @@
+import { env } from "@/env";
+import type { SearchBackend, SearchDocument, SearchHit } from "./types";
+
+// Typesense over its REST API, so no client package is needed. Collections are named after
+// the entries of `indexes.ts`; only title and description are indexed, `href` is just stored.
+
+function typesense(path: string, init: { method: string; body?: string; contentType?: string }) {
+  return fetch(new URL(path, env.TYPESENSE_URL), {
+    method: init.method,
+    headers: {
+      "Content-Type": init.contentType ?? "application/json",
+      "X-TYPESENSE-API-KEY": env.TYPESENSE_API_KEY,
+    },
+    body: init.body,
+  });
+}
+
+async function expectOk(response: Response, what: string) {
+  if (!response.ok) {
+    throw new Error(`Typesense ${what} failed with ${response.status}: ${await response.text()}`);
+  }
+  return response;
+}
+
+type SearchResult = { hits?: { document: SearchDocument; text_match: number }[]; error?: string };
+
+/** Matches exactly these ids; backticks keep ids with commas or brackets intact */
+function idFilter(ids: string[]) {
+  return `id:[${ids.map((id) => `\`${id}\``).join(",")}]`;
+}
+
+export const backend: SearchBackend = {
+  async search(query, indexes, limit) {
+    const response = await typesense("/multi_search", {
+      method: "POST",
+      body: JSON.stringify({
+        searches: indexes.map((collection) => ({
+          collection,
+          q: query,
+          query_by: "title,description",
+          prefix: true,
+          per_page: limit,
+        })),
+      }),
+    });
+    const { results } = (await (await expectOk(response, "search")).json()) as { results: SearchResult[] };
+
+    // text_match uses the same scale in every collection, so the hits merge by it
+    return results
+      .flatMap((result, i) => {
+        if (result.error) {
+          throw new Error(`Typesense search of "${indexes[i]}" failed: ${result.error}`);
+        }
+        return (result.hits ?? []).map((hit) => ({ hit: { ...hit.document, index: indexes[i] } satisfies SearchHit, score: hit.text_match }));
+      })
+      .sort((a, b) => b.score - a.score)
+      .slice(0, limit)
+      .map(({ hit }) => hit);
+  },
+
+  async setup(name) {
+    const response = await typesense("/collections", {
+      method: "POST",
+      body: JSON.stringify({
+        name,
+        fields: [
+          { name: "title", type: "string" },
+          { name: "description", type: "string", optional: true },
+        ],
+      }),
+    });
+    // 409: the collection exists already
+    if (response.status !== 409) {
+      await expectOk(response, `creating collection "${name}"`);
+    }
+  },
+
+  async upsert(name, documents) {
+    if (documents.length === 0) {
+      return;
+    }
+    const response = await typesense(`/collections/${name}/documents/import?action=upsert`, {
+      method: "POST",
+      body: documents.map((document) => JSON.stringify(document)).join("\n"),
+      contentType: "text/plain",
+    });
+    // The import answers 200 with one result line per document, failed ones included
+    const failures = (await (await expectOk(response, "import")).text())
+      .split("\n")
+      .filter((line) => line && !(JSON.parse(line) as { success: boolean }).success);
+    if (failures.length > 0) {
+      throw new Error(`Typesense rejected ${failures.length} of ${documents.length} documents: ${failures[0]}`);
+    }
+  },
+
+  async remove(name, ids) {
+    if (ids.length === 0) {
+      return;
+    }
+    const filter = encodeURIComponent(idFilter(ids));
+    await expectOk(await typesense(`/collections/${name}/documents?filter_by=${filter}`, { method: "DELETE" }), "delete");
+  },
+};
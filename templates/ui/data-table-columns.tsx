@@ -0,0 +1,79 @@
+/**
+ * DataTable column helpers - Sortable headers and a row actions column
+ * Row actions use the dropdown-menu component
+ */
+"use client"
+
+import * as React from "react"
+import type { Column, ColumnDef } from "@tanstack/react-table"
+import { ArrowDown, ArrowUp, ArrowUpDown, MoreHorizontal } from "lucide-react"
+
+import { Button } from "@/components/ui/button"
+import {
+  DropdownMenu,
+  DropdownMenuContent,
+  DropdownMenuItem,
+  DropdownMenuTrigger,
+} from "@/components/ui/dropdown-menu"
+
+/** Header that toggles the column's sort order; use as `header: sortableHeader("Name")` */
+function sortableHeader(title: string) {
+  return function SortableHeader<TData, TValue>({ column }: { column: Column<TData, TValue> }) {
+    const sorted = column.getIsSorted()
+    const Icon = sorted === "asc" ? ArrowUp : sorted === "desc" ? ArrowDown : ArrowUpDown
+
+    return (
+      <Button
+        variant="ghost"
+        size="sm"
+        className="-ml-2.5"
+        onClick={() => column.toggleSorting(sorted === "asc")}
+      >
+        {title}
+        <Icon className="text-muted-foreground" />
+      </Button>
+    )
+  }
+}
+
+interface RowAction<TData> {
+  label: string
+  onSelect: (row: TData) => void
+  icon?: React.ReactNode
+  variant?: "default" | "destructive"
+}
+
+/** Trailing column with a "..." menu of per-row actions */
+function rowActionsColumn<TData>(actions: RowAction<TData>[]): ColumnDef<TData> {
+  return {
+    id: "actions",
+    enableSorting: false,
+    enableGlobalFilter: false,
+    cell: ({ row }) => (
+      <div className="flex justify-end">
+        <DropdownMenu>
+          <DropdownMenuTrigger asChild>
+            <Button variant="ghost" size="icon-sm" aria-label="Row actions">
+              <MoreHorizontal />
+            </Button>
+          </DropdownMenuTrigger>
+          <DropdownMenuContent align="end">
+            {actions.map((action) => (
+              <DropdownMenuItem
+                key={action.label}
+                variant={action.variant}
+                onClick={() => action.onSelect(row.original)}
+              >
+                {action.icon}
+                {action.label}
+              </DropdownMenuItem>
+            ))}
+          </DropdownMenuContent>
+        </DropdownMenu>
+      </div>
+    ),
+  }
+}
+
+export { rowActionsColumn, sortableHeader }
+export type { RowAction }
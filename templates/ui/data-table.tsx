@@ -0,0 +1,225 @@
+/**
+ * DataTable component - Built on TanStack Table
+ * Sorting, global filtering, and pagination; client-side by default,
+ * server-side when `rowCount` is passed with controlled `state`
+ */
+"use client"
+
+import * as React from "react"
+import {
+  type ColumnDef,
+  type OnChangeFn,
+  type PaginationState,
+  type SortingState,
+  flexRender,
+  getCoreRowModel,
+  getFilteredRowModel,
+  getPaginationRowModel,
+  getSortedRowModel,
+  useReactTable,
+} from "@tanstack/react-table"
+import { ChevronLeft, ChevronRight } from "lucide-react"
+
+import { cn } from "@/utils/utils"
+import { Button } from "@/components/ui/button"
+import { Input } from "@/components/ui/input"
+import {
+  Table,
+  TableBody,
+  TableCell,
+  TableHead,
+  TableHeader,
+  TableRow,
+} from "@/components/ui/table"
+
+const PAGE_SIZES = [10, 20, 50]
+
+/** Table state owned by the caller, e.g. to send it to a tRPC query */
+function useDataTableState({ pageSize = PAGE_SIZES[0] ?? 10 }: { pageSize?: number } = {}) {
+  const [pagination, setPagination] = React.useState<PaginationState>({
+    pageIndex: 0,
+    pageSize,
+  })
+  const [sorting, setSorting] = React.useState<SortingState>([])
+  const [globalFilter, setGlobalFilter] = React.useState("")
+
+  return { pagination, setPagination, sorting, setSorting, globalFilter, setGlobalFilter }
+}
+
+type DataTableState = ReturnType<typeof useDataTableState>
+
+interface DataTableProps<TData> {
+  // biome-ignore lint/suspicious/noExplicitAny: column values are heterogeneous
+  columns: ColumnDef<TData, any>[]
+  data: TData[]
+  /** Total rows on the server; switches sorting, filtering, and paging to manual */
+  rowCount?: number
+  /** Controlled state from `useDataTableState`; required for server-side tables */
+  state?: DataTableState
+  /** Placeholder of the search input; omit to hide it */
+  filterPlaceholder?: string
+  isLoading?: boolean
+  emptyMessage?: string
+  className?: string
+}
+
+function DataTable<TData>({
+  columns,
+  data,
+  rowCount,
+  state,
+  filterPlaceholder,
+  isLoading,
+  emptyMessage = "No results.",
+  className,
+}: DataTableProps<TData>) {
+  const localState = useDataTableState()
+  const { pagination, setPagination, sorting, setSorting, globalFilter, setGlobalFilter } =
+    state ?? localState
+  const manual = rowCount !== undefined
+
+  // Any new sort order or search starts again from the first page
+  const onSortingChange: OnChangeFn<SortingState> = (updater) => {
+    setSorting(updater)
+    setPagination((prev) => ({ ...prev, pageIndex: 0 }))
+  }
+
+  const table = useReactTable({
+    data,
+    columns,
+    state: { pagination, sorting, globalFilter },
+    rowCount,
+    manualPagination: manual,
+    manualSorting: manual,
+    manualFiltering: manual,
+    onPaginationChange: setPagination,
+    onSortingChange,
+    onGlobalFilterChange: setGlobalFilter,
+    getCoreRowModel: getCoreRowModel(),
+    getSortedRowModel: manual ? undefined : getSortedRowModel(),
+    getFilteredRowModel: manual ? undefined : getFilteredRowModel(),
+    getPaginationRowModel: manual ? undefined : getPaginationRowModel(),
+  })
+
+  return (
+    <div data-slot="data-table" className={cn("flex flex-col gap-4", className)}>
+      {filterPlaceholder && (
+        <Input
+          value={globalFilter}
+          onChange={(event) => {
+            setGlobalFilter(event.target.value)
+            setPagination((prev) => ({ ...prev, pageIndex: 0 }))
+          }}
+          placeholder={filterPlaceholder}
+          className="max-w-sm"
+        />
+      )}
+
+      <div className="rounded-lg border">
+        <Table>
+          <TableHeader>
+            {table.getHeaderGroups().map((headerGroup) => (
+              <TableRow key={headerGroup.id}>
+                {headerGroup.headers.map((header) => (
+                  <TableHead key={header.id}>
+                    {header.isPlaceholder
+                      ? null
+                      : flexRender(header.column.columnDef.header, header.getContext())}
+                  </TableHead>
+                ))}
+              </TableRow>
+            ))}
+          </TableHeader>
+          <TableBody>
+            {table.getRowModel().rows.length ? (
+              table.getRowModel().rows.map((row) => (
+                <TableRow key={row.id} data-state={row.getIsSelected() && "selected"}>
+                  {row.getVisibleCells().map((cell) => (
+                    <TableCell key={cell.id}>
+                      {flexRender(cell.column.columnDef.cell, cell.getContext())}
+                    </TableCell>
+                  ))}
+                </TableRow>
+              ))
+            ) : (
+              <TableRow>
+                <TableCell
+                  colSpan={columns.length}
+                  className="h-24 text-center text-muted-foreground"
+                >
+                  {isLoading ? "Loading..." : emptyMessage}
+                </TableCell>
+              </TableRow>
+            )}
+          </TableBody>
+        </Table>
+      </div>
+
+      <DataTablePagination
+        pageIndex={pagination.pageIndex}
+        pageCount={Math.max(table.getPageCount(), 1)}
+        pageSize={pagination.pageSize}
+        canPrevious={table.getCanPreviousPage()}
+        canNext={table.getCanNextPage()}
+        onPrevious={() => table.previousPage()}
+        onNext={() => table.nextPage()}
+        onPageSizeChange={(size) => table.setPageSize(size)}
+      />
+    </div>
+  )
+}
+
+interface DataTablePaginationProps {
+  pageIndex: number
+  pageCount: number
+  pageSize: number
+  canPrevious: boolean
+  canNext: boolean
+  onPrevious: () => void
+  onNext: () => void
+  onPageSizeChange: (size: number) => void
+}
+
+function DataTablePagination({
+  pageIndex,
+  pageCount,
+  pageSize,
+  canPrevious,
+  canNext,
+  onPrevious,
+  onNext,
+  onPageSizeChange,
+}: DataTablePaginationProps) {
+  return (
+    <div className="flex items-center justify-between gap-4 text-sm">
+      <label className="flex items-center gap-2 text-muted-foreground">
+        Rows per page
+        <select
+          value={pageSize}
+          onChange={(event) => onPageSizeChange(Number(event.target.value))}
+          className="h-8 rounded-md border border-input bg-transparent px-2 text-foreground"
+        >
+          {PAGE_SIZES.map((size) => (
+            <option key={size} value={size}>
+              {size}
+            </option>
+          ))}
+        </select>
+      </label>
+      <div className="flex items-center gap-2">
+        <span className="text-muted-foreground">
+          Page {pageIndex + 1} of {pageCount}
+        </span>
+        <Button variant="outline" size="icon-sm" onClick={onPrevious} disabled={!canPrevious} aria-label="Previous page">
+          <ChevronLeft />
+        </Button>
+        <Button variant="outline" size="icon-sm" onClick={onNext} disabled={!canNext} aria-label="Next page">
+          <ChevronRight />
+        </Button>
+      </div>
+    </div>
+  )
+}
+
+export { DataTable, DataTablePagination, useDataTableState }
+export type { DataTableProps, DataTableState }
@@ -52,8 +52,8 @@ function Slider({
 
   const getPercentage = (val: number) => ((val - min) / (max - min)) * 100
 
-  const rangeStart = currentValue.length > 1 ? getPercentage(currentValue[0]) : 0
-  const rangeEnd = getPercentage(currentValue[currentValue.length - 1])
+  const rangeStart = currentValue.length > 1 ? getPercentage(currentValue[0] ?? min) : 0
+  const rangeEnd = getPercentage(currentValue[currentValue.length - 1] ?? min)
 
   return (
     <div
@@ -0,0 +1,35 @@
+import { z } from "zod";
+import { env } from "@/env";
+import { attemptDelivery, processDueDeliveries } from "@/server/webhooks";
+
+// The webhook worker. GET retries every due delivery; call it every minute from a
+// scheduler, e.g. a cron job. POST makes one attempt at a single delivery. Both require
+// `Authorization: Bearer <WEBHOOK_WORKER_SECRET>`.
+
+const attemptSchema = z.object({ deliveryId: z.string() });
+
+function authorized(request: Request) {
+  const secret = env.WEBHOOK_WORKER_SECRET;
+  return !!secret && request.headers.get("authorization") === `Bearer ${secret}`;
+}
+
+export async function GET(request: Request) {
+  if (!authorized(request)) {
+    return Response.json({ error: "Unauthorized" }, { status: 401 });
+  }
+
+  return Response.json({ processed: await processDueDeliveries() });
+}
+
+export async function POST(request: Request) {
+  if (!authorized(request)) {
+    return Response.json({ error: "Unauthorized" }, { status: 401 });
+  }
+
+  const input = attemptSchema.safeParse(await request.json().catch(() => null));
+  if (!input.success) {
+    return Response.json({ error: "Expected { deliveryId }" }, { status: 400 });
+  }
+
+  return Response.json(await attemptDelivery(input.data.deliveryId));
+}
@@ -0,0 +1,123 @@
+"use client";
+
+import { useState } from "react";
+import { useTranslations } from "next-intl";
+
+import { createWebhookEndpointSchema, WEBHOOK_EVENTS } from "@/schemas/webhook";
+import { api } from "@/trpc/react";
+
+const inputClassName = "rounded-lg border border-border bg-background px-4 py-2 font-normal";
+
+export function CreateEndpoint() {
+  const t = useTranslations("webhooks");
+  const utils = api.useUtils();
+  const [url, setUrl] = useState("");
+  const [description, setDescription] = useState("");
+  const [events, setEvents] = useState<string[]>(["*"]);
+  const parsed = createWebhookEndpointSchema.safeParse({ url, description, events });
+  const urlIssue = url ? parsed.error?.issues.find((issue) => issue.path[0] === "url")?.message : undefined;
+
+  const create = api.webhook.create.useMutation({
+    onSuccess: async () => {
+      setUrl("");
+      setDescription("");
+      setEvents(["*"]);
+      await utils.webhook.list.invalidate();
+    },
+  });
+
+  function toggle(event: string) {
+    setEvents((current) => (current.includes(event) ? current.filter((e) => e !== event) : [...current, event]));
+  }
+
+  return (
+    <div className="grid gap-4">
+      <form
+        onSubmit={(e) => {
+          e.preventDefault();
+          if (parsed.success) {
+            create.mutate(parsed.data);
+          }
+        }}
+        className="grid gap-4"
+      >
+        <label className="grid gap-1.5 text-sm font-medium">
+          {t("create.url")}
+          <input
+            type="url"
+            placeholder="https://"
+            value={url}
+            onChange={(e) => setUrl(e.target.value)}
+            aria-invalid={urlIssue ? true : undefined}
+            className={inputClassName}
+          />
+          {urlIssue && <span className="text-xs font-normal text-destructive">{urlIssue}</span>}
+        </label>
+
+        <label className="grid gap-1.5 text-sm font-medium">
+          {t("create.description")}
+          <input value={description} onChange={(e) => setDescription(e.target.value)} className={inputClassName} />
+          <span className="text-xs font-normal text-muted-foreground">{t("create.descriptionHint")}</span>
+        </label>
+
+        <fieldset className="grid gap-2 text-sm">
+          <legend className="mb-1.5 font-medium">{t("create.events")}</legend>
+          <label className="flex items-center gap-2">
+            <input type="checkbox" checked={events.includes("*")} onChange={() => toggle("*")} />
+            {t("create.allEvents")}
+          </label>
+          {WEBHOOK_EVENTS.map((event) => (
+            <label key={event} className="flex items-center gap-2">
+              <input
+                type="checkbox"
+                checked={events.includes(event)}
+                disabled={events.includes("*")}
+                onChange={() => toggle(event)}
+              />
+              <code>{event}</code>
+            </label>
+          ))}
+        </fieldset>
+
+        <div className="flex items-center gap-3">
+          <button
+            type="submit"
+            disabled={create.isPending || !parsed.success}
+            className="rounded-lg bg-primary px-4 py-2 text-sm font-medium text-primary-foreground disabled:opacity-50"
+          >
+            {create.isPending ? t("create.adding") : t("create.submit")}
+          </button>
+          {create.error && <span className="text-sm text-destructive">{create.error.message}</span>}
+        </div>
+      </form>
+
+      {create.data && <SecretNotice secret={create.data.secret} />}
+    </div>
+  );
+}
+
+/** A signing secret, shown once after an endpoint is created or its secret rotated */
+export function SecretNotice({ secret }: { secret: string }) {
+  const t = useTranslations("webhooks");
+  const [copied, setCopied] = useState(false);
+
+  return (
+    <div className="rounded-lg border border-border bg-muted p-4 text-sm">
+      <p className="font-medium">{t("secret.title")}</p>
+      <p className="mt-1 text-muted-foreground">{t("secret.hint")}</p>
+      <div className="mt-3 flex items-center gap-2">
+        <code className="flex-1 truncate rounded bg-background px-3 py-2">{secret}</code>
+        <button
+          type="button"
+          onClick={async () => {
+            await navigator.clipboard.writeText(secret);
+            setCopied(true);
+          }}
+          className="rounded-lg border border-border px-3 py-2 font-medium hover:bg-background"
+        >
+          {copied ? t("secret.copied") : t("secret.copy")}
+        </button>
+      </div>
+    </div>
+  );
+}
@@ -0,0 +1,64 @@
+"use client";
+
+import { useTranslations } from "next-intl";
+
+import type { WebhookDeliveryStatus } from "@/schemas/webhook";
+import { api } from "@/trpc/react";
+
+const statusClassName: Record<WebhookDeliveryStatus, string> = {
+  pending: "text-muted-foreground",
+  succeeded: "text-primary",
+  failed: "text-destructive",
+};
+
+/** The latest deliveries of an endpoint; failed ones can be sent again */
+export function Deliveries({ endpointId }: { endpointId: string }) {
+  const t = useTranslations("webhooks");
+  const utils = api.useUtils();
+  const deliveries = api.webhook.deliveries.useQuery(
+    { endpointId },
+    // Poll while deliveries are still being attempted
+    { refetchInterval: (query) => (query.state.data?.some((d) => d.status === "pending") ? 5000 : false) },
+  );
+  const redeliver = api.webhook.redeliver.useMutation({
+    onSuccess: () => utils.webhook.deliveries.invalidate({ endpointId }),
+  });
+
+  if (!deliveries.data) {
+    return <p className="mt-4 text-sm text-muted-foreground">{t("deliveries.loading")}</p>;
+  }
+  if (deliveries.data.length === 0) {
+    return <p className="mt-4 text-sm text-muted-foreground">{t("deliveries.empty")}</p>;
+  }
+
+  return (
+    <ul className="mt-4 divide-y divide-border border-t border-border text-sm">
+      {deliveries.data.map((delivery) => (
+        <li key={delivery.id} className="flex items-center justify-between gap-4 py-3">
+          <div className="min-w-0">
+            <p>
+              <code>{delivery.event}</code>{" "}
+              <span className={statusClassName[delivery.status as WebhookDeliveryStatus]}>
+                {t(`deliveries.${delivery.status as WebhookDeliveryStatus}`)}
+              </span>
+            </p>
+            <p className="text-xs text-muted-foreground truncate">
+              {delivery.createdAt.toLocaleString()} · {t("deliveries.attempts", { count: delivery.attempts })}
+              {delivery.lastError && ` · ${delivery.lastError}`}
+            </p>
+          </div>
+          {delivery.status === "failed" && (
+            <button
+              type="button"
+              disabled={redeliver.isPending}
+              onClick={() => redeliver.mutate({ deliveryId: delivery.id })}
+              className="shrink-0 rounded-lg border border-border px-3 py-1.5 font-medium hover:bg-muted disabled:opacity-50"
+            >
+              {t("deliveries.redeliver")}
+            </button>
+          )}
+        </li>
+      ))}
+    </ul>
+  );
+}
@@ -0,0 +1,121 @@
+"use client";
+
+import { useState } from "react";
+import { useTranslations } from "next-intl";
+
+import { type RouterOutputs, api } from "@/trpc/react";
+import { SecretNotice } from "./CreateEndpoint";
+import { Deliveries } from "./Deliveries";
+
+type Endpoint = RouterOutputs["webhook"]["list"][number];
+
+const actionClassName =
+  "rounded-lg border border-border px-3 py-1.5 text-sm font-medium hover:bg-muted disabled:opacity-50";
+
+export function EndpointList() {
+  const t = useTranslations("webhooks");
+  const [endpoints] = api.webhook.list.useSuspenseQuery();
+
+  if (endpoints.length === 0) {
+    return <p className="text-sm text-muted-foreground">{t("endpoints.empty")}</p>;
+  }
+
+  return (
+    <ul className="flex flex-col gap-4">
+      {endpoints.map((endpoint) => (
+        <li key={endpoint.id}>
+          <EndpointCard endpoint={endpoint} />
+        </li>
+      ))}
+    </ul>
+  );
+}
+
+function EndpointCard({ endpoint }: { endpoint: Endpoint }) {
+  const t = useTranslations("webhooks");
+  const utils = api.useUtils();
+  const [showDeliveries, setShowDeliveries] = useState(false);
+  const refresh = () => utils.webhook.list.invalidate();
+
+  const update = api.webhook.update.useMutation({ onSuccess: refresh });
+  const remove = api.webhook.delete.useMutation({ onSuccess: refresh });
+  const rotate = api.webhook.rotateSecret.useMutation();
+  const sendTest = api.webhook.sendTest.useMutation({
+    onSuccess: () => utils.webhook.deliveries.invalidate({ endpointId: endpoint.id }),
+  });
+  const error = update.error ?? remove.error ?? rotate.error ?? sendTest.error;
+
+  return (
+    <div className="rounded-xl border border-border p-6">
+      <div className="flex items-start justify-between gap-4">
+        <div className="min-w-0">
+          <p className="font-medium truncate">{endpoint.url}</p>
+          {endpoint.description && <p className="text-sm text-muted-foreground">{endpoint.description}</p>}
+          <p className="mt-1 text-xs text-muted-foreground">
+            {endpoint.events.map((event) => (event === "*" ? t("create.allEvents") : event)).join(", ")}
+          </p>
+        </div>
+        <span
+          className={`shrink-0 rounded-full px-2.5 py-0.5 text-xs font-medium ${
+            endpoint.enabled ? "bg-primary text-primary-foreground" : "bg-muted text-muted-foreground"
+          }`}
+        >
+          {endpoint.enabled ? t("endpoints.enabled") : t("endpoints.disabled")}
+        </span>
+      </div>
+
+      <div className="mt-4 flex flex-wrap gap-2">
+        <button
+          type="button"
+          disabled={update.isPending}
+          onClick={() => update.mutate({ id: endpoint.id, enabled: !endpoint.enabled })}
+          className={actionClassName}
+        >
+          {endpoint.enabled ? t("endpoints.disable") : t("endpoints.enable")}
+        </button>
+        <button
+          type="button"
+          disabled={sendTest.isPending || !endpoint.enabled}
+          onClick={() => {
+            setShowDeliveries(true);
+            sendTest.mutate({ id: endpoint.id });
+          }}
+          className={actionClassName}
+        >
+          {t("endpoints.test")}
+        </button>
+        <button
+          type="button"
+          disabled={rotate.isPending}
+          onClick={() => rotate.mutate({ id: endpoint.id })}
+          className={actionClassName}
+        >
+          {t("endpoints.rotate")}
+        </button>
+        <button type="button" onClick={() => setShowDeliveries(!showDeliveries)} className={actionClassName}>
+          {showDeliveries ? t("endpoints.hideDeliveries") : t("endpoints.deliveries")}
+        </button>
+        <button
+          type="button"
+          disabled={remove.isPending}
+          onClick={() => {
+            if (window.confirm(t("endpoints.confirmDelete"))) {
+              remove.mutate({ id: endpoint.id });
+            }
+          }}
+          className={`${actionClassName} text-destructive`}
+        >
+          {t("endpoints.delete")}
+        </button>
+      </div>
+
+      {error && <p className="mt-3 text-sm text-destructive">{error.message}</p>}
+      {rotate.data && (
+        <div className="mt-4">
+          <SecretNotice secret={rotate.data.secret} />
+        </div>
+      )}
+      {showDeliveries && <Deliveries endpointId={endpoint.id} />}
+    </div>
+  );
+}
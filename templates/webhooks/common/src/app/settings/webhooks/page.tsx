@@ -0,0 +1,41 @@
+import { redirect } from "next/navigation";
+import { getTranslations } from "next-intl/server";
+
+import { Header } from "@/app/_components/Header";
+import { getSession, SIGN_IN_PATH } from "@/server/session";
+import { api, HydrateClient } from "@/trpc/server";
+import { CreateEndpoint } from "./_components/CreateEndpoint";
+import { EndpointList } from "./_components/EndpointList";
+
+export default async function WebhookSettingsPage() {
+  const session = await getSession();
+  if (!session) {
+    redirect(SIGN_IN_PATH);
+  }
+
+  const t = await getTranslations("webhooks");
+  // Prefetched, so the list renders without a loading state
+  void api.webhook.list.prefetch();
+
+  return (
+    <HydrateClient>
+      <div className="min-h-screen flex flex-col bg-background">
+        <Header />
+
+        <main className="flex-1 max-w-3xl mx-auto px-4 sm:px-6 lg:px-8 py-8 w-full flex flex-col gap-8">
+          <div>
+            <h1 className="text-2xl font-semibold">{t("title")}</h1>
+            <p className="mt-1 text-sm text-muted-foreground">{t("description")}</p>
+          </div>
+
+          <section className="rounded-xl border border-border p-6">
+            <h2 className="text-lg font-semibold mb-4">{t("create.title")}</h2>
+            <CreateEndpoint />
+          </section>
+
+          <EndpointList />
+        </main>
+      </div>
+    </HydrateClient>
+  );
+}
@@ -0,0 +1,64 @@
+import { z } from "zod";
+
+// Webhook events and the inputs of the `webhook` router, shared with the settings page
+
+/** Every event endpoints can subscribe to, with the schema of its payload; add yours here */
+export const webhookEvents = {
+  // Sent by "Send test event" on the settings page
+  ping: z.object({ message: z.string() }),
+};
+
+export type WebhookEvent = keyof typeof webhookEvents;
+export type WebhookPayload<E extends WebhookEvent> = z.input<(typeof webhookEvents)[E]>;
+
+export const WEBHOOK_EVENTS = Object.keys(webhookEvents) as [WebhookEvent, ...WebhookEvent[]];
+
+/** `*` subscribes an endpoint to every event, including ones added later */
+export const webhookEventsSchema = z
+  .array(z.enum([...WEBHOOK_EVENTS, "*"]))
+  .min(1, "Pick at least one event");
+
+// Deliveries must not reach the server's own network in production
+const PRIVATE_HOST = /^(localhost|127\.|10\.|192\.168\.|172\.(1[6-9]|2\d|3[01])\.|169\.254\.|\[::1\]|0\.0\.0\.0)/;
+
+const webhookUrl = z
+  .url("Enter a valid URL")
+  .refine(
+    (url) => process.env.NODE_ENV !== "production" || (url.startsWith("https://") && !PRIVATE_HOST.test(new URL(url).hostname)),
+    "Use a public HTTPS URL",
+  );
+
+const description = z.string().trim().max(200);
+
+export const createWebhookEndpointSchema = z.object({
+  url: webhookUrl,
+  description: description.optional(),
+  events: webhookEventsSchema,
+});
+
+export const updateWebhookEndpointSchema = z.object({
+  id: z.string(),
+  url: webhookUrl.optional(),
+  description: description.optional(),
+  events: webhookEventsSchema.optional(),
+  enabled: z.boolean().optional(),
+});
+
+export const webhookEndpointIdSchema = z.object({
+  id: z.string(),
+});
+
+export const listWebhookDeliveriesSchema = z.object({
+  endpointId: z.string(),
+  limit: z.number().int().min(1).max(100).default(20),
+});
+
+export const webhookDeliveryIdSchema = z.object({
+  deliveryId: z.string(),
+});
+
+export const WEBHOOK_DELIVERY_STATUSES = ["pending", "succeeded", "failed"] as const;
+
+export type WebhookDeliveryStatus = (typeof WEBHOOK_DELIVERY_STATUSES)[number];
+export type CreateWebhookEndpointInput = z.infer<typeof createWebhookEndpointSchema>;
+export type UpdateWebhookEndpointInput = z.infer<typeof updateWebhookEndpointSchema>;
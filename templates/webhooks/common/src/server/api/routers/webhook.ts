@@ -0,0 +1,144 @@
+import { TRPCError } from "@trpc/server";
+import {
+  createWebhookEndpointSchema,
+  listWebhookDeliveriesSchema,
+  updateWebhookEndpointSchema,
+  webhookDeliveryIdSchema,
+  webhookEndpointIdSchema,
+} from "@/schemas/webhook";
+import { createTRPCRouter, protectedProcedure } from "@/server/api/trpc";
+import { generateWebhookSecret, scheduleDeliveries, sendWebhook } from "@/server/webhooks";
+
+const endpointFields = {
+  id: true,
+  url: true,
+  description: true,
+  events: true,
+  enabled: true,
+  createdAt: true,
+} as const;
+
+/** Endpoints store their events comma-separated */
+function toEndpoint<T extends { events: string }>(endpoint: T) {
+  return { ...endpoint, events: endpoint.events.split(",") };
+}
+
+function notFound(): never {
+  throw new TRPCError({ code: "NOT_FOUND", message: "Webhook endpoint not found" });
+}
+
+// Every procedure only touches endpoints and deliveries of the signed-in user
+export const webhookRouter = createTRPCRouter({
+  list: protectedProcedure.query(async ({ ctx }) => {
+    const endpoints = await ctx.db.webhookEndpoint.findMany({
+      where: { userId: ctx.userId },
+      orderBy: { createdAt: "desc" },
+      select: endpointFields,
+    });
+    return endpoints.map(toEndpoint);
+  }),
+
+  // The secret is only returned here and by rotateSecret
+  create: protectedProcedure.input(createWebhookEndpointSchema).mutation(async ({ ctx, input }) => {
+    const secret = generateWebhookSecret();
+    const endpoint = await ctx.db.webhookEndpoint.create({
+      data: {
+        userId: ctx.userId,
+        url: input.url,
+        description: input.description || null,
+        events: input.events.join(","),
+        secret,
+      },
+      select: endpointFields,
+    });
+    return { ...toEndpoint(endpoint), secret };
+  }),
+
+  update: protectedProcedure.input(updateWebhookEndpointSchema).mutation(async ({ ctx, input }) => {
+    const { count } = await ctx.db.webhookEndpoint.updateMany({
+      where: { id: input.id, userId: ctx.userId },
+      data: {
+        url: input.url,
+        description: input.description === undefined ? undefined : input.description || null,
+        events: input.events?.join(","),
+        enabled: input.enabled,
+      },
+    });
+    if (count === 0) {
+      notFound();
+    }
+
+    const endpoint = await ctx.db.webhookEndpoint.findUniqueOrThrow({ where: { id: input.id }, select: endpointFields });
+    return toEndpoint(endpoint);
+  }),
+
+  // Deletes the endpoint's delivery history with it
+  delete: protectedProcedure.input(webhookEndpointIdSchema).mutation(async ({ ctx, input }) => {
+    const { count } = await ctx.db.webhookEndpoint.deleteMany({ where: { id: input.id, userId: ctx.userId } });
+    if (count === 0) {
+      notFound();
+    }
+  }),
+
+  // Pending retries are signed with the new secret
+  rotateSecret: protectedProcedure.input(webhookEndpointIdSchema).mutation(async ({ ctx, input }) => {
+    const secret = generateWebhookSecret();
+    const { count } = await ctx.db.webhookEndpoint.updateMany({
+      where: { id: input.id, userId: ctx.userId },
+      data: { secret },
+    });
+    if (count === 0) {
+      notFound();
+    }
+    return { secret };
+  }),
+
+  sendTest: protectedProcedure.input(webhookEndpointIdSchema).mutation(async ({ ctx, input }) => {
+    const endpoint = await ctx.db.webhookEndpoint.findFirst({
+      where: { id: input.id, userId: ctx.userId, enabled: true },
+      select: { id: true },
+    });
+    if (!endpoint) {
+      notFound();
+    }
+
+    const [deliveryId] = await sendWebhook(
+      ctx.userId,
+      "ping",
+      { message: "Test event from the webhook settings" },
+      { endpointId: endpoint.id },
+    );
+    return { deliveryId };
+  }),
+
+  deliveries: protectedProcedure.input(listWebhookDeliveriesSchema).query(({ ctx, input }) => {
+    return ctx.db.webhookDelivery.findMany({
+      where: { endpointId: input.endpointId, endpoint: { userId: ctx.userId } },
+      orderBy: { createdAt: "desc" },
+      take: input.limit,
+      select: {
+        id: true,
+        event: true,
+        status: true,
+        attempts: true,
+        lastStatus: true,
+        lastError: true,
+        createdAt: true,
+        deliveredAt: true,
+      },
+    });
+  }),
+
+  // Starts the delivery over with a fresh set of attempts
+  redeliver: protectedProcedure.input(webhookDeliveryIdSchema).mutation(async ({ ctx, input }) => {
+    const { count } = await ctx.db.webhookDelivery.updateMany({
+      where: { id: input.deliveryId, status: "failed", endpoint: { userId: ctx.userId } },
+      data: { status: "pending", attempts: 0, nextAttemptAt: new Date() },
+    });
+    if (count === 0) {
+      throw new TRPCError({ code: "NOT_FOUND", message: "Failed delivery not found" });
+    }
+
+    scheduleDeliveries([input.deliveryId]);
+  }),
+});
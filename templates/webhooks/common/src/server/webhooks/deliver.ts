@@ -0,0 +1,106 @@
+import "server-only";
+
+import { db } from "@/server/db";
+import { signWebhook } from "./signature";
+
+/** Wait before each retry: 1 minute, then 5 minutes, 30 minutes, 2 hours, and 12 hours */
+const RETRY_DELAYS_MS = [60_000, 300_000, 1_800_000, 7_200_000, 43_200_000];
+
+/** Attempts per delivery, the first one included */
+export const MAX_ATTEMPTS = RETRY_DELAYS_MS.length + 1;
+
+const TIMEOUT_MS = 10_000;
+
+/** How long an attempt holds a delivery, so the worker and an inline attempt never both send it */
+const LEASE_MS = TIMEOUT_MS + 20_000;
+
+/** `done` once the delivery succeeded; otherwise `retryInMs` until the next attempt, or `null` when it gave up */
+export type DeliveryAttempt = { done: boolean; retryInMs: number | null };
+
+/** Send a pending delivery that is due and record the outcome, scheduling a retry when it fails */
+export async function attemptDelivery(deliveryId: string): Promise<DeliveryAttempt> {
+  const now = new Date();
+  const claimed = await db.webhookDelivery.updateMany({
+    where: { id: deliveryId, status: "pending", nextAttemptAt: { lte: now } },
+    data: { nextAttemptAt: new Date(now.getTime() + LEASE_MS) },
+  });
+  if (claimed.count === 0) {
+    // Already sent, given up, or not due yet
+    const delivery = await db.webhookDelivery.findUnique({
+      where: { id: deliveryId },
+      select: { status: true, nextAttemptAt: true },
+    });
+    if (delivery?.status !== "pending") {
+      return { done: delivery?.status === "succeeded", retryInMs: null };
+    }
+    return { done: false, retryInMs: Math.max(delivery.nextAttemptAt.getTime() - now.getTime(), 1000) };
+  }
+
+  const delivery = await db.webhookDelivery.findUniqueOrThrow({
+    where: { id: deliveryId },
+    include: { endpoint: { select: { url: true, secret: true, enabled: true } } },
+  });
+  const attempts = delivery.attempts + 1;
+
+  let status: number | null = null;
+  let error: string | null = null;
+  if (!delivery.endpoint.enabled) {
+    error = "The endpoint is disabled";
+  } else {
+    const timestamp = Math.floor(Date.now() / 1000);
+    try {
+      const response = await fetch(delivery.endpoint.url, {
+        method: "POST",
+        headers: {
+          "content-type": "application/json",
+          "webhook-id": delivery.id,
+          "webhook-timestamp": String(timestamp),
+          "webhook-signature": signWebhook(delivery.endpoint.secret, delivery.id, timestamp, delivery.payload),
+        },
+        body: delivery.payload,
+        // A redirect could point anywhere; only a 2xx from the registered URL counts
+        redirect: "manual",
+        signal: AbortSignal.timeout(TIMEOUT_MS),
+      });
+      status = response.status;
+      if (!response.ok) {
+        error = `The endpoint answered ${response.status}`;
+      }
+    } catch (cause) {
+      error = cause instanceof Error ? cause.message : String(cause);
+    }
+  }
+
+  if (!error) {
+    await db.webhookDelivery.update({
+      where: { id: deliveryId },
+      data: { status: "succeeded", attempts, lastStatus: status, lastError: null, deliveredAt: new Date() },
+    });
+    return { done: true, retryInMs: null };
+  }
+
+  const retryInMs = delivery.endpoint.enabled && attempts < MAX_ATTEMPTS ? (RETRY_DELAYS_MS[attempts - 1] ?? null) : null;
+  await db.webhookDelivery.update({
+    where: { id: deliveryId },
+    data: {
+      status: retryInMs === null ? "failed" : "pending",
+      attempts,
+      lastStatus: status,
+      lastError: error.slice(0, 1000),
+      nextAttemptAt: new Date(Date.now() + (retryInMs ?? 0)),
+    },
+  });
+  return { done: false, retryInMs };
+}
+
+/** Attempt every pending delivery that is due, oldest first; returns how many were attempted */
+export async function processDueDeliveries(limit = 50) {
+  const due = await db.webhookDelivery.findMany({
+    where: { status: "pending", nextAttemptAt: { lte: new Date() } },
+    orderBy: { nextAttemptAt: "asc" },
+    take: limit,
+    select: { id: true },
+  });
+  await Promise.allSettled(due.map((delivery) => attemptDelivery(delivery.id)));
+  return due.length;
+}
@@ -0,0 +1,3 @@
+export { attemptDelivery, MAX_ATTEMPTS, processDueDeliveries } from "./deliver";
+export { scheduleDeliveries, sendWebhook, subscribes } from "./send";
+export { generateWebhookSecret, signWebhook, verifyWebhook } from "./signature";
@@ -0,0 +1,61 @@
+import "server-only";
+
+import { after } from "next/server";
+import { type WebhookEvent, type WebhookPayload, webhookEvents } from "@/schemas/webhook";
+import { db } from "@/server/db";
+import { enqueueDeliveries } from "./queue";
+
+/**
+ * Send `event` to every enabled endpoint of `userId` that subscribed to it, or only to
+ * `endpointId` whatever its events. The deliveries are recorded right away and sent after
+ * the response, so a slow endpoint never holds up the request; returns their ids.
+ *
+ * @example
+ * await sendWebhook(ctx.userId, "ping", { message: "Hello" });
+ */
+export async function sendWebhook<E extends WebhookEvent>(
+  userId: string,
+  event: E,
+  payload: WebhookPayload<E>,
+  options: { endpointId?: string } = {},
+) {
+  const data = webhookEvents[event].parse(payload);
+  const endpoints = await db.webhookEndpoint.findMany({
+    where: { userId, enabled: true, ...(options.endpointId ? { id: options.endpointId } : {}) },
+    select: { id: true, events: true },
+  });
+  const recipients = options.endpointId ? endpoints : endpoints.filter((endpoint) => subscribes(endpoint.events, event));
+  if (recipients.length === 0) {
+    return [];
+  }
+
+  // The Standard Webhooks payload shape; every recipient gets the same body
+  const body = JSON.stringify({ type: event, timestamp: new Date().toISOString(), data });
+  const deliveries = await db.$transaction(
+    recipients.map((endpoint) =>
+      db.webhookDelivery.create({
+        data: { endpointId: endpoint.id, event, payload: body },
+        select: { id: true },
+      }),
+    ),
+  );
+
+  const ids = deliveries.map((delivery) => delivery.id);
+  scheduleDeliveries(ids);
+  return ids;
+}
+
+/** Send deliveries after the current response; outside a request the worker route picks them up */
+export function scheduleDeliveries(deliveryIds: string[]) {
+  try {
+    after(() => enqueueDeliveries(deliveryIds));
+  } catch {
+    // Not in a request, e.g. a script
+  }
+}
+
+/** Whether stored `events`, comma-separated or `*`, include `event` */
+export function subscribes(events: string, event: string) {
+  const types = events.split(",");
+  return types.includes("*") || types.includes(event);
+}
@@ -0,0 +1,36 @@
+import { describe, expect, it } from "vitest";
+import { generateWebhookSecret, signWebhook, verifyWebhook } from "@/server/webhooks/signature";
+
+const body = JSON.stringify({ type: "ping", data: { message: "Hello" } });
+
+function headers(secret: string, timestamp: number, payload = body) {
+  return new Headers({
+    "webhook-id": "delivery_1",
+    "webhook-timestamp": String(timestamp),
+    "webhook-signature": signWebhook(secret, "delivery_1", timestamp, payload),
+  });
+}
+
+describe("webhook signatures", () => {
+  const secret = generateWebhookSecret();
+  const now = Date.now();
+  const timestamp = Math.floor(now / 1000);
+
+  it("generates secrets in the Standard Webhooks format", () => {
+    expect(secret).toMatch(/^whsec_[A-Za-z0-9+/]+=*$/);
+    expect(generateWebhookSecret()).not.toBe(secret);
+  });
+
+  it("verifies its own signatures", () => {
+    expect(verifyWebhook(secret, headers(secret, timestamp), body, now)).toBe(true);
+  });
+
+  it("rejects a changed body or another secret", () => {
+    expect(verifyWebhook(secret, headers(secret, timestamp), `${body} `, now)).toBe(false);
+    expect(verifyWebhook(generateWebhookSecret(), headers(secret, timestamp), body, now)).toBe(false);
+  });
+
+  it("rejects stale deliveries", () => {
+    expect(verifyWebhook(secret, headers(secret, timestamp - 600), body, now)).toBe(false);
+  });
+});
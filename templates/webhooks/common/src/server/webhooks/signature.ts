@@ -0,0 +1,41 @@
+import { createHmac, randomBytes, timingSafeEqual } from "node:crypto";
+
+// Signatures follow the Standard Webhooks spec (https://www.standardwebhooks.com), so
+// receivers can verify deliveries with any of its libraries
+
+const SECRET_PREFIX = "whsec_";
+
+/** Deliveries older or newer than this are rejected by `verifyWebhook` */
+const TOLERANCE_SECONDS = 5 * 60;
+
+/** A new signing secret, e.g. `whsec_MfKQ9r8GKYqrTwjUPD8ILPZIo2LaLaSw` */
+export function generateWebhookSecret() {
+  return `${SECRET_PREFIX}${randomBytes(24).toString("base64")}`;
+}
+
+/** The `webhook-signature` header of a delivery: `v1,` and the base64 HMAC-SHA256 of `id.timestamp.body` */
+export function signWebhook(secret: string, id: string, timestamp: number, body: string) {
+  const key = Buffer.from(secret.startsWith(SECRET_PREFIX) ? secret.slice(SECRET_PREFIX.length) : secret, "base64");
+  const signature = createHmac("sha256", key).update(`${id}.${timestamp}.${body}`).digest("base64");
+  return `v1,${signature}`;
+}
+
+/** Whether a received delivery was signed with `secret` within the last five minutes */
+export function verifyWebhook(secret: string, headers: Headers, body: string, now = Date.now()) {
+  const id = headers.get("webhook-id");
+  const timestamp = Number(headers.get("webhook-timestamp"));
+  const signatures = headers.get("webhook-signature");
+  if (!id || !signatures || !Number.isInteger(timestamp)) {
+    return false;
+  }
+  if (Math.abs(now / 1000 - timestamp) > TOLERANCE_SECONDS) {
+    return false;
+  }
+
+  // The header may list several space-separated signatures, e.g. while a secret rotates
+  const expected = Buffer.from(signWebhook(secret, id, timestamp, body));
+  return signatures.split(" ").some((signature) => {
+    const candidate = Buffer.from(signature);
+    return candidate.length === expected.length && timingSafeEqual(candidate, expected);
+  });
+}
@@ -0,0 +1,11 @@
+import "server-only";
+
+import { attemptDelivery } from "./deliver";
+
+/**
+ * Make the first attempt of each delivery right away. Failed ones are retried by the
+ * worker route, /api/webhooks/process, so schedule it every minute, e.g. with a cron job.
+ */
+export async function enqueueDeliveries(deliveryIds: string[]) {
+  await Promise.allSettled(deliveryIds.map((deliveryId) => attemptDelivery(deliveryId)));
+}
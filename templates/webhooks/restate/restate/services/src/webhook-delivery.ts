@@ -0,0 +1,46 @@
+import * as restate from "@restatedev/restate-sdk";
+import type { Context } from "@restatedev/restate-sdk";
+import { WebhookDeliveryInputSchema } from "./contracts";
+import type { WebhookDeliveryInput, WebhookDeliveryOutput } from "./contracts";
+
+// The app's worker route; the services call it from outside the compose network
+const APP_URL = process.env.APP_URL ?? "http://localhost:3000";
+
+type Attempt = { done: boolean; retryInMs: number | null };
+
+async function attempt(deliveryId: string): Promise<Attempt> {
+  const response = await fetch(`${APP_URL}/api/webhooks/process`, {
+    method: "POST",
+    headers: {
+      "content-type": "application/json",
+      authorization: `Bearer ${process.env.WEBHOOK_WORKER_SECRET ?? ""}`,
+    },
+    body: JSON.stringify({ deliveryId }),
+  });
+  if (!response.ok) {
+    // Thrown errors make Restate retry the step until the app answers again
+    throw new Error(`${APP_URL}/api/webhooks/process answered ${response.status}`);
+  }
+  return (await response.json()) as Attempt;
+}
+
+// Drives one webhook delivery to completion: the app signs, sends, and records each
+// attempt, and tells the service when to retry; the sleeps between attempts are durable,
+// so pending retries survive restarts of the services and the app.
+export const WebhookDelivery = restate.service({
+  name: "WebhookDelivery",
+  handlers: {
+    deliver: async (ctx: Context, input: WebhookDeliveryInput): Promise<WebhookDeliveryOutput> => {
+      const { deliveryId } = WebhookDeliveryInputSchema.parse(input);
+
+      for (;;) {
+        const result = await ctx.run("attempt", () => attempt(deliveryId));
+        if (result.done || result.retryInMs === null) {
+          ctx.console.info({ service: "WebhookDelivery", deliveryId, delivered: result.done });
+          return { deliveryId, delivered: result.done };
+        }
+        await ctx.sleep(result.retryInMs);
+      }
+    },
+  },
+});
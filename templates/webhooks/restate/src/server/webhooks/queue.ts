@@ -0,0 +1,26 @@
+import "server-only";
+
+import { WebhookDeliveryInputSchema } from "@restate/contracts";
+
+const RESTATE_INGRESS_URL = process.env.RESTATE_INGRESS_URL ?? "http://localhost:8080";
+
+/**
+ * Hand each delivery to the `WebhookDelivery` Restate service, which makes the attempts
+ * through /api/webhooks/process and sleeps durably between retries. Deliveries Restate
+ * doesn't accept stay pending for the worker route to pick up.
+ */
+export async function enqueueDeliveries(deliveryIds: string[]) {
+  await Promise.allSettled(
+    deliveryIds.map(async (deliveryId) => {
+      // `/send` returns once Restate has the invocation, without waiting for the attempts
+      const response = await fetch(`${RESTATE_INGRESS_URL}/WebhookDelivery/deliver/send`, {
+        method: "POST",
+        headers: { "content-type": "application/json" },
+        body: JSON.stringify(WebhookDeliveryInputSchema.parse({ deliveryId })),
+      });
+      if (!response.ok) {
+        throw new Error(`WebhookDelivery/deliver failed with ${response.status}: ${await response.text()}`);
+      }
+    }),
+  );
+}
@@ -0,0 +1,103 @@
+//! `add` on projects rendered with `t3_mono::testing`: conflicting template files are kept
+//! or refused per the conflict mode, while edits to existing files always apply. Run with
+//! `cargo test --features testing`.
+#![cfg(feature = "testing")]
+
+use std::path::Path;
+use std::process::{Command, Output};
+
+use t3_mono::testing;
+
+const USER_SEND: &str = "// Our own delivery code\n";
+
+fn add(project: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_t3-mono"))
+        .arg("add")
+        .args(args)
+        .current_dir(project)
+        .env("DO_NOT_TRACK", "1")
+        .env("CI", "1")
+        .output()
+        .expect("failed to run t3-mono")
+}
+
+fn read(project: &Path, path: &str) -> String {
+    std::fs::read_to_string(project.join(path))
+        .unwrap_or_else(|e| panic!("Failed to read {}: {}", path, e))
+}
+
+/// The webhooks extension's models, router, and variables are all in place
+fn assert_webhooks_installed(project: &Path) {
+    assert!(read(project, "prisma/schema.prisma").contains("model WebhookEndpoint {"));
+    assert!(read(project, "src/server/api/root.ts").contains("webhook: webhookRouter"));
+    assert!(read(project, "src/env.js").contains("WEBHOOK_WORKER_SECRET"));
+    assert!(read(project, ".env.example").contains("WEBHOOK_WORKER_SECRET"));
+}
+
+#[tokio::test]
+async fn skip_existing_keeps_templates_and_applies_edits() {
+    let tree = testing::scaffold("").await.unwrap();
+    let project = tree.root();
+    std::fs::create_dir_all(project.join("src/server/webhooks")).unwrap();
+    std::fs::write(project.join("src/server/webhooks/send.ts"), USER_SEND).unwrap();
+
+    let output = add(project, &["webhooks", "--skip-existing"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "{}{}",
+        stdout,
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(read(project, "src/server/webhooks/send.ts"), USER_SEND);
+    assert!(stdout.contains("Kept existing src/server/webhooks/send.ts"));
+    for edited in [
+        "prisma/schema.prisma",
+        "src/server/api/root.ts",
+        "src/env.js",
+        "package.json",
+    ] {
+        assert!(
+            !stdout.contains(&format!("Kept existing {}", edited)),
+            "{} was kept:\n{}",
+            edited,
+            stdout
+        );
+    }
+    assert_webhooks_installed(project);
+}
+
+#[tokio::test]
+async fn prompt_applies_edits_without_asking() {
+    let tree = testing::scaffold("").await.unwrap();
+    let project = tree.root();
+
+    // Outside a terminal the prompt can't be answered, so any question fails the run
+    let output = add(project, &["webhooks"]);
+    assert!(
+        output.status.success(),
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_webhooks_installed(project);
+}
+
+#[tokio::test]
+async fn prompt_asks_about_conflicting_templates_only() {
+    let tree = testing::scaffold("").await.unwrap();
+    let project = tree.root();
+    std::fs::create_dir_all(project.join("src/server/webhooks")).unwrap();
+    std::fs::write(project.join("src/server/webhooks/send.ts"), USER_SEND).unwrap();
+
+    let output = add(project, &["webhooks"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(
+        stderr.contains("src/server/webhooks/send.ts already exists"),
+        "{}",
+        stderr
+    );
+    assert_eq!(read(project, "src/server/webhooks/send.ts"), USER_SEND);
+}
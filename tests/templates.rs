@@ -0,0 +1,97 @@
+//! Template combinations rendered with `t3_mono::testing`. Run with
+//! `cargo test --features testing`; `T3_MONO_UPDATE_SNAPSHOTS=1` rewrites `tests/snapshots/`.
+#![cfg(feature = "testing")]
+
+use t3_mono::testing;
+
+#[tokio::test]
+async fn default_project() {
+    let tree = testing::scaffold("").await.unwrap();
+
+    assert!(tree.text("package.json").contains("\"name\": \"app\""));
+    assert!(tree.contains(".gitignore"));
+    assert!(tree.contains("prisma/schema.prisma"));
+    assert!(tree.contains("src/app/api/auth/[...all]/route.ts"));
+    assert!(tree
+        .text("src/app/_components/Header.tsx")
+        .contains("<UserMenu />"));
+    assert!(tree
+        .text("src/server/api/root.ts")
+        .contains("user: userRouter"));
+    assert!(tree
+        .text("src/app/settings/page.tsx")
+        .contains("<PasswordForm />"));
+    assert!(!tree.paths().any(|path| path.starts_with("restate/")));
+    #[cfg(unix)]
+    assert!(tree.get("start-database.sh").unwrap().executable);
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn default_project_snapshot() {
+    let tree = testing::scaffold("name: snapshot").await.unwrap();
+    tree.assert_snapshot("tests/snapshots/default.txt");
+}
+
+#[tokio::test]
+async fn next_auth_without_git() {
+    let tree = testing::scaffold("auth: next-auth\ngit: false")
+        .await
+        .unwrap();
+
+    assert!(tree.contains("src/app/api/auth/[...nextauth]/route.ts"));
+    assert!(!tree.contains("src/app/api/auth/[...all]/route.ts"));
+    assert!(tree
+        .text("src/app/_components/UserMenu.tsx")
+        .contains("<SessionProvider>"));
+    assert!(!tree.contains("src/app/settings/_components/PasswordForm.tsx"));
+    assert!(!tree
+        .text("src/server/api/routers/user.ts")
+        .contains("changePassword"));
+    assert!(!tree.contains(".gitignore"));
+}
+
+#[tokio::test]
+async fn ui_with_analytics() {
+    let tree = testing::scaffold("extensions: [ui]\nanalytics: true")
+        .await
+        .unwrap();
+
+    assert!(tree.contains("src/app/dashboard/analytics/page.tsx"));
+    assert!(tree.text("src/server/api/root.ts").contains("analytics"));
+    let header = tree.text("src/app/_components/Header.tsx");
+    assert!(header.contains(r#"{ href: "/dashboard/members", labelKey: "members" }"#));
+    assert!(header.contains(r#"{ href: "/dashboard/analytics", labelKey: "analytics" }"#));
+    assert!(tree
+        .text("messages/de.json")
+        .contains(r#""analytics": "Analysen""#));
+}
+
+#[tokio::test]
+async fn restate_with_kafka() {
+    let tree = testing::scaffold("extensions: [restate]\nrestate-kafka: true")
+        .await
+        .unwrap();
+
+    assert!(tree.contains("restate/restate.toml"));
+    assert!(tree
+        .text("restate/services/src/index.ts")
+        .contains(".bind(OrderEvents)"));
+    assert!(tree.text("package.json").contains("restate:subscribe"));
+}
+
+#[tokio::test]
+async fn renders_are_independent() {
+    let first = testing::scaffold("extensions: [ai]").await.unwrap();
+    let second = testing::scaffold("").await.unwrap();
+
+    assert_ne!(first.root(), second.root());
+    assert!(first.under("src/components/ai/core/").next().is_some());
+    assert!(second.under("src/components/ai/core/").next().is_none());
+}
+
+#[tokio::test]
+async fn answers_are_checked() {
+    let error = testing::scaffold("rest: true").await.unwrap_err();
+    assert!(error.to_string().contains("rest requires example"));
+}
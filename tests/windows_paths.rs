@@ -0,0 +1,132 @@
+//! Scaffolding in long paths, through symlinks, and with `/`-separated manifest paths.
+//! Run with `cargo test --features windows-paths`; CI runs them on Windows, where paths
+//! past 260 characters and `\` separators break naive path handling.
+#![cfg(feature = "windows-paths")]
+
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+/// An empty scratch directory, removed when dropped
+struct Scratch(PathBuf);
+
+impl Scratch {
+    fn new(name: &str) -> Self {
+        let dir =
+            std::env::temp_dir().join(format!("t3-mono-test-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        Scratch(dir)
+    }
+}
+
+impl Drop for Scratch {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+fn t3_mono(dir: &Path, args: &[&str]) -> Output {
+    let output = Command::new(env!("CARGO_BIN_EXE_t3-mono"))
+        .args(args)
+        .current_dir(dir)
+        .env("DO_NOT_TRACK", "1")
+        .env("CI", "1")
+        .output()
+        .expect("failed to run t3-mono");
+    assert!(
+        output.status.success(),
+        "t3-mono {} failed:\n{}\n{}",
+        args.join(" "),
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    output
+}
+
+fn create(dir: &Path, name: &str) -> PathBuf {
+    t3_mono(dir, &[name, "--no-git", "--plain"]);
+    dir.join(name)
+}
+
+#[cfg(unix)]
+fn symlink_dir(target: &Path, link: &Path) -> bool {
+    std::os::unix::fs::symlink(target, link).is_ok()
+}
+
+/// Needs Developer Mode or admin rights on Windows; tests skip the symlink part without them
+#[cfg(windows)]
+fn symlink_dir(target: &Path, link: &Path) -> bool {
+    std::os::windows::fs::symlink_dir(target, link).is_ok()
+}
+
+#[test]
+fn creates_a_project_past_the_windows_path_limit() {
+    let scratch = Scratch::new("long-path");
+    let mut dir = scratch.0.clone();
+    while dir.as_os_str().len() < 280 {
+        dir = dir.join("a-deeply-nested-workspace-directory");
+    }
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let project = create(&dir, "app");
+    assert!(project.join("src/app/api/auth/[...all]/route.ts").is_file());
+    assert!(project.join("start-database.sh").is_file());
+
+    let status = t3_mono(&project, &["status"]);
+    assert!(String::from_utf8_lossy(&status.stdout)
+        .contains("Everything matches the current templates"));
+}
+
+#[test]
+fn manifest_paths_use_forward_slashes() {
+    let scratch = Scratch::new("manifest");
+    let project = create(&scratch.0, "app");
+
+    let manifest: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(project.join(".t3mono.json")).unwrap())
+            .unwrap();
+    let files = manifest["files"]
+        .as_object()
+        .expect("manifest has a files map");
+    assert!(files.contains_key("src/server/db.ts"));
+    for path in files.keys() {
+        assert!(!path.contains('\\'), "{} is not /-separated", path);
+    }
+}
+
+#[test]
+fn status_skips_symlinked_and_broken_links() {
+    let scratch = Scratch::new("status-links");
+    let project = create(&scratch.0, "app");
+
+    // A link back up the tree would loop if followed
+    if !symlink_dir(&project, &project.join("loop")) {
+        return;
+    }
+    symlink_dir(&project.join("missing"), &project.join("broken"));
+
+    let status = t3_mono(&project, &["status"]);
+    assert!(String::from_utf8_lossy(&status.stdout)
+        .contains("Everything matches the current templates"));
+}
+
+#[test]
+fn follows_a_symlinked_override_directory() {
+    let scratch = Scratch::new("override-link");
+    let shared = scratch.0.join("shared-brand");
+    std::fs::create_dir_all(&shared).unwrap();
+    std::fs::write(
+        shared.join("logo.svg"),
+        "<svg xmlns=\"http://www.w3.org/2000/svg\"/>\n",
+    )
+    .unwrap();
+
+    let workspace = scratch.0.join("workspace");
+    std::fs::create_dir_all(workspace.join("templates/public")).unwrap();
+    if !symlink_dir(&shared, &workspace.join("templates/public/brand")) {
+        return;
+    }
+
+    let project = create(&workspace, "app");
+    assert!(project.join("public/brand/logo.svg").is_file());
+}